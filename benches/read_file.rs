@@ -1,4 +1,5 @@
-use lofty::Probe;
+use lofty::mp3::Mp3File;
+use lofty::{AudioFile, Probe};
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -42,5 +43,22 @@ fn content_infer_read(c: &mut Criterion) {
 	);
 }
 
-criterion_group!(benches, content_infer_read);
+// Reading without properties should skip the frame scanning done by the accurate MP3 reader
+fn mp3_read_properties(c: &mut Criterion) {
+	const MP3: &[u8] = include_bytes!("../tests/files/assets/issue_39.mp3");
+
+	let mut g = c.benchmark_group("MP3 reading (Properties)");
+
+	g.bench_function("Accurate", |b| {
+		b.iter(|| Mp3File::read_from_accurate(&mut Cursor::new(MP3)).unwrap())
+	});
+	g.bench_function("Standard", |b| {
+		b.iter(|| Mp3File::read_from(&mut Cursor::new(MP3), true).unwrap())
+	});
+	g.bench_function("None", |b| {
+		b.iter(|| Mp3File::read_from(&mut Cursor::new(MP3), false).unwrap())
+	});
+}
+
+criterion_group!(benches, content_infer_read, mp3_read_properties);
 criterion_main!(benches);
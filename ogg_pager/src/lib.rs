@@ -302,7 +302,7 @@ pub fn paginate(packet: &[u8], stream_serial: u32, abgp: u64, flags: u8) -> Vec<
 			header_type: {
 				if first_page {
 					if flags & CONTAINS_FIRST_PAGE_OF_BITSTREAM == 0x02 {
-						CONTAINS_LAST_PAGE_OF_BITSTREAM
+						CONTAINS_FIRST_PAGE_OF_BITSTREAM
 					} else {
 						0
 					}
@@ -325,6 +325,20 @@ pub fn paginate(packet: &[u8], stream_serial: u32, abgp: u64, flags: u8) -> Vec<
 		pages.push(p);
 	}
 
+	// A packet filling its final page can only be terminated by an empty page
+	if packet.len() % MAX_CONTENT_SIZE == 0 {
+		pages.push(Page {
+			content: Vec::new(),
+			header_type: if first_page { 0 } else { CONTINUED_PACKET },
+			abgp,
+			serial: stream_serial,
+			seq_num: (pages.len() + 1) as u32,
+			checksum: 0,
+			start: pos,
+			end: pos,
+		});
+	}
+
 	if flags & CONTAINS_LAST_PAGE_OF_BITSTREAM == 0x04 {
 		if let Some(last) = pages.last_mut() {
 			last.header_type |= CONTAINS_LAST_PAGE_OF_BITSTREAM;
@@ -348,40 +362,28 @@ pub fn paginate(packet: &[u8], stream_serial: u32, abgp: u64, flags: u8) -> Vec<
 
 /// Creates a segment table based on the length
 ///
+/// A length of [`MAX_CONTENT_SIZE`] is assumed to be a packet that continues onto the next page.
+/// Otherwise, the table will finish the packet, ending in a segment < 255 (possibly 0).
+///
 /// # Errors
 ///
 /// `length` > [`MAX_CONTENT_SIZE`]
 pub fn segment_table(length: usize) -> Result<Vec<u8>> {
 	match length {
-		0 => return Ok(vec![1, 0]),
 		l if l > MAX_CONTENT_SIZE => return Err(PageError::TooMuchData),
+		MAX_CONTENT_SIZE => return Ok(vec![255; 255]),
 		_ => {},
 	};
 
-	let mut last_len = (length % 255) as u8;
-	if last_len == 0 {
-		last_len = 255;
-	}
-
-	let mut needed = (length / 255) + 1;
-	needed = std::cmp::min(needed, 255);
-
-	let mut segments = Vec::with_capacity(needed);
-
-	for i in 0..needed {
-		if i + 1 < needed {
-			segments.push(255)
-		} else {
-			segments.push(last_len)
-		}
-	}
+	let mut segments = vec![255; length / 255];
+	segments.push((length % 255) as u8);
 
 	Ok(segments)
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::{paginate, segment_table, Page};
+	use crate::{paginate, segment_table, Page, MAX_CONTENT_SIZE};
 	use std::io::Cursor;
 
 	#[test]
@@ -445,4 +447,32 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn segment_table_lacing() {
+		assert_eq!(segment_table(0).unwrap(), vec![0]);
+		assert_eq!(segment_table(254).unwrap(), vec![254]);
+		// A packet with a length that is a multiple of 255 is terminated with a 0 segment
+		assert_eq!(segment_table(255).unwrap(), vec![255, 0]);
+		assert_eq!(segment_table(510).unwrap(), vec![255, 255, 0]);
+		assert_eq!(segment_table(MAX_CONTENT_SIZE).unwrap(), vec![255; 255]);
+		assert!(segment_table(MAX_CONTENT_SIZE + 1).is_err());
+	}
+
+	#[test]
+	fn paginate_exact_page_size() {
+		let packet = vec![0; MAX_CONTENT_SIZE * 2];
+
+		let pages = paginate(&*packet, 1234, 0, 0);
+
+		// The packet has to be terminated by an empty page
+		assert_eq!(pages.len(), 3);
+		assert!(pages[2].content().is_empty());
+		assert_eq!(pages[2].header_type(), 1);
+		assert_eq!(pages[2].segment_table().unwrap(), vec![0]);
+
+		assert_eq!(pages[0].abgp, u64::MAX);
+		assert_eq!(pages[1].abgp, u64::MAX);
+		assert_eq!(pages[2].abgp, 0);
+	}
 }
@@ -0,0 +1,151 @@
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::mp4::AudioObjectType;
+
+use std::io::Read;
+
+// https://wiki.multimedia.cx/index.php?title=ADTS
+pub(crate) const ADTS_HEADER_SIZE: u16 = 7;
+
+// https://wiki.multimedia.cx/index.php?title=MPEG-4_Audio#Sampling_Frequencies
+const SAMPLE_RATES: [u32; 13] = [
+	96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+// Unlike MPEG audio, ADTS headers have 12 set bits, followed by the ID and a layer of 0
+//
+// An MPEG audio frame will never have a layer of 0, as it is reserved.
+pub(crate) fn verify_adts_sync(frame_sync: [u8; 2]) -> bool {
+	frame_sync[0] == 0xFF && frame_sync[1] & 0xF6 == 0xF0
+}
+
+// Searches for an ADTS frame sync in the reader, see `search_for_frame_sync`
+pub(crate) fn search_for_adts_sync<R>(input: &mut R) -> std::io::Result<Option<u64>>
+where
+	R: Read,
+{
+	let mut iterator = input.bytes();
+	let mut buffer = [0u8; 2];
+
+	if let Some(byte) = iterator.next() {
+		buffer[0] = byte?;
+	}
+
+	for (index, byte) in iterator.enumerate() {
+		buffer[1] = byte?;
+
+		if verify_adts_sync(buffer) {
+			return Ok(Some(index as u64));
+		}
+
+		buffer[0] = buffer[1];
+	}
+
+	Ok(None)
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct AdtsHeader {
+	pub(crate) audio_object_type: AudioObjectType,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+	pub(crate) copyright: bool,
+	pub(crate) original: bool,
+	pub(crate) len: u16,
+	pub(crate) samples: u32,
+}
+
+impl AdtsHeader {
+	pub(crate) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let mut header = [0; ADTS_HEADER_SIZE as usize];
+		reader.read_exact(&mut header)?;
+
+		if !verify_adts_sync([header[0], header[1]]) {
+			return Err(
+				FileDecodingError::new(FileType::Aac, "Found an invalid frame sync").into(),
+			);
+		}
+
+		// The profile is the audio object type - 1
+		let audio_object_type = AudioObjectType::try_from((header[2] >> 6) + 1)?;
+
+		let sample_rate_idx = (header[2] >> 2) & 0x0F;
+		let sample_rate = match SAMPLE_RATES.get(sample_rate_idx as usize) {
+			Some(sample_rate) => *sample_rate,
+			None => {
+				return Err(FileDecodingError::new(
+					FileType::Aac,
+					"Found an invalid sample rate index",
+				)
+				.into())
+			},
+		};
+
+		// A channel configuration of 0 means the channels are defined in the stream.
+		// A configuration of 7 is 7.1 audio.
+		let channels = match ((header[2] & 0x01) << 2) | (header[3] >> 6) {
+			7 => 8,
+			config => config,
+		};
+
+		let original = (header[3] >> 5) & 1 == 1;
+		let copyright = (header[3] >> 3) & 1 == 1;
+
+		let len = (u16::from(header[3] & 0x03) << 11)
+			| (u16::from(header[4]) << 3)
+			| u16::from(header[5] >> 5);
+
+		if len < ADTS_HEADER_SIZE {
+			return Err(
+				FileDecodingError::new(FileType::Aac, "Found an invalid frame length").into(),
+			);
+		}
+
+		// Each raw data block contains 1024 samples
+		let samples = (u32::from(header[6] & 0x03) + 1) * 1024;
+
+		Ok(Self {
+			audio_object_type,
+			sample_rate,
+			channels,
+			copyright,
+			original,
+			len,
+			samples,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{verify_adts_sync, AdtsHeader};
+	use crate::mp4::AudioObjectType;
+
+	#[test]
+	fn adts_sync() {
+		// MPEG-4 and MPEG-2 ADTS, with and without a CRC
+		assert!(verify_adts_sync([0xFF, 0xF1]));
+		assert!(verify_adts_sync([0xFF, 0xF9]));
+		assert!(verify_adts_sync([0xFF, 0xF0]));
+
+		// MPEG-1 Layer 3
+		assert!(!verify_adts_sync([0xFF, 0xFB]));
+		assert!(!verify_adts_sync([0xFF, 0xE1]));
+	}
+
+	#[test]
+	fn read_header() {
+		// AAC LC, 44100 Hz, 2 channels, 100 bytes, 1 raw data block
+		let header =
+			AdtsHeader::read(&mut &[0xFF, 0xF1, 0x50, 0x80, 0x0C, 0x9F, 0xFC][..]).unwrap();
+
+		assert_eq!(header.audio_object_type, AudioObjectType::AacLowComplexity);
+		assert_eq!(header.sample_rate, 44100);
+		assert_eq!(header.channels, 2);
+		assert_eq!(header.len, 100);
+		assert_eq!(header.samples, 1024);
+	}
+}
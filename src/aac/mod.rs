@@ -0,0 +1,116 @@
+//! AAC (ADTS) specific items
+//!
+//! ## File notes
+//!
+//! This only supports raw AAC streams using ADTS headers. AAC audio stored in an MP4 container
+//! is handled by the [`mp4`](crate::mp4) module.
+//!
+//! ADTS streams have no tag format of their own, although `ID3v2` and `ID3v1` tags are commonly found.
+pub(crate) mod header;
+mod properties;
+pub(crate) mod read;
+pub(crate) mod write;
+
+use crate::error::Result;
+use crate::file::{AudioFile, FileType, TaggedFile};
+#[cfg(feature = "id3v1")]
+use crate::id3::v1::tag::Id3v1Tag;
+#[cfg(feature = "id3v2")]
+use crate::id3::v2::tag::Id3v2Tag;
+use crate::probe::ParseContext;
+use crate::properties::FileProperties;
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+use std::io::{Read, Seek};
+
+pub use properties::AacProperties;
+
+/// An AAC (ADTS) file
+#[derive(Default)]
+pub struct AacFile {
+	#[cfg(feature = "id3v2")]
+	/// An ID3v2 tag
+	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	#[cfg(feature = "id3v1")]
+	/// An ID3v1 tag
+	pub(crate) id3v1_tag: Option<Id3v1Tag>,
+	/// The file's audio properties
+	pub(crate) properties: AacProperties,
+}
+
+impl From<AacFile> for TaggedFile {
+	#[allow(clippy::vec_init_then_push, unused_mut)]
+	fn from(input: AacFile) -> Self {
+		let mut tags = Vec::<Option<Tag>>::with_capacity(2);
+
+		#[cfg(feature = "id3v2")]
+		tags.push(input.id3v2_tag.map(Into::into));
+		#[cfg(feature = "id3v1")]
+		tags.push(input.id3v1_tag.map(Into::into));
+
+		Self {
+			ty: FileType::Aac,
+			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
+			tags: tags.into_iter().flatten().collect(),
+		}
+	}
+}
+
+impl AudioFile for AacFile {
+	type Properties = AacProperties;
+
+	fn read_from<R>(reader: &mut R, read_properties: bool) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
+	}
+
+	fn properties(&self) -> &Self::Properties {
+		&self.properties
+	}
+
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v2")]
+			id3v2_tag,
+
+			#[cfg(feature = "id3v1")]
+			id3v1_tag
+		}
+	}
+
+	#[allow(unreachable_code)]
+	fn contains_tag(&self) -> bool {
+		#[cfg(feature = "id3v2")]
+		return self.id3v2_tag.is_some();
+		#[cfg(feature = "id3v1")]
+		return self.id3v1_tag.is_some();
+
+		false
+	}
+
+	fn contains_tag_type(&self, tag_type: TagType) -> bool {
+		match tag_type {
+			#[cfg(feature = "id3v2")]
+			TagType::Id3v2 => self.id3v2_tag.is_some(),
+			#[cfg(feature = "id3v1")]
+			TagType::Id3v1 => self.id3v1_tag.is_some(),
+			_ => false,
+		}
+	}
+}
+
+impl AacFile {
+	crate::macros::tag_methods! {
+		#[cfg(feature = "id3v2")]
+		id3v2_tag, Id3v2Tag;
+
+		#[cfg(feature = "id3v1")]
+		id3v1_tag, Id3v1Tag
+	}
+}
@@ -0,0 +1,128 @@
+use super::header::AdtsHeader;
+use crate::error::Result;
+use crate::mp4::AudioObjectType;
+use crate::properties::FileProperties;
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
+/// An AAC (ADTS) file's audio properties
+pub struct AacProperties {
+	pub(crate) audio_object_type: AudioObjectType,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+	pub(crate) copyright: bool,
+	pub(crate) original: bool,
+}
+
+impl From<AacProperties> for FileProperties {
+	fn from(input: AacProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: None,
+			channels: Some(input.channels),
+		}
+	}
+}
+
+impl AacProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Channel count
+	///
+	/// NOTE: This will be 0 if the channel configuration is stored in the stream itself
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Audio object type
+	pub fn audio_object_type(&self) -> AudioObjectType {
+		self.audio_object_type
+	}
+
+	/// Whether the audio is copyrighted
+	pub fn is_copyright(&self) -> bool {
+		self.copyright
+	}
+
+	/// Whether the media is original or a copy
+	pub fn is_original(&self) -> bool {
+		self.original
+	}
+}
+
+pub(super) fn read_properties<R>(
+	reader: &mut R,
+	first_frame: (AdtsHeader, u64),
+	stream_end: u64,
+	file_length: u64,
+) -> Result<AacProperties>
+where
+	R: Read + Seek,
+{
+	let (first_frame_header, first_frame_offset) = first_frame;
+
+	let mut properties = AacProperties {
+		audio_object_type: first_frame_header.audio_object_type,
+		sample_rate: first_frame_header.sample_rate,
+		channels: first_frame_header.channels,
+		copyright: first_frame_header.copyright,
+		original: first_frame_header.original,
+		..AacProperties::default()
+	};
+
+	// ADTS streams have no header containing the total sample count,
+	// so every frame needs to be counted
+	let mut total_samples = u64::from(first_frame_header.samples);
+	let mut offset = first_frame_offset + u64::from(first_frame_header.len);
+
+	while offset < stream_end {
+		reader.seek(SeekFrom::Start(offset))?;
+
+		// Stop at the first invalid frame, which is likely to be junk at the end of the stream
+		match AdtsHeader::read(reader) {
+			Ok(header) => {
+				total_samples += u64::from(header.samples);
+				offset += u64::from(header.len);
+			},
+			Err(_) => break,
+		}
+	}
+
+	let stream_length = offset.min(stream_end) - first_frame_offset;
+	let length = (total_samples * 1000) / u64::from(properties.sample_rate);
+
+	if length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = ((file_length * 8) / length) as u32;
+		properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+	}
+
+	Ok(properties)
+}
@@ -0,0 +1,93 @@
+use super::header::{search_for_adts_sync, AdtsHeader};
+use super::{AacFile, AacProperties};
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+#[cfg(feature = "id3v2")]
+use crate::id3::v2::read::parse_id3v2;
+use crate::id3::v2::read_id3v2_header;
+use crate::id3::{find_id3v1, ID3FindResults};
+use crate::probe::ParseContext;
+
+use std::io::{Read, Seek, SeekFrom};
+
+pub(crate) fn read_from<R>(
+	reader: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<AacFile>
+where
+	R: Read + Seek,
+{
+	let mut file = AacFile::default();
+
+	let mut header = [0; 3];
+	reader.read_exact(&mut header)?;
+	reader.seek(SeekFrom::Current(-3))?;
+
+	if &header == b"ID3" {
+		let header = read_id3v2_header(reader)?;
+		let skip_footer = header.flags.footer;
+
+		#[cfg(feature = "id3v2")]
+		if read_tags {
+			let id3v2 = parse_id3v2(reader, header, context)?;
+			file.id3v2_tag = Some(id3v2);
+		} else {
+			reader.seek(SeekFrom::Current(i64::from(
+				header.size - header.extended_size,
+			)))?;
+		}
+
+		#[cfg(not(feature = "id3v2"))]
+		reader.seek(SeekFrom::Current(i64::from(
+			header.size - header.extended_size,
+		)))?;
+
+		// Skip over the footer
+		if skip_footer {
+			reader.seek(SeekFrom::Current(10))?;
+		}
+	}
+
+	// The tag may be followed by junk bytes before the first frame begins
+	//
+	// The first frame is only needed for the properties, so there's no need to search for it otherwise
+	let mut first_frame = None;
+
+	if read_properties {
+		let start_of_search_area = reader.stream_position()?;
+
+		if let Some(first_frame_start_relative) = search_for_adts_sync(reader)? {
+			let first_frame_offset = start_of_search_area + first_frame_start_relative;
+
+			reader.seek(SeekFrom::Start(first_frame_offset))?;
+			first_frame = Some((AdtsHeader::read(reader)?, first_frame_offset));
+		}
+	}
+
+	#[allow(unused_variables)]
+	let ID3FindResults(header, id3v1) = find_id3v1(reader, read_tags)?;
+
+	#[cfg(feature = "id3v1")]
+	if header.is_some() {
+		file.id3v1_tag = id3v1;
+	}
+
+	// `find_id3v1` leaves us at the start of the tag, or the end of the file if none was found
+	let stream_end = reader.stream_position()?;
+
+	if read_properties {
+		let first_frame = first_frame
+			.ok_or_else(|| FileDecodingError::new(FileType::Aac, "File contains no ADTS frames"))?;
+
+		let file_length = reader.seek(SeekFrom::End(0))?;
+
+		file.properties =
+			super::properties::read_properties(reader, first_frame, stream_end, file_length)?;
+	} else {
+		file.properties = AacProperties::default();
+	}
+
+	Ok(file)
+}
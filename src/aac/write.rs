@@ -0,0 +1,24 @@
+use crate::error::{ErrorKind, LoftyError, Result};
+#[cfg(feature = "id3v1")]
+use crate::id3::v1;
+#[cfg(feature = "id3v2")]
+use crate::id3::v2;
+#[allow(unused_imports)]
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+#[allow(unused_variables)]
+pub(crate) fn write_to<F: FileLike>(data: &mut F, tag: &Tag) -> Result<()> {
+	match tag.tag_type() {
+		#[cfg(feature = "id3v1")]
+		TagType::Id3v1 => Into::<v1::tag::Id3v1TagRef<'_>>::into(tag).write_to(data),
+		#[cfg(feature = "id3v2")]
+		TagType::Id3v2 => v2::tag::Id3v2TagRef {
+			flags: v2::Id3v2TagFlags::default(),
+			version: v2::Id3v2Version::V4,
+			frames: v2::tag::tag_frames(tag),
+		}
+		.write_to(data),
+		_ => Err(LoftyError::new(ErrorKind::UnsupportedTag)),
+	}
+}
@@ -11,6 +11,8 @@ pub(crate) struct ApeHeader {
 	pub(crate) size: u32,
 	#[cfg(feature = "ape")]
 	pub(crate) item_count: u32,
+	#[cfg(feature = "ape")]
+	pub(crate) version: u32,
 }
 
 pub(crate) fn read_ape_header<R>(data: &mut R, footer: bool) -> Result<ApeHeader>
@@ -54,5 +56,7 @@ where
 		size,
 		#[cfg(feature = "ape")]
 		item_count,
+		#[cfg(feature = "ape")]
+		version,
 	})
 }
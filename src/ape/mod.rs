@@ -8,7 +8,7 @@
 pub(crate) mod constants;
 pub(crate) mod header;
 mod properties;
-mod read;
+pub(crate) mod read;
 pub(crate) mod write;
 
 use crate::error::Result;
@@ -17,8 +17,10 @@ use crate::file::{AudioFile, FileType, TaggedFile};
 use crate::id3::v1::tag::Id3v1Tag;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::probe::ParseContext;
 use crate::properties::FileProperties;
 use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
 
 use std::io::{Read, Seek};
 
@@ -27,7 +29,7 @@ use std::io::{Read, Seek};
 cfg_if::cfg_if! {
 	if #[cfg(feature = "ape")] {
 		pub(crate) mod tag;
-		pub use tag::ApeTag;
+		pub use tag::{ApeTag, ApeTagLocation, ApeTagVersion};
 		pub use tag::item::ApeItem;
 
 		pub use crate::picture::APE_PICTURE_TYPES;
@@ -66,6 +68,8 @@ impl From<ApeFile> for TaggedFile {
 		Self {
 			ty: FileType::APE,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			tags: tags.into_iter().flatten().collect(),
 		}
 	}
@@ -79,13 +83,24 @@ impl AudioFile for ApeFile {
 		R: Read + Seek,
 		Self: Sized,
 	{
-		read::read_from(reader, read_properties)
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
 	}
 
 	fn properties(&self) -> &Self::Properties {
 		&self.properties
 	}
 
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v1")]
+			id3v1_tag,
+
+			#[cfg(feature = "ape")]
+			ape_tag
+		}
+	}
+
 	#[allow(unreachable_code)]
 	fn contains_tag(&self) -> bool {
 		#[cfg(feature = "ape")]
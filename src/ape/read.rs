@@ -10,10 +10,16 @@ use crate::id3::v1::tag::Id3v1Tag;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::{read::parse_id3v2, tag::Id3v2Tag};
 use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2, ID3FindResults};
+use crate::probe::ParseContext;
 
 use std::io::{Read, Seek, SeekFrom};
 
-pub(crate) fn read_from<R>(data: &mut R, read_properties: bool) -> Result<ApeFile>
+pub(crate) fn read_from<R>(
+	data: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<ApeFile>
 where
 	R: Read + Seek,
 {
@@ -33,7 +39,7 @@ where
 
 	// ID3v2 tags are unsupported in APE files, but still possible
 	#[allow(unused_variables)]
-	if let ID3FindResults(Some(header), Some(content)) = find_id3v2(data, true)? {
+	if let ID3FindResults(Some(header), content) = find_id3v2(data, read_tags)? {
 		stream_len -= u64::from(header.size);
 
 		// Exclude the footer
@@ -42,10 +48,10 @@ where
 		}
 
 		#[cfg(feature = "id3v2")]
-		{
+		if let Some(content) = content {
 			let reader = &mut &*content;
 
-			let id3v2 = parse_id3v2(reader, header)?;
+			let id3v2 = parse_id3v2(reader, header, context)?;
 			id3v2_tag = Some(id3v2)
 		}
 	}
@@ -84,14 +90,19 @@ where
 				let ape_header = read_ape_header(data, false)?;
 				stream_len -= u64::from(ape_header.size);
 
+				// The header has already been read
 				#[cfg(feature = "ape")]
-				{
-					let ape = read_ape_tag(data, ape_header)?;
+				if read_tags {
+					let ape = read_ape_tag(data, ape_header, context)?;
 					ape_tag = Some(ape)
+				} else {
+					data.seek(SeekFrom::Current(i64::from(ape_header.size - 32)))?;
 				}
 
 				#[cfg(not(feature = "ape"))]
-				data.seek(SeekFrom::Current(ape_header.size as i64))?;
+				data.seek(SeekFrom::Current(i64::from(ape_header.size - 32)))?;
+
+				data.read_exact(&mut header)?;
 			},
 			_ => {
 				return Err(FileDecodingError::new(
@@ -109,7 +120,7 @@ where
 	// Starts with ['T', 'A', 'G']
 	// Exactly 128 bytes long (including the identifier)
 	#[allow(unused_variables)]
-	let ID3FindResults(id3v1_header, id3v1) = find_id3v1(data, true)?;
+	let ID3FindResults(id3v1_header, id3v1) = find_id3v1(data, read_tags)?;
 
 	if id3v1_header.is_some() {
 		stream_len -= 128;
@@ -141,8 +152,8 @@ where
 		stream_len -= u64::from(ape_header.size);
 
 		#[cfg(feature = "ape")]
-		{
-			let ape = read_ape_tag(data, ape_header)?;
+		if read_tags {
+			let ape = read_ape_tag(data, ape_header, context)?;
 			ape_tag = Some(ape)
 		}
 
@@ -13,6 +13,11 @@ use std::convert::TryFrom;
 /// so these are still able to use [`ItemValue`]s
 pub struct ApeItem {
 	/// Whether or not to mark the item as read only
+	///
+	/// This is preserved when reading and writing. When writing, an item with the same key and
+	/// value as a read only item already in the file will stay read only, even if this is `false`.
+	///
+	/// See [`ApeTag::try_insert`](crate::ape::ApeTag::try_insert) to avoid replacing read only items.
 	pub read_only: bool,
 	pub(crate) key: String,
 	pub(crate) value: ItemValue,
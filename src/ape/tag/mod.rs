@@ -3,13 +3,15 @@ pub(crate) mod read;
 mod write;
 
 use crate::ape::tag::item::{ApeItem, ApeItemRef};
-use crate::error::{LoftyError, Result};
-use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::error::{ErrorKind, FileEncodingError, LoftyError, Result};
+use crate::file::FileType;
+use crate::picture::{Picture, PictureType};
+use crate::tag::item::{ItemKey, ItemValue, ItemValueRef, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 
+use std::borrow::Cow;
 use std::convert::TryInto;
-use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
@@ -49,6 +51,40 @@ macro_rules! impl_accessor {
 	}
 }
 
+/// The version of an `APE` tag
+///
+/// This is used to select the version to write with [`ApeTag::version`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ApeTagVersion {
+	/// APEv1
+	///
+	/// NOTE: This version has no header and no item flags, meaning only Latin-1 text
+	/// items can be written, and read only flags are discarded.
+	V1,
+	/// APEv2
+	V2,
+}
+
+impl Default for ApeTagVersion {
+	fn default() -> Self {
+		Self::V2
+	}
+}
+
+/// The location of an `APE` tag in a file
+///
+/// This is used to override the location to write to with [`ApeTag::location`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ApeTagLocation {
+	/// At the start of the file, after any ID3v2 tag
+	///
+	/// NOTE: This goes against the spec, and is only supported for [`FileType::APE`] and [`FileType::MP3`]. Other
+	/// formats will always have the tag written at the end.
+	Start,
+	/// At the end of the file, before any ID3v1 tag
+	End,
+}
+
 #[derive(Default, Debug, PartialEq, Clone)]
 /// An `APE` tag
 ///
@@ -68,6 +104,10 @@ macro_rules! impl_accessor {
 ///
 /// ## Conversions
 ///
+/// ### To `Tag`
+///
+/// Binary items with a picture key will be converted to [`Picture`]s.
+///
 /// ### From `Tag`
 ///
 /// When converting pictures, any of type [`PictureType::Undefined`](crate::PictureType::Undefined) will be discarded.
@@ -75,6 +115,16 @@ macro_rules! impl_accessor {
 pub struct ApeTag {
 	/// Whether or not to mark the tag as read only
 	pub read_only: bool,
+	/// The version to write the tag as
+	///
+	/// NOTE: This defaults to [`ApeTagVersion::V2`]. When reading, this is set to the version of the tag
+	/// in the file, so an APEv1 tag will need to be changed to [`ApeTagVersion::V2`] to store anything APEv1 can't.
+	pub version: ApeTagVersion,
+	/// Where to write the tag
+	///
+	/// By default, this is `None`, meaning the tag will be written wherever the file's existing
+	/// tag was found. If the file has no tag, it will be written at the end.
+	pub location: Option<ApeTagLocation>,
 	pub(super) items: Vec<ApeItem>,
 }
 
@@ -98,12 +148,31 @@ impl ApeTag {
 
 	/// Insert an [`ApeItem`]
 	///
-	/// This will remove any item with the same key prior to insertion
+	/// This will remove any item with the same key prior to insertion, **including** read only items.
+	/// See [`ApeTag::try_insert`] to respect them.
 	pub fn insert(&mut self, value: ApeItem) {
 		self.remove_key(value.key());
 		self.items.push(value);
 	}
 
+	/// Insert an [`ApeItem`], unless it would replace a read only item
+	///
+	/// # Errors
+	///
+	/// An item with the same key exists, and is marked read only
+	pub fn try_insert(&mut self, value: ApeItem) -> Result<()> {
+		if matches!(self.get_key(value.key()), Some(item) if item.read_only) {
+			return Err(FileEncodingError::new(
+				FileType::APE,
+				"Attempted to replace a read only item",
+			)
+			.into());
+		}
+
+		self.insert(value);
+		Ok(())
+	}
+
 	/// Remove an [`ApeItem`] by key
 	///
 	/// NOTE: Like [`ApeTag::get_key`], this is not case-sensitive
@@ -134,7 +203,7 @@ impl TagExt for ApeTag {
 	/// * `path` does not exist
 	/// * See [`ApeTag::save_to`]
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
 	/// Write an `APE` tag to a file
@@ -143,9 +212,11 @@ impl TagExt for ApeTag {
 	///
 	/// * Attempting to write the tag to a format that does not support it
 	/// * An existing tag has an invalid size
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		ApeTagRef {
 			read_only: self.read_only,
+			version: self.version,
+			location: self.location,
 			items: self.items.iter().map(Into::into),
 		}
 		.write_to(file)
@@ -159,6 +230,8 @@ impl TagExt for ApeTag {
 	fn dump_to<W: Write>(&self, writer: &mut W) -> std::result::Result<(), Self::Err> {
 		ApeTagRef {
 			read_only: self.read_only,
+			version: self.version,
+			location: self.location,
 			items: self.items.iter().map(Into::into),
 		}
 		.dump_to(writer)
@@ -168,13 +241,52 @@ impl TagExt for ApeTag {
 		TagType::Ape.remove_from_path(path)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		TagType::Ape.remove_from(file)
 	}
 
 	fn clear(&mut self) {
 		self.items.clear();
 	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		self.items
+			.iter()
+			.filter_map(|i| match i.value() {
+				ItemValue::Binary(bytes)
+					if PictureType::from_ape_key(i.key()) != PictureType::Undefined(0) =>
+				{
+					Picture::from_ape_bytes(i.key(), bytes).ok()
+				},
+				_ => None,
+			})
+			.collect()
+	}
+
+	/// Inserts a [`Picture`]
+	///
+	/// # Errors
+	///
+	/// * The picture is of type [`PictureType::Undefined`], which APE has no key for
+	fn replace_picture(&mut self, picture: Picture) -> std::result::Result<(), Self::Err> {
+		let key = picture
+			.pic_type
+			.as_ape_key()
+			.ok_or_else(|| LoftyError::new(ErrorKind::UnsupportedPicture))?;
+
+		self.insert(ApeItem::new(
+			key.to_string(),
+			ItemValue::Binary(picture.as_ape_bytes()),
+		)?);
+
+		Ok(())
+	}
+
+	fn remove_picture_type(&mut self, picture_type: PictureType) {
+		if let Some(key) = picture_type.as_ape_key() {
+			self.remove_key(key)
+		}
+	}
 }
 
 impl From<ApeTag> for Tag {
@@ -217,6 +329,17 @@ impl From<ApeTag> for Tag {
 				{
 					continue
 				},
+				// Pictures are only kept as items if they fail to parse
+				(k, ItemValue::Binary(bytes))
+					if PictureType::from_ape_key(item.key()) != PictureType::Undefined(0) =>
+				{
+					match Picture::from_ape_bytes(item.key(), bytes) {
+						Ok(picture) => tag.pictures.push(picture),
+						Err(_) => tag
+							.items
+							.push(TagItem::new(k, ItemValue::Binary(bytes.clone()))),
+					}
+				},
 				(k, _) => tag.items.push(TagItem::new(k, item.value)),
 			}
 		}
@@ -254,6 +377,8 @@ where
 	I: Iterator<Item = ApeItemRef<'a>>,
 {
 	pub(crate) read_only: bool,
+	pub(crate) version: ApeTagVersion,
+	pub(crate) location: Option<ApeTagLocation>,
 	pub(crate) items: I,
 }
 
@@ -261,7 +386,7 @@ impl<'a, I> ApeTagRef<'a, I>
 where
 	I: Iterator<Item = ApeItemRef<'a>>,
 {
-	pub(crate) fn write_to(&mut self, file: &mut File) -> Result<()> {
+	pub(crate) fn write_to<F: FileLike>(&mut self, file: &mut F) -> Result<()> {
 		write::write_to(file, self)
 	}
 
@@ -273,20 +398,31 @@ where
 	}
 }
 
-pub(crate) fn tagitems_into_ape(items: &[TagItem]) -> impl Iterator<Item = ApeItemRef<'_>> {
-	items.iter().filter_map(|i| {
+pub(crate) fn tagitems_into_ape(tag: &Tag) -> impl Iterator<Item = ApeItemRef<'_>> {
+	let items = tag.items().iter().filter_map(|i| {
 		i.key().map_key(TagType::Ape, true).map(|key| ApeItemRef {
 			read_only: false,
 			key,
 			value: (&i.item_value).into(),
 		})
-	})
+	});
+
+	let pictures = tag.pictures().iter().filter_map(|pic| {
+		pic.pic_type.as_ape_key().map(|key| ApeItemRef {
+			read_only: false,
+			key,
+			value: ItemValueRef::Binary(Cow::Owned(pic.as_ape_bytes())),
+		})
+	});
+
+	items.chain(pictures)
 }
 
 #[cfg(test)]
 mod tests {
 	use crate::ape::header::read_ape_header;
-	use crate::ape::{ApeItem, ApeTag};
+	use crate::ape::{ApeItem, ApeTag, ApeTagVersion};
+	use crate::probe::ParseContext;
 	use crate::{ItemValue, Tag, TagExt, TagType};
 
 	use std::io::Cursor;
@@ -343,8 +479,11 @@ mod tests {
 		let mut reader = Cursor::new(tag);
 
 		let header = read_ape_header(&mut reader, false).unwrap();
-		let parsed_tag = crate::ape::tag::read::read_ape_tag(&mut reader, header).unwrap();
+		let parsed_tag =
+			crate::ape::tag::read::read_ape_tag(&mut reader, header, &mut ParseContext::default())
+				.unwrap();
 
+		assert_eq!(parsed_tag.version, ApeTagVersion::V2);
 		assert_eq!(expected_tag.items().len(), parsed_tag.items().len());
 
 		for item in expected_tag.items() {
@@ -358,7 +497,9 @@ mod tests {
 		let mut reader = Cursor::new(tag_bytes);
 
 		let header = read_ape_header(&mut reader, false).unwrap();
-		let parsed_tag = crate::ape::tag::read::read_ape_tag(&mut reader, header).unwrap();
+		let parsed_tag =
+			crate::ape::tag::read::read_ape_tag(&mut reader, header, &mut ParseContext::default())
+				.unwrap();
 
 		let mut writer = Vec::new();
 		parsed_tag.dump_to(&mut writer).unwrap();
@@ -367,8 +508,12 @@ mod tests {
 		let mut temp_reader = Cursor::new(&writer[8..]);
 
 		let temp_header = read_ape_header(&mut temp_reader, false).unwrap();
-		let temp_parsed_tag =
-			crate::ape::tag::read::read_ape_tag(&mut temp_reader, temp_header).unwrap();
+		let temp_parsed_tag = crate::ape::tag::read::read_ape_tag(
+			&mut temp_reader,
+			temp_header,
+			&mut ParseContext::default(),
+		)
+		.unwrap();
 
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
@@ -379,7 +524,9 @@ mod tests {
 		let mut reader = Cursor::new(tag_bytes);
 
 		let header = read_ape_header(&mut reader, false).unwrap();
-		let ape = crate::ape::tag::read::read_ape_tag(&mut reader, header).unwrap();
+		let ape =
+			crate::ape::tag::read::read_ape_tag(&mut reader, header, &mut ParseContext::default())
+				.unwrap();
 
 		let tag: Tag = ape.into();
 
@@ -406,4 +553,62 @@ mod tests {
 		verify_key(&ape_tag, "Track", "1");
 		verify_key(&ape_tag, "Genre", "Classical");
 	}
+
+	#[test]
+	fn write_apev1() {
+		let mut tag = ApeTag {
+			version: ApeTagVersion::V1,
+			..ApeTag::default()
+		};
+
+		tag.insert(
+			ApeItem::new(
+				String::from("Title"),
+				ItemValue::Text(String::from("Foo títle")),
+			)
+			.unwrap(),
+		);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		// No header, the tag starts with the first item
+		assert_eq!(&writer[..4], &9_u32.to_le_bytes());
+
+		// The value is written as Latin-1
+		assert_eq!(&writer[14..23], b"Foo t\xEDtle");
+
+		let footer = &writer[writer.len() - 32..];
+		assert_eq!(&footer[..8], b"APETAGEX");
+		assert_eq!(&footer[8..12], &1000_u32.to_le_bytes());
+		// No flags
+		assert_eq!(&footer[20..24], &[0; 4]);
+
+		// Skip the APE preamble
+		let mut reader = Cursor::new(&writer);
+		reader.set_position(writer.len() as u64 - 24);
+
+		let header = read_ape_header(&mut reader, true).unwrap();
+		assert_eq!(header.size as usize, writer.len());
+
+		let parsed_tag =
+			crate::ape::tag::read::read_ape_tag(&mut reader, header, &mut ParseContext::default())
+				.unwrap();
+		assert_eq!(parsed_tag.version, ApeTagVersion::V1);
+		assert_eq!(parsed_tag.items(), tag.items());
+
+		// Anything that can't be represented in APEv1 is an error
+		tag.insert(
+			ApeItem::new(
+				String::from("Artist"),
+				ItemValue::Text(String::from("Bar ärtist 🎵")),
+			)
+			.unwrap(),
+		);
+		assert!(tag.dump_to(&mut Vec::new()).is_err());
+
+		tag.remove_key("Artist");
+		tag.insert(ApeItem::new(String::from("Foo"), ItemValue::Binary(vec![1, 2, 3])).unwrap());
+		assert!(tag.dump_to(&mut Vec::new()).is_err());
+	}
 }
@@ -1,21 +1,33 @@
 use super::item::ApeItem;
-use super::ApeTag;
+use super::{ApeTag, ApeTagVersion};
 use crate::ape::constants::INVALID_KEYS;
 use crate::ape::header::ApeHeader;
-use crate::error::{FileDecodingError, Result};
+use crate::error::{FileDecodingError, Result, WarningCategory};
 use crate::file::FileType;
 use crate::macros::try_vec;
+use crate::probe::ParseContext;
 use crate::tag::item::ItemValue;
 
 use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 
-pub(crate) fn read_ape_tag<R>(data: &mut R, header: ApeHeader) -> Result<ApeTag>
+pub(crate) fn read_ape_tag<R>(
+	data: &mut R,
+	header: ApeHeader,
+	context: &mut ParseContext,
+) -> Result<ApeTag>
 where
 	R: Read + Seek,
 {
-	let mut tag = ApeTag::default();
+	let mut tag = ApeTag {
+		version: if header.version == 1000 {
+			ApeTagVersion::V1
+		} else {
+			ApeTagVersion::V2
+		},
+		..ApeTag::default()
+	};
 
 	for _ in 0..header.item_count {
 		let value_size = data.read_u32::<LittleEndian>()?;
@@ -57,6 +69,17 @@ where
 		data.read_exact(&mut value)?;
 
 		let parsed_value = match item_type {
+			// APEv1 text is not required to be UTF-8, files written as Latin-1 are common
+			0 if header.version == 1000 => match String::from_utf8(value) {
+				Ok(text) => ItemValue::Text(text),
+				Err(e) => {
+					context.warn(
+						WarningCategory::Encoding,
+						"Found an APEv1 text item that isn't UTF-8, decoding it as Latin-1",
+					);
+					ItemValue::Text(e.into_bytes().into_iter().map(char::from).collect())
+				},
+			},
 			0 => ItemValue::Text(String::from_utf8(value).map_err(|_| {
 				FileDecodingError::new(
 					FileType::APE,
@@ -1,44 +1,50 @@
 use super::item::ApeItemRef;
 use super::read::read_ape_tag;
-use super::ApeTagRef;
+use super::{ApeTagLocation, ApeTagRef, ApeTagVersion};
 use crate::ape::constants::APE_PREAMBLE;
 use crate::ape::header::read_ape_header;
-use crate::error::{ErrorKind, FileDecodingError, LoftyError, Result};
+use crate::error::{ErrorKind, FileDecodingError, FileEncodingError, LoftyError, Result};
 use crate::file::FileType;
 use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2};
-use crate::probe::Probe;
+use crate::probe::{ParseContext, Probe};
 use crate::tag::item::ItemValueRef;
+use crate::traits::FileLike;
 
-use std::fs::File;
-use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::borrow::Cow;
+use std::io::{Cursor, Seek, SeekFrom, Write};
 
 use byteorder::{LittleEndian, WriteBytesExt};
 
 #[allow(clippy::shadow_unrelated)]
-pub(crate) fn write_to<'a, I>(data: &mut File, tag: &mut ApeTagRef<'a, I>) -> Result<()>
+pub(crate) fn write_to<'a, I, F: FileLike>(data: &mut F, tag: &mut ApeTagRef<'a, I>) -> Result<()>
 where
 	I: Iterator<Item = ApeItemRef<'a>>,
 {
 	let probe = Probe::new(data).guess_file_type()?;
 
-	match probe.file_type() {
-		Some(FileType::APE | FileType::MP3) => {},
+	// Only these formats will look for a tag at the start of the file (after ID3v2)
+	let supports_header_tag = match probe.file_type() {
+		Some(FileType::APE | FileType::MP3) => true,
+		Some(FileType::Mpc | FileType::WavPack) => false,
 		_ => return Err(LoftyError::new(ErrorKind::UnsupportedTag)),
-	}
+	};
 
 	let data = probe.into_inner();
 
 	// We don't actually need the ID3v2 tag, but reading it will seek to the end of it if it exists
 	find_id3v2(data, false)?;
 
+	// In case the tag is being moved to the start, this is the spot it belongs
+	let start_position = data.stream_position()?;
+
 	let mut ape_preamble = [0; 8];
 	data.read_exact(&mut ape_preamble)?;
 
 	// We have to check the APE tag for any read only items first
-	let mut read_only = None;
+	let mut read_only = Vec::new();
 
-	// An APE tag in the beginning of a file is against the spec
-	// If one is found, it'll be removed and rewritten at the bottom, where it should be
+	// An APE tag in the beginning of a file is against the spec, but some players expect it there
+	// If one is found, it'll be rewritten in the same place, unless the location is overridden
 	let mut header_ape_tag = (false, (0, 0));
 
 	if &ape_preamble == APE_PREAMBLE {
@@ -49,14 +55,10 @@ where
 		let header = read_ape_header(data, false)?;
 		let size = header.size;
 
-		let mut existing = read_ape_tag(data, header)?;
+		let existing = read_ape_tag(data, header, &mut ParseContext::default())?;
 
 		// Only keep metadata around that's marked read only
-		existing.items.retain(|i| i.read_only);
-
-		if !existing.items.is_empty() {
-			read_only = Some(existing)
-		}
+		read_only.extend(existing.items.into_iter().filter(|i| i.read_only));
 
 		header_ape_tag = (true, (start, start + u64::from(size)))
 	} else {
@@ -84,13 +86,9 @@ where
 		let header = read_ape_header(data, true)?;
 		let size = header.size;
 
-		let mut existing = read_ape_tag(data, header)?;
-
-		existing.items.retain(|i| i.read_only);
+		let existing = read_ape_tag(data, header, &mut ParseContext::default())?;
 
-		if !existing.items.is_empty() {
-			read_only = Some(existing)
-		}
+		read_only.extend(existing.items.into_iter().filter(|i| i.read_only));
 
 		// Since the "start" was really at the end of the tag, this sanity check seems necessary
 		if let Some(start) = start.checked_sub(size as usize) {
@@ -104,35 +102,78 @@ where
 		}
 	}
 
-	// Preserve any metadata marked as read only
-	let tag = if let Some(read_only) = read_only {
-		create_ape_tag(&mut ApeTagRef {
-			read_only: read_only.read_only,
-			items: read_only.items.iter().map(Into::into),
-		})?
+	// If a file somehow has a tag in both locations, the one at the end takes priority
+	let existing_location = if header_ape_tag.0 && ape_tag_location.is_none() {
+		ApeTagLocation::Start
 	} else {
-		create_ape_tag(tag)?
+		ApeTagLocation::End
 	};
 
+	let mut location = tag.location.unwrap_or(existing_location);
+	if !supports_header_tag {
+		location = ApeTagLocation::End;
+	}
+
+	if location == ApeTagLocation::Start && tag.version == ApeTagVersion::V1 {
+		return Err(FileEncodingError::new(
+			FileType::APE,
+			"APEv1 tags have no header, and can only be written at the end of the file",
+		)
+		.into());
+	}
+
+	// Items that are unchanged from a read only item in the file stay read only, since
+	// their flags can be lost in conversions (ex. to `Tag`)
+	let tag = create_ape_tag(&mut ApeTagRef {
+		read_only: tag.read_only,
+		version: tag.version,
+		location: tag.location,
+		items: (&mut tag.items).map(|mut item| {
+			if !item.read_only {
+				item.read_only = read_only
+					.iter()
+					.any(|ro| ro.key().eq_ignore_ascii_case(item.key) && item.value == *ro.value());
+			}
+
+			item
+		}),
+	})?;
+
 	data.rewind()?;
 
 	let mut file_bytes = Vec::new();
 	data.read_to_end(&mut file_bytes)?;
 
-	// Write the tag in the appropriate place
-	if let Some(range) = ape_tag_location {
-		file_bytes.splice(range, tag);
-	} else {
-		file_bytes.splice(ape_position as usize..ape_position as usize, tag);
-	}
-
-	// Now, if there was a tag at the beginning, remove it
-	if header_ape_tag.0 {
-		file_bytes.drain(header_ape_tag.1 .0 as usize..header_ape_tag.1 .1 as usize);
+	// Write the tag in the appropriate place, removing the tag in the other location
+	// The tag at the end has to be handled first, so the range of the tag at the start stays valid
+	let header_range = header_ape_tag.1 .0 as usize..header_ape_tag.1 .1 as usize;
+	match location {
+		ApeTagLocation::Start => {
+			if let Some(range) = ape_tag_location {
+				file_bytes.drain(range);
+			}
+
+			if header_ape_tag.0 {
+				file_bytes.splice(header_range, tag);
+			} else {
+				file_bytes.splice(start_position as usize..start_position as usize, tag);
+			}
+		},
+		ApeTagLocation::End => {
+			if let Some(range) = ape_tag_location {
+				file_bytes.splice(range, tag);
+			} else {
+				file_bytes.splice(ape_position as usize..ape_position as usize, tag);
+			}
+
+			if header_ape_tag.0 {
+				file_bytes.drain(header_range);
+			}
+		},
 	}
 
 	data.rewind()?;
-	data.set_len(0)?;
+	data.truncate(0)?;
 	data.write_all(&*file_bytes)?;
 
 	Ok(())
@@ -150,37 +191,38 @@ where
 		return Ok(Vec::<u8>::new());
 	}
 
+	let is_v1 = tag.version == ApeTagVersion::V1;
+
 	let mut tag_write = Cursor::new(Vec::<u8>::new());
 
 	let mut item_count = 0_u32;
 
 	for item in peek {
 		let (mut flags, value) = match item.value {
-			ItemValueRef::Binary(value) => {
-				tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
-
-				(1_u32 << 1, value)
-			},
-			ItemValueRef::Text(value) => {
-				tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
-
-				(0_u32, value.as_bytes())
-			},
-			ItemValueRef::Locator(value) => {
-				tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
-
-				(2_u32 << 1, value.as_bytes())
+			// APEv1 has no item types, everything is Latin-1 text
+			ItemValueRef::Text(value) if is_v1 => (0_u32, Cow::Owned(latin1_text(value)?)),
+			ItemValueRef::Binary(_) | ItemValueRef::Locator(_) if is_v1 => {
+				return Err(FileEncodingError::new(
+					FileType::APE,
+					"APEv1 tags can only contain text items",
+				)
+				.into());
 			},
+			ItemValueRef::Binary(ref value) => (1_u32 << 1, Cow::Borrowed(&**value)),
+			ItemValueRef::Text(value) => (0_u32, Cow::Borrowed(value.as_bytes())),
+			ItemValueRef::Locator(value) => (2_u32 << 1, Cow::Borrowed(value.as_bytes())),
 		};
 
-		if item.read_only {
+		// APEv1 has no flags, so items can't be marked read only
+		if item.read_only && !is_v1 {
 			flags |= 1_u32
 		}
 
+		tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
 		tag_write.write_u32::<LittleEndian>(flags)?;
 		tag_write.write_all(item.key.as_bytes())?;
 		tag_write.write_u8(0)?;
-		tag_write.write_all(value)?;
+		tag_write.write_all(&value)?;
 
 		item_count += 1;
 	}
@@ -196,11 +238,22 @@ where
 
 	footer.write_all(APE_PREAMBLE)?;
 	// This is the APE tag version
-	// Even if we read a v1 tag, we end up adding a header anyway
-	footer.write_u32::<LittleEndian>(2000)?;
+	// Even if we read a v1 tag, we end up writing the version of `tag`
+	footer.write_u32::<LittleEndian>(if is_v1 { 1000 } else { 2000 })?;
 	// The total size includes the 32 bytes of the footer
 	footer.write_u32::<LittleEndian>((size + 32) as u32)?;
 	footer.write_u32::<LittleEndian>(item_count)?;
+
+	// APEv1 has no flags or header, the footer is all we need
+	if is_v1 {
+		footer.write_u32::<LittleEndian>(0)?;
+		footer.write_u64::<LittleEndian>(0)?;
+
+		tag_write.write_all(footer.get_ref())?;
+
+		return Ok(tag_write.into_inner());
+	}
+
 	// Bit 29 unset: this is the footer
 	// Bit 30 set: tag contains a footer
 	// Bit 31 set: tag contains a header
@@ -240,3 +293,16 @@ where
 
 	Ok(tag_write)
 }
+
+fn latin1_text(text: &str) -> Result<Vec<u8>> {
+	text.chars()
+		.map(|c| u8::try_from(u32::from(c)).ok())
+		.collect::<Option<Vec<u8>>>()
+		.ok_or_else(|| {
+			FileEncodingError::new(
+				FileType::APE,
+				"APEv1 text items must only contain Latin-1 characters",
+			)
+			.into()
+		})
+}
@@ -7,16 +7,17 @@ use crate::id3::v1;
 use crate::id3::v2;
 #[allow(unused_imports)]
 use crate::tag::{Tag, TagType};
-
-use std::fs::File;
+use crate::traits::FileLike;
 
 #[allow(unused_variables)]
-pub(crate) fn write_to(data: &mut File, tag: &Tag) -> Result<()> {
+pub(crate) fn write_to<F: FileLike>(data: &mut F, tag: &Tag) -> Result<()> {
 	match tag.tag_type() {
 		#[cfg(feature = "ape")]
 		TagType::Ape => ape::tag::ApeTagRef {
 			read_only: false,
-			items: ape::tag::tagitems_into_ape(tag.items()),
+			version: ape::ApeTagVersion::V2,
+			location: None,
+			items: ape::tag::tagitems_into_ape(tag),
 		}
 		.write_to(data),
 		// This tag can *only* be removed in this format
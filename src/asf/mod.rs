@@ -0,0 +1,97 @@
+//! ASF (WMA) specific items
+//!
+//! ## File notes
+//!
+//! ASF files are currently **read only**.
+//!
+//! Attributes are read from the Content Description Object and the Extended Content Description Object,
+//! and are stored in a [`Tag`] of type [`TagType::AsfAttributes`].
+pub(crate) mod constants;
+mod properties;
+pub(crate) mod read;
+#[cfg(feature = "asf_attributes")]
+mod tag;
+
+use crate::error::Result;
+use crate::file::{AudioFile, FileType, TaggedFile};
+use crate::properties::FileProperties;
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+use std::io::{Read, Seek};
+
+pub use properties::AsfProperties;
+
+/// An ASF file
+#[derive(Default)]
+pub struct AsfFile {
+	#[cfg(feature = "asf_attributes")]
+	/// The file's attributes
+	pub(crate) attributes: Option<Tag>,
+	/// The file's audio properties
+	pub(crate) properties: AsfProperties,
+}
+
+impl From<AsfFile> for TaggedFile {
+	#[allow(clippy::vec_init_then_push, unused_mut)]
+	fn from(input: AsfFile) -> Self {
+		let mut tags = Vec::<Option<Tag>>::with_capacity(1);
+
+		#[cfg(feature = "asf_attributes")]
+		tags.push(input.attributes);
+
+		Self {
+			ty: FileType::Asf,
+			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
+			tags: tags.into_iter().flatten().collect(),
+		}
+	}
+}
+
+impl AudioFile for AsfFile {
+	type Properties = AsfProperties;
+
+	fn read_from<R>(reader: &mut R, read_properties: bool) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		read::read_from(reader, true, read_properties)
+	}
+
+	fn properties(&self) -> &Self::Properties {
+		&self.properties
+	}
+
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "asf_attributes")]
+			attributes
+		}
+	}
+
+	#[allow(unreachable_code)]
+	fn contains_tag(&self) -> bool {
+		#[cfg(feature = "asf_attributes")]
+		return self.attributes.is_some();
+
+		false
+	}
+
+	fn contains_tag_type(&self, tag_type: TagType) -> bool {
+		match tag_type {
+			#[cfg(feature = "asf_attributes")]
+			TagType::AsfAttributes => self.attributes.is_some(),
+			_ => false,
+		}
+	}
+}
+
+impl AsfFile {
+	crate::macros::tag_methods! {
+		#[cfg(feature = "asf_attributes")]
+		attributes, Tag
+	}
+}
@@ -0,0 +1,68 @@
+use crate::properties::FileProperties;
+
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
+/// An ASF file's audio properties
+pub struct AsfProperties {
+	pub(crate) codec_id: u16,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: u8,
+	pub(crate) channels: u8,
+}
+
+impl From<AsfProperties> for FileProperties {
+	fn from(input: AsfProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+		}
+	}
+}
+
+impl AsfProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// The codec ID (`wFormatTag`) of the audio stream
+	///
+	/// For example, WMA 2 is `0x0161`, and WMA Lossless is `0x0163`.
+	pub fn codec_id(&self) -> u16 {
+		self.codec_id
+	}
+}
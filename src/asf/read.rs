@@ -0,0 +1,234 @@
+use super::constants::{
+	Guid, AUDIO_MEDIA_GUID, FILE_PROPERTIES_GUID, HEADER_GUID, OBJECT_HEADER_SIZE,
+	STREAM_PROPERTIES_GUID,
+};
+use super::{AsfFile, AsfProperties};
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::macros::try_vec;
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[allow(unused_variables)]
+pub(crate) fn read_from<R>(
+	reader: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+) -> Result<AsfFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))? - start;
+
+	reader.seek(SeekFrom::Start(start))?;
+
+	let mut guid: Guid = [0; 16];
+	reader.read_exact(&mut guid)?;
+
+	if guid != HEADER_GUID {
+		return Err(
+			FileDecodingError::new(FileType::Asf, "File is missing a header object").into(),
+		);
+	}
+
+	let header_size = reader.read_u64::<LittleEndian>()?;
+	let object_count = reader.read_u32::<LittleEndian>()?;
+
+	// Skip 2 bytes
+	// Reserved (2)
+	reader.read_u16::<LittleEndian>()?;
+
+	// GUID (16) + Size (8) + Object count (4) + Reserved (2)
+	let mut offset = OBJECT_HEADER_SIZE + 6;
+
+	if header_size < offset || header_size > file_length {
+		return Err(
+			FileDecodingError::new(FileType::Asf, "Found an invalid header object size").into(),
+		);
+	}
+
+	let mut file = AsfFile::default();
+
+	#[cfg(feature = "asf_attributes")]
+	let mut tag = crate::tag::Tag::new(crate::tag::TagType::AsfAttributes);
+
+	let mut file_properties = None;
+	let mut audio_stream = None;
+
+	for _ in 0..object_count {
+		if offset + OBJECT_HEADER_SIZE > header_size {
+			break;
+		}
+
+		reader.read_exact(&mut guid)?;
+		let object_size = reader.read_u64::<LittleEndian>()?;
+
+		if object_size < OBJECT_HEADER_SIZE || offset + object_size > header_size {
+			return Err(
+				FileDecodingError::new(FileType::Asf, "Found an invalid object size").into(),
+			);
+		}
+
+		let content_size = object_size - OBJECT_HEADER_SIZE;
+
+		match guid {
+			FILE_PROPERTIES_GUID if read_properties => {
+				let mut content = try_vec![0; content_size as usize];
+				reader.read_exact(&mut content)?;
+
+				file_properties = Some(read_file_properties(&mut &*content)?);
+			},
+			STREAM_PROPERTIES_GUID if read_properties && audio_stream.is_none() => {
+				let mut content = try_vec![0; content_size as usize];
+				reader.read_exact(&mut content)?;
+
+				audio_stream = read_stream_properties(&mut &*content)?;
+			},
+			#[cfg(feature = "asf_attributes")]
+			super::constants::CONTENT_DESCRIPTION_GUID if read_tags => {
+				let mut content = try_vec![0; content_size as usize];
+				reader.read_exact(&mut content)?;
+
+				super::tag::read_content_description(&mut &*content, &mut tag)?;
+			},
+			#[cfg(feature = "asf_attributes")]
+			super::constants::EXTENDED_CONTENT_DESCRIPTION_GUID if read_tags => {
+				let mut content = try_vec![0; content_size as usize];
+				reader.read_exact(&mut content)?;
+
+				super::tag::read_extended_content_description(&mut &*content, &mut tag)?;
+			},
+			_ => {
+				reader.seek(SeekFrom::Current(content_size as i64))?;
+			},
+		}
+
+		offset += object_size;
+	}
+
+	#[cfg(feature = "asf_attributes")]
+	if !(tag.items().is_empty() && tag.pictures().is_empty()) {
+		file.attributes = Some(tag);
+	}
+
+	if read_properties {
+		let duration = file_properties.ok_or_else(|| {
+			FileDecodingError::new(FileType::Asf, "File is missing a file properties object")
+		})?;
+
+		let mut properties = audio_stream.ok_or_else(|| {
+			FileDecodingError::new(FileType::Asf, "File contains no audio streams")
+		})?;
+
+		properties.duration = duration;
+
+		let length = duration.as_millis() as u64;
+		if length > 0 {
+			properties.overall_bitrate = ((file_length * 8) / length) as u32;
+		}
+
+		file.properties = properties;
+	}
+
+	Ok(file)
+}
+
+// Returns the play duration
+fn read_file_properties(content: &mut &[u8]) -> Result<Duration> {
+	// Skip 40 bytes
+	// File ID (16)
+	// File size (8)
+	// Creation date (8)
+	// Data packets count (8)
+	let mut skip = [0; 40];
+	content.read_exact(&mut skip)?;
+
+	// Stored in 100-nanosecond units
+	let play_duration = content.read_u64::<LittleEndian>()?;
+
+	// Skip 8 bytes
+	// Send duration (8)
+	content.read_u64::<LittleEndian>()?;
+
+	// The amount of time to buffer before playing, in milliseconds.
+	// This is included in the play duration.
+	let preroll = content.read_u64::<LittleEndian>()?;
+
+	let flags = content.read_u32::<LittleEndian>()?;
+
+	// If the broadcast flag is set, the duration is invalid
+	if flags & 1 == 1 {
+		return Ok(Duration::ZERO);
+	}
+
+	Ok(Duration::from_millis(
+		(play_duration / 10_000).saturating_sub(preroll),
+	))
+}
+
+fn read_stream_properties(content: &mut &[u8]) -> Result<Option<AsfProperties>> {
+	let mut stream_type: Guid = [0; 16];
+	content.read_exact(&mut stream_type)?;
+
+	// Only audio streams are of interest
+	if stream_type != AUDIO_MEDIA_GUID {
+		return Ok(None);
+	}
+
+	// Skip 38 bytes
+	// Error correction type (16)
+	// Time offset (8)
+	// Type-specific data length (4)
+	// Error correction data length (4)
+	// Flags (2)
+	// Reserved (4)
+	let mut skip = [0; 38];
+	content.read_exact(&mut skip)?;
+
+	// The type-specific data is a `WAVEFORMATEX` structure
+	let codec_id = content.read_u16::<LittleEndian>()?;
+	let channels = content.read_u16::<LittleEndian>()? as u8;
+	let sample_rate = content.read_u32::<LittleEndian>()?;
+	let average_bytes_per_second = content.read_u32::<LittleEndian>()?;
+
+	// Skip 2 bytes
+	// Block alignment (2)
+	content.read_u16::<LittleEndian>()?;
+
+	let bit_depth = content.read_u16::<LittleEndian>()? as u8;
+
+	Ok(Some(AsfProperties {
+		codec_id,
+		audio_bitrate: (u64::from(average_bytes_per_second) * 8 / 1000) as u32,
+		sample_rate,
+		bit_depth,
+		channels,
+		..AsfProperties::default()
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::read_stream_properties;
+	use crate::asf::constants::AUDIO_MEDIA_GUID;
+
+	#[test]
+	fn large_average_bytes_per_second() {
+		let mut content = AUDIO_MEDIA_GUID.to_vec();
+		content.extend([0; 38]);
+
+		// Codec ID (2), channels (2), sample rate (4)
+		content.extend([0x61, 0x01, 2, 0]);
+		content.extend(44100_u32.to_le_bytes());
+		content.extend(u32::MAX.to_le_bytes());
+		// Block alignment (2), bit depth (2)
+		content.extend([0, 0, 16, 0]);
+
+		let properties = read_stream_properties(&mut &content[..]).unwrap().unwrap();
+		assert_eq!(properties.audio_bitrate, 34_359_738);
+	}
+}
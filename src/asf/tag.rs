@@ -0,0 +1,219 @@
+use super::constants::CONTENT_DESCRIPTION_KEYS;
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::id3::v2::util::text_utils::utf16_decode;
+use crate::picture::{MimeType, Picture, PictureType};
+use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::{Tag, TagType};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+const DESCRIPTOR_UNICODE: u16 = 0;
+const DESCRIPTOR_BYTE_ARRAY: u16 = 1;
+const DESCRIPTOR_BOOL: u16 = 2;
+const DESCRIPTOR_DWORD: u16 = 3;
+const DESCRIPTOR_QWORD: u16 = 4;
+const DESCRIPTOR_WORD: u16 = 5;
+
+// All strings are stored as UTF-16 LE, and may or may not be null terminated
+fn take_utf16(content: &mut &[u8], len: usize) -> Result<String> {
+	if len > content.len() {
+		return Err(FileDecodingError::new(FileType::Asf, "Found an invalid string length").into());
+	}
+
+	let (string, remaining) = content.split_at(len);
+	*content = remaining;
+
+	utf16_decode(string, u16::from_le_bytes)
+}
+
+fn push_text(tag: &mut Tag, key: &str, value: String) {
+	if value.is_empty() {
+		return;
+	}
+
+	tag.push_item_unchecked(TagItem::new(
+		ItemKey::from_key(TagType::AsfAttributes, key),
+		ItemValue::Text(value),
+	));
+}
+
+pub(super) fn read_content_description(content: &mut &[u8], tag: &mut Tag) -> Result<()> {
+	let mut lengths = [0; CONTENT_DESCRIPTION_KEYS.len()];
+	for len in &mut lengths {
+		*len = content.read_u16::<LittleEndian>()?;
+	}
+
+	for (key, len) in CONTENT_DESCRIPTION_KEYS.iter().zip(lengths) {
+		let value = take_utf16(content, len as usize)?;
+		push_text(tag, key, value);
+	}
+
+	Ok(())
+}
+
+pub(super) fn read_extended_content_description(content: &mut &[u8], tag: &mut Tag) -> Result<()> {
+	let descriptor_count = content.read_u16::<LittleEndian>()?;
+
+	for _ in 0..descriptor_count {
+		let name_len = content.read_u16::<LittleEndian>()?;
+		let name = take_utf16(content, name_len as usize)?;
+
+		let value_type = content.read_u16::<LittleEndian>()?;
+		let value_len = content.read_u16::<LittleEndian>()? as usize;
+
+		if value_len > content.len() {
+			return Err(FileDecodingError::new(
+				FileType::Asf,
+				"Content descriptor value is larger than its object",
+			)
+			.into());
+		}
+
+		let (value, remaining) = content.split_at(value_len);
+		*content = remaining;
+
+		let value = &mut &*value;
+		let text = match value_type {
+			DESCRIPTOR_UNICODE => take_utf16(value, value_len)?,
+			DESCRIPTOR_BYTE_ARRAY if name == "WM/Picture" => {
+				tag.push_picture(read_picture(value)?);
+				continue;
+			},
+			DESCRIPTOR_BYTE_ARRAY => {
+				tag.push_item_unchecked(TagItem::new(
+					ItemKey::from_key(TagType::AsfAttributes, &name),
+					ItemValue::Binary(value.to_vec()),
+				));
+				continue;
+			},
+			// Booleans in this object are 32 bits
+			DESCRIPTOR_BOOL | DESCRIPTOR_DWORD => value.read_u32::<LittleEndian>()?.to_string(),
+			DESCRIPTOR_QWORD => value.read_u64::<LittleEndian>()?.to_string(),
+			DESCRIPTOR_WORD => value.read_u16::<LittleEndian>()?.to_string(),
+			_ => {
+				return Err(FileDecodingError::new(
+					FileType::Asf,
+					"Found an unknown content descriptor value type",
+				)
+				.into())
+			},
+		};
+
+		push_text(tag, &name, text);
+	}
+
+	Ok(())
+}
+
+// Reads a null terminated UTF-16 string
+fn read_terminated_utf16(content: &mut &[u8]) -> Result<String> {
+	let len = content
+		.chunks_exact(2)
+		.position(|c| c == [0, 0])
+		.map(|pos| (pos + 1) * 2)
+		.ok_or_else(|| FileDecodingError::new(FileType::Asf, "Found an unterminated string"))?;
+
+	take_utf16(content, len)
+}
+
+// WM/Picture values are a `WM_PICTURE` structure
+fn read_picture(content: &mut &[u8]) -> Result<Picture> {
+	let pic_type = PictureType::from_u8(content.read_u8()?);
+	let data_len = content.read_u32::<LittleEndian>()? as usize;
+
+	let mime_type = MimeType::from_str(&read_terminated_utf16(content)?);
+	let description = read_terminated_utf16(content)?;
+
+	if data_len > content.len() {
+		return Err(FileDecodingError::new(
+			FileType::Asf,
+			"Picture data is larger than its content descriptor",
+		)
+		.into());
+	}
+
+	Ok(Picture::new_unchecked(
+		pic_type,
+		mime_type,
+		(!description.is_empty()).then(|| description),
+		content[..data_len].to_vec(),
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{read_content_description, read_extended_content_description};
+	use crate::{ItemKey, ItemValue, MimeType, PictureType, Tag, TagItem, TagType};
+
+	fn utf16(s: &str) -> Vec<u8> {
+		s.encode_utf16()
+			.chain(std::iter::once(0))
+			.flat_map(u16::to_le_bytes)
+			.collect()
+	}
+
+	fn descriptor(name: &str, value_type: u16, value: &[u8]) -> Vec<u8> {
+		let name = utf16(name);
+
+		let mut descriptor = Vec::new();
+		descriptor.extend((name.len() as u16).to_le_bytes());
+		descriptor.extend(name);
+		descriptor.extend(value_type.to_le_bytes());
+		descriptor.extend((value.len() as u16).to_le_bytes());
+		descriptor.extend(value);
+
+		descriptor
+	}
+
+	#[test]
+	fn content_description() {
+		let title = utf16("Foo title");
+		let artist = utf16("Bar artist");
+
+		let mut content = Vec::new();
+		content.extend((title.len() as u16).to_le_bytes());
+		content.extend((artist.len() as u16).to_le_bytes());
+		content.extend([0; 6]);
+		content.extend(title);
+		content.extend(artist);
+
+		let mut tag = Tag::new(TagType::AsfAttributes);
+		read_content_description(&mut &*content, &mut tag).unwrap();
+
+		assert_eq!(tag.item_count(), 2);
+		assert_eq!(tag.get_string(&ItemKey::TrackTitle), Some("Foo title"));
+		assert_eq!(tag.get_string(&ItemKey::TrackArtist), Some("Bar artist"));
+	}
+
+	#[test]
+	fn extended_content_description() {
+		let mut picture = vec![3];
+		picture.extend(4_u32.to_le_bytes());
+		picture.extend(utf16("image/png"));
+		picture.extend(utf16(""));
+		picture.extend([1, 2, 3, 4]);
+
+		let mut content = 4_u16.to_le_bytes().to_vec();
+		content.extend(descriptor("WM/AlbumTitle", 0, &utf16("Baz album")));
+		content.extend(descriptor("WM/TrackNumber", 3, &5_u32.to_le_bytes()));
+		content.extend(descriptor("WM/Picture", 1, &picture));
+		content.extend(descriptor("Custom", 1, &[1, 2]));
+
+		let mut tag = Tag::new(TagType::AsfAttributes);
+		read_extended_content_description(&mut &*content, &mut tag).unwrap();
+
+		assert_eq!(tag.get_string(&ItemKey::AlbumTitle), Some("Baz album"));
+		assert_eq!(tag.get_string(&ItemKey::TrackNumber), Some("5"));
+		assert_eq!(
+			tag.get_item_ref(&ItemKey::Unknown(String::from("Custom")))
+				.map(TagItem::value),
+			Some(&ItemValue::Binary(vec![1, 2]))
+		);
+
+		assert_eq!(tag.pictures().len(), 1);
+		assert_eq!(tag.pictures()[0].pic_type(), PictureType::CoverFront);
+		assert_eq!(tag.pictures()[0].mime_type(), &MimeType::Png);
+		assert_eq!(tag.pictures()[0].data(), &[1, 2, 3, 4]);
+	}
+}
@@ -0,0 +1,96 @@
+//! DSF specific items
+//!
+//! ## File notes
+//!
+//! The only tag supported by DSF is `ID3v2`, which is stored in a metadata chunk at the end of the file.
+mod properties;
+pub(crate) mod read;
+#[cfg(feature = "id3v2")]
+pub(crate) mod write;
+
+use crate::error::Result;
+use crate::file::{AudioFile, FileType, TaggedFile};
+#[cfg(feature = "id3v2")]
+use crate::id3::v2::tag::Id3v2Tag;
+use crate::probe::ParseContext;
+use crate::properties::FileProperties;
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+use std::io::{Read, Seek};
+
+pub use properties::DsfProperties;
+
+/// A DSF file
+#[derive(Default)]
+pub struct DsfFile {
+	#[cfg(feature = "id3v2")]
+	/// An ID3v2 tag
+	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// The file's audio properties
+	pub(crate) properties: DsfProperties,
+}
+
+impl From<DsfFile> for TaggedFile {
+	#[allow(clippy::vec_init_then_push, unused_mut)]
+	fn from(input: DsfFile) -> Self {
+		let mut tags = Vec::<Option<Tag>>::with_capacity(1);
+
+		#[cfg(feature = "id3v2")]
+		tags.push(input.id3v2_tag.map(Into::into));
+
+		Self {
+			ty: FileType::Dsf,
+			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
+			tags: tags.into_iter().flatten().collect(),
+		}
+	}
+}
+
+impl AudioFile for DsfFile {
+	type Properties = DsfProperties;
+
+	fn read_from<R>(reader: &mut R, read_properties: bool) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
+	}
+
+	fn properties(&self) -> &Self::Properties {
+		&self.properties
+	}
+
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v2")]
+			id3v2_tag
+		}
+	}
+
+	#[allow(unreachable_code)]
+	fn contains_tag(&self) -> bool {
+		#[cfg(feature = "id3v2")]
+		return self.id3v2_tag.is_some();
+
+		false
+	}
+
+	fn contains_tag_type(&self, tag_type: TagType) -> bool {
+		match tag_type {
+			#[cfg(feature = "id3v2")]
+			TagType::Id3v2 => self.id3v2_tag.is_some(),
+			_ => false,
+		}
+	}
+}
+
+impl DsfFile {
+	crate::macros::tag_methods! {
+		#[cfg(feature = "id3v2")]
+		id3v2_tag, Id3v2Tag
+	}
+}
@@ -0,0 +1,120 @@
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::properties::FileProperties;
+
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
+/// A DSF file's audio properties
+pub struct DsfProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+	pub(crate) bit_depth: u8,
+}
+
+impl From<DsfProperties> for FileProperties {
+	fn from(input: DsfProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+		}
+	}
+}
+
+impl DsfProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Bits per sample
+	///
+	/// This will either be 1 or 8, depending on the order the samples are stored.
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+}
+
+pub(super) fn read_properties(
+	fmt: &mut &[u8],
+	stream_length: u64,
+	file_length: u64,
+) -> Result<DsfProperties> {
+	let format_version = fmt.read_u32::<LittleEndian>()?;
+	let format_id = fmt.read_u32::<LittleEndian>()?;
+
+	// Only version 1 of the format exists, and the only format ID is 0 (DSD raw)
+	if format_version != 1 || format_id != 0 {
+		return Err(FileDecodingError::new(FileType::Dsf, "Found an unsupported format").into());
+	}
+
+	// Skip 4 bytes
+	// Channel type (4)
+	fmt.read_u32::<LittleEndian>()?;
+
+	let channels = fmt.read_u32::<LittleEndian>()?;
+	let sample_rate = fmt.read_u32::<LittleEndian>()?;
+	let bit_depth = fmt.read_u32::<LittleEndian>()?;
+
+	if channels == 0 || channels > 6 {
+		return Err(FileDecodingError::new(FileType::Dsf, "Found an invalid channel count").into());
+	}
+
+	if bit_depth != 1 && bit_depth != 8 {
+		return Err(
+			FileDecodingError::new(FileType::Dsf, "Found an invalid bits per sample").into(),
+		);
+	}
+
+	// The number of samples per channel
+	let sample_count = fmt.read_u64::<LittleEndian>()?;
+
+	let mut properties = DsfProperties {
+		sample_rate,
+		channels: channels as u8,
+		bit_depth: bit_depth as u8,
+		..DsfProperties::default()
+	};
+
+	if sample_rate > 0 {
+		let length = (sample_count * 1000) / u64::from(sample_rate);
+
+		if length > 0 {
+			properties.duration = Duration::from_millis(length);
+			properties.overall_bitrate = ((file_length * 8) / length) as u32;
+			properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+		}
+	}
+
+	Ok(properties)
+}
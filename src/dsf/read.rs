@@ -0,0 +1,154 @@
+use super::DsfFile;
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::macros::try_vec;
+use crate::probe::ParseContext;
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+// The "DSD " chunk is always 28 bytes
+const DSD_CHUNK_SIZE: u64 = 28;
+
+// Reads the "DSD " chunk, returning the offset of the metadata chunk
+//
+// An offset of 0 means there is no metadata chunk
+pub(super) fn read_dsd_chunk<R>(reader: &mut R) -> Result<u64>
+where
+	R: Read,
+{
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"DSD " {
+		return Err(
+			FileDecodingError::new(FileType::Dsf, "File is missing a \"DSD \" chunk").into(),
+		);
+	}
+
+	if reader.read_u64::<LittleEndian>()? != DSD_CHUNK_SIZE {
+		return Err(
+			FileDecodingError::new(FileType::Dsf, "Found an invalid \"DSD \" chunk size").into(),
+		);
+	}
+
+	// Skip 8 bytes
+	// Total file size (8)
+	reader.read_u64::<LittleEndian>()?;
+
+	Ok(reader.read_u64::<LittleEndian>()?)
+}
+
+// Skips the "fmt " chunk, returning the offset of the end of the "data" chunk
+//
+// The reader is expected to be positioned directly after the "DSD " chunk
+#[cfg(feature = "id3v2")]
+pub(super) fn read_data_chunk_end<R>(reader: &mut R) -> Result<u64>
+where
+	R: Read + Seek,
+{
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	// The chunk size includes the ID and itself
+	let fmt_size = reader.read_u64::<LittleEndian>()?;
+
+	let fmt_content_size = fmt_size.checked_sub(12).and_then(|s| i64::try_from(s).ok());
+	let fmt_content_size = match fmt_content_size {
+		Some(size) if &id == b"fmt " => size,
+		_ => {
+			return Err(
+				FileDecodingError::new(FileType::Dsf, "Found an invalid \"fmt \" chunk").into(),
+			)
+		},
+	};
+
+	reader.seek(SeekFrom::Current(fmt_content_size))?;
+	reader.read_exact(&mut id)?;
+
+	if &id != b"data" {
+		return Err(
+			FileDecodingError::new(FileType::Dsf, "File is missing a \"data\" chunk").into(),
+		);
+	}
+
+	// The "data" chunk size also includes the ID and itself
+	let data_size = reader.read_u64::<LittleEndian>()?;
+
+	Ok(DSD_CHUNK_SIZE
+		.saturating_add(fmt_size)
+		.saturating_add(data_size))
+}
+
+pub(crate) fn read_from<R>(
+	reader: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<DsfFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))? - start;
+
+	reader.seek(SeekFrom::Start(start))?;
+
+	let metadata_offset = read_dsd_chunk(reader)?;
+
+	let mut file = DsfFile::default();
+
+	if read_properties {
+		let mut id = [0; 4];
+		reader.read_exact(&mut id)?;
+
+		// The chunk size includes the ID and itself
+		let fmt_size = reader.read_u64::<LittleEndian>()?;
+
+		if &id != b"fmt " || !(12..=file_length).contains(&fmt_size) {
+			return Err(
+				FileDecodingError::new(FileType::Dsf, "Found an invalid \"fmt \" chunk").into(),
+			);
+		}
+
+		let mut fmt = try_vec![0; (fmt_size - 12) as usize];
+		reader.read_exact(&mut fmt)?;
+
+		reader.read_exact(&mut id)?;
+
+		if &id != b"data" {
+			return Err(
+				FileDecodingError::new(FileType::Dsf, "File is missing a \"data\" chunk").into(),
+			);
+		}
+
+		let stream_length = reader.read_u64::<LittleEndian>()?.saturating_sub(12);
+
+		file.properties =
+			super::properties::read_properties(&mut &*fmt, stream_length, file_length)?;
+	}
+
+	if read_tags && metadata_offset > 0 {
+		if metadata_offset >= file_length {
+			return Err(FileDecodingError::new(
+				FileType::Dsf,
+				"Metadata chunk offset is past the end of the file",
+			)
+			.into());
+		}
+
+		#[cfg(feature = "id3v2")]
+		{
+			use crate::id3::v2::read::parse_id3v2;
+			use crate::id3::v2::read_id3v2_header;
+
+			reader.seek(SeekFrom::Start(start + metadata_offset))?;
+
+			let header = read_id3v2_header(reader)?;
+			file.id3v2_tag = Some(parse_id3v2(reader, header, context)?);
+		}
+	}
+
+	Ok(file)
+}
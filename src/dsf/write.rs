@@ -0,0 +1,73 @@
+use super::read::{read_data_chunk_end, read_dsd_chunk};
+use crate::error::{ErrorKind, FileDecodingError, LoftyError, Result};
+use crate::file::FileType;
+use crate::id3::v2;
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+use std::io::SeekFrom;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+pub(crate) fn write_to<F: FileLike>(data: &mut F, tag: &Tag) -> Result<()> {
+	match tag.tag_type() {
+		TagType::Id3v2 => v2::tag::Id3v2TagRef {
+			flags: v2::Id3v2TagFlags::default(),
+			version: v2::Id3v2Version::V4,
+			frames: v2::tag::tag_frames(tag),
+		}
+		.write_to(data),
+		_ => Err(LoftyError::new(ErrorKind::UnsupportedTag)),
+	}
+}
+
+// The metadata chunk is always at the end of the file, so the existing tag is simply
+// truncated, and replaced with the new one. The "DSD " chunk is then updated to point to it.
+pub(crate) fn write_id3v2_to_dsf<F: FileLike>(data: &mut F, tag: &[u8]) -> Result<()> {
+	data.rewind()?;
+
+	let metadata_offset = read_dsd_chunk(data)?;
+	let data_chunk_end = read_data_chunk_end(data)?;
+	let file_length = data.seek(SeekFrom::End(0))?;
+
+	let mut end = file_length;
+	if metadata_offset > 0 {
+		if metadata_offset > file_length {
+			return Err(FileDecodingError::new(
+				FileType::Dsf,
+				"Metadata chunk offset is past the end of the file",
+			)
+			.into());
+		}
+
+		// Truncating here would cut into the audio data
+		if metadata_offset < data_chunk_end {
+			return Err(FileDecodingError::new(
+				FileType::Dsf,
+				"Metadata chunk offset is before the end of the \"data\" chunk",
+			)
+			.into());
+		}
+
+		end = metadata_offset;
+	}
+
+	data.truncate(end)?;
+
+	let mut new_metadata_offset = 0;
+	if !tag.is_empty() {
+		new_metadata_offset = end;
+
+		data.seek(SeekFrom::Start(end))?;
+		data.write_all(tag)?;
+	}
+
+	let file_size = end + tag.len() as u64;
+
+	// Skip the chunk ID and size, the total file size is followed by the metadata pointer
+	data.seek(SeekFrom::Start(12))?;
+	data.write_u64::<LittleEndian>(file_size)?;
+	data.write_u64::<LittleEndian>(new_metadata_offset)?;
+
+	Ok(())
+}
@@ -23,6 +23,8 @@ pub enum ErrorKind {
 
 	// File data related errors
 	/// Attempting to read/write an abnormally large amount of data
+	///
+	/// This is also returned when a size read from a file exceeds [`max_allocation`](crate::max_allocation).
 	TooMuchData,
 	/// Errors that occur while decoding a file
 	FileDecoding(FileDecodingError),
@@ -71,17 +73,29 @@ pub enum Id3v2ErrorKind {
 	/// Arises when an invalid ID3v2 version is found
 	BadId3v2Version(u8, u8),
 	#[cfg(feature = "id3v2")]
-	/// Arises when a frame ID contains invalid characters (must be within `'A'..'Z'` or `'0'..'9'`)
-	BadFrameID,
+	/// Arises when a frame ID is invalid
+	///
+	/// A frame ID must be 4 characters long (or 3 for ID3v2.2), and only contain characters within
+	/// `'A'..='Z'` or `'0'..='9'`.
+	BadFrameID(String),
 	#[cfg(feature = "id3v2")]
 	/// Arises when a frame doesn't have enough data
 	BadFrameLength,
 	#[cfg(feature = "id3v2")]
+	/// Arises when the CRC-32 in a tag's extended header doesn't match its content (expected, calculated)
+	///
+	/// NOTE: With [`Probe::set_lenient_id3v2_parsing`](crate::Probe::set_lenient_id3v2_parsing),
+	/// this is reported as a [`ParseWarning`] instead, and the frames are still read.
+	CrcMismatch(u32, u32),
+	#[cfg(feature = "id3v2")]
 	/// Arises when invalid data is encountered while reading an ID3v2 synchronized text frame
 	BadSyncText,
 	#[cfg(feature = "id3v2")]
 	/// Arises when attempting to write an invalid Frame (Bad `FrameID`/`FrameValue` pairing)
 	BadFrame(String, &'static str),
+	#[cfg(feature = "id3v2_restrictions")]
+	/// Arises when a tag does not conform to its [`TagRestrictions`](crate::id3::v2::TagRestrictions)
+	RestrictionViolation(String),
 	/// A catch-all for all remaining errors
 	///
 	/// NOTE: This will likely be deprecated in the future
@@ -97,13 +111,24 @@ impl Display for Id3v2ErrorKind {
 				major, minor
 			),
 			#[cfg(feature = "id3v2")]
-			Id3v2ErrorKind::BadFrameID => write!(f, "Failed to parse a frame ID"),
+			Id3v2ErrorKind::BadFrameID(ref frame_id) => write!(
+				f,
+				"invalid frame ID \"{}\", expected 4 characters (3 for ID3v2.2) within 'A'..='Z' or \
+				 '0'..='9'",
+				frame_id
+			),
 			#[cfg(feature = "id3v2")]
 			Id3v2ErrorKind::BadFrameLength => write!(
 				f,
 				"Frame isn't long enough to extract the necessary information"
 			),
 			#[cfg(feature = "id3v2")]
+			Id3v2ErrorKind::CrcMismatch(expected, calculated) => write!(
+				f,
+				"Tag CRC-32 mismatch, expected {:#010X}, calculated {:#010X}",
+				expected, calculated
+			),
+			#[cfg(feature = "id3v2")]
 			Id3v2ErrorKind::BadSyncText => write!(f, "Encountered invalid data in SYLT frame"),
 			#[cfg(feature = "id3v2")]
 			Id3v2ErrorKind::BadFrame(ref frame_id, frame_value) => write!(
@@ -111,6 +136,10 @@ impl Display for Id3v2ErrorKind {
 				"Attempted to write an invalid frame. ID: \"{}\", Value: \"{}\"",
 				frame_id, frame_value
 			),
+			#[cfg(feature = "id3v2_restrictions")]
+			Id3v2ErrorKind::RestrictionViolation(message) => {
+				write!(f, "Tag violates its restrictions: {}", message)
+			},
 			#[cfg(feature = "id3v2")]
 			Id3v2ErrorKind::BadPictureFormat(format) => {
 				write!(f, "Picture: Found unexpected format \"{}\"", format)
@@ -382,3 +411,34 @@ impl Display for LoftyError {
 		}
 	}
 }
+
+/// The category of a [`ParseWarning`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WarningCategory {
+	/// The audio properties may be inaccurate (Ex. an invalid sample rate index)
+	Properties,
+	/// Part of a tag was skipped (Ex. a corrupt ID3v2 frame)
+	Tag,
+	/// Text was not stored in the expected encoding, and had to be repaired
+	Encoding,
+	/// The file structure was invalid, but could be recovered (Ex. an atom extending past the end of the file)
+	Structure,
+}
+
+/// A problem that was worked around while reading a file
+///
+/// See [`Probe::read_with_warnings`](crate::Probe::read_with_warnings).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseWarning {
+	/// The category of the warning
+	pub category: WarningCategory,
+	/// A description of what was encountered
+	pub message: String,
+}
+
+impl Display for ParseWarning {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:?}: {}", self.category, self.message)
+	}
+}
@@ -1,11 +1,13 @@
-use crate::error::Result;
+use crate::error::{ErrorKind, LoftyError, Result};
 use crate::properties::FileProperties;
+use crate::tag::editor::TagEditor;
+use crate::tag::item::ItemKey;
+use crate::tag::merged::MergedTag;
 use crate::tag::{Tag, TagType};
-use crate::traits::TagExt;
+use crate::traits::{FileLike, TagExt};
 
 use std::convert::TryInto;
 use std::ffi::OsStr;
-use std::fs::{File, OpenOptions};
 use std::io::{Read, Seek};
 use std::path::Path;
 
@@ -18,6 +20,11 @@ pub trait AudioFile: Into<TaggedFile> {
 
 	/// Read a file from a reader
 	///
+	/// If `read_properties` is false, the properties will be zeroed out. In this case, the audio stream
+	/// is only read as far as needed to locate the tags. For example, the first frame of an MP3 file is still
+	/// located, as it marks the end of the tags, and the pages holding the comments of an OGG file are still
+	/// read, but the rest of the stream (Ex. the remaining MP3 frames, or the last OGG page) is not scanned.
+	///
 	/// # Errors
 	///
 	/// Errors depend on the file and tags being read. See [`LoftyError`](crate::LoftyError)
@@ -27,6 +34,33 @@ pub trait AudioFile: Into<TaggedFile> {
 		Self: Sized;
 	/// Returns a reference to the file's properties
 	fn properties(&self) -> &Self::Properties;
+	/// Attempts to write all of the file's tags to a path
+	///
+	/// As with [`TaggedFile::save_to_path`], the tags are written to a copy of the file, which then replaces the original.
+	///
+	/// # Errors
+	///
+	/// * `path` does not exist, or is not writable
+	/// * See [`AudioFile::save_to`]
+	fn save_to_path(&self, path: impl AsRef<Path>) -> Result<()> {
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
+	}
+	/// Attempts to write all of the file's tags to a file
+	///
+	/// Each tag is written in its native location within the file. Tags that are not present
+	/// will be left untouched, see [`TagType::remove_from`] for removing them.
+	///
+	/// Read only tags, such as ID3v2 in FLAC and APE files, will not be written.
+	///
+	/// NOTE: The default implementation writes nothing, and returns [`ErrorKind::UnsupportedTag`].
+	///
+	/// # Errors
+	///
+	/// See [`TagExt::save_to`](crate::TagExt::save_to), however this is applicable to every tag in the file.
+	#[allow(unused_variables)]
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		Err(LoftyError::new(ErrorKind::UnsupportedTag))
+	}
 	/// Checks if the file contains any tags
 	fn contains_tag(&self) -> bool;
 	/// Checks if the file contains the given [`TagType`]
@@ -41,6 +75,10 @@ pub struct TaggedFile {
 	pub(crate) ty: FileType,
 	/// The file's audio properties
 	pub(crate) properties: FileProperties,
+	/// The caller's preferred [`TagType`] order, see [`TaggedFile::set_tag_priority`]
+	pub(crate) tag_priority: Vec<TagType>,
+	/// Tags that will be removed from the file when saving, see [`TaggedFile::remove`]
+	pub(crate) removed_tags: Vec<TagType>,
 	/// A collection of the file's tags
 	pub(crate) tags: Vec<Tag>,
 }
@@ -56,11 +94,77 @@ impl TaggedFile {
 		self.tags.as_slice()
 	}
 
-	/// Returns the file type's primary [`TagType`]
+	/// Returns the file's primary [`TagType`]
+	///
+	/// Without a tag priority, this is always [`FileType::primary_tag_type`], whether or not
+	/// the file contains that tag.
+	///
+	/// With a tag priority (see [`TaggedFile::set_tag_priority`]), this is the first of:
 	///
-	/// See [`FileType::primary_tag_type`]
+	/// 1. The highest priority [`TagType`] present in the file
+	/// 2. The first [`TagType`] present in the file, in the format's default order
+	///    (Ex. ID3v2, ID3v1, then APE for MP3)
+	/// 3. The highest priority [`TagType`] supported by the file, if it has no tags
+	/// 4. [`FileType::primary_tag_type`], if none of the priority is supported
 	pub fn primary_tag_type(&self) -> TagType {
-		self.ty.primary_tag_type()
+		if self.tag_priority.is_empty() {
+			return self.ty.primary_tag_type();
+		}
+
+		let mut supported = self
+			.tag_priority
+			.iter()
+			.copied()
+			.filter(|t| self.supports_tag_type(*t));
+
+		let present = |t: &TagType| self.tag(t).is_some();
+
+		supported
+			.clone()
+			.find(present)
+			.or_else(|| {
+				self.ty
+					.tag_type_order()
+					.iter()
+					.copied()
+					.filter(|t| self.supports_tag_type(*t))
+					.find(present)
+			})
+			.or_else(|| supported.next())
+			.unwrap_or_else(|| self.ty.primary_tag_type())
+	}
+
+	/// Returns the [`TagType`] priority set with [`TaggedFile::set_tag_priority`]
+	pub fn tag_priority(&self) -> &[TagType] {
+		&self.tag_priority
+	}
+
+	/// Sets the order in which [`TagType`]s are preferred
+	///
+	/// This affects [`TaggedFile::primary_tag`] and [`TaggedFile::merged_tag`]. Any tags not
+	/// in `priority` fall back to the default order, with [`FileType::primary_tag_type`] first, followed
+	/// by the remaining tags in the order they were read.
+	///
+	/// NOTE: [`TagType`]s the file does not support are ignored. See [`FileType::supports_tag_type`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use lofty::LoftyError;
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::TagType;
+	///
+	/// let mut tagged_file = lofty::read_from_path("tests/files/assets/minimal/full_test.mp3", false)?;
+	/// assert_eq!(tagged_file.primary_tag_type(), TagType::Id3v2);
+	///
+	/// // Prefer the APE tag, if there is one
+	/// tagged_file.set_tag_priority(&[TagType::Ape]);
+	/// assert_eq!(tagged_file.primary_tag_type(), TagType::Ape);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn set_tag_priority(&mut self, priority: &[TagType]) {
+		self.tag_priority = priority.to_vec();
 	}
 
 	/// Determines whether the file supports the given [`TagType`]
@@ -80,18 +184,77 @@ impl TaggedFile {
 
 	/// Returns the primary tag
 	///
-	/// See [`FileType::primary_tag_type`]
+	/// See [`TaggedFile::primary_tag_type`]
 	pub fn primary_tag(&self) -> Option<&Tag> {
 		self.tag(&self.primary_tag_type())
 	}
 
 	/// Gets a mutable reference to the file's "Primary tag"
 	///
-	/// See [`FileType::primary_tag_type`]
+	/// See [`TaggedFile::primary_tag_type`]
 	pub fn primary_tag_mut(&mut self) -> Option<&mut Tag> {
 		self.tag_mut(&self.primary_tag_type())
 	}
 
+	/// Gets a mutable reference to the file's "Primary tag", inserting an empty one if it doesn't exist
+	///
+	/// See [`TaggedFile::primary_tag_type`]
+	///
+	/// NOTE: This will return `None` if the file doesn't support any of its [`TagType`]s, due to disabled features.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use lofty::LoftyError;
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::{Accessor, TagExt, TagType};
+	///
+	/// let mut tagged_file = lofty::read_from_path("tests/files/assets/minimal/full_test.mp3", false)?;
+	/// tagged_file.clear();
+	///
+	/// // An empty ID3v2 tag is created
+	/// let primary_tag = tagged_file.primary_tag_mut_or_insert().unwrap();
+	/// assert_eq!(primary_tag.tag_type(), TagType::Id3v2);
+	/// assert!(primary_tag.is_empty());
+	///
+	/// primary_tag.set_title(String::from("Foo title"));
+	/// assert_eq!(tagged_file.primary_tag().unwrap().title(), Some("Foo title"));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn primary_tag_mut_or_insert(&mut self) -> Option<&mut Tag> {
+		let tag_type = self.primary_tag_type();
+
+		if self.tag(&tag_type).is_none() {
+			// Unsupported tag types are never inserted
+			let _ = self.insert_tag(Tag::new(tag_type));
+		}
+
+		self.tag_mut(&tag_type)
+	}
+
+	/// Returns a read-only view over all of the file's tags
+	///
+	/// Items are taken from the tags in order of priority (see [`TaggedFile::set_tag_priority`]),
+	/// followed by the primary tag, falling back to the other tags in the order they were read.
+	/// See [`MergedTag`]
+	pub fn merged_tag(&self) -> MergedTag<'_> {
+		let mut priority = self.tag_priority.clone();
+		priority.push(self.ty.primary_tag_type());
+
+		MergedTag::new(&self.tags, &priority)
+	}
+
+	/// Returns a [`TagEditor`] for the primary tag
+	///
+	/// The primary tag will be created if it doesn't exist, see [`TagEditor`]
+	///
+	/// NOTE: This will return `None` if the primary tag can't be created, see [`TaggedFile::primary_tag_mut_or_insert`]
+	pub fn edit(&mut self) -> Option<TagEditor<'_>> {
+		self.primary_tag_mut_or_insert()?;
+		Some(TagEditor::new(self))
+	}
+
 	/// Gets the first tag, if there are any
 	pub fn first_tag(&self) -> Option<&Tag> {
 		self.tags.first()
@@ -113,6 +276,7 @@ impl TaggedFile {
 
 		if self.supports_tag_type(tag_type) {
 			let ret = self.take(tag_type);
+			self.removed_tags.retain(|t| *t != tag_type);
 			self.tags.push(tag);
 
 			return ret;
@@ -121,7 +285,96 @@ impl TaggedFile {
 		None
 	}
 
+	/// Copies every tag from another `TaggedFile`
+	///
+	/// Tags are copied as-is if the [`FileType`] supports their [`TagType`]. Otherwise, they are
+	/// remapped to the [`TaggedFile::primary_tag_type`], which may lose any items without a mapping
+	/// (see [`Tag::re_map`]).
+	///
+	/// When multiple tags end up in the same [`TagType`], items are taken from the tags in order of
+	/// priority (see [`TaggedFile::merged_tag`]), with lower priority tags only filling in missing items.
+	/// Items copied from `other` will replace any existing items with the same [`ItemKey`](crate::ItemKey).
+	///
+	/// Pictures are copied from every tag, skipping any that already exist in the destination tag.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use lofty::LoftyError;
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::{Accessor, TagType};
+	///
+	/// let flac_file = lofty::read_from_path("tests/files/assets/minimal/full_test.flac", false)?;
+	///
+	/// let mut mp3_file = lofty::read_from_path("tests/files/assets/minimal/full_test.mp3", false)?;
+	/// mp3_file.clear();
+	///
+	/// // The Vorbis comments are converted to ID3v2
+	/// mp3_file.merge_from(&flac_file);
+	///
+	/// let id3v2 = mp3_file.tag(&TagType::Id3v2).unwrap();
+	/// assert_eq!(id3v2.artist(), Some("Foo artist"));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn merge_from(&mut self, other: &TaggedFile) {
+		let fallback_tag_type = self.primary_tag_type();
+
+		// The keys that have been copied into each tag, which lower priority tags can't overwrite
+		let mut copied_keys: Vec<(TagType, ItemKey)> = Vec::new();
+
+		for source in other.merged_tag().tags() {
+			let tag_type = if self.supports_tag_type(source.tag_type()) {
+				source.tag_type()
+			} else {
+				fallback_tag_type
+			};
+
+			let mut source = (*source).clone();
+			source.re_map(tag_type);
+
+			if self.tag(&tag_type).is_none() {
+				self.insert_tag(Tag::new(tag_type));
+			}
+
+			let destination = match self.tag_mut(&tag_type) {
+				Some(destination) => destination,
+				None => continue,
+			};
+
+			let mut new_keys = Vec::new();
+			for item in source.items() {
+				let key = item.key();
+				if !new_keys.contains(key)
+					&& !copied_keys
+						.iter()
+						.any(|(ty, k)| *ty == tag_type && k == key)
+				{
+					new_keys.push(key.clone());
+				}
+			}
+
+			destination.retain(|item| !new_keys.contains(item.key()));
+
+			for picture in source.pictures() {
+				if !destination.pictures().contains(picture) {
+					destination.push_picture(picture.clone());
+				}
+			}
+
+			for item in source {
+				if new_keys.contains(item.key()) {
+					destination.push_item_unchecked(item);
+				}
+			}
+
+			copied_keys.extend(new_keys.into_iter().map(|key| (tag_type, key)));
+		}
+	}
+
 	/// Removes a specific [`TagType`] and returns it
+	///
+	/// NOTE: This will not remove the tag from the file when saving, see [`TaggedFile::remove`]
 	pub fn take(&mut self, tag_type: TagType) -> Option<Tag> {
 		self.tags
 			.iter()
@@ -129,6 +382,40 @@ impl TaggedFile {
 			.map(|pos| self.tags.remove(pos))
 	}
 
+	/// Removes a specific [`TagType`], and marks it to be removed from the file
+	///
+	/// The tag will be removed from the file when the `TaggedFile` is saved (see [`TaggedFile::save_to`]),
+	/// unless a tag of the same [`TagType`] is inserted beforehand.
+	///
+	/// This does nothing if the [`FileType`] does not support the [`TagType`]. See [`FileType::supports_tag_type`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// # use lofty::LoftyError;
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::TagType;
+	///
+	/// let mut tagged_file = lofty::read_from_path("foo.mp3", false)?;
+	///
+	/// // Strip the ID3v1 tag, leaving any others untouched
+	/// tagged_file.remove(TagType::Id3v1);
+	/// tagged_file.save_to_path("foo.mp3")?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn remove(&mut self, tag_type: TagType) -> Option<Tag> {
+		if !self.supports_tag_type(tag_type) {
+			return None;
+		}
+
+		if !self.removed_tags.contains(&tag_type) {
+			self.removed_tags.push(tag_type);
+		}
+
+		self.take(tag_type)
+	}
+
 	/// Changes the [`FileType`]
 	///
 	/// NOTES:
@@ -140,29 +427,45 @@ impl TaggedFile {
 		self.properties = FileProperties::default();
 		self.tags
 			.retain(|t| self.ty.supports_tag_type(t.tag_type()));
+		self.removed_tags.retain(|t| self.ty.supports_tag_type(*t));
 	}
 
 	/// Removes all tags from the file
+	///
+	/// NOTE: This will not remove the tags when saving, as [`TaggedFile::save_to`] only writes the tags
+	/// that are present. See [`remove_tags_from`](crate::remove_tags_from).
 	pub fn clear(&mut self) {
 		self.tags.clear()
 	}
 
 	/// Attempts to write all tags to a path
 	///
+	/// The tags are written to a copy of the file, which then replaces the original.
+	/// Should an error occur, the original file is left untouched.
+	///
 	/// # Errors
 	///
 	/// See [`TaggedFile::save_to`]
 	pub fn save_to_path(&self, path: impl AsRef<Path>) -> Result<()> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
 	/// Attempts to write all tags to a file
 	///
+	/// Any tags marked for removal with [`TaggedFile::remove`] are removed first.
+	///
 	/// # Errors
 	///
-	/// See [`Tag::save_to`], however this is applicable to every tag in the `TaggedFile`.
-	pub fn save_to(&self, file: &mut File) -> Result<()> {
+	/// See [`Tag::save_to`] and [`TagType::remove_from`], however this is applicable to every tag in the `TaggedFile`.
+	pub fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		for tag_type in &self.removed_tags {
+			file.rewind()?;
+			tag_type.remove_from(file)?;
+		}
+
 		for tag in &self.tags {
+			// Every tag writer needs to start from the beginning of the file
+			file.rewind()?;
 			tag.save_to(file)?;
 		}
 
@@ -187,6 +490,10 @@ impl AudioFile for TaggedFile {
 		&self.properties
 	}
 
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		TaggedFile::save_to(self, file)
+	}
+
 	fn contains_tag(&self) -> bool {
 		!self.tags.is_empty()
 	}
@@ -201,45 +508,59 @@ impl AudioFile for TaggedFile {
 #[non_exhaustive]
 /// The type of file read
 pub enum FileType {
+	Aac,
 	AIFF,
 	APE,
+	Asf,
+	Dsf,
 	FLAC,
 	MP3,
 	MP4,
+	Mpc,
 	Opus,
 	Vorbis,
 	Speex,
 	WAV,
+	WavPack,
 }
 
 impl FileType {
-	#[allow(unreachable_patterns, clippy::match_same_arms)]
 	/// Returns the file type's "primary" [`TagType`], or the one most likely to be used in the target format
 	///
-	/// | [`FileType`]             | [`TagType`]      |
-	/// |--------------------------|------------------|
-	/// | `AIFF`, `MP3`, `WAV`     | `Id3v2`          |
-	/// | `APE`                    | `Ape`            |
-	/// | `FLAC`, `Opus`, `Vorbis` | `VorbisComments` |
-	/// | `MP4`                    | `Mp4Ilst`        |
+	/// | [`FileType`]                       | [`TagType`]      |
+	/// |------------------------------------|------------------|
+	/// | `Aac`, `AIFF`, `Dsf`, `MP3`, `WAV` | `Id3v2`          |
+	/// | `APE`, `Mpc`, `WavPack`            | `Ape`            |
+	/// | `FLAC`, `Opus`, `Vorbis`           | `VorbisComments` |
+	/// | `MP4`                              | `Mp4Ilst`        |
+	///
+	/// NOTE: If the [`TagType`]'s feature is disabled, this falls back to the next [`TagType`] the
+	/// format supports (Ex. `Id3v1` for `MP3` without the `id3v2` feature).
 	pub fn primary_tag_type(&self) -> TagType {
+		let order = self.tag_type_order();
+
+		order
+			.iter()
+			.copied()
+			.find(|t| self.supports_tag_type(*t))
+			.unwrap_or(order[0])
+	}
+
+	// Every `TagType` the format can store, in the order they're preferred
+	pub(crate) fn tag_type_order(self) -> &'static [TagType] {
 		match self {
-			#[cfg(all(not(feature = "id3v2"), feature = "aiff_text_chunks"))]
-			FileType::AIFF => TagType::AiffText,
-			#[cfg(all(not(feature = "id3v2"), feature = "riff_info_list"))]
-			FileType::WAV => TagType::RiffInfo,
-			#[cfg(all(not(feature = "id3v2"), feature = "id3v1"))]
-			FileType::MP3 => TagType::Id3v1,
-			#[cfg(all(not(feature = "id3v2"), not(feature = "id3v1"), feature = "ape"))]
-			FileType::MP3 => TagType::Ape,
-			FileType::AIFF | FileType::MP3 | FileType::WAV => TagType::Id3v2,
-			#[cfg(all(not(feature = "ape"), feature = "id3v1"))]
-			FileType::MP3 => TagType::Id3v1,
-			FileType::APE => TagType::Ape,
+			FileType::MP3 => &[TagType::Id3v2, TagType::Id3v1, TagType::Ape],
+			FileType::Aac => &[TagType::Id3v2, TagType::Id3v1],
+			FileType::AIFF => &[TagType::Id3v2, TagType::AiffText],
+			FileType::WAV => &[TagType::Id3v2, TagType::RiffInfo],
+			FileType::Dsf => &[TagType::Id3v2],
+			FileType::APE => &[TagType::Ape, TagType::Id3v1, TagType::Id3v2],
+			FileType::Mpc | FileType::WavPack => &[TagType::Ape, TagType::Id3v1],
 			FileType::FLAC | FileType::Opus | FileType::Vorbis | FileType::Speex => {
-				TagType::VorbisComments
+				&[TagType::VorbisComments]
 			},
-			FileType::MP4 => TagType::Mp4Ilst,
+			FileType::MP4 => &[TagType::Mp4Ilst],
+			FileType::Asf => &[TagType::AsfAttributes],
 		}
 	}
 
@@ -247,7 +568,12 @@ impl FileType {
 	pub fn supports_tag_type(&self, tag_type: TagType) -> bool {
 		match self {
 			#[cfg(feature = "id3v2")]
-			FileType::AIFF | FileType::APE | FileType::MP3 | FileType::WAV
+			FileType::Aac
+			| FileType::AIFF
+			| FileType::APE
+			| FileType::Dsf
+			| FileType::MP3
+			| FileType::WAV
 				if tag_type == TagType::Id3v2 =>
 			{
 				true
@@ -255,9 +581,17 @@ impl FileType {
 			#[cfg(feature = "aiff_text_chunks")]
 			FileType::AIFF if tag_type == TagType::AiffText => true,
 			#[cfg(feature = "id3v1")]
-			FileType::APE | FileType::MP3 if tag_type == TagType::Id3v1 => true,
+			FileType::Aac | FileType::APE | FileType::MP3 | FileType::Mpc | FileType::WavPack
+				if tag_type == TagType::Id3v1 =>
+			{
+				true
+			},
 			#[cfg(feature = "ape")]
-			FileType::APE | FileType::MP3 if tag_type == TagType::Ape => true,
+			FileType::APE | FileType::MP3 | FileType::Mpc | FileType::WavPack
+				if tag_type == TagType::Ape =>
+			{
+				true
+			},
 			#[cfg(feature = "vorbis_comments")]
 			FileType::Opus | FileType::FLAC | FileType::Vorbis | FileType::Speex => {
 				tag_type == TagType::VorbisComments
@@ -266,12 +600,26 @@ impl FileType {
 			FileType::MP4 => tag_type == TagType::Mp4Ilst,
 			#[cfg(feature = "riff_info_list")]
 			FileType::WAV => tag_type == TagType::RiffInfo,
+			#[cfg(feature = "asf_attributes")]
+			FileType::Asf => tag_type == TagType::AsfAttributes,
 			_ => false,
 		}
 	}
 
 	/// Attempts to extract a [`FileType`] from an extension
-	pub fn from_ext<E>(ext: E) -> Option<Self>
+	///
+	/// This is used by [`FileType::from_path`] and [`Probe::open`](crate::Probe::open).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::FileType;
+	///
+	/// assert_eq!(FileType::from_extension("mp3"), Some(FileType::MP3));
+	/// assert_eq!(FileType::from_extension("M4A"), Some(FileType::MP4));
+	/// assert_eq!(FileType::from_extension("txt"), None);
+	/// ```
+	pub fn from_extension<E>(ext: E) -> Option<Self>
 	where
 		E: AsRef<OsStr>,
 	{
@@ -287,28 +635,143 @@ impl FileType {
 			"ogg" => Some(Self::Vorbis),
 			"mp4" | "m4a" | "m4b" | "m4p" | "m4r" | "m4v" | "3gp" => Some(Self::MP4),
 			"spx" => Some(Self::Speex),
+			"wv" => Some(Self::WavPack),
+			"dsf" => Some(Self::Dsf),
+			"aac" => Some(Self::Aac),
+			"wma" | "asf" => Some(Self::Asf),
+			"mpc" | "mp+" | "mpp" => Some(Self::Mpc),
+			_ => None,
+		}
+	}
+
+	#[deprecated(note = "Use `FileType::from_extension` instead")]
+	#[allow(missing_docs)]
+	pub fn from_ext<E>(ext: E) -> Option<Self>
+	where
+		E: AsRef<OsStr>,
+	{
+		Self::from_extension(ext)
+	}
+
+	/// Attempts to extract a [`FileType`] from a MIME type
+	///
+	/// Parameters are ignored, with the exception of `codecs`, which is used to distinguish
+	/// between the formats stored in an OGG container.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::FileType;
+	///
+	/// assert_eq!(FileType::from_mime_type("audio/x-flac"), Some(FileType::FLAC));
+	/// assert_eq!(FileType::from_mime_type("audio/ogg"), Some(FileType::Vorbis));
+	/// assert_eq!(
+	/// 	FileType::from_mime_type("audio/ogg; codecs=opus"),
+	/// 	Some(FileType::Opus)
+	/// );
+	/// assert_eq!(FileType::from_mime_type("image/png"), None);
+	/// ```
+	pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+		let mime_type = mime_type.to_ascii_lowercase();
+
+		let mut parts = mime_type.split(';').map(str::trim);
+		let essence = parts.next()?;
+
+		let codecs = parts.find_map(|param| {
+			let (name, value) = param.split_once('=')?;
+			(name.trim() == "codecs").then(|| value.trim().trim_matches('"'))
+		});
+
+		match essence {
+			"audio/aac" | "audio/aacp" | "audio/x-aac" => Some(Self::Aac),
+			"audio/aiff" | "audio/x-aiff" => Some(Self::AIFF),
+			"audio/ape" | "audio/x-ape" | "audio/x-monkeys-audio" => Some(Self::APE),
+			"audio/x-ms-wma" | "video/x-ms-asf" | "application/vnd.ms-asf" => Some(Self::Asf),
+			"audio/dsf" | "audio/x-dsf" => Some(Self::Dsf),
+			"audio/flac" | "audio/x-flac" => Some(Self::FLAC),
+			"audio/mpeg" | "audio/mp3" | "audio/mpeg3" | "audio/x-mpeg" | "audio/x-mp3" => {
+				Some(Self::MP3)
+			},
+			"audio/mp4" | "audio/m4a" | "audio/x-m4a" | "audio/x-m4b" | "video/mp4" => {
+				Some(Self::MP4)
+			},
+			"audio/musepack" | "audio/x-musepack" => Some(Self::Mpc),
+			"audio/opus" => Some(Self::Opus),
+			"audio/vorbis" | "audio/x-vorbis+ogg" => Some(Self::Vorbis),
+			"audio/speex" | "audio/x-speex" => Some(Self::Speex),
+			"audio/ogg" | "application/ogg" => match codecs {
+				Some("opus") => Some(Self::Opus),
+				Some("speex") => Some(Self::Speex),
+				Some("vorbis") | None => Some(Self::Vorbis),
+				_ => None,
+			},
+			"audio/wav" | "audio/wave" | "audio/x-wav" | "audio/vnd.wave" => Some(Self::WAV),
+			"audio/wavpack" | "audio/x-wavpack" => Some(Self::WavPack),
 			_ => None,
 		}
 	}
 
+	/// Returns the MIME type of the [`FileType`]
+	///
+	/// The result can be converted back with [`FileType::from_mime_type`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::FileType;
+	///
+	/// assert_eq!(FileType::FLAC.mime_type(), "audio/flac");
+	/// assert_eq!(FileType::Opus.mime_type(), "audio/ogg; codecs=opus");
+	/// ```
+	pub fn mime_type(self) -> &'static str {
+		match self {
+			FileType::Aac => "audio/aac",
+			FileType::AIFF => "audio/aiff",
+			FileType::APE => "audio/x-ape",
+			FileType::Asf => "audio/x-ms-wma",
+			FileType::Dsf => "audio/x-dsf",
+			FileType::FLAC => "audio/flac",
+			FileType::MP3 => "audio/mpeg",
+			FileType::MP4 => "audio/mp4",
+			FileType::Mpc => "audio/x-musepack",
+			FileType::Opus => "audio/ogg; codecs=opus",
+			FileType::Vorbis => "audio/ogg; codecs=vorbis",
+			FileType::Speex => "audio/ogg; codecs=speex",
+			FileType::WAV => "audio/wav",
+			FileType::WavPack => "audio/x-wavpack",
+		}
+	}
+
 	/// Attempts to determine a [`FileType`] from a path
 	pub fn from_path<P>(path: P) -> Option<Self>
 	where
 		P: AsRef<Path>,
 	{
 		let ext = path.as_ref().extension();
-		ext.and_then(Self::from_ext)
+		ext.and_then(Self::from_extension)
 	}
 
 	/// Attempts to extract a [`FileType`] from a buffer
 	///
 	/// NOTES:
 	///
-	/// * This is for use in [`Probe::guess_file_type`], it
-	/// is recommended to use it that way
+	/// * This is used by [`Probe::guess_file_type`], so the results will be consistent
 	/// * This **will not** search past tags at the start of the buffer.
 	/// For this behavior, use [`Probe::guess_file_type`].
 	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::FileType;
+	///
+	/// assert_eq!(FileType::from_buffer(b"fLaC\0\0\0\x22"), Some(FileType::FLAC));
+	///
+	/// // The type can't be determined past an ID3v2 tag
+	/// let mp3 = std::fs::read("tests/files/assets/minimal/full_test.mp3")?;
+	/// assert_eq!(FileType::from_buffer(&mp3), None);
+	/// # Ok::<(), std::io::Error>(())
+	/// ```
+	///
 	/// [`Probe::guess_file_type`]: crate::Probe::guess_file_type
 	pub fn from_buffer(buf: &[u8]) -> Option<Self> {
 		match Self::from_buffer_inner(buf) {
@@ -340,8 +803,15 @@ impl FileType {
 			None if buf.len() >= 10 && &buf[..3] == b"ID3" => {
 				// This is infallible, but preferable to an unwrap
 				if let Ok(arr) = buf[6..10].try_into() {
+					let mut size = unsynch_u32(u32::from_be_bytes(arr));
+
+					// The footer (ID3v2.4 only) isn't included in the size
+					if buf[3] == 4 && buf[5] & 0x10 == 0x10 {
+						size += 10;
+					}
+
 					// Set the ID3v2 size
-					ret.1 = Some(unsynch_u32(u32::from_be_bytes(arr)));
+					ret.1 = Some(size);
 				}
 			},
 			// We aren't able to determine a format
@@ -352,11 +822,13 @@ impl FileType {
 	}
 
 	fn quick_type_guess(buf: &[u8]) -> Option<Self> {
+		use crate::aac::header::verify_adts_sync;
 		use crate::mp3::header::verify_frame_sync;
 
 		// Safe to index, since we return early on an empty buffer
 		match buf[0] {
 			77 if buf.starts_with(b"MAC") => Some(Self::APE),
+			255 if buf.len() >= 2 && verify_adts_sync([buf[0], buf[1]]) => Some(Self::Aac),
 			255 if buf.len() >= 2 && verify_frame_sync([buf[0], buf[1]]) => Some(Self::MP3),
 			70 if buf.len() >= 12 && &buf[..4] == b"FORM" => {
 				let id = &buf[8..12];
@@ -378,6 +850,8 @@ impl FileType {
 
 				None
 			},
+			48 if buf.starts_with(&crate::asf::constants::HEADER_GUID) => Some(Self::Asf),
+			68 if buf.starts_with(b"DSD ") => Some(Self::Dsf),
 			102 if buf.starts_with(b"fLaC") => Some(Self::FLAC),
 			82 if buf.len() >= 12 && &buf[..4] == b"RIFF" => {
 				if &buf[8..12] == b"WAVE" {
@@ -386,8 +860,108 @@ impl FileType {
 
 				None
 			},
+			77 if buf.starts_with(b"MPCK") || buf.starts_with(b"MP+") => Some(Self::Mpc),
+			119 if buf.starts_with(b"wvpk") => Some(Self::WavPack),
 			_ if buf.len() >= 8 && &buf[4..8] == b"ftyp" => Some(Self::MP4),
 			_ => None,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{FileProperties, FileType, Tag, TagExt, TagType, TaggedFile};
+
+	fn tagged_file(ty: FileType, tags: &[TagType]) -> TaggedFile {
+		TaggedFile {
+			ty,
+			properties: FileProperties::default(),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
+			tags: tags.iter().copied().map(Tag::new).collect(),
+		}
+	}
+
+	#[test]
+	#[cfg(all(feature = "id3v2", feature = "id3v1", feature = "ape"))]
+	fn primary_tag_type_without_priority() {
+		let file = tagged_file(FileType::MP3, &[TagType::Ape, TagType::Id3v1]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v2);
+
+		let file = tagged_file(FileType::MP3, &[]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "id3v2", feature = "id3v1", feature = "ape"))]
+	fn priority_falls_back_to_default_order() {
+		let mut file = tagged_file(
+			FileType::MP3,
+			&[TagType::Ape, TagType::Id3v1, TagType::Id3v2],
+		);
+		file.set_tag_priority(&[TagType::Ape]);
+		assert_eq!(file.primary_tag_type(), TagType::Ape);
+
+		// Missing and unsupported tags in the priority are skipped
+		let mut file = tagged_file(FileType::MP3, &[TagType::Ape, TagType::Id3v1]);
+		file.set_tag_priority(&[TagType::Mp4Ilst, TagType::Id3v2, TagType::Id3v1]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v1);
+
+		// The APE tag is missing, so the MP3 default order (ID3v2, ID3v1, APE) should be used,
+		// not the order the tags were read in
+		let mut file = tagged_file(FileType::MP3, &[TagType::Id3v1, TagType::Id3v2]);
+		file.set_tag_priority(&[TagType::Ape]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v2);
+
+		let mut file = tagged_file(FileType::MP3, &[TagType::Id3v1]);
+		file.set_tag_priority(&[TagType::Ape]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v1);
+
+		// No tags present, the highest priority supported type is used
+		let mut file = tagged_file(FileType::MP3, &[]);
+		file.set_tag_priority(&[TagType::Mp4Ilst, TagType::Ape]);
+		assert_eq!(file.primary_tag_type(), TagType::Ape);
+
+		// None of the priority is supported
+		let mut file = tagged_file(FileType::MP3, &[]);
+		file.set_tag_priority(&[TagType::Mp4Ilst]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "ape", feature = "id3v2"))]
+	fn primary_tag_mut_or_insert() {
+		let mut file = tagged_file(FileType::MP3, &[TagType::Ape]);
+
+		let tag = file.primary_tag_mut_or_insert().unwrap();
+		assert_eq!(tag.tag_type(), TagType::Id3v2);
+		assert!(tag.is_empty());
+
+		assert_eq!(file.tags().len(), 2);
+		assert!(file.tag(&TagType::Ape).is_some());
+	}
+
+	#[test]
+	#[cfg(not(any(feature = "id3v2", feature = "id3v1", feature = "ape")))]
+	fn primary_tag_mut_or_insert_unsupported() {
+		let mut file = tagged_file(FileType::MP3, &[]);
+
+		assert!(file.primary_tag_mut_or_insert().is_none());
+		assert!(file.edit().is_none());
+		assert!(file.tags().is_empty());
+	}
+
+	#[test]
+	#[cfg(all(not(feature = "id3v2"), feature = "id3v1", feature = "ape"))]
+	fn primary_tag_type_without_id3v2() {
+		assert_eq!(FileType::MP3.primary_tag_type(), TagType::Id3v1);
+		assert_eq!(FileType::APE.primary_tag_type(), TagType::Ape);
+
+		let mut file = tagged_file(FileType::MP3, &[TagType::Ape, TagType::Id3v1]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v1);
+
+		// ID3v2 is ignored, falling back to ID3v1 before APE
+		file.set_tag_priority(&[TagType::Id3v2]);
+		assert_eq!(file.primary_tag_type(), TagType::Id3v1);
+	}
+}
@@ -6,7 +6,7 @@
 
 mod block;
 mod properties;
-mod read;
+pub(crate) mod read;
 #[cfg(feature = "vorbis_comments")]
 pub(crate) mod write;
 
@@ -15,21 +15,26 @@ use crate::file::{AudioFile, FileType, TaggedFile};
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
 #[cfg(feature = "vorbis_comments")]
-use crate::ogg::VorbisComments;
-use crate::properties::FileProperties;
+use crate::ogg::{tag::VorbisCommentsRef, VorbisComments};
+#[cfg(feature = "vorbis_comments")]
+use crate::picture::{Picture, PictureInformation, PictureType};
+use crate::probe::ParseContext;
 use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
 
 use std::io::{Read, Seek};
 
+pub use properties::FlacProperties;
+
 /// A FLAC file
 ///
 /// ## Notes
 ///
-/// * The ID3v2 tag is **read only**, and it's use is discouraged by spec
-/// * Picture blocks will be stored in the `VorbisComments` tag, meaning a file could have no vorbis
-///   comments block, but `FlacFile::vorbis_comments` will exist.
-///   * When writing, the pictures will be stored in their own picture blocks
-///   * This behavior will likely change in the future
+/// * The ID3v2 tag is **read only** by default, and it's use is discouraged by spec.
+///   See [`FlacFile::save_id3v2_to`] for writing it anyway.
+/// * Picture blocks are stored separately from the `VorbisComments` tag, see [`FlacFile::pictures`].
+///   * When converting to a [`TaggedFile`], the pictures will be moved into the `VorbisComments` tag
+///   * When writing, all pictures will be stored in their own picture blocks
 pub struct FlacFile {
 	#[cfg(feature = "id3v2")]
 	/// An ID3v2 tag
@@ -37,10 +42,12 @@ pub struct FlacFile {
 	#[cfg(feature = "vorbis_comments")]
 	/// The vorbis comments contained in the file
 	///
-	/// NOTE: This field being `Some` does not mean the file has vorbis comments, as Picture blocks exist.
 	pub(crate) vorbis_comments: Option<VorbisComments>,
+	#[cfg(feature = "vorbis_comments")]
+	/// The pictures stored in FLAC picture blocks
+	pub(crate) pictures: Vec<(Picture, PictureInformation)>,
 	/// The file's audio properties
-	pub(crate) properties: FileProperties,
+	pub(crate) properties: FlacProperties,
 }
 
 impl From<FlacFile> for TaggedFile {
@@ -49,13 +56,29 @@ impl From<FlacFile> for TaggedFile {
 		let mut tags = Vec::<Option<Tag>>::with_capacity(2);
 
 		#[cfg(feature = "vorbis_comments")]
-		tags.push(input.vorbis_comments.map(Into::into));
+		{
+			let vorbis_comments = match input.vorbis_comments {
+				Some(mut vorbis_comments) => {
+					vorbis_comments.pictures.extend(input.pictures);
+					Some(vorbis_comments)
+				},
+				None if !input.pictures.is_empty() => Some(VorbisComments {
+					pictures: input.pictures,
+					..VorbisComments::default()
+				}),
+				None => None,
+			};
+
+			tags.push(vorbis_comments.map(Into::into));
+		}
 		#[cfg(feature = "id3v2")]
 		tags.push(input.id3v2_tag.map(Into::into));
 
 		Self {
 			ty: FileType::FLAC,
-			properties: input.properties,
+			properties: input.properties.into(),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			#[cfg(any(feature = "vorbis_comments", feature = "id3v2"))]
 			tags: tags.into_iter().flatten().collect(),
 			#[cfg(not(any(feature = "vorbis_comments", feature = "id3v2")))]
@@ -65,19 +88,35 @@ impl From<FlacFile> for TaggedFile {
 }
 
 impl AudioFile for FlacFile {
-	type Properties = FileProperties;
+	type Properties = FlacProperties;
 
 	fn read_from<R>(reader: &mut R, read_properties: bool) -> Result<Self>
 	where
 		R: Read + Seek,
 	{
-		read::read_from(reader, read_properties)
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
 	}
 
 	fn properties(&self) -> &Self::Properties {
 		&self.properties
 	}
 
+	/// Writes the Vorbis comments and pictures, see [`FlacFile::save_to`]
+	///
+	/// If there are no Vorbis comments or pictures, the file is left untouched. Use
+	/// [`FlacFile::save_to`] directly to remove the existing blocks.
+	///
+	/// The ID3v2 tag is **not** written, see [`FlacFile::save_id3v2_to`].
+	#[allow(unused_variables)]
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		#[cfg(feature = "vorbis_comments")]
+		if self.vorbis_comments.is_some() || !self.pictures.is_empty() {
+			FlacFile::save_to(self, file)?;
+		}
+
+		Ok(())
+	}
+
 	fn contains_tag(&self) -> bool {
 		#[cfg(feature = "vorbis_comments")]
 		return self.vorbis_comments.is_some();
@@ -104,4 +143,114 @@ impl FlacFile {
 		#[cfg(feature = "id3v2")]
 		id3v2_tag, Id3v2Tag
 	}
+
+	#[cfg(feature = "vorbis_comments")]
+	/// Returns the pictures stored in FLAC picture blocks
+	///
+	/// NOTE: This does not include any pictures stored in the [`VorbisComments`]
+	pub fn pictures(&self) -> &[(Picture, PictureInformation)] {
+		&self.pictures
+	}
+
+	#[cfg(feature = "vorbis_comments")]
+	/// Inserts a [`Picture`], to be written in a FLAC picture block
+	///
+	/// NOTES:
+	///
+	/// * If `information` is `None`, the [`PictureInformation`] will be inferred using [`PictureInformation::from_picture`].
+	/// * According to spec, there can only be one picture of type [`PictureType::Icon`] and [`PictureType::OtherIcon`].
+	///   When attempting to insert these types, if another is found it will be removed and returned.
+	///
+	/// # Errors
+	///
+	/// * See [`PictureInformation::from_picture`]
+	pub fn insert_picture(
+		&mut self,
+		picture: Picture,
+		information: Option<PictureInformation>,
+	) -> Result<Option<(Picture, PictureInformation)>> {
+		let ret = match picture.pic_type {
+			PictureType::Icon | PictureType::OtherIcon => self
+				.pictures
+				.iter()
+				.position(|(p, _)| p.pic_type == picture.pic_type)
+				.map(|pos| self.pictures.remove(pos)),
+			_ => None,
+		};
+
+		let info = match information {
+			Some(pic_info) => pic_info,
+			None => PictureInformation::from_picture(&picture)?,
+		};
+
+		self.pictures.push((picture, info));
+
+		Ok(ret)
+	}
+
+	#[cfg(feature = "vorbis_comments")]
+	/// Removes a certain [`PictureType`]
+	pub fn remove_picture_type(&mut self, picture_type: PictureType) {
+		self.pictures.retain(|(p, _)| p.pic_type != picture_type)
+	}
+
+	#[cfg(feature = "vorbis_comments")]
+	/// Writes the Vorbis comments and pictures to a FLAC file
+	///
+	/// The existing comment and picture blocks are replaced, keeping their positions relative to the other blocks.
+	/// Any pictures in the [`VorbisComments`] will also be written as picture blocks.
+	///
+	/// If [`FlacFile::vorbis_comments`] is `None` (or empty), the comment block will be removed.
+	///
+	/// # Errors
+	///
+	/// * `file` is not a FLAC file
+	/// * A block is too large to be written
+	/// * [`std::io::Error`]
+	pub fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		let (vendor, items, comment_pictures) = match self.vorbis_comments {
+			Some(ref vorbis_comments) => (
+				vorbis_comments.vendor.as_str(),
+				vorbis_comments.items.as_slice(),
+				vorbis_comments.pictures.as_slice(),
+			),
+			None => ("", &[][..], &[][..]),
+		};
+
+		write::write_to(
+			file,
+			&mut VorbisCommentsRef {
+				vendor,
+				items: items.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+				pictures: self
+					.pictures
+					.iter()
+					.chain(comment_pictures)
+					.map(|(p, i)| (p, *i)),
+			},
+		)
+	}
+
+	#[cfg(feature = "id3v2")]
+	/// Writes the ID3v2 tag to a FLAC file
+	///
+	/// ⚠ **Warning** ⚠
+	///
+	/// ID3v2 tags are **not** part of the FLAC specification, and many applications will
+	/// either ignore them or refuse to read the file. Vorbis comments should always be used instead,
+	/// this only exists to update tags written by tools that already put them there.
+	///
+	/// The tag is written ahead of the "fLaC" stream marker, replacing any existing ID3v2 tag. The FLAC
+	/// stream itself is left untouched. If [`FlacFile::id3v2_tag`] is `None`, the tag will be removed.
+	///
+	/// # Errors
+	///
+	/// * `file` is not a FLAC file
+	/// * See [`Id3v2Tag::save_to`](crate::TagExt::save_to)
+	pub fn save_id3v2_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		match self.id3v2_tag {
+			Some(ref tag) => tag.as_tag_ref().write_to_flac(file),
+			None => crate::id3::v2::tag::Id3v2TagRef::empty().write_to_flac(file),
+		}
+	}
 }
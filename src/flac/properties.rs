@@ -6,18 +6,103 @@ use std::time::Duration;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+#[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
+/// A FLAC file's audio properties
+pub struct FlacProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: u8,
+	pub(crate) channels: u8,
+	pub(crate) min_block_size: u16,
+	pub(crate) max_block_size: u16,
+	pub(crate) total_samples: u64,
+	pub(crate) md5_signature: [u8; 16],
+}
+
+impl From<FlacProperties> for FileProperties {
+	fn from(input: FlacProperties) -> Self {
+		// Unknown values are stored as 0, such as the bitrates without a known duration,
+		// or everything when the properties weren't read
+		Self {
+			duration: input.duration,
+			overall_bitrate: (input.overall_bitrate > 0).then(|| input.overall_bitrate),
+			audio_bitrate: (input.audio_bitrate > 0).then(|| input.audio_bitrate),
+			sample_rate: (input.sample_rate > 0).then(|| input.sample_rate),
+			bit_depth: (input.bit_depth > 0).then(|| input.bit_depth),
+			channels: (input.channels > 0).then(|| input.channels),
+		}
+	}
+}
+
+impl FlacProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Minimum block size (in samples) used in the stream
+	pub fn min_block_size(&self) -> u16 {
+		self.min_block_size
+	}
+
+	/// Maximum block size (in samples) used in the stream
+	pub fn max_block_size(&self) -> u16 {
+		self.max_block_size
+	}
+
+	/// Total number of samples (per channel) in the stream
+	///
+	/// This will be `0` if unknown
+	pub fn total_samples(&self) -> u64 {
+		self.total_samples
+	}
+
+	/// MD5 signature of the unencoded audio data
+	///
+	/// This will be `None` if the signature is all zeros, meaning it is unknown
+	pub fn md5_signature(&self) -> Option<[u8; 16]> {
+		(self.md5_signature != [0; 16]).then(|| self.md5_signature)
+	}
+}
+
 pub(crate) fn read_properties<R>(
 	stream_info: &mut R,
 	stream_length: u64,
 	file_length: u64,
-) -> Result<FileProperties>
+) -> Result<FlacProperties>
 where
 	R: Read,
 {
-	// Skip 4 bytes
-	// Minimum block size (2)
-	// Maximum block size (2)
-	stream_info.read_u32::<BigEndian>()?;
+	let min_block_size = stream_info.read_u16::<BigEndian>()?;
+	let max_block_size = stream_info.read_u16::<BigEndian>()?;
 
 	// Skip 6 bytes
 	// Minimum frame size (3)
@@ -36,26 +121,32 @@ where
 	let channels = ((info >> 9) & 7) + 1;
 
 	// Read the remaining 32 bits of the total samples
-	let total_samples = stream_info.read_u32::<BigEndian>()? | (info << 28);
-
-	let (duration, overall_bitrate, audio_bitrate) = if sample_rate > 0 && total_samples > 0 {
-		let length = (u64::from(total_samples) * 1000) / u64::from(sample_rate);
-
-		(
-			Duration::from_millis(length),
-			Some(((file_length * 8) / length) as u32),
-			Some(((stream_length * 8) / length) as u32),
-		)
-	} else {
-		(Duration::ZERO, None, None)
+	let total_samples =
+		u64::from(stream_info.read_u32::<BigEndian>()?) | (u64::from(info & 0xF) << 32);
+
+	let mut md5_signature = [0; 16];
+	stream_info.read_exact(&mut md5_signature)?;
+
+	let mut properties = FlacProperties {
+		sample_rate,
+		bit_depth: bits_per_sample as u8,
+		channels: channels as u8,
+		min_block_size,
+		max_block_size,
+		total_samples,
+		md5_signature,
+		..FlacProperties::default()
 	};
 
-	Ok(FileProperties {
-		duration,
-		overall_bitrate,
-		audio_bitrate,
-		sample_rate: Some(sample_rate),
-		bit_depth: Some(bits_per_sample as u8),
-		channels: Some(channels as u8),
-	})
+	if sample_rate > 0 && total_samples > 0 {
+		let length = (total_samples * 1000) / u64::from(sample_rate);
+
+		if length > 0 {
+			properties.duration = Duration::from_millis(length);
+			properties.overall_bitrate = ((file_length * 8) / length) as u32;
+			properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+		}
+	}
+
+	Ok(properties)
 }
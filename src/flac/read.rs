@@ -1,11 +1,12 @@
 use super::block::Block;
+use super::properties::FlacProperties;
 use super::FlacFile;
 use crate::error::{FileDecodingError, Result};
 use crate::file::FileType;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::read::parse_id3v2;
 use crate::id3::{find_id3v2, ID3FindResults};
-use crate::properties::FileProperties;
+use crate::probe::ParseContext;
 #[cfg(feature = "vorbis_comments")]
 use crate::{
 	ogg::{read::read_comments, tag::VorbisComments},
@@ -40,7 +41,12 @@ where
 	Ok(block)
 }
 
-pub(crate) fn read_from<R>(data: &mut R, read_properties: bool) -> Result<FlacFile>
+pub(crate) fn read_from<R>(
+	data: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<FlacFile>
 where
 	R: Read + Seek,
 {
@@ -49,16 +55,18 @@ where
 		id3v2_tag: None,
 		#[cfg(feature = "vorbis_comments")]
 		vorbis_comments: None,
-		properties: FileProperties::default(),
+		#[cfg(feature = "vorbis_comments")]
+		pictures: Vec::new(),
+		properties: FlacProperties::default(),
 	};
 
 	// It is possible for a FLAC file to contain an ID3v2 tag
-	if let ID3FindResults(Some(header), Some(content)) = find_id3v2(data, true)? {
+	if let ID3FindResults(Some(header), Some(content)) = find_id3v2(data, read_tags)? {
 		#[cfg(feature = "id3v2")]
 		{
 			let reader = &mut &*content;
 
-			let id3v2 = parse_id3v2(reader, header)?;
+			let id3v2 = parse_id3v2(reader, header, context)?;
 			flac_file.id3v2_tag = Some(id3v2)
 		}
 	}
@@ -89,9 +97,9 @@ where
 
 		match block.ty {
 			#[cfg(feature = "vorbis_comments")]
-			4 => read_comments(&mut &*block.content, &mut tag)?,
+			4 if read_tags => read_comments(&mut &*block.content, &mut tag)?,
 			#[cfg(feature = "vorbis_comments")]
-			6 => tag
+			6 if read_tags => flac_file
 				.pictures
 				.push(Picture::from_flac_bytes(&*block.content, false)?),
 			_ => {},
@@ -114,7 +122,7 @@ where
 	flac_file.properties = if read_properties {
 		super::properties::read_properties(&mut &*stream_info.content, stream_length, file_length)?
 	} else {
-		FileProperties::default()
+		FlacProperties::default()
 	};
 
 	Ok(flac_file)
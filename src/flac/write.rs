@@ -1,112 +1,121 @@
 use super::block::Block;
 use super::read::verify_flac;
 use crate::error::{ErrorKind, LoftyError, Result};
+use crate::id3::find_id3v2;
 use crate::ogg::tag::VorbisCommentsRef;
 use crate::ogg::write::create_comments;
 use crate::picture::{Picture, PictureInformation};
+use crate::traits::FileLike;
 
-use std::fs::File;
-use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::io::{Cursor, Seek, SeekFrom, Write};
 
 use byteorder::{LittleEndian, WriteBytesExt};
 
 const MAX_BLOCK_SIZE: u32 = 16_777_215;
 
-pub(crate) fn write_to<'a, II, IP>(
-	data: &mut File,
+pub(crate) fn write_to<'a, II, IP, F: FileLike>(
+	data: &mut F,
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
 ) -> Result<()>
 where
 	II: Iterator<Item = (&'a str, &'a str)>,
 	IP: Iterator<Item = (&'a Picture, PictureInformation)>,
 {
+	// A (non-standard) ID3v2 tag may precede the stream marker, it is left untouched
+	find_id3v2(data, false)?;
+
 	let stream_info = verify_flac(data)?;
-	let stream_info_end = stream_info.end as usize;
 
 	let mut last_block = stream_info.last;
+	let mut blocks = Vec::new();
 
-	let mut file_bytes = Vec::new();
-	data.read_to_end(&mut file_bytes)?;
+	while !last_block {
+		let block = Block::read(data)?;
+		last_block = block.last;
 
-	let mut cursor = Cursor::new(file_bytes);
+		blocks.push(block);
+	}
 
-	let mut padding = false;
-	let mut last_block_info = (
-		stream_info.byte,
-		stream_info_end - ((stream_info.end - stream_info.start) as u32 + 4) as usize,
-		stream_info_end,
-	);
+	let mut audio = Vec::new();
+	data.read_to_end(&mut audio)?;
 
-	let mut blocks_remove = Vec::new();
+	let mut comment_block = Cursor::new(Vec::new());
+	create_comment_block(&mut comment_block, tag.vendor, &mut tag.items)?;
 
-	while !last_block {
-		let block = Block::read(&mut cursor)?;
-		let start = block.start;
-		let end = block.end;
+	let comment_block = comment_block.into_inner();
+	let mut comment_block = (!comment_block.is_empty()).then(|| comment_block);
+	let mut picture_blocks = Some(create_picture_blocks(&mut tag.pictures)?);
 
-		let block_type = block.ty;
-		last_block = block.last;
+	// The new blocks take the place of the first existing blocks of the same type,
+	// so the order of the remaining blocks is preserved
+	let mut new_blocks = Vec::with_capacity(blocks.len() + 1);
+	let mut comment_pos = None;
+	let mut padding = false;
 
-		if last_block {
-			last_block_info = (block.byte, (end - start) as usize, end as usize)
+	for block in blocks {
+		match block.ty {
+			4 => {
+				if let Some(comment_block) = comment_block.take() {
+					comment_pos = Some(new_blocks.len());
+					new_blocks.push(comment_block);
+				}
+			},
+			6 => new_blocks.extend(picture_blocks.take().into_iter().flatten()),
+			ty => {
+				padding |= ty == 1;
+				new_blocks.push(block_bytes(block.byte, &block.content));
+			},
 		}
+	}
 
-		match block_type {
-			4 | 6 => blocks_remove.push((start, end)),
-			1 => padding = true,
-			_ => {},
-		}
+	// Otherwise, the comment block goes directly after STREAMINFO, followed by the pictures
+	if let Some(comment_block) = comment_block {
+		comment_pos = Some(0);
+		new_blocks.insert(0, comment_block);
 	}
 
-	let mut file_bytes = cursor.into_inner();
+	if let Some(picture_blocks) = picture_blocks {
+		let pos = comment_pos.map_or(0, |pos| pos + 1);
+		new_blocks.splice(pos..pos, picture_blocks);
+	}
 
 	if !padding {
-		let mut first_byte = 0_u8;
-		first_byte |= last_block_info.0 & 0x7F;
-
-		file_bytes[last_block_info.1] = first_byte;
-
-		let mut padding_block = [0; 1028];
-		let mut padding_byte = 0;
-		padding_byte |= 0x80;
-		padding_byte |= 1 & 0x7F;
-
-		padding_block[0] = padding_byte;
-
 		// [0, 4, 0] = 1024
-		padding_block[2] = 4;
-
-		file_bytes.splice(last_block_info.2..last_block_info.2, padding_block);
+		new_blocks.push(block_bytes(1, &[0; 1024]));
 	}
 
-	let mut comment_blocks = Cursor::new(Vec::new());
+	// Only the final block can have the last-metadata-block flag set
+	if let Some((last, blocks)) = new_blocks.split_last_mut() {
+		for block in blocks {
+			block[0] &= 0x7F;
+		}
 
-	create_comment_block(&mut comment_blocks, tag.vendor, &mut tag.items)?;
+		last[0] |= 0x80;
+	}
 
-	let mut comment_blocks = comment_blocks.into_inner();
+	data.seek(SeekFrom::Start(stream_info.start))?;
+	data.truncate(stream_info.start)?;
 
-	create_picture_blocks(&mut comment_blocks, &mut tag.pictures)?;
+	data.write_all(&block_bytes(stream_info.byte & 0x7F, &stream_info.content))?;
 
-	if blocks_remove.is_empty() {
-		file_bytes.splice(0..0, comment_blocks);
-	} else {
-		blocks_remove.sort_unstable();
-		blocks_remove.reverse();
+	for block in new_blocks {
+		data.write_all(&block)?;
+	}
 
-		let first = blocks_remove.pop().unwrap(); // Infallible
+	data.write_all(&audio)?;
 
-		for (s, e) in &blocks_remove {
-			file_bytes.drain(*s as usize..*e as usize);
-		}
+	Ok(())
+}
 
-		file_bytes.splice(first.0 as usize..first.1 as usize, comment_blocks);
-	}
+// The block header is the type (with the last-metadata-block flag), followed by a 24-bit size
+fn block_bytes(byte: u8, content: &[u8]) -> Vec<u8> {
+	let mut block = Vec::with_capacity(content.len() + 4);
 
-	data.seek(SeekFrom::Start(stream_info_end as u64))?;
-	data.set_len(stream_info_end as u64)?;
-	data.write_all(&*file_bytes)?;
+	block.push(byte);
+	block.extend_from_slice(&(content.len() as u32).to_be_bytes()[1..]);
+	block.extend_from_slice(content);
 
-	Ok(())
+	block
 }
 
 fn create_comment_block(
@@ -152,25 +161,19 @@ fn create_comment_block(
 }
 
 fn create_picture_blocks(
-	writer: &mut Vec<u8>,
 	pictures: &mut dyn Iterator<Item = (&Picture, PictureInformation)>,
-) -> Result<()> {
-	let mut byte = 0_u8;
-	byte |= 6 & 0x7F;
+) -> Result<Vec<Vec<u8>>> {
+	let mut picture_blocks = Vec::new();
 
 	for (pic, info) in pictures {
-		writer.write_u8(byte)?;
-
 		let pic_bytes = pic.as_flac_bytes(info, false);
-		let pic_len = pic_bytes.len() as u32;
 
-		if pic_len > MAX_BLOCK_SIZE {
+		if pic_bytes.len() > MAX_BLOCK_SIZE as usize {
 			return Err(LoftyError::new(ErrorKind::TooMuchData));
 		}
 
-		writer.write_all(&pic_len.to_be_bytes()[1..])?;
-		writer.write_all(pic_bytes.as_slice())?;
+		picture_blocks.push(block_bytes(6, &pic_bytes));
 	}
 
-	Ok(())
+	Ok(picture_blocks)
 }
@@ -0,0 +1,103 @@
+use crate::id3::v1::constants::GENRES;
+
+#[cfg(feature = "id3v1")]
+// Used to pass through genre indices that aren't in `GENRES`
+static GENRE_INDICES: once_cell::sync::Lazy<Vec<String>> =
+	once_cell::sync::Lazy::new(|| (0..=u8::MAX).map(|i| i.to_string()).collect());
+
+#[cfg(feature = "id3v1")]
+/// Get the name of an ID3v1 genre index, or the index itself if it is unknown
+pub(crate) fn genre_name(index: u8) -> &'static str {
+	match GENRES.get(index as usize) {
+		Some(genre) => genre,
+		None => GENRE_INDICES[index as usize].as_str(),
+	}
+}
+
+#[cfg(feature = "id3v1")]
+/// Get the ID3v1 genre index of a genre
+///
+/// This accepts genre names (case-insensitive), raw indices, and any of the forms
+/// accepted by [`resolve_id3v2_genre`].
+pub(crate) fn genre_index(genre: &str) -> Option<u8> {
+	let genre = resolve_id3v2_genre(genre);
+
+	GENRES
+		.iter()
+		.position(|g| g.eq_ignore_ascii_case(genre))
+		.map_or_else(|| genre.parse::<u8>().ok(), |i| Some(i as u8))
+}
+
+/// Resolve an ID3v2 "TCON" value into a human-readable genre
+///
+/// The following forms are handled:
+///
+/// * "17" or "(17)" - An ID3v1 genre index, resolved using [`GENRES`]. Unknown indices are returned as-is.
+/// * "(17)Rock" - An ID3v1 genre index with a refinement, which is returned
+/// * "(RX)" and "(CR)" - "Remix" and "Cover" respectively
+/// * "((Foo)" - An escaped opening parenthesis, "(Foo)"
+pub(crate) fn resolve_id3v2_genre(genre: &str) -> &str {
+	fn resolve_index(index: &str) -> &str {
+		match index {
+			"RX" => "Remix",
+			"CR" => "Cover",
+			_ => match index.parse::<usize>().ok().and_then(|i| GENRES.get(i)) {
+				Some(genre) => genre,
+				None => index,
+			},
+		}
+	}
+
+	if genre.starts_with("((") {
+		return &genre[1..];
+	}
+
+	if let Some(reference) = genre.strip_prefix('(') {
+		if let Some((index, refinement)) = reference.split_once(')') {
+			if !refinement.is_empty() {
+				return refinement;
+			}
+
+			return resolve_index(index);
+		}
+	}
+
+	if !genre.is_empty() && genre.bytes().all(|b| b.is_ascii_digit()) {
+		return resolve_index(genre);
+	}
+
+	genre
+}
+
+#[cfg(test)]
+mod tests {
+	use super::resolve_id3v2_genre;
+
+	#[test]
+	fn id3v2_genres() {
+		assert_eq!(resolve_id3v2_genre("Rock"), "Rock");
+		assert_eq!(resolve_id3v2_genre("17"), "Rock");
+		assert_eq!(resolve_id3v2_genre("(17)"), "Rock");
+		assert_eq!(resolve_id3v2_genre("(17)Hard Rock"), "Hard Rock");
+		assert_eq!(resolve_id3v2_genre("(RX)"), "Remix");
+		assert_eq!(resolve_id3v2_genre("(CR)"), "Cover");
+		assert_eq!(resolve_id3v2_genre("((Foo)"), "(Foo)");
+		// Unknown indices are passed through
+		assert_eq!(resolve_id3v2_genre("(250)"), "250");
+		assert_eq!(resolve_id3v2_genre("1000"), "1000");
+	}
+
+	#[test]
+	#[cfg(feature = "id3v1")]
+	fn id3v1_genres() {
+		use super::{genre_index, genre_name};
+
+		assert_eq!(genre_name(17), "Rock");
+		assert_eq!(genre_name(250), "250");
+
+		assert_eq!(genre_index("rock"), Some(17));
+		assert_eq!(genre_index("(17)"), Some(17));
+		assert_eq!(genre_index("250"), Some(250));
+		assert_eq!(genre_index("Foo"), None);
+	}
+}
@@ -6,6 +6,9 @@
 pub mod v1;
 pub mod v2;
 
+#[cfg(any(feature = "id3v1", feature = "id3v2"))]
+pub(crate) mod genre;
+
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::macros::try_vec;
 use v2::{read_id3v2_header, Id3v2Header};
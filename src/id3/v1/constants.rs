@@ -1,4 +1,4 @@
-#[cfg(any(feature = "id3v1", feature = "mp4_ilst"))]
+#[cfg(any(feature = "id3v1", feature = "id3v2", feature = "mp4_ilst"))]
 /// All possible genres for ID3v1
 pub const GENRES: [&str; 192] = [
 	"Blues",
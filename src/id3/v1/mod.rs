@@ -6,8 +6,9 @@
 //!
 //! ## Genres
 //!
-//! ID3v1 stores the genre in a single byte ranging from 0 to 192 (inclusive).
+//! ID3v1 stores the genre in a single byte ranging from 0 to 191 (inclusive).
 //! All possible genres have been stored in the [`GENRES`] constant.
+//! Indices outside of this range are kept as-is, and will be shown as the raw number.
 //!
 //! ## Track Numbers
 //!
@@ -1,16 +1,7 @@
-use super::constants::GENRES;
 use super::tag::Id3v1Tag;
 
 pub fn parse_id3v1(reader: [u8; 128]) -> Id3v1Tag {
-	let mut tag = Id3v1Tag {
-		title: None,
-		artist: None,
-		album: None,
-		year: None,
-		comment: None,
-		track_number: None,
-		genre: None,
-	};
+	let mut tag = Id3v1Tag::default();
 
 	let reader = &reader[3..];
 
@@ -32,7 +23,8 @@ pub fn parse_id3v1(reader: [u8; 128]) -> Id3v1Tag {
 
 	tag.comment = decode_text(&reader[range]);
 
-	if reader[124] < GENRES.len() as u8 {
+	// 255 is used to indicate no genre, any other unknown index is kept as-is
+	if reader[124] != u8::MAX {
 		tag.genre = Some(reader[124]);
 	}
 
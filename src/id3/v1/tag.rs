@@ -1,10 +1,10 @@
-use crate::error::{LoftyError, Result};
-use crate::id3::v1::constants::GENRES;
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::id3::genre::{genre_index, genre_name};
+use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 
-use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
@@ -43,9 +43,10 @@ macro_rules! impl_accessor {
 ///
 /// ### From `Tag`
 ///
-/// Two checks are performed when converting a genre:
+/// Three checks are performed when converting a genre:
 ///
-/// * [`GENRES`] contains the string
+/// * [`GENRES`](crate::id3::v1::GENRES) contains the string (case-insensitive)
+/// * The string is an ID3v2 genre reference, such as "(17)"
 /// * The [`ItemValue`](crate::ItemValue) can be parsed into a `u8`
 pub struct Id3v1Tag {
 	/// Track title, 30 bytes max
@@ -62,7 +63,8 @@ pub struct Id3v1Tag {
 	/// A V1 tag may have been read, which limits this field to 30 bytes.
 	/// A V1.1 tag, however, only has 28 bytes available.
 	///
-	/// **Lofty** will *always* write a V1.1 tag.
+	/// **Lofty** will write a V1.1 tag if there is a track number, otherwise a V1 tag is written
+	/// and the full 30 bytes are available.
 	pub comment: Option<String>,
 	/// The track number, 1 byte max
 	///
@@ -78,31 +80,90 @@ pub struct Id3v1Tag {
 	/// ID3v1 has a predefined set of genres, see [`GENRES`](crate::id3::v1::GENRES).
 	/// This byte should be an index to a genre.
 	pub genre: Option<u8>,
+	pub(crate) encoding: Id3v1Encoding,
 }
 
-impl Accessor for Id3v1Tag {
-	impl_accessor!(title, artist, album,);
+// The encoding used to write the tag's text, this can only be changed with the `id3v1_encodings` feature
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Id3v1Encoding(
+	#[cfg(feature = "id3v1_encodings")] pub(crate) Option<&'static encoding_rs::Encoding>,
+);
 
-	fn genre(&self) -> Option<&str> {
-		if let Some(g) = self.genre {
-			let g = g as usize;
+#[cfg(feature = "id3v1_encodings")]
+impl Id3v1Tag {
+	/// Returns the encoding used to write the tag's text
+	///
+	/// If this is `None`, the text will be written as Latin-1.
+	pub fn encoding(&self) -> Option<&'static encoding_rs::Encoding> {
+		self.encoding.0
+	}
 
-			if g < GENRES.len() {
-				return Some(GENRES[g]);
+	/// Sets the encoding used to write the tag's text
+	///
+	/// Characters that can't be represented in the encoding will be replaced.
+	/// See [`Id3v1Tag::encoding`].
+	pub fn set_encoding(&mut self, encoding: Option<&'static encoding_rs::Encoding>) {
+		self.encoding = Id3v1Encoding(encoding)
+	}
+
+	/// Reinterprets the tag's text with another encoding
+	///
+	/// The text of an ID3v1 tag is always read as Latin-1. If the tag was written using another
+	/// encoding (Ex. Shift-JIS or Windows-1251), this will decode the original bytes again with `encoding`.
+	/// The encoding will then be used when writing, see [`Id3v1Tag::set_encoding`].
+	///
+	/// NOTE: Fields containing characters outside of Latin-1 (such as those already reinterpreted) are left untouched.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v1::Id3v1Tag;
+	///
+	/// let mut tag = Id3v1Tag::default();
+	///
+	/// // "Тест" in Windows-1251, read as Latin-1
+	/// tag.title = Some(String::from("\u{D2}\u{E5}\u{F1}\u{F2}"));
+	///
+	/// tag.reinterpret(encoding_rs::WINDOWS_1251);
+	/// assert_eq!(tag.title.as_deref(), Some("Тест"));
+	/// ```
+	pub fn reinterpret(&mut self, encoding: &'static encoding_rs::Encoding) {
+		for field in [
+			&mut self.title,
+			&mut self.artist,
+			&mut self.album,
+			&mut self.year,
+			&mut self.comment,
+		]
+		.into_iter()
+		.flatten()
+		{
+			if !field.chars().all(|c| u32::from(c) <= 0xFF) {
+				continue;
 			}
+
+			let bytes = field.chars().map(|c| c as u8).collect::<Vec<u8>>();
+			*field = encoding.decode_without_bom_handling(&bytes).0.into_owned();
 		}
 
-		None
+		self.encoding = Id3v1Encoding(Some(encoding));
 	}
+}
 
-	fn set_genre(&mut self, genre: String) {
-		let g_str = genre.as_str();
+impl Accessor for Id3v1Tag {
+	impl_accessor!(title, artist, album,);
 
-		for (i, g) in GENRES.iter().enumerate() {
-			if g.eq_ignore_ascii_case(g_str) {
-				self.genre = Some(i as u8);
-				break;
-			}
+	/// Returns the genre name, or the raw index if it isn't in [`GENRES`](crate::id3::v1::GENRES)
+	fn genre(&self) -> Option<&str> {
+		self.genre.map(genre_name)
+	}
+
+	/// Sets the genre from a genre name or index
+	///
+	/// This will also accept the ID3v2 "(17)" form. Unknown genres will be ignored.
+	fn set_genre(&mut self, genre: String) {
+		if let Some(index) = genre_index(&genre) {
+			self.genre = Some(index);
 		}
 	}
 
@@ -125,10 +186,10 @@ impl TagExt for Id3v1Tag {
 	}
 
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		Into::<Id3v1TagRef<'_>>::into(self).write_to(file)
 	}
 
@@ -145,13 +206,28 @@ impl TagExt for Id3v1Tag {
 		TagType::Id3v1.remove_from_path(path)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		TagType::Id3v1.remove_from(file)
 	}
 
 	fn clear(&mut self) {
 		*self = Self::default();
 	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		Vec::new()
+	}
+
+	/// ID3v1 cannot store pictures
+	///
+	/// # Errors
+	///
+	/// This will always error with [`ErrorKind::UnsupportedPicture`]
+	fn replace_picture(&mut self, _: Picture) -> std::result::Result<(), Self::Err> {
+		Err(LoftyError::new(ErrorKind::UnsupportedPicture))
+	}
+
+	fn remove_picture_type(&mut self, _: PictureType) {}
 }
 
 impl From<Id3v1Tag> for Tag {
@@ -173,10 +249,8 @@ impl From<Id3v1Tag> for Tag {
 			))
 		}
 
-		if let Some(genre_index) = input.genre {
-			if let Some(genre) = GENRES.get(genre_index as usize) {
-				tag.insert_text(ItemKey::Genre, (*genre).to_string());
-			}
+		if let Some(genre) = input.genre {
+			tag.insert_text(ItemKey::Genre, String::from(genre_name(genre)));
 		}
 
 		tag
@@ -195,15 +269,8 @@ impl From<Tag> for Id3v1Tag {
 				.get_string(&ItemKey::TrackNumber)
 				.map(|g| g.parse::<u8>().ok())
 				.and_then(|g| g),
-			genre: input
-				.get_string(&ItemKey::Genre)
-				.map(|g| {
-					GENRES
-						.iter()
-						.position(|v| v == &g)
-						.map_or_else(|| g.parse::<u8>().ok(), |p| Some(p as u8))
-				})
-				.and_then(|g| g),
+			genre: input.get_string(&ItemKey::Genre).and_then(genre_index),
+			encoding: Id3v1Encoding::default(),
 		}
 	}
 }
@@ -216,6 +283,7 @@ pub(crate) struct Id3v1TagRef<'a> {
 	pub comment: Option<&'a str>,
 	pub track_number: Option<u8>,
 	pub genre: Option<u8>,
+	pub encoding: Id3v1Encoding,
 }
 
 impl<'a> Into<Id3v1TagRef<'a>> for &'a Id3v1Tag {
@@ -228,6 +296,7 @@ impl<'a> Into<Id3v1TagRef<'a>> for &'a Id3v1Tag {
 			comment: self.comment.as_deref(),
 			track_number: self.track_number,
 			genre: self.genre,
+			encoding: self.encoding,
 		}
 	}
 }
@@ -244,15 +313,8 @@ impl<'a> Into<Id3v1TagRef<'a>> for &'a Tag {
 				.get_string(&ItemKey::TrackNumber)
 				.map(|g| g.parse::<u8>().ok())
 				.and_then(|g| g),
-			genre: self
-				.get_string(&ItemKey::Genre)
-				.map(|g| {
-					GENRES
-						.iter()
-						.position(|v| v == &g)
-						.map_or_else(|| g.parse::<u8>().ok(), |p| Some(p as u8))
-				})
-				.and_then(|g| g),
+			genre: self.get_string(&ItemKey::Genre).and_then(genre_index),
+			encoding: Id3v1Encoding::default(),
 		}
 	}
 }
@@ -268,7 +330,7 @@ impl<'a> Id3v1TagRef<'a> {
 			&& self.genre.is_none()
 	}
 
-	pub(crate) fn write_to(&self, file: &mut File) -> Result<()> {
+	pub(crate) fn write_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
 		super::write::write_id3v1(file, self)
 	}
 
@@ -295,6 +357,7 @@ mod tests {
 			comment: Some(String::from("Qux comment")),
 			track_number: Some(1),
 			genre: Some(32),
+			..Id3v1Tag::default()
 		};
 
 		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.id3v1");
@@ -339,4 +402,103 @@ mod tests {
 		assert_eq!(id3v1_tag.track_number, Some(1));
 		assert_eq!(id3v1_tag.genre, Some(32));
 	}
+
+	#[test]
+	fn v1_and_v1_1() {
+		let comment = "A comment that is 30 bytes lon";
+
+		let mut tag = Id3v1Tag {
+			comment: Some(String::from(comment)),
+			..Id3v1Tag::default()
+		};
+
+		// With no track number, a V1 tag is written, keeping the entire comment
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let parsed_tag = crate::id3::v1::read::parse_id3v1(writer.try_into().unwrap());
+		assert_eq!(parsed_tag.comment.as_deref(), Some(comment));
+		assert_eq!(parsed_tag.track_number, None);
+
+		// With a track number, a V1.1 tag is written, shrinking the comment to 28 bytes
+		tag.track_number = Some(5);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+		assert_eq!(writer[125], 0);
+		assert_eq!(writer[126], 5);
+
+		let parsed_tag = crate::id3::v1::read::parse_id3v1(writer.try_into().unwrap());
+		assert_eq!(parsed_tag.comment.as_deref(), Some(&comment[..28]));
+		assert_eq!(parsed_tag.track_number, Some(5));
+	}
+
+	#[test]
+	fn latin1_text() {
+		let tag = Id3v1Tag {
+			title: Some(String::from("Café 日本")),
+			..Id3v1Tag::default()
+		};
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		// Characters outside of Latin-1 are replaced
+		assert_eq!(&writer[3..12], b"Caf\xE9 ??\0\0");
+
+		let parsed_tag = crate::id3::v1::read::parse_id3v1(writer.try_into().unwrap());
+		assert_eq!(parsed_tag.title.as_deref(), Some("Café ??"));
+	}
+
+	#[test]
+	#[cfg(feature = "id3v1_encodings")]
+	fn reinterpret_encoding() {
+		// "Привет" in Windows-1251
+		let title = [0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2];
+
+		let mut bytes = [0; 128];
+		bytes[..3].copy_from_slice(b"TAG");
+		bytes[3..9].copy_from_slice(&title);
+		bytes[127] = 255;
+
+		let mut tag = crate::id3::v1::read::parse_id3v1(bytes);
+		assert_ne!(tag.title.as_deref(), Some("Привет"));
+
+		tag.reinterpret(encoding_rs::WINDOWS_1251);
+		assert_eq!(tag.title.as_deref(), Some("Привет"));
+		assert_eq!(tag.encoding(), Some(encoding_rs::WINDOWS_1251));
+
+		// The tag is written back with the same encoding
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+		assert_eq!(&writer[..], &bytes[..]);
+	}
+
+	#[test]
+	#[cfg(feature = "id3v1_encodings")]
+	fn encoding_truncation() {
+		let mut tag = Id3v1Tag {
+			// 29 bytes, followed by a 2 byte character
+			title: Some(format!("{}é", "a".repeat(29))),
+			artist: Some(String::from("Тест 日本")),
+			..Id3v1Tag::default()
+		};
+
+		tag.set_encoding(Some(encoding_rs::UTF_8));
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		// The character doesn't fit, so it is left out entirely
+		assert_eq!(&writer[3..32], "a".repeat(29).as_bytes());
+		assert_eq!(writer[32], 0);
+
+		tag.set_encoding(Some(encoding_rs::WINDOWS_1251));
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		// Characters outside of the encoding are replaced
+		assert_eq!(&writer[33..41], b"\xD2\xE5\xF1\xF2 ??\0");
+	}
 }
@@ -1,20 +1,21 @@
-use super::tag::Id3v1TagRef;
+use super::tag::{Id3v1Encoding, Id3v1TagRef};
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::file::FileType;
 use crate::id3::{find_id3v1, ID3FindResults};
 use crate::probe::Probe;
+use crate::traits::FileLike;
 
-use std::fs::File;
-use std::io::{Cursor, Read, Seek, Write};
+use std::io::Write;
 
 use byteorder::WriteBytesExt;
 
 #[allow(clippy::shadow_unrelated)]
-pub(crate) fn write_id3v1(writer: &mut File, tag: &Id3v1TagRef<'_>) -> Result<()> {
+pub(crate) fn write_id3v1<F: FileLike>(writer: &mut F, tag: &Id3v1TagRef<'_>) -> Result<()> {
 	let probe = Probe::new(writer).guess_file_type()?;
 
 	match probe.file_type() {
-		Some(ft) if ft == FileType::APE || ft == FileType::MP3 => {},
+		Some(FileType::Aac | FileType::APE | FileType::MP3 | FileType::Mpc | FileType::WavPack) => {
+		},
 		_ => return Err(LoftyError::new(ErrorKind::UnsupportedTag)),
 	}
 
@@ -30,7 +31,7 @@ pub(crate) fn write_id3v1(writer: &mut File, tag: &Id3v1TagRef<'_>) -> Result<()
 		writer.read_to_end(&mut file_bytes)?;
 
 		writer.rewind()?;
-		writer.set_len(0)?;
+		writer.truncate(0)?;
 		writer.write_all(&file_bytes[..file_bytes.len() - 128])?;
 
 		return Ok(());
@@ -44,20 +45,16 @@ pub(crate) fn write_id3v1(writer: &mut File, tag: &Id3v1TagRef<'_>) -> Result<()
 }
 
 pub(super) fn encode(tag: &Id3v1TagRef<'_>) -> std::io::Result<Vec<u8>> {
-	fn resize_string(value: Option<&str>, size: usize) -> std::io::Result<Vec<u8>> {
-		let mut cursor = Cursor::new(vec![0; size]);
-		cursor.rewind()?;
+	// Encodes the string, truncating it on a character boundary to fit into `size` bytes
+	let resize_string = |value: Option<&str>, size: usize| -> std::io::Result<Vec<u8>> {
+		let mut bytes = vec![0; size];
 
 		if let Some(val) = value {
-			if val.len() > size {
-				cursor.write_all(val.split_at(size).0.as_bytes())?;
-			} else {
-				cursor.write_all(val.as_bytes())?;
-			}
+			encode_into(tag.encoding, val, &mut bytes);
 		}
 
-		Ok(cursor.into_inner())
-	}
+		Ok(bytes)
+	};
 
 	let mut writer = Vec::with_capacity(128);
 
@@ -75,13 +72,68 @@ pub(super) fn encode(tag: &Id3v1TagRef<'_>) -> std::io::Result<Vec<u8>> {
 	let year = resize_string(tag.year, 4)?;
 	writer.write_all(&*year)?;
 
-	let comment = resize_string(tag.comment, 28)?;
-	writer.write_all(&*comment)?;
-
-	writer.write_u8(0)?;
+	// A V1.1 tag is only written if there's a track number, as it takes 2 bytes from the comment
+	match tag.track_number {
+		Some(track_number) if track_number != 0 => {
+			let comment = resize_string(tag.comment, 28)?;
+			writer.write_all(&*comment)?;
+
+			writer.write_u8(0)?;
+			writer.write_u8(track_number)?;
+		},
+		_ => {
+			let comment = resize_string(tag.comment, 30)?;
+			writer.write_all(&*comment)?;
+		},
+	}
 
-	writer.write_u8(tag.track_number.unwrap_or(0))?;
 	writer.write_u8(tag.genre.unwrap_or(255))?;
 
 	Ok(writer)
 }
+
+#[cfg(feature = "id3v1_encodings")]
+fn encode_into(encoding: Id3v1Encoding, value: &str, dst: &mut [u8]) {
+	match encoding.0 {
+		Some(encoding) => encode_with(encoding, value, dst),
+		None => encode_latin1(value, dst),
+	}
+}
+
+#[cfg(not(feature = "id3v1_encodings"))]
+fn encode_into(_: Id3v1Encoding, value: &str, dst: &mut [u8]) {
+	encode_latin1(value, dst)
+}
+
+// Characters outside of Latin-1 are replaced with '?'
+fn encode_latin1(value: &str, dst: &mut [u8]) {
+	for (b, c) in dst.iter_mut().zip(value.chars()) {
+		*b = u8::try_from(u32::from(c)).unwrap_or(b'?');
+	}
+}
+
+// The encoder stops before any character that doesn't fit into `dst`.
+// Characters that can't be represented in the encoding are replaced with '?'
+#[cfg(feature = "id3v1_encodings")]
+fn encode_with(encoding: &'static encoding_rs::Encoding, mut value: &str, dst: &mut [u8]) {
+	use encoding_rs::EncoderResult;
+
+	let mut encoder = encoding.new_encoder();
+	let mut written = 0;
+
+	loop {
+		let (result, read, len) =
+			encoder.encode_from_utf8_without_replacement(value, &mut dst[written..], true);
+
+		value = &value[read..];
+		written += len;
+
+		match result {
+			EncoderResult::Unmappable(_) if written < dst.len() => {
+				dst[written] = b'?';
+				written += 1;
+			},
+			_ => break,
+		}
+	}
+}
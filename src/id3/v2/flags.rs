@@ -6,6 +6,8 @@ use super::restrictions::TagRestrictions;
 /// Flags that apply to the entire tag
 pub struct Id3v2TagFlags {
 	/// Whether or not all frames are unsynchronised. See [`FrameFlags::unsynchronisation`](crate::id3::v2::FrameFlags::unsynchronisation)
+	///
+	/// If the tag is written, this will unsynchronise every frame
 	pub unsynchronisation: bool,
 	/// Indicates if the tag is in an experimental stage
 	pub experimental: bool,
@@ -15,8 +17,15 @@ pub struct Id3v2TagFlags {
 	pub footer: bool,
 	/// Whether or not to include a CRC-32 in the extended header
 	///
-	/// This is calculated if the tag is written
+	/// This is calculated if the tag is written, and verified if the tag is read.
+	/// A mismatch will result in [`Id3v2ErrorKind::CrcMismatch`](crate::error::Id3v2ErrorKind::CrcMismatch),
+	/// or a [`ParseWarning`](crate::error::ParseWarning) when parsing leniently (see [`Probe::set_lenient_id3v2_parsing`](crate::Probe::set_lenient_id3v2_parsing)).
 	pub crc: bool,
+	/// The number of bytes of padding to reserve after the frames
+	///
+	/// Reserving padding allows future edits to be written without shifting the rest of the file.
+	/// This is only used when writing, and will be ignored if the tag has a footer or a CRC-32.
+	pub padding: u32,
 	#[cfg(feature = "id3v2_restrictions")]
 	/// Restrictions on the tag, written in the extended header
 	///
@@ -1,10 +1,18 @@
 use crate::error::{ErrorKind, Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
 use crate::id3::v2::frame::FrameValue;
+use crate::id3::v2::items::chapter::ChapterFrame;
+use crate::id3::v2::items::comment_frame::CommentFrame;
 use crate::id3::v2::items::encoded_text_frame::EncodedTextFrame;
-use crate::id3::v2::items::language_frame::LanguageFrame;
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
+use crate::id3::v2::items::private_frame::PrivateFrame;
+use crate::id3::v2::items::relative_volume_adjustment::RelativeVolumeAdjustmentFrame;
+use crate::id3::v2::items::table_of_contents::TableOfContentsFrame;
+use crate::id3::v2::items::unique_file_identifier::UniqueFileIdentifierFrame;
+use crate::id3::v2::items::unsynchronized_text::UnsynchronizedTextFrame;
 use crate::id3::v2::util::text_utils::{decode_text, TextEncoding};
 use crate::id3::v2::Id3v2Version;
 use crate::picture::Picture;
+use crate::probe::ParseContext;
 
 use std::io::Read;
 
@@ -15,6 +23,7 @@ pub(super) fn parse_content(
 	content: &mut &[u8],
 	id: &str,
 	version: Id3v2Version,
+	context: &mut ParseContext,
 ) -> Result<FrameValue> {
 	Ok(match id {
 		// The ID was previously upgraded, but the content remains unchanged, so version is necessary
@@ -25,13 +34,25 @@ pub(super) fn parse_content(
 		},
 		"TXXX" => parse_user_defined(content, false, version)?,
 		"WXXX" => parse_user_defined(content, true, version)?,
-		"COMM" | "USLT" => parse_text_language(content, id, version)?,
+		"COMM" => FrameValue::Comment(CommentFrame::parse(content, version)?),
+		"USLT" => FrameValue::UnSyncText(UnsynchronizedTextFrame::parse(content, version)?),
+		// "IPLS" and "IPL" were previously upgraded to "TIPL"
+		"TIPL" | "TMCL" => FrameValue::KeyValueList(KeyValueFrame::parse(content, version)?),
 		_ if id.starts_with('T') => parse_text(content, version)?,
 		// Apple proprietary frames
 		// WFED (Podcast URL), GRP1 (Grouping), MVNM (Movement Name), MVIN (Movement Number)
 		"WFED" | "GRP1" | "MVNM" | "MVIN" => parse_text(content, version)?,
 		_ if id.starts_with('W') => parse_link(content)?,
 		"POPM" => parse_popularimeter(content)?,
+		"CHAP" => FrameValue::Chapter(ChapterFrame::read(content, version, context)?),
+		"CTOC" => {
+			FrameValue::TableOfContents(TableOfContentsFrame::read(content, version, context)?)
+		},
+		"RVA2" => {
+			FrameValue::RelativeVolumeAdjustment(RelativeVolumeAdjustmentFrame::parse(content)?)
+		},
+		"PRIV" => FrameValue::Private(PrivateFrame::parse(content)?),
+		"UFID" => FrameValue::UniqueFileIdentifier(UniqueFileIdentifierFrame::parse(content)?),
 		// SYLT, GEOB, and any unknown frames
 		_ => FrameValue::Binary(content.to_vec()),
 	})
@@ -70,38 +91,6 @@ fn parse_user_defined(
 	})
 }
 
-fn parse_text_language(content: &mut &[u8], id: &str, version: Id3v2Version) -> Result<FrameValue> {
-	if content.len() < 5 {
-		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
-	}
-
-	let encoding = verify_encoding(content.read_u8()?, version)?;
-
-	let mut lang = [0; 3];
-	content.read_exact(&mut lang)?;
-
-	let lang = std::str::from_utf8(&lang)
-		.map_err(|_| LoftyError::new(ErrorKind::TextDecode("Unable to decode language string")))?;
-
-	let description = decode_text(content, encoding, true)?;
-	let content = decode_text(content, encoding, false)?.unwrap_or_default();
-
-	let information = LanguageFrame {
-		encoding,
-		language: lang.to_string(),
-		description: description.unwrap_or_default(),
-		content,
-	};
-
-	let value = match id {
-		"COMM" => FrameValue::Comment(information),
-		"USLT" => FrameValue::UnSyncText(information),
-		_ => unreachable!(),
-	};
-
-	Ok(value)
-}
-
 fn parse_text(content: &mut &[u8], version: Id3v2Version) -> Result<FrameValue> {
 	let encoding = verify_encoding(content.read_u8()?, version)?;
 	let text = decode_text(content, encoding, true)?.unwrap_or_default();
@@ -145,7 +134,10 @@ fn parse_popularimeter(content: &mut &[u8]) -> Result<FrameValue> {
 	}))
 }
 
-fn verify_encoding(encoding: u8, version: Id3v2Version) -> Result<TextEncoding> {
+pub(in crate::id3::v2) fn verify_encoding(
+	encoding: u8,
+	version: Id3v2Version,
+) -> Result<TextEncoding> {
 	if let Id3v2Version::V2 = version {
 		if encoding != 0 && encoding != 1 {
 			return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
@@ -20,8 +20,11 @@ where
 		return Ok(None);
 	}
 
-	let id_str = std::str::from_utf8(&frame_header[..3])
-		.map_err(|_| Id3v2Error::new(Id3v2ErrorKind::BadFrameID))?;
+	let id_str = std::str::from_utf8(&frame_header[..3]).map_err(|_| {
+		Id3v2Error::new(Id3v2ErrorKind::BadFrameID(
+			String::from_utf8_lossy(&frame_header[..3]).into_owned(),
+		))
+	})?;
 	let id = upgrade_v2(id_str).unwrap_or(id_str);
 
 	let frame_id = FrameID::new(id)?;
@@ -50,8 +53,11 @@ where
 		return Ok(None);
 	}
 
-	let id_str = std::str::from_utf8(&frame_header[..4])
-		.map_err(|_| Id3v2Error::new(Id3v2ErrorKind::BadFrameID))?;
+	let id_str = std::str::from_utf8(&frame_header[..4]).map_err(|_| {
+		Id3v2Error::new(Id3v2ErrorKind::BadFrameID(
+			String::from_utf8_lossy(&frame_header[..4]).into_owned(),
+		))
+	})?;
 
 	let (id, size) = if synchsafe {
 		let size = crate::id3::v2::unsynch_u32(u32::from_be_bytes([
@@ -119,10 +125,12 @@ pub(crate) fn parse_flags(flags: u16, v4: bool) -> FrameFlags {
 			(flags & 0x0040 == 0x0040, 0)
 		},
 		unsynchronisation: if v4 { flags & 0x0002 == 0x0002 } else { false },
+		// ID3v2.3 compressed frames always store their decompressed size, which is
+		// equivalent to an ID3v2.4 data length indicator
 		data_length_indicator: if v4 {
 			(flags & 0x0001 == 0x0001, 0)
 		} else {
-			(false, 0)
+			(flags & 0x0080 == 0x0080, 0)
 		},
 	}
 }
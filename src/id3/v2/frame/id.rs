@@ -4,6 +4,8 @@ use crate::tag::TagType;
 
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
 /// An `ID3v2` frame ID
+///
+/// This should be created with [`FrameID::new`], which will verify the ID. Any invalid ID will cause an error when writing.
 pub enum FrameID {
 	/// A valid `ID3v2.3/4` frame
 	Valid(String),
@@ -18,17 +20,33 @@ pub enum FrameID {
 impl FrameID {
 	/// Attempts to create a `FrameID` from an ID string
 	///
+	/// Lowercase characters will be converted to uppercase.
+	///
 	/// # Errors
 	///
 	/// * `id` contains invalid characters (must be 'A'..='Z' and '0'..='9')
 	/// * `id` is an invalid length (must be 3 or 4)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::FrameID;
+	///
+	/// assert_eq!(FrameID::new("tit2").unwrap(), FrameID::Valid(String::from("TIT2")));
+	/// assert_eq!(FrameID::new("TT2").unwrap(), FrameID::Outdated(String::from("TT2")));
+	///
+	/// assert!(FrameID::new("TIT").is_ok());
+	/// assert!(FrameID::new("TI-2").is_err());
+	/// assert!(FrameID::new("TITLE").is_err());
+	/// ```
 	pub fn new(id: &str) -> Result<Self> {
-		Self::verify_id(id)?;
+		let id = id.to_ascii_uppercase();
+		Self::verify_id(&id)?;
 
 		match id.len() {
-			3 => Ok(FrameID::Outdated(id.to_string())),
-			4 => Ok(FrameID::Valid(id.to_string())),
-			_ => Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID).into()),
+			3 => Ok(FrameID::Outdated(id)),
+			4 => Ok(FrameID::Valid(id)),
+			_ => Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID(id)).into()),
 		}
 	}
 
@@ -42,7 +60,7 @@ impl FrameID {
 	pub(crate) fn verify_id(id_str: &str) -> Result<()> {
 		for c in id_str.chars() {
 			if !('A'..='Z').contains(&c) && !('0'..='9').contains(&c) {
-				return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID).into());
+				return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID(id_str.to_string())).into());
 			}
 		}
 
@@ -63,10 +81,10 @@ impl TryFrom<ItemKey> for FrameID {
 			{
 				Ok(Self::Valid(unknown))
 			},
-			k => k.map_key(TagType::Id3v2, false).map_or(
-				Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID).into()),
-				|id| Ok(Self::Valid(id.to_string())),
-			),
+			k => match k.map_key(TagType::Id3v2, false) {
+				Some(id) => Ok(Self::Valid(id.to_string())),
+				None => Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID(format!("{:?}", k))).into()),
+			},
 		}
 	}
 }
@@ -5,8 +5,17 @@ pub(super) mod read;
 
 use super::util::text_utils::TextEncoding;
 use crate::error::{Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
+use crate::id3::v2::items::chapter::ChapterFrame;
+use crate::id3::v2::items::comment_frame::CommentFrame;
 use crate::id3::v2::items::encoded_text_frame::EncodedTextFrame;
-use crate::id3::v2::items::language_frame::LanguageFrame;
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
+use crate::id3::v2::items::private_frame::PrivateFrame;
+use crate::id3::v2::items::relative_volume_adjustment::RelativeVolumeAdjustmentFrame;
+use crate::id3::v2::items::table_of_contents::TableOfContentsFrame;
+use crate::id3::v2::items::unique_file_identifier::{
+	UniqueFileIdentifierFrame, MUSICBRAINZ_UFID_OWNER,
+};
+use crate::id3::v2::items::unsynchronized_text::UnsynchronizedTextFrame;
 use crate::id3::v2::util::text_utils::encode_text;
 use crate::id3::v2::util::upgrade::{upgrade_v2, upgrade_v3};
 use crate::id3::v2::Id3v2Version;
@@ -47,7 +56,7 @@ pub struct Frame {
 impl PartialEq for Frame {
 	fn eq(&self, other: &Self) -> bool {
 		match self.value {
-			FrameValue::Text { .. } => self.id == other.id,
+			FrameValue::Text { .. } | FrameValue::KeyValueList(_) => self.id == other.id,
 			_ => self.id == other.id && self.value == other.value,
 		}
 	}
@@ -56,7 +65,7 @@ impl PartialEq for Frame {
 impl Hash for Frame {
 	fn hash<H: Hasher>(&self, state: &mut H) {
 		match self.value {
-			FrameValue::Text { .. } => self.id.hash(state),
+			FrameValue::Text { .. } | FrameValue::KeyValueList(_) => self.id.hash(state),
 			_ => {
 				self.id.hash(state);
 				self.content().hash(state);
@@ -73,19 +82,21 @@ impl Frame {
 	/// # Errors
 	///
 	/// * `id` is less than 3 or greater than 4 bytes
-	/// * `id` contains non-ascii characters
+	/// * `id` contains characters outside of `'A'..='Z'` and `'0'..='9'` (lowercase characters will be converted to uppercase)
 	pub fn new(id: &str, value: FrameValue, flags: FrameFlags) -> Result<Self> {
+		let id = id.to_ascii_uppercase();
+
 		let id_updated = match id.len() {
 			// An ID with a length of 4 could be either V3 or V4.
-			4 => match upgrade_v3(id) {
-				None => id,
+			4 => match upgrade_v3(&id) {
+				None => &id,
 				Some(upgraded) => upgraded,
 			},
-			3 => match upgrade_v2(id) {
-				None => id,
+			3 => match upgrade_v2(&id) {
+				None => &id,
 				Some(upgraded) => upgraded,
 			},
-			_ => return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID).into()),
+			_ => return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID(id)).into()),
 		};
 
 		let id = FrameID::new(id_updated)?;
@@ -120,12 +131,12 @@ impl Frame {
 pub enum FrameValue {
 	/// Represents a "COMM" frame
 	///
-	/// Due to the amount of information needed, it is contained in a separate struct, [`LanguageFrame`]
-	Comment(LanguageFrame),
+	/// Due to the amount of information needed, it is contained in a separate struct, [`CommentFrame`]
+	Comment(CommentFrame),
 	/// Represents a "USLT" frame
 	///
-	/// Due to the amount of information needed, it is contained in a separate struct, [`LanguageFrame`]
-	UnSyncText(LanguageFrame),
+	/// Due to the amount of information needed, it is contained in a separate struct, [`UnsynchronizedTextFrame`]
+	UnSyncText(UnsynchronizedTextFrame),
 	/// Represents a "T..." (excluding TXXX) frame
 	///
 	/// NOTE: Text frame descriptions **must** be unique
@@ -139,6 +150,10 @@ pub enum FrameValue {
 	///
 	/// Due to the amount of information needed, it is contained in a separate struct, [`EncodedTextFrame`]
 	UserText(EncodedTextFrame),
+	/// Represents a "TIPL" or "TMCL" frame
+	///
+	/// Due to the amount of information needed, it is contained in a separate struct, [`KeyValueFrame`]
+	KeyValueList(KeyValueFrame),
 	/// Represents a "W..." (excluding WXXX) frame
 	///
 	/// NOTE: URL frame descriptions **must** be unique
@@ -158,6 +173,26 @@ pub enum FrameValue {
 	},
 	/// Represents a "POPM" frame
 	Popularimeter(Popularimeter),
+	/// Represents a "CHAP" frame
+	///
+	/// Due to the amount of information needed, it is contained in a separate struct, [`ChapterFrame`]
+	Chapter(ChapterFrame),
+	/// Represents a "CTOC" frame
+	///
+	/// Due to the amount of information needed, it is contained in a separate struct, [`TableOfContentsFrame`]
+	TableOfContents(TableOfContentsFrame),
+	/// Represents a "RVA2" frame
+	///
+	/// Due to the amount of information needed, it is contained in a separate struct, [`RelativeVolumeAdjustmentFrame`]
+	RelativeVolumeAdjustment(RelativeVolumeAdjustmentFrame),
+	/// Represents a "PRIV" frame
+	///
+	/// Due to the amount of information needed, it is contained in a separate struct, [`PrivateFrame`]
+	Private(PrivateFrame),
+	/// Represents a "UFID" frame
+	///
+	/// Due to the amount of information needed, it is contained in a separate struct, [`UniqueFileIdentifierFrame`]
+	UniqueFileIdentifier(UniqueFileIdentifierFrame),
 	/// Binary data
 	///
 	/// NOTES:
@@ -165,7 +200,7 @@ pub enum FrameValue {
 	/// * This is used for "GEOB" and "SYLT" frames, see
 	/// [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse) and [`SynchronizedText::parse`](crate::id3::v2::SynchronizedText::parse) respectively
 	/// * This is used for **all** frames with an ID of [`FrameID::Outdated`]
-	/// * This is used for unknown frames
+	/// * This is used for unknown frames, which will be written back unchanged (along with their [`FrameFlags`])
 	Binary(Vec<u8>),
 }
 
@@ -183,9 +218,10 @@ impl From<ItemValue> for FrameValue {
 }
 
 impl FrameValue {
-	pub(super) fn as_bytes(&self) -> Result<Vec<u8>> {
+	pub(super) fn as_bytes(&self, version: Id3v2Version) -> Result<Vec<u8>> {
 		Ok(match self {
-			FrameValue::Comment(lf) | FrameValue::UnSyncText(lf) => lf.as_bytes()?,
+			FrameValue::Comment(lf) => lf.as_bytes()?,
+			FrameValue::UnSyncText(uslt) => uslt.as_bytes()?,
 			FrameValue::Text { encoding, value } => {
 				let mut content = encode_text(value, *encoding, false);
 
@@ -193,11 +229,17 @@ impl FrameValue {
 				content
 			},
 			FrameValue::UserText(content) | FrameValue::UserURL(content) => content.as_bytes(),
+			FrameValue::KeyValueList(key_value) => key_value.as_bytes(),
 			FrameValue::URL(link) => link.as_bytes().to_vec(),
 			FrameValue::Picture { encoding, picture } => {
-				picture.as_apic_bytes(Id3v2Version::V4, *encoding)?
+				picture.as_apic_bytes(version, *encoding)?
 			},
 			FrameValue::Popularimeter(popularimeter) => popularimeter.as_bytes(),
+			FrameValue::Chapter(chapter) => chapter.as_bytes_for_version(version)?,
+			FrameValue::TableOfContents(toc) => toc.as_bytes_for_version(version)?,
+			FrameValue::RelativeVolumeAdjustment(rva2) => rva2.as_bytes()?,
+			FrameValue::Private(private) => private.as_bytes(),
+			FrameValue::UniqueFileIdentifier(ufid) => ufid.as_bytes()?,
 			FrameValue::Binary(binary) => binary.clone(),
 		})
 	}
@@ -227,23 +269,21 @@ pub struct FrameFlags {
 	/// NOTE: Since the encryption method is unknown, lofty cannot do anything with these frames
 	///
 	/// In addition to setting this flag, an encryption method symbol must be added.
-	/// The method symbol **must** be > 0x80.
+	/// The method symbol **must** be >= 0x80.
 	pub encryption: (bool, u8),
 	/// Frame is unsynchronised
 	///
 	/// In short, this makes all "0xFF X (X >= 0xE0)" combinations into "0xFF 0x00 X" to avoid confusion
 	/// with the MPEG frame header, which is often identified by its "frame sync" (11 set bits).
-	/// It is preferred an ID3v2 tag is either *completely* unsynchronised or not unsynchronised at all.
-	///
-	/// NOTE: While unsynchronized data is read, for the sake of simplicity, this flag has no effect when
-	/// writing. There isn't much reason to write unsynchronized data.
-	pub unsynchronisation: bool, /* TODO: Maybe? This doesn't seem very useful, and it is wasted effort if one forgets to make this false when writing. */
+	/// It is preferred an ID3v2 tag is either *completely* unsynchronised or not unsynchronised at all,
+	/// see [`Id3v2TagFlags::unsynchronisation`](crate::id3::v2::Id3v2TagFlags::unsynchronisation).
+	pub unsynchronisation: bool,
 	/// Frame has a data length indicator
 	///
 	/// The data length indicator is the size of the frame if the flags were all zeroed out.
 	/// This is usually used in combination with `compression` and `encryption` (depending on encryption method).
 	///
-	/// If using `encryption`, the final size must be added.
+	/// If using `encryption`, the final size must be added. Otherwise, the size will be calculated when writing.
 	pub data_length_indicator: (bool, u32),
 }
 
@@ -251,25 +291,23 @@ impl TryFrom<TagItem> for Frame {
 	type Error = LoftyError;
 
 	fn try_from(value: TagItem) -> std::prelude::rust_2015::Result<Self, Self::Error> {
+		if let Some(frame) = user_text_frame(&value)
+			.or_else(|| involved_people_frame(&value))
+			.or_else(|| described_comment_frame(&value))
+			.or_else(|| unique_file_identifier_frame(&value))
+		{
+			return Ok(frame);
+		}
+
 		let id: FrameID = value.item_key.try_into()?;
 
 		// We make the VERY bold assumption the language is English
 		let value = match (&id, value.item_value) {
 			(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "COMM" => {
-				FrameValue::Comment(LanguageFrame {
-					encoding: TextEncoding::UTF8,
-					language: String::from("eng"),
-					description: String::new(),
-					content: text,
-				})
+				FrameValue::Comment(CommentFrame::new(*b"eng", text))
 			},
 			(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "USLT" => {
-				FrameValue::UnSyncText(LanguageFrame {
-					encoding: TextEncoding::UTF8,
-					language: String::from("eng"),
-					description: String::new(),
-					content: text,
-				})
+				FrameValue::UnSyncText(UnsynchronizedTextFrame::new(*b"eng", text))
 			},
 			(FrameID::Valid(ref s), ItemValue::Locator(text) | ItemValue::Text(text))
 				if s == "WXXX" =>
@@ -280,6 +318,12 @@ impl TryFrom<TagItem> for Frame {
 					content: text,
 				})
 			},
+			(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "TIPL" || s == "TMCL" => {
+				FrameValue::KeyValueList(KeyValueFrame::from_null_separated(
+					TextEncoding::UTF8,
+					&text,
+				))
+			},
 			(FrameID::Valid(ref s), ItemValue::Text(text)) if s == "TXXX" => {
 				FrameValue::UserText(EncodedTextFrame {
 					encoding: TextEncoding::UTF8,
@@ -287,6 +331,12 @@ impl TryFrom<TagItem> for Frame {
 					content: text,
 				})
 			},
+			(FrameID::Valid(ref s), ItemValue::Text(flag)) if s == "PCST" => podcast_flag(&flag)?,
+			// "WFED" is a text frame, despite its ID
+			(FrameID::Valid(ref s), ItemValue::Locator(url)) if s == "WFED" => FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: url,
+			},
 			(_, value) => value.into(),
 		};
 
@@ -298,6 +348,85 @@ impl TryFrom<TagItem> for Frame {
 	}
 }
 
+// "PCST" is always written as 4 zero bytes (as iTunes does), its presence alone marks the file as a podcast
+fn podcast_flag(flag: &str) -> Result<FrameValue> {
+	if flag == "1" || flag.eq_ignore_ascii_case("true") {
+		return Ok(FrameValue::Binary(vec![0; 4]));
+	}
+
+	Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+		"Podcast flag is not set, there is no frame to write",
+	))
+	.into())
+}
+
+// Some keys have no dedicated frame, and are instead stored in "TXXX" frames
+fn user_text_frame(tag_item: &TagItem) -> Option<Frame> {
+	let description = tag_item.key().id3v2_user_text_description()?;
+
+	match tag_item.value() {
+		ItemValue::Text(text) => Some(Frame {
+			id: FrameID::Valid(String::from("TXXX")),
+			value: FrameValue::UserText(EncodedTextFrame {
+				encoding: TextEncoding::UTF8,
+				description: String::from(description),
+				content: text.clone(),
+			}),
+			flags: FrameFlags::default(),
+		}),
+		_ => None,
+	}
+}
+
+// Some keys are stored as roles in a "TIPL" frame
+fn involved_people_frame(tag_item: &TagItem) -> Option<Frame> {
+	let role = tag_item.key().id3v2_involved_people_role()?;
+
+	match tag_item.value() {
+		ItemValue::Text(text) => Some(Frame {
+			id: FrameID::Valid(String::from("TIPL")),
+			value: FrameValue::KeyValueList(KeyValueFrame {
+				encoding: TextEncoding::UTF8,
+				key_value_pairs: vec![(String::from(role), text.clone())],
+			}),
+			flags: FrameFlags::default(),
+		}),
+		_ => None,
+	}
+}
+
+// Comments with a description are stored as `ItemKey::Unknown("COMM:<description>")`
+fn described_comment_frame(tag_item: &TagItem) -> Option<Frame> {
+	let description = tag_item.key().id3v2_comment_description()?;
+
+	match tag_item.value() {
+		ItemValue::Text(text) => Some(Frame {
+			id: FrameID::Valid(String::from("COMM")),
+			value: FrameValue::Comment(CommentFrame {
+				description: String::from(description),
+				..CommentFrame::new(*b"eng", text.clone())
+			}),
+			flags: FrameFlags::default(),
+		}),
+		_ => None,
+	}
+}
+
+// MusicBrainz recording IDs are stored in a "UFID" frame
+fn unique_file_identifier_frame(tag_item: &TagItem) -> Option<Frame> {
+	match (tag_item.key(), tag_item.value()) {
+		(ItemKey::MusicBrainzRecordingId, ItemValue::Text(text)) => Some(Frame {
+			id: FrameID::Valid(String::from("UFID")),
+			value: FrameValue::UniqueFileIdentifier(UniqueFileIdentifierFrame {
+				owner: String::from(MUSICBRAINZ_UFID_OWNER),
+				identifier: text.as_bytes().to_vec(),
+			}),
+			flags: FrameFlags::default(),
+		}),
+		_ => None,
+	}
+}
+
 pub(crate) struct FrameRef<'a> {
 	pub id: &'a str,
 	pub value: Cow<'a, FrameValue>,
@@ -322,6 +451,38 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 	type Error = LoftyError;
 
 	fn try_from(tag_item: &'a TagItem) -> std::result::Result<Self, Self::Error> {
+		if let Some(frame) = user_text_frame(tag_item) {
+			return Ok(FrameRef {
+				id: "TXXX",
+				value: Cow::Owned(frame.value),
+				flags: frame.flags,
+			});
+		}
+
+		if let Some(frame) = involved_people_frame(tag_item) {
+			return Ok(FrameRef {
+				id: "TIPL",
+				value: Cow::Owned(frame.value),
+				flags: frame.flags,
+			});
+		}
+
+		if let Some(frame) = described_comment_frame(tag_item) {
+			return Ok(FrameRef {
+				id: "COMM",
+				value: Cow::Owned(frame.value),
+				flags: frame.flags,
+			});
+		}
+
+		if let Some(frame) = unique_file_identifier_frame(tag_item) {
+			return Ok(FrameRef {
+				id: "UFID",
+				value: Cow::Owned(frame.value),
+				flags: frame.flags,
+			});
+		}
+
 		let id = match tag_item.key() {
 			ItemKey::Unknown(unknown) if unknown.len() == 4 => {
 				id::FrameID::verify_id(unknown)?;
@@ -329,24 +490,18 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 			},
 			k => k
 				.map_key(TagType::Id3v2, false)
-				.ok_or_else(|| Id3v2Error::new(Id3v2ErrorKind::BadFrameID)),
+				.ok_or_else(|| Id3v2Error::new(Id3v2ErrorKind::BadFrameID(format!("{:?}", k)))),
 		}?;
 
 		Ok(FrameRef {
 			id,
 			value: Cow::Owned(match (id, tag_item.value()) {
-				("COMM", ItemValue::Text(text)) => FrameValue::Comment(LanguageFrame {
-					encoding: TextEncoding::UTF8,
-					language: String::from("eng"),
-					description: String::new(),
-					content: text.clone(),
-				}),
-				("USLT", ItemValue::Text(text)) => FrameValue::UnSyncText(LanguageFrame {
-					encoding: TextEncoding::UTF8,
-					language: String::from("eng"),
-					description: String::new(),
-					content: text.clone(),
-				}),
+				("COMM", ItemValue::Text(text)) => {
+					FrameValue::Comment(CommentFrame::new(*b"eng", text.clone()))
+				},
+				("USLT", ItemValue::Text(text)) => {
+					FrameValue::UnSyncText(UnsynchronizedTextFrame::new(*b"eng", text.clone()))
+				},
 				("WXXX", ItemValue::Locator(text) | ItemValue::Text(text)) => {
 					FrameValue::UserURL(EncodedTextFrame {
 						encoding: TextEncoding::UTF8,
@@ -354,11 +509,19 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 						content: text.clone(),
 					})
 				},
+				("TIPL" | "TMCL", ItemValue::Text(text)) => FrameValue::KeyValueList(
+					KeyValueFrame::from_null_separated(TextEncoding::UTF8, text),
+				),
 				("TXXX", ItemValue::Text(text)) => FrameValue::UserText(EncodedTextFrame {
 					encoding: TextEncoding::UTF8,
 					description: String::new(),
 					content: text.clone(),
 				}),
+				("PCST", ItemValue::Text(flag)) => podcast_flag(flag)?,
+				("WFED", ItemValue::Locator(url)) => FrameValue::Text {
+					encoding: TextEncoding::UTF8,
+					value: url.clone(),
+				},
 				(_, value) => value.into(),
 			}),
 			flags: FrameFlags::default(),
@@ -1,16 +1,33 @@
 use super::header::{parse_header, parse_v2_header};
-use super::Frame;
-use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use super::{Frame, FrameFlags};
+use crate::error::{ErrorKind, Id3v2Error, Id3v2ErrorKind, LoftyError, Result, WarningCategory};
 use crate::id3::v2::frame::content::parse_content;
-use crate::id3::v2::{FrameValue, Id3v2Version};
+use crate::id3::v2::{unsynch_u32, FrameID, FrameValue, Id3v2Version};
 use crate::macros::try_vec;
+use crate::probe::ParseContext;
 
 use std::io::Read;
 
 use byteorder::{BigEndian, ReadBytesExt};
+use flate2::read::ZlibDecoder;
+
+pub(crate) enum ParsedFrame {
+	Next(Frame),
+	// The frame was read, but its content is unusable
+	Skip,
+	// The frame was read, but its content failed to parse
+	//
+	// Since the entire frame was read, it's possible to continue to the next one
+	Corrupt(FrameID, LoftyError),
+	Eof,
+}
 
 impl Frame {
-	pub(crate) fn read<R>(reader: &mut R, version: Id3v2Version) -> Result<Option<Self>>
+	pub(crate) fn read<R>(
+		reader: &mut R,
+		version: Id3v2Version,
+		context: &mut ParseContext,
+	) -> Result<ParsedFrame>
 	where
 		R: Read,
 	{
@@ -20,60 +37,139 @@ impl Frame {
 			Id3v2Version::V3 => parse_header(reader, false)?,
 			Id3v2Version::V4 => parse_header(reader, true)?,
 		} {
-			None => return Ok(None),
+			None => return Ok(ParsedFrame::Eof),
 			Some(frame_header) => frame_header,
 		};
 
 		let mut content = try_vec![0; size as usize];
 		reader.read_exact(&mut content)?;
 
-		if flags.unsynchronisation {
-			content = crate::id3::v2::util::unsynch_content(content.as_slice())?;
+		match decode_content(content, id.as_str(), &mut flags, version, context) {
+			Ok(Some(value)) => Ok(ParsedFrame::Next(Self { id, value, flags })),
+			Ok(None) => Ok(ParsedFrame::Skip),
+			Err(err) => Ok(ParsedFrame::Corrupt(id, err)),
 		}
+	}
+}
 
-		if flags.compression {
-			let mut decompressed = Vec::new();
-			flate2::Decompress::new(true)
-				.decompress_vec(&content, &mut decompressed, flate2::FlushDecompress::None)
-				.map_err(|_| {
-					Id3v2Error::new(Id3v2ErrorKind::Other(
-						"Encountered a compressed frame, failed to decompress",
-					))
-				})?;
-
-			content = decompressed
-		}
+// Decodes the content of a frame, returning `None` if it's unusable
+fn decode_content(
+	mut content: Vec<u8>,
+	id: &str,
+	flags: &mut FrameFlags,
+	version: Id3v2Version,
+	context: &mut ParseContext,
+) -> Result<Option<FrameValue>> {
+	let additions_len = read_header_additions(&mut &*content, flags, version)?;
+	content.drain(..additions_len);
 
-		let mut content_reader = &*content;
+	if flags.unsynchronisation {
+		content = crate::id3::v2::util::unsynch_content(content.as_slice())?;
+	}
 
-		// Get the encryption method symbol
-		if flags.encryption.0 {
-			flags.encryption.1 = content_reader.read_u8()?;
+	// Encrypted frames are compressed prior to encryption, so there's nothing we can do with them
+	if flags.compression && !flags.encryption.0 {
+		match decompress(&content, *flags) {
+			Ok(decompressed) => content = decompressed,
+			// Exceeding the allocation limit is not a sign of corruption
+			Err(err) if matches!(err.kind(), ErrorKind::TooMuchData | ErrorKind::Alloc(_)) => {
+				return Err(err)
+			},
+			// The frame is likely corrupt, there's no reason to fail the entire tag
+			Err(_) => {
+				context.warn(
+					WarningCategory::Tag,
+					format!(
+						"Skipping \"{}\" frame, failed to decompress its content",
+						id
+					),
+				);
+				return Ok(None);
+			},
 		}
+	}
+
+	if flags.encryption.0 {
+		if !flags.data_length_indicator.0 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+				"Encountered an encrypted frame without a data length indicator",
+			))
+			.into());
+		}
+
+		return Ok(Some(FrameValue::Binary(content)));
+	}
+
+	parse_content(&mut &*content, id, version, context).map(Some)
+}
+
+// The data length indicator is the size of the content after decompression
+fn decompress(content: &[u8], flags: FrameFlags) -> Result<Vec<u8>> {
+	let mut decoder = ZlibDecoder::new(content);
+
+	let decompressed = match flags.data_length_indicator {
+		(true, len) => {
+			let mut decompressed = try_vec![0; len as usize];
+			decoder.read_exact(&mut decompressed)?;
+			decompressed
+		},
+		// A data length indicator is required, but that doesn't stop some encoders from leaving it out.
+		// Without it, the content can decompress to any size, so it has to be limited.
+		(false, _) => {
+			let max_allocation = crate::max_allocation();
+
+			let mut decompressed = Vec::new();
+			decoder
+				.take((max_allocation as u64).saturating_add(1))
+				.read_to_end(&mut decompressed)?;
 
-		// Get the group identifier
+			if decompressed.len() > max_allocation {
+				return Err(LoftyError::new(ErrorKind::TooMuchData));
+			}
+
+			decompressed
+		},
+	};
+
+	Ok(decompressed)
+}
+
+// Reads the information added after the frame header, returning the number of bytes read
+//
+// ID3v2.4 stores these in the same order as the flags (group identifier, encryption method, data length indicator),
+// while ID3v2.3 stores the decompressed size first, followed by the encryption method and group identifier.
+fn read_header_additions(
+	content: &mut &[u8],
+	flags: &mut FrameFlags,
+	version: Id3v2Version,
+) -> Result<usize> {
+	let start_len = content.len();
+
+	if version == Id3v2Version::V4 {
 		if flags.grouping_identity.0 {
-			flags.grouping_identity.1 = content_reader.read_u8()?;
+			flags.grouping_identity.1 = content.read_u8()?;
 		}
 
-		// Get the real data length
-		if flags.data_length_indicator.0 {
-			flags.data_length_indicator.1 = content_reader.read_u32::<BigEndian>()?;
+		if flags.encryption.0 {
+			flags.encryption.1 = content.read_u8()?;
 		}
 
-		let value = if flags.encryption.0 {
-			if !flags.data_length_indicator.0 {
-				return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
-					"Encountered an encrypted frame without a data length indicator",
-				))
-				.into());
-			}
+		if flags.data_length_indicator.0 {
+			flags.data_length_indicator.1 = unsynch_u32(content.read_u32::<BigEndian>()?);
+		}
+	} else {
+		if flags.data_length_indicator.0 {
+			flags.data_length_indicator.1 = content.read_u32::<BigEndian>()?;
+		}
 
-			FrameValue::Binary(content)
-		} else {
-			parse_content(&mut content_reader, id.as_str(), version)?
-		};
+		if flags.encryption.0 {
+			flags.encryption.1 = content.read_u8()?;
+		}
 
-		Ok(Some(Self { id, value, flags }))
+		if flags.grouping_identity.0 {
+			flags.grouping_identity.1 = content.read_u8()?;
+		}
 	}
+
+	Ok(start_len - content.len())
 }
@@ -0,0 +1,267 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::frame::read::ParsedFrame;
+use crate::id3::v2::frame::Frame;
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
+use crate::id3::v2::write::frame::create_items;
+use crate::id3::v2::Id3v2Version;
+use crate::probe::ParseContext;
+
+use std::hash::{Hash, Hasher};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+// Used in the start and end offsets to signify that the offset is unknown
+const UNKNOWN_OFFSET: u32 = u32::MAX;
+
+/// The contents of a chapter ("CHAP") frame
+///
+/// A chapter describes a segment of the audio, and may contain any number of
+/// embedded frames describing it, usually a title ("TIT2") and/or a picture ("APIC").
+///
+/// A tag can contain multiple "CHAP" frames, but each must have a unique element ID.
+#[derive(Clone, Debug, Eq)]
+pub struct ChapterFrame {
+	/// A unique identifier for the chapter, used by [`TableOfContentsFrame`](crate::id3::v2::TableOfContentsFrame)s
+	pub element_id: String,
+	/// The start of the chapter in milliseconds
+	pub start_time: u32,
+	/// The end of the chapter in milliseconds
+	pub end_time: u32,
+	/// The byte offset of the chapter's first audio frame from the start of the file
+	///
+	/// This is `None` if the offset is unknown, in which case [`ChapterFrame::start_time`] should be used.
+	pub start_offset: Option<u32>,
+	/// The byte offset of the chapter's last audio frame from the start of the file
+	///
+	/// This is `None` if the offset is unknown, in which case [`ChapterFrame::end_time`] should be used.
+	pub end_offset: Option<u32>,
+	/// Frames describing the chapter
+	pub embedded_frames: Vec<Frame>,
+}
+
+impl ChapterFrame {
+	/// Read a [`ChapterFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is too short to contain the element ID and times
+	/// * Any of the embedded frames are invalid
+	pub fn parse(data: &[u8], version: Id3v2Version) -> Result<Self> {
+		Self::read(data, version, &mut ParseContext::default())
+	}
+
+	// Warnings from the embedded frames belong to the read that they're a part of
+	pub(in crate::id3::v2) fn read(
+		data: &[u8],
+		version: Id3v2Version,
+		context: &mut ParseContext,
+	) -> Result<Self> {
+		// Element ID terminator (1) + times (8) + offsets (8)
+		if data.len() < 17 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let reader = &mut &*data;
+
+		let element_id = decode_text(reader, TextEncoding::Latin1, true)?.unwrap_or_default();
+
+		let start_time = reader.read_u32::<BigEndian>()?;
+		let end_time = reader.read_u32::<BigEndian>()?;
+		let start_offset = read_offset(reader)?;
+		let end_offset = read_offset(reader)?;
+
+		let embedded_frames = read_embedded_frames(reader, version, context)?;
+
+		Ok(Self {
+			element_id,
+			start_time,
+			end_time,
+			start_offset,
+			end_offset,
+			embedded_frames,
+		})
+	}
+
+	/// Convert a [`ChapterFrame`] into an ID3v2 CHAP frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	///
+	/// # Errors
+	///
+	/// * Any of the embedded frames are invalid, see [`Id3v2Tag::save_to`](crate::TagExt::save_to)
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		self.as_bytes_for_version(Id3v2Version::V4)
+	}
+
+	// The embedded frames need to be written in the same version as the tag
+	pub(in crate::id3::v2) fn as_bytes_for_version(
+		&self,
+		version: Id3v2Version,
+	) -> Result<Vec<u8>> {
+		let mut content = encode_text(&self.element_id, TextEncoding::Latin1, true);
+
+		content.extend(self.start_time.to_be_bytes());
+		content.extend(self.end_time.to_be_bytes());
+		content.extend(self.start_offset.unwrap_or(UNKNOWN_OFFSET).to_be_bytes());
+		content.extend(self.end_offset.unwrap_or(UNKNOWN_OFFSET).to_be_bytes());
+
+		write_embedded_frames(&mut content, &self.embedded_frames, version)?;
+
+		Ok(content)
+	}
+}
+
+impl PartialEq for ChapterFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.element_id == other.element_id
+	}
+}
+
+impl Hash for ChapterFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.element_id.hash(state);
+	}
+}
+
+fn read_offset(reader: &mut &[u8]) -> Result<Option<u32>> {
+	let offset = reader.read_u32::<BigEndian>()?;
+
+	if offset == UNKNOWN_OFFSET {
+		return Ok(None);
+	}
+
+	Ok(Some(offset))
+}
+
+// Both CHAP and CTOC frames end with embedded frames, which extend to the end of the frame
+pub(in crate::id3::v2) fn read_embedded_frames(
+	reader: &mut &[u8],
+	version: Id3v2Version,
+	context: &mut ParseContext,
+) -> Result<Vec<Frame>> {
+	let mut frames = Vec::new();
+
+	loop {
+		// Chapters don't nest, and parsing an embedded CHAP/CTOC would recurse without limit
+		if let Some(b"CHAP" | b"CTOC") = reader.get(..4) {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+				"Found a CHAP/CTOC frame embedded in another CHAP/CTOC frame",
+			))
+			.into());
+		}
+
+		match Frame::read(reader, version, context)? {
+			ParsedFrame::Next(frame) => frames.push(frame),
+			ParsedFrame::Skip => {},
+			// A corrupt embedded frame fails the entire CHAP/CTOC frame
+			ParsedFrame::Corrupt(_, err) => return Err(err),
+			ParsedFrame::Eof => break,
+		}
+	}
+
+	Ok(frames)
+}
+
+pub(in crate::id3::v2) fn write_embedded_frames(
+	content: &mut Vec<u8>,
+	frames: &[Frame],
+	version: Id3v2Version,
+) -> Result<()> {
+	create_items(
+		content,
+		&mut frames.iter().filter_map(Frame::as_opt_ref),
+		version,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{
+		ChapterFrame, Frame, FrameFlags, FrameID, FrameValue, Id3v2Version, TextEncoding,
+	};
+
+	fn title_frame(title: &str) -> Frame {
+		Frame {
+			id: FrameID::Valid(String::from("TIT2")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::Latin1,
+				value: String::from(title),
+			},
+			flags: FrameFlags::default(),
+		}
+	}
+
+	#[test]
+	fn chap_decode() {
+		let mut data = b"chp0\0".to_vec();
+		data.extend(0_u32.to_be_bytes());
+		data.extend(5000_u32.to_be_bytes());
+		data.extend(u32::MAX.to_be_bytes());
+		data.extend(1024_u32.to_be_bytes());
+
+		// TIT2 frame, ID3v2.4
+		data.extend(b"TIT2\0\0\0\x06\0\0\0Intro");
+
+		let chapter = ChapterFrame::parse(&data, Id3v2Version::V4).unwrap();
+
+		assert_eq!(chapter.element_id, "chp0");
+		assert_eq!(chapter.start_time, 0);
+		assert_eq!(chapter.end_time, 5000);
+		assert_eq!(chapter.start_offset, None);
+		assert_eq!(chapter.end_offset, Some(1024));
+		assert_eq!(chapter.embedded_frames, vec![title_frame("Intro")]);
+	}
+
+	#[test]
+	fn chap_re_read() {
+		let chapter = ChapterFrame {
+			element_id: String::from("chp1"),
+			start_time: 5000,
+			end_time: 10000,
+			start_offset: Some(2048),
+			end_offset: None,
+			embedded_frames: vec![title_frame("Verse")],
+		};
+
+		let bytes = chapter.as_bytes().unwrap();
+		let parsed = ChapterFrame::parse(&bytes, Id3v2Version::V4).unwrap();
+
+		assert_eq!(parsed.element_id, chapter.element_id);
+		assert_eq!(parsed.start_time, chapter.start_time);
+		assert_eq!(parsed.end_time, chapter.end_time);
+		assert_eq!(parsed.start_offset, chapter.start_offset);
+		assert_eq!(parsed.end_offset, chapter.end_offset);
+		assert_eq!(parsed.embedded_frames, chapter.embedded_frames);
+	}
+
+	#[test]
+	fn chap_too_short() {
+		assert!(ChapterFrame::parse(b"chp0\0\0\0\0\0", Id3v2Version::V4).is_err());
+	}
+
+	#[test]
+	fn chap_nested() {
+		let mut chapter = ChapterFrame {
+			element_id: String::from("chp0"),
+			start_time: 0,
+			end_time: 5000,
+			start_offset: None,
+			end_offset: None,
+			embedded_frames: vec![title_frame("Intro")],
+		};
+
+		for _ in 0..2 {
+			let nested = chapter.clone();
+			chapter.embedded_frames = vec![Frame {
+				id: FrameID::Valid(String::from("CHAP")),
+				value: FrameValue::Chapter(nested),
+				flags: FrameFlags::default(),
+			}];
+		}
+
+		let bytes = chapter.as_bytes().unwrap();
+		assert!(ChapterFrame::parse(&bytes, Id3v2Version::V4).is_err());
+	}
+}
@@ -0,0 +1,140 @@
+use crate::error::Result;
+use crate::id3::v2::items::language_text::{self, LanguageText};
+use crate::id3::v2::util::text_utils::TextEncoding;
+use crate::id3::v2::Id3v2Version;
+
+use std::hash::{Hash, Hasher};
+
+/// The contents of a comment ("COMM") frame
+///
+/// A tag can contain multiple "COMM" frames, but there must only be
+/// one with the same language and description.
+///
+/// NOTE: Only a comment with an empty description will be converted to
+/// [`ItemKey::Comment`](crate::ItemKey::Comment) when converting to a [`Tag`](crate::Tag).
+/// Other comments, such as iTunes' "iTunNORM", are application specific.
+#[derive(Clone, Debug, Eq)]
+pub struct CommentFrame {
+	/// The encoding of the description and content
+	///
+	/// NOTE: When writing, [`TextEncoding::Latin1`] will be upgraded to [`TextEncoding::UTF16`]
+	/// if the description or content contain characters outside of the Latin-1 range.
+	pub encoding: TextEncoding,
+	/// ISO-639-2 language code (3 bytes)
+	pub language: [u8; 3],
+	/// Unique content description
+	pub description: String,
+	/// The comment itself
+	pub content: String,
+}
+
+impl PartialEq for CommentFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.language == other.language && self.description == other.description
+	}
+}
+
+impl Hash for CommentFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.language.hash(state);
+		self.description.hash(state);
+	}
+}
+
+impl CommentFrame {
+	/// Create a new [`CommentFrame`] with no description
+	///
+	/// This will use [`TextEncoding::Latin1`] if possible, otherwise [`TextEncoding::UTF16`].
+	pub fn new(language: [u8; 3], content: String) -> Self {
+		Self {
+			encoding: language_text::encoding_for(&content),
+			language,
+			description: String::new(),
+			content,
+		}
+	}
+
+	/// Read a [`CommentFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is too short to contain the encoding and language
+	/// * The encoding is invalid (or not supported by `version`)
+	/// * The description or content are not valid for the encoding
+	pub fn parse(data: &[u8], version: Id3v2Version) -> Result<Self> {
+		let LanguageText {
+			encoding,
+			language,
+			description,
+			content,
+		} = language_text::parse(data, version)?;
+
+		Ok(Self {
+			encoding,
+			language,
+			description,
+			content,
+		})
+	}
+
+	/// Convert a [`CommentFrame`] into an ID3v2 COMM frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	///
+	/// # Errors
+	///
+	/// * `language` contains non-alphabetic characters
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		language_text::as_bytes(
+			self.encoding,
+			self.language,
+			&self.description,
+			&self.content,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{CommentFrame, Id3v2Version, TextEncoding};
+
+	#[test]
+	fn comm_decode() {
+		let data = b"\x00engID3v1 Comment\0Foo comment";
+
+		let comm = CommentFrame::parse(data, Id3v2Version::V4).unwrap();
+
+		assert_eq!(comm.encoding, TextEncoding::Latin1);
+		assert_eq!(&comm.language, b"eng");
+		assert_eq!(comm.description, "ID3v1 Comment");
+		assert_eq!(comm.content, "Foo comment");
+
+		// Only the encoding and language are required
+		let comm = CommentFrame::parse(b"\x00eng\0", Id3v2Version::V4).unwrap();
+		assert!(comm.description.is_empty());
+		assert!(comm.content.is_empty());
+
+		assert!(CommentFrame::parse(b"\x00en", Id3v2Version::V4).is_err());
+	}
+
+	#[test]
+	fn comm_encode() {
+		let comm = CommentFrame::new(*b"eng", String::from("Foo comment"));
+		assert_eq!(comm.encoding, TextEncoding::Latin1);
+		assert_eq!(comm.as_bytes().unwrap(), b"\x00eng\0Foo comment");
+
+		let comm = CommentFrame {
+			description: String::from("ID3v1 Comment"),
+			..comm
+		};
+
+		let parsed = CommentFrame::parse(&comm.as_bytes().unwrap(), Id3v2Version::V4).unwrap();
+		assert_eq!(parsed.description, "ID3v1 Comment");
+		assert_eq!(parsed.content, "Foo comment");
+
+		let bad_language = CommentFrame::new(*b"e\0g", String::from("Foo comment"));
+		assert!(bad_language.as_bytes().is_err());
+	}
+}
@@ -1,7 +1,9 @@
 use crate::error::{ErrorKind, Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
 use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
 
-use std::io::{Cursor, Read};
+use std::fs::File;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
 
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
 /// Information about a [`GeneralEncapsulatedObject`]
@@ -85,6 +87,56 @@ impl GeneralEncapsulatedObject {
 
 		bytes
 	}
+
+	/// The object's mimetype
+	pub fn mime_type(&self) -> Option<&str> {
+		self.information.mime_type.as_deref()
+	}
+
+	/// The object's file name
+	pub fn file_name(&self) -> Option<&str> {
+		self.information.file_name.as_deref()
+	}
+
+	/// The object's content descriptor
+	pub fn description(&self) -> Option<&str> {
+		self.information.descriptor.as_deref()
+	}
+
+	/// The object's content
+	pub fn data(&self) -> &[u8] {
+		&self.data
+	}
+
+	/// Writes the object's content to a file, creating or truncating it
+	///
+	/// NOTE: The path is used as-is, the object's file name is available through [`GeneralEncapsulatedObject::file_name`]
+	///
+	/// # Errors
+	///
+	/// * [`std::io::Error`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// # use lofty::LoftyError;
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::id3::v2::GeneralEncapsulatedObject;
+	///
+	/// # let geob_bytes = [0, b'\0', b'\0', b'\0'];
+	/// let geob = GeneralEncapsulatedObject::parse(&geob_bytes)?;
+	///
+	/// let file_name = geob.file_name().unwrap_or("object.bin").to_owned();
+	/// geob.into_file(file_name)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn into_file<P: AsRef<Path>>(self, path: P) -> Result<()> {
+		let mut file = File::create(path)?;
+		file.write_all(&self.data)?;
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -133,4 +185,23 @@ mod tests {
 
 		assert_eq!(encoded, expected_bytes);
 	}
+
+	#[test]
+	fn geob_accessors() {
+		let cont = crate::tag::utils::test_utils::read_path("tests/tags/assets/id3v2/test.geob");
+		let geob = GeneralEncapsulatedObject::parse(&*cont).unwrap();
+
+		assert_eq!(geob.mime_type(), Some("audio/mpeg"));
+		assert_eq!(geob.file_name(), Some("a.mp3"));
+		assert_eq!(geob.description(), Some("Test Asset"));
+
+		let expected_data =
+			crate::tag::utils::test_utils::read_path("tests/files/assets/minimal/full_test.mp3");
+		assert_eq!(geob.data(), &*expected_data);
+
+		let temp_file = tempfile::NamedTempFile::new().unwrap();
+
+		geob.into_file(temp_file.path()).unwrap();
+		assert_eq!(std::fs::read(temp_file.path()).unwrap(), expected_data);
+	}
 }
@@ -0,0 +1,174 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::frame::content::verify_encoding;
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
+use crate::id3::v2::Id3v2Version;
+
+use byteorder::ReadBytesExt;
+
+/// The contents of an involved people list ("TIPL") or musician credits list ("TMCL") frame
+///
+/// Both frames store a list of key/value pairs, mapping a role (or instrument) to a name.
+///
+/// NOTE: When converting to a [`Tag`](crate::Tag), the roles in a "TIPL" frame with an equivalent
+/// [`ItemKey`](crate::ItemKey) (Ex. "producer" => [`ItemKey::Producer`](crate::ItemKey::Producer)) will be split
+/// into their own items. The rest are stored as null separated pairs.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyValueFrame {
+	/// The encoding of the keys and values
+	pub encoding: TextEncoding,
+	/// The key/value pairs, such as `("producer", "Foo")`
+	pub key_value_pairs: Vec<(String, String)>,
+}
+
+impl KeyValueFrame {
+	/// Read a [`KeyValueFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is empty
+	/// * The encoding is invalid for the version
+	/// * Unable to decode the text
+	pub fn parse(data: &[u8], version: Id3v2Version) -> Result<Self> {
+		if data.is_empty() {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let reader = &mut &*data;
+
+		let encoding = verify_encoding(reader.read_u8()?, version)?;
+
+		let mut key_value_pairs = Vec::new();
+		while !reader.is_empty() {
+			let key = decode_text(reader, encoding, true)?.unwrap_or_default();
+			let value = decode_text(reader, encoding, true)?.unwrap_or_default();
+
+			key_value_pairs.push((key, value));
+		}
+
+		Ok(Self {
+			encoding,
+			key_value_pairs,
+		})
+	}
+
+	/// Convert a [`KeyValueFrame`] into an ID3v2 TIPL/TMCL frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = vec![self.encoding as u8];
+
+		for (key, value) in &self.key_value_pairs {
+			content.append(&mut encode_text(key, self.encoding, true));
+			content.append(&mut encode_text(value, self.encoding, true));
+		}
+
+		content
+	}
+
+	// "key\0value\0key\0value" => [(key, value), (key, value)]
+	//
+	// A trailing key with no value is kept, with an empty value. An empty string has no pairs.
+	pub(crate) fn from_null_separated(encoding: TextEncoding, text: &str) -> Self {
+		let mut key_value_pairs = Vec::new();
+
+		if !text.is_empty() {
+			let mut split = text.split('\0');
+			while let Some(key) = split.next() {
+				let value = split.next().unwrap_or_default();
+				key_value_pairs.push((String::from(key), String::from(value)));
+			}
+		}
+
+		Self {
+			encoding,
+			key_value_pairs,
+		}
+	}
+
+	// The inverse of `from_null_separated`
+	pub(crate) fn to_null_separated(&self) -> String {
+		let mut text = String::new();
+
+		for (key, value) in &self.key_value_pairs {
+			if !text.is_empty() {
+				text.push('\0');
+			}
+
+			text.push_str(key);
+			text.push('\0');
+			text.push_str(value);
+		}
+
+		text
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{Id3v2Version, KeyValueFrame, TextEncoding};
+
+	fn pairs() -> Vec<(String, String)> {
+		vec![
+			(String::from("producer"), String::from("Foo")),
+			(String::from("engineer"), String::from("Bar")),
+			(String::from("arranger"), String::from("Baz")),
+		]
+	}
+
+	#[test]
+	fn tipl_round_trip() {
+		for encoding in [
+			TextEncoding::Latin1,
+			TextEncoding::UTF16,
+			TextEncoding::UTF16BE,
+			TextEncoding::UTF8,
+		] {
+			let frame = KeyValueFrame {
+				encoding,
+				key_value_pairs: pairs(),
+			};
+
+			let bytes = frame.as_bytes();
+			assert_eq!(bytes[0], encoding as u8);
+
+			let parsed = KeyValueFrame::parse(&bytes, Id3v2Version::V4).unwrap();
+			assert_eq!(parsed, frame);
+		}
+	}
+
+	#[test]
+	fn tipl_decode() {
+		let parsed = KeyValueFrame::parse(
+			b"\x00producer\x00Foo\x00engineer\x00Bar\x00arranger\x00Baz",
+			Id3v2Version::V4,
+		)
+		.unwrap();
+
+		assert_eq!(parsed.encoding, TextEncoding::Latin1);
+		assert_eq!(parsed.key_value_pairs, pairs());
+
+		// ID3v2.2 only supports Latin-1 and UTF-16
+		assert!(KeyValueFrame::parse(b"\x03producer\x00Foo", Id3v2Version::V2).is_err());
+		assert!(KeyValueFrame::parse(&[], Id3v2Version::V4).is_err());
+	}
+
+	#[test]
+	fn null_separated() {
+		let frame = KeyValueFrame::from_null_separated(
+			TextEncoding::UTF8,
+			"producer\0Foo\0engineer\0Bar\0arranger\0Baz",
+		);
+
+		assert_eq!(frame.key_value_pairs, pairs());
+		assert_eq!(
+			frame.to_null_separated(),
+			"producer\0Foo\0engineer\0Bar\0arranger\0Baz"
+		);
+
+		let frame = KeyValueFrame::from_null_separated(TextEncoding::UTF8, "");
+		assert!(frame.key_value_pairs.is_empty());
+		assert_eq!(frame.to_null_separated(), "");
+	}
+}
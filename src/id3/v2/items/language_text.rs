@@ -0,0 +1,84 @@
+//! The layout shared by comment ("COMM") and unsynchronized text ("USLT") frames
+//!
+//! ```text
+//! <Header for 'COMM'/'USLT', ID: "COMM"/"USLT">
+//! Text encoding          $xx
+//! Language               $xx xx xx
+//! Content descriptor     <text string according to encoding> $00 (00)
+//! The actual text        <full text string according to encoding>
+//! ```
+
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::frame::content::verify_encoding;
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, is_latin1, TextEncoding};
+use crate::id3::v2::Id3v2Version;
+
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+pub(super) struct LanguageText {
+	pub(super) encoding: TextEncoding,
+	pub(super) language: [u8; 3],
+	pub(super) description: String,
+	pub(super) content: String,
+}
+
+/// The encoding used for a new frame with `content`
+pub(super) fn encoding_for(content: &str) -> TextEncoding {
+	if is_latin1(content) {
+		TextEncoding::Latin1
+	} else {
+		TextEncoding::UTF16
+	}
+}
+
+pub(super) fn parse(data: &[u8], version: Id3v2Version) -> Result<LanguageText> {
+	if data.len() < 5 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+	}
+
+	let reader = &mut &*data;
+
+	let encoding = verify_encoding(reader.read_u8()?, version)?;
+
+	let mut language = [0; 3];
+	reader.read_exact(&mut language)?;
+
+	let description = decode_text(reader, encoding, true)?.unwrap_or_default();
+	let content = decode_text(reader, encoding, false)?.unwrap_or_default();
+
+	Ok(LanguageText {
+		encoding,
+		language,
+		description,
+		content,
+	})
+}
+
+pub(super) fn as_bytes(
+	encoding: TextEncoding,
+	language: [u8; 3],
+	description: &str,
+	content: &str,
+) -> Result<Vec<u8>> {
+	if !language.iter().all(u8::is_ascii_alphabetic) {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+			"Invalid frame language found (expected 3 ascii characters)",
+		))
+		.into());
+	}
+
+	let mut encoding = encoding;
+	if encoding == TextEncoding::Latin1 && (!is_latin1(description) || !is_latin1(content)) {
+		encoding = TextEncoding::UTF16;
+	}
+
+	let mut bytes = vec![encoding as u8];
+
+	bytes.extend(language);
+	bytes.extend(encode_text(description, encoding, true));
+	bytes.extend(encode_text(content, encoding, false));
+
+	Ok(bytes)
+}
@@ -1,5 +1,14 @@
+pub(super) mod chapter;
+pub(super) mod comment_frame;
 pub(super) mod encapsulated_object;
 pub(super) mod encoded_text_frame;
-pub(super) mod language_frame;
+pub(super) mod key_value_frame;
+mod language_text;
 pub(super) mod popularimeter;
+pub(super) mod private_frame;
+pub(super) mod relative_volume_adjustment;
 pub(super) mod sync_text;
+pub(super) mod table_of_contents;
+pub(super) mod timestamp;
+pub(super) mod unique_file_identifier;
+pub(super) mod unsynchronized_text;
@@ -23,6 +23,46 @@ pub struct Popularimeter {
 }
 
 impl Popularimeter {
+	/// Get the rating on a scale of 0-5 stars
+	///
+	/// This uses the same ranges as Windows Media Player:
+	///
+	/// | Stars | `rating`  |
+	/// |-------|-----------|
+	/// | 0     | 0         |
+	/// | 1     | 1-31      |
+	/// | 2     | 32-95     |
+	/// | 3     | 96-159    |
+	/// | 4     | 160-223   |
+	/// | 5     | 224-255   |
+	pub fn star_rating(&self) -> u8 {
+		match self.rating {
+			0 => 0,
+			1..=31 => 1,
+			32..=95 => 2,
+			96..=159 => 3,
+			160..=223 => 4,
+			224..=255 => 5,
+		}
+	}
+
+	/// Set the rating from a scale of 0-5 stars
+	///
+	/// This uses the same values as Windows Media Player (0, 1, 64, 128, 196, 255).
+	/// Values greater than 5 will be treated as 5.
+	///
+	/// See [`Popularimeter::star_rating`] for the inverse.
+	pub fn set_star_rating(&mut self, stars: u8) {
+		self.rating = match stars {
+			0 => 0,
+			1 => 1,
+			2 => 64,
+			3 => 128,
+			4 => 196,
+			_ => 255,
+		}
+	}
+
 	/// Convert a [`Popularimeter`] into an ID3v2 POPM frame byte Vec
 	///
 	/// NOTE: This does not include a frame header
@@ -31,6 +71,11 @@ impl Popularimeter {
 		content.extend(encode_text(self.email.as_str(), TextEncoding::Latin1, true));
 		content.push(self.rating);
 
+		// The counter can be omitted entirely
+		if self.counter == 0 {
+			return content;
+		}
+
 		// When the counter reaches all one's, one byte is inserted in front of the counter
 		// thus making the counter eight bits bigger in the same away as the play counter ("PCNT")
 		//
@@ -74,7 +119,9 @@ mod tests {
 		assert_eq!(popm_bytes[email.len()], 0);
 		assert_eq!(popm_bytes[email.len() + 1], rating);
 
-		let counter_len = if u32::try_from(counter).is_ok() {
+		let counter_len = if counter == 0 {
+			0
+		} else if u32::try_from(counter).is_ok() {
 			4
 		} else {
 			let counter_bytes = counter.to_be_bytes();
@@ -99,7 +146,37 @@ mod tests {
 			counter: u64::from(u32::MAX) + 1,
 		};
 
+		let popm_no_counter = Popularimeter {
+			email: String::from("foo@bar.com"),
+			rating: 64,
+			counter: 0,
+		};
+
 		test_popm(&popm_u32_boundary);
 		test_popm(&popm_u40);
+		test_popm(&popm_no_counter);
+	}
+
+	#[test]
+	fn popm_star_rating() {
+		let mut popm = Popularimeter {
+			email: String::new(),
+			rating: 0,
+			counter: 0,
+		};
+
+		for stars in 0..=5 {
+			popm.set_star_rating(stars);
+			assert_eq!(popm.star_rating(), stars);
+		}
+
+		popm.set_star_rating(10);
+		assert_eq!(popm.rating, 255);
+
+		popm.rating = 31;
+		assert_eq!(popm.star_rating(), 1);
+
+		popm.rating = 32;
+		assert_eq!(popm.star_rating(), 2);
 	}
 }
@@ -0,0 +1,95 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
+
+use std::hash::{Hash, Hasher};
+
+/// The contents of a private ("PRIV") frame
+///
+/// A tag can contain multiple "PRIV" frames, but there must only be
+/// one with the same owner and data.
+#[derive(Clone, Debug, Eq)]
+pub struct PrivateFrame {
+	/// Identifies the owner of the frame, usually a URL or email address
+	pub owner: String,
+	/// The private data, whose format is defined by the owner
+	pub data: Vec<u8>,
+}
+
+impl PrivateFrame {
+	/// Read a [`PrivateFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is empty
+	pub fn parse(data: &[u8]) -> Result<Self> {
+		if data.is_empty() {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let reader = &mut &*data;
+
+		let owner = decode_text(reader, TextEncoding::Latin1, true)?.unwrap_or_default();
+
+		Ok(Self {
+			owner,
+			data: reader.to_vec(),
+		})
+	}
+
+	/// Convert a [`PrivateFrame`] into an ID3v2 PRIV frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = encode_text(&self.owner, TextEncoding::Latin1, true);
+		content.extend_from_slice(&self.data);
+
+		content
+	}
+}
+
+impl PartialEq for PrivateFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.owner == other.owner && self.data == other.data
+	}
+}
+
+impl Hash for PrivateFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.owner.hash(state);
+		self.data.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::PrivateFrame;
+
+	const EXPECTED_BYTES: &[u8] = b"WM/MediaClassPrimaryID\0\xBC\x7D\x60\xD1\x23\xE3";
+
+	#[test]
+	fn priv_decode() {
+		let parsed = PrivateFrame::parse(EXPECTED_BYTES).unwrap();
+
+		assert_eq!(parsed.owner, "WM/MediaClassPrimaryID");
+		assert_eq!(parsed.data, b"\xBC\x7D\x60\xD1\x23\xE3");
+
+		// A frame with only an owner identifier
+		let parsed = PrivateFrame::parse(b"Foo\0").unwrap();
+		assert_eq!(parsed.owner, "Foo");
+		assert!(parsed.data.is_empty());
+
+		assert!(PrivateFrame::parse(&[]).is_err());
+	}
+
+	#[test]
+	fn priv_encode() {
+		let frame = PrivateFrame {
+			owner: String::from("WM/MediaClassPrimaryID"),
+			data: b"\xBC\x7D\x60\xD1\x23\xE3".to_vec(),
+		};
+
+		assert_eq!(frame.as_bytes(), EXPECTED_BYTES);
+	}
+}
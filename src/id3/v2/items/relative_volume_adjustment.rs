@@ -0,0 +1,250 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
+
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// A channel identifier used in [`RelativeVolumeAdjustmentFrame`]s
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum ChannelType {
+	Other,
+	MasterVolume,
+	FrontRight,
+	FrontLeft,
+	BackRight,
+	BackLeft,
+	FrontCentre,
+	BackCentre,
+	Subwoofer,
+	/// A channel type not defined by the specification
+	Unknown(u8),
+}
+
+impl ChannelType {
+	/// Get a `ChannelType` from a u8
+	///
+	/// Values greater than 8 will be stored as [`ChannelType::Unknown`]
+	pub fn from_u8(byte: u8) -> Self {
+		match byte {
+			0 => Self::Other,
+			1 => Self::MasterVolume,
+			2 => Self::FrontRight,
+			3 => Self::FrontLeft,
+			4 => Self::BackRight,
+			5 => Self::BackLeft,
+			6 => Self::FrontCentre,
+			7 => Self::BackCentre,
+			8 => Self::Subwoofer,
+			b => Self::Unknown(b),
+		}
+	}
+
+	/// Get a u8 from a `ChannelType`
+	pub fn as_u8(&self) -> u8 {
+		match self {
+			Self::Other => 0,
+			Self::MasterVolume => 1,
+			Self::FrontRight => 2,
+			Self::FrontLeft => 3,
+			Self::BackRight => 4,
+			Self::BackLeft => 5,
+			Self::FrontCentre => 6,
+			Self::BackCentre => 7,
+			Self::Subwoofer => 8,
+			Self::Unknown(b) => *b,
+		}
+	}
+}
+
+/// Volume adjustment information for a specific channel
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChannelInformation {
+	/// The type of channel being described
+	pub channel_type: ChannelType,
+	/// A fixed point decibel value, representing (adjustment*512), giving +/- 64 dB with a precision of 0.001953125 dB
+	///
+	/// See [`ChannelInformation::volume_adjustment_db`] for the value in decibels.
+	pub volume_adjustment: i16,
+	/// The number of bits the peak volume field occupies, with 0 meaning there is no peak volume field
+	pub bits_representing_peak: u8,
+	/// An optional peak volume, stored big-endian in the smallest number of bytes that can hold `bits_representing_peak` bits
+	pub peak_volume: Option<Vec<u8>>,
+}
+
+impl ChannelInformation {
+	/// Get the volume adjustment in decibels
+	pub fn volume_adjustment_db(&self) -> f32 {
+		f32::from(self.volume_adjustment) / 512.0
+	}
+
+	// The peak volume field is rounded up to the nearest whole byte
+	fn peak_volume_len(&self) -> usize {
+		(self.bits_representing_peak as usize + 7) / 8
+	}
+}
+
+/// The contents of a relative volume adjustment ("RVA2") frame
+///
+/// A tag can contain multiple "RVA2" frames, but there must only be
+/// one with the same identification.
+#[derive(Clone, Debug, Eq)]
+pub struct RelativeVolumeAdjustmentFrame {
+	/// Identifies the situation and/or device where the adjustment should apply
+	pub identification: String,
+	/// The information for each channel, in the order they were read
+	pub channels: Vec<ChannelInformation>,
+}
+
+impl RelativeVolumeAdjustmentFrame {
+	/// Read a [`RelativeVolumeAdjustmentFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is empty
+	/// * A channel's information is cut short
+	pub fn parse(data: &[u8]) -> Result<Self> {
+		if data.is_empty() {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let reader = &mut &*data;
+
+		let identification = decode_text(reader, TextEncoding::Latin1, true)?.unwrap_or_default();
+
+		let mut channels = Vec::new();
+		while !reader.is_empty() {
+			let channel_type = ChannelType::from_u8(reader.read_u8()?);
+			let volume_adjustment = reader.read_i16::<BigEndian>()?;
+			let bits_representing_peak = reader.read_u8()?;
+
+			let mut channel_information = ChannelInformation {
+				channel_type,
+				volume_adjustment,
+				bits_representing_peak,
+				peak_volume: None,
+			};
+
+			let peak_volume_len = channel_information.peak_volume_len();
+			if peak_volume_len > 0 {
+				if reader.len() < peak_volume_len {
+					return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+				}
+
+				let mut peak_volume = vec![0; peak_volume_len];
+				reader.read_exact(&mut peak_volume)?;
+
+				channel_information.peak_volume = Some(peak_volume);
+			}
+
+			channels.push(channel_information);
+		}
+
+		Ok(Self {
+			identification,
+			channels,
+		})
+	}
+
+	/// Convert a [`RelativeVolumeAdjustmentFrame`] into an ID3v2 RVA2 frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	///
+	/// # Errors
+	///
+	/// * A channel's `peak_volume` length does not match its `bits_representing_peak`
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		let mut content = encode_text(&self.identification, TextEncoding::Latin1, true);
+
+		for channel_information in &self.channels {
+			let peak_volume = channel_information
+				.peak_volume
+				.as_deref()
+				.unwrap_or_default();
+
+			if peak_volume.len() != channel_information.peak_volume_len() {
+				return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+					"Peak volume length does not match the bits representing peak",
+				))
+				.into());
+			}
+
+			content.push(channel_information.channel_type.as_u8());
+			content.extend(channel_information.volume_adjustment.to_be_bytes());
+			content.push(channel_information.bits_representing_peak);
+			content.extend(peak_volume);
+		}
+
+		Ok(content)
+	}
+}
+
+impl PartialEq for RelativeVolumeAdjustmentFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.identification == other.identification
+	}
+}
+
+impl Hash for RelativeVolumeAdjustmentFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.identification.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{ChannelInformation, ChannelType, RelativeVolumeAdjustmentFrame};
+
+	fn expected() -> RelativeVolumeAdjustmentFrame {
+		RelativeVolumeAdjustmentFrame {
+			identification: String::from("album"),
+			channels: vec![
+				ChannelInformation {
+					channel_type: ChannelType::MasterVolume,
+					volume_adjustment: -1024,
+					bits_representing_peak: 12,
+					peak_volume: Some(vec![0x0F, 0xFF]),
+				},
+				ChannelInformation {
+					channel_type: ChannelType::Unknown(42),
+					volume_adjustment: 256,
+					bits_representing_peak: 0,
+					peak_volume: None,
+				},
+			],
+		}
+	}
+
+	const EXPECTED_BYTES: &[u8] = b"album\0\x01\xFC\x00\x0C\x0F\xFF\x2A\x01\x00\x00";
+
+	#[test]
+	fn rva2_decode() {
+		let parsed = RelativeVolumeAdjustmentFrame::parse(EXPECTED_BYTES).unwrap();
+		let expected = expected();
+
+		assert_eq!(parsed.identification, expected.identification);
+		assert_eq!(parsed.channels, expected.channels);
+		assert!((parsed.channels[0].volume_adjustment_db() - -2.0).abs() < f32::EPSILON);
+		assert!((parsed.channels[1].volume_adjustment_db() - 0.5).abs() < f32::EPSILON);
+	}
+
+	#[test]
+	fn rva2_encode() {
+		assert_eq!(expected().as_bytes().unwrap(), EXPECTED_BYTES);
+	}
+
+	#[test]
+	fn rva2_bad_peak_volume() {
+		// 9 bits need 2 bytes, only 1 is present
+		assert!(RelativeVolumeAdjustmentFrame::parse(b"album\0\x01\x00\x00\x09\xFF").is_err());
+
+		let mut frame = expected();
+		frame.channels[0].bits_representing_peak = 17;
+
+		assert!(frame.as_bytes().is_err());
+	}
+}
@@ -187,7 +187,7 @@ impl SynchronizedText {
 	/// # Errors
 	///
 	/// * `content`'s length > [`u32::MAX`]
-	/// * See [`LanguageFrame`](crate::id3::v2::LanguageFrame)
+	/// * See [`CommentFrame`](crate::id3::v2::CommentFrame)
 	pub fn as_bytes(&self) -> Result<Vec<u8>> {
 		let information = &self.information;
 
@@ -0,0 +1,189 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::frame::Frame;
+use crate::id3::v2::items::chapter::{read_embedded_frames, write_embedded_frames};
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
+use crate::id3::v2::Id3v2Version;
+use crate::probe::ParseContext;
+
+use std::hash::{Hash, Hasher};
+
+use byteorder::ReadBytesExt;
+
+/// The contents of a table of contents ("CTOC") frame
+///
+/// A table of contents lists the element IDs of [`ChapterFrame`](crate::id3::v2::ChapterFrame)s
+/// and other [`TableOfContentsFrame`]s, allowing for a hierarchy of chapters.
+///
+/// A tag can contain multiple "CTOC" frames, but each must have a unique element ID,
+/// and only one may be marked as top-level.
+#[derive(Clone, Debug, Eq)]
+pub struct TableOfContentsFrame {
+	/// A unique identifier for the table of contents
+	pub element_id: String,
+	/// Whether this is the root of the table of contents tree
+	pub top_level: bool,
+	/// Whether the child elements are ordered
+	pub ordered: bool,
+	/// The element IDs of the child elements
+	pub child_element_ids: Vec<String>,
+	/// Frames describing the table of contents, usually a title ("TIT2")
+	pub embedded_frames: Vec<Frame>,
+}
+
+impl TableOfContentsFrame {
+	/// Read a [`TableOfContentsFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is too short to contain the element ID, flags, and entry count
+	/// * There are fewer child element IDs than specified
+	/// * Any of the embedded frames are invalid
+	pub fn parse(data: &[u8], version: Id3v2Version) -> Result<Self> {
+		Self::read(data, version, &mut ParseContext::default())
+	}
+
+	// See `ChapterFrame::read`
+	pub(in crate::id3::v2) fn read(
+		data: &[u8],
+		version: Id3v2Version,
+		context: &mut ParseContext,
+	) -> Result<Self> {
+		// Element ID terminator (1) + flags (1) + entry count (1)
+		if data.len() < 3 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let reader = &mut &*data;
+
+		let element_id = decode_text(reader, TextEncoding::Latin1, true)?.unwrap_or_default();
+
+		let flags = reader.read_u8()?;
+		let top_level = flags & 0x02 == 0x02;
+		let ordered = flags & 0x01 == 0x01;
+
+		let entry_count = reader.read_u8()?;
+
+		let mut child_element_ids = Vec::with_capacity(entry_count as usize);
+		for _ in 0..entry_count {
+			if reader.is_empty() {
+				return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+			}
+
+			child_element_ids
+				.push(decode_text(reader, TextEncoding::Latin1, true)?.unwrap_or_default());
+		}
+
+		let embedded_frames = read_embedded_frames(reader, version, context)?;
+
+		Ok(Self {
+			element_id,
+			top_level,
+			ordered,
+			child_element_ids,
+			embedded_frames,
+		})
+	}
+
+	/// Convert a [`TableOfContentsFrame`] into an ID3v2 CTOC frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	///
+	/// # Errors
+	///
+	/// * There are more than 255 child element IDs
+	/// * Any of the embedded frames are invalid, see [`Id3v2Tag::save_to`](crate::TagExt::save_to)
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		self.as_bytes_for_version(Id3v2Version::V4)
+	}
+
+	// The embedded frames need to be written in the same version as the tag
+	pub(in crate::id3::v2) fn as_bytes_for_version(
+		&self,
+		version: Id3v2Version,
+	) -> Result<Vec<u8>> {
+		let entry_count = u8::try_from(self.child_element_ids.len()).map_err(|_| {
+			Id3v2Error::new(Id3v2ErrorKind::Other(
+				"Table of contents has more than 255 child elements",
+			))
+		})?;
+
+		let mut content = encode_text(&self.element_id, TextEncoding::Latin1, true);
+
+		let mut flags = 0;
+		if self.top_level {
+			flags |= 0x02;
+		}
+
+		if self.ordered {
+			flags |= 0x01;
+		}
+
+		content.push(flags);
+		content.push(entry_count);
+
+		for child_element_id in &self.child_element_ids {
+			content.extend(encode_text(child_element_id, TextEncoding::Latin1, true));
+		}
+
+		write_embedded_frames(&mut content, &self.embedded_frames, version)?;
+
+		Ok(content)
+	}
+}
+
+impl PartialEq for TableOfContentsFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.element_id == other.element_id
+	}
+}
+
+impl Hash for TableOfContentsFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.element_id.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{Id3v2Version, TableOfContentsFrame};
+
+	#[test]
+	fn ctoc_decode() {
+		let data = b"toc\0\x03\x02chp0\0chp1\0";
+
+		let toc = TableOfContentsFrame::parse(data, Id3v2Version::V4).unwrap();
+
+		assert_eq!(toc.element_id, "toc");
+		assert!(toc.top_level);
+		assert!(toc.ordered);
+		assert_eq!(toc.child_element_ids, vec!["chp0", "chp1"]);
+		assert!(toc.embedded_frames.is_empty());
+	}
+
+	#[test]
+	fn ctoc_re_read() {
+		let toc = TableOfContentsFrame {
+			element_id: String::from("toc"),
+			top_level: true,
+			ordered: false,
+			child_element_ids: vec![String::from("chp0"), String::from("chp1")],
+			embedded_frames: Vec::new(),
+		};
+
+		let bytes = toc.as_bytes().unwrap();
+		assert_eq!(bytes, b"toc\0\x02\x02chp0\0chp1\0");
+
+		let parsed = TableOfContentsFrame::parse(&bytes, Id3v2Version::V4).unwrap();
+
+		assert_eq!(parsed.top_level, toc.top_level);
+		assert_eq!(parsed.ordered, toc.ordered);
+		assert_eq!(parsed.child_element_ids, toc.child_element_ids);
+	}
+
+	#[test]
+	fn ctoc_missing_children() {
+		assert!(TableOfContentsFrame::parse(b"toc\0\x03\x02chp0\0", Id3v2Version::V4).is_err());
+	}
+}
@@ -0,0 +1,152 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// A timestamp, as stored in frames such as "TDRC" (recording time)
+///
+/// ID3v2.4 timestamps are a subset of ISO-8601, in the form `yyyy-MM-ddTHH:mm:ss`.
+/// They can be truncated at any component, so everything other than the year is optional.
+///
+/// NOTE: A component is only meaningful if all of the components before it are present.
+/// When converted to a string, everything after the first missing component is discarded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Timestamp {
+	/// The year (0-9999)
+	pub year: u16,
+	/// The month (1-12)
+	pub month: Option<u8>,
+	/// The day of the month (1-31)
+	pub day: Option<u8>,
+	/// The hour (0-23)
+	pub hour: Option<u8>,
+	/// The minute (0-59)
+	pub minute: Option<u8>,
+	/// The second (0-59)
+	pub second: Option<u8>,
+}
+
+impl Timestamp {
+	/// Parses a timestamp in the form `yyyy-MM-ddTHH:mm:ss`
+	///
+	/// Partial timestamps (Ex. `yyyy` or `yyyy-MM`) are accepted, and parsing will
+	/// stop at the first invalid component. This will only return `None` if the year is invalid.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Timestamp;
+	///
+	/// let timestamp = Timestamp::parse("2022-05-14T13:37").unwrap();
+	///
+	/// assert_eq!(timestamp.year, 2022);
+	/// assert_eq!(timestamp.month, Some(5));
+	/// assert_eq!(timestamp.minute, Some(37));
+	/// assert_eq!(timestamp.second, None);
+	///
+	/// // Only the year is required
+	/// assert_eq!(Timestamp::parse("2022").unwrap().month, None);
+	/// assert!(Timestamp::parse("Foo").is_none());
+	/// ```
+	pub fn parse(timestamp: &str) -> Option<Self> {
+		let timestamp = timestamp.trim();
+
+		let component = |range: Range<usize>| {
+			timestamp
+				.get(range)
+				.filter(|c| c.bytes().all(|b| b.is_ascii_digit()))
+		};
+
+		let mut parsed = Self {
+			year: component(0..4)?.parse().ok()?,
+			..Self::default()
+		};
+
+		// (separator, position, valid values, field)
+		let fields = [
+			(b'-', 5..7, 1..=12, &mut parsed.month),
+			(b'-', 8..10, 1..=31, &mut parsed.day),
+			(b'T', 11..13, 0..=23, &mut parsed.hour),
+			(b':', 14..16, 0..=59, &mut parsed.minute),
+			(b':', 17..19, 0..=59, &mut parsed.second),
+		];
+
+		for (separator, range, valid, field) in fields {
+			if timestamp.as_bytes().get(range.start - 1) != Some(&separator) {
+				break;
+			}
+
+			match component(range).and_then(|c| c.parse().ok()) {
+				Some(value) if valid.contains(&value) => *field = Some(value),
+				_ => break,
+			}
+		}
+
+		Some(parsed)
+	}
+}
+
+impl Display for Timestamp {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04}", self.year)?;
+
+		let components = [
+			('-', self.month),
+			('-', self.day),
+			('T', self.hour),
+			(':', self.minute),
+			(':', self.second),
+		];
+
+		for (separator, component) in components {
+			match component {
+				Some(component) => write!(f, "{}{:02}", separator, component)?,
+				None => break,
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::Timestamp;
+
+	#[test]
+	fn timestamp_round_trip() {
+		for timestamp in [
+			"2022",
+			"2022-05",
+			"2022-05-14",
+			"2022-05-14T13",
+			"2022-05-14T13:37",
+			"2022-05-14T13:37:00",
+		] {
+			assert_eq!(Timestamp::parse(timestamp).unwrap().to_string(), timestamp);
+		}
+	}
+
+	#[test]
+	fn timestamp_partial() {
+		// Parsing stops at the first invalid component
+		let timestamp = Timestamp::parse("2022-13-14").unwrap();
+		assert_eq!(timestamp.month, None);
+		assert_eq!(timestamp.day, None);
+
+		let timestamp = Timestamp::parse("2022-05-14 13:37").unwrap();
+		assert_eq!(timestamp.day, Some(14));
+		assert_eq!(timestamp.hour, None);
+
+		assert!(Timestamp::parse("22").is_none());
+
+		// Components after a missing one are discarded
+		let timestamp = Timestamp {
+			year: 999,
+			month: Some(5),
+			day: None,
+			hour: Some(13),
+			minute: None,
+			second: None,
+		};
+		assert_eq!(timestamp.to_string(), "0999-05");
+	}
+}
@@ -0,0 +1,112 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::util::text_utils::{decode_text, encode_text, TextEncoding};
+
+use std::hash::{Hash, Hasher};
+
+/// The owner identifier used by MusicBrainz for recording IDs
+pub(crate) const MUSICBRAINZ_UFID_OWNER: &str = "http://musicbrainz.org";
+
+// The identifier can be at most 64 bytes
+const MAX_IDENTIFIER_LEN: usize = 64;
+
+/// The contents of a unique file identifier ("UFID") frame
+///
+/// A tag can contain multiple "UFID" frames, but there must only be
+/// one with the same owner.
+///
+/// NOTE: A "UFID" frame with an owner of `http://musicbrainz.org` will be converted to
+/// [`ItemKey::MusicBrainzRecordingId`](crate::ItemKey::MusicBrainzRecordingId) when converting to a [`Tag`](crate::Tag).
+#[derive(Clone, Debug, Eq)]
+pub struct UniqueFileIdentifierFrame {
+	/// Identifies the owner of the identifier, usually a URL pointing to a database
+	pub owner: String,
+	/// The identifier itself, which can be at most 64 bytes
+	pub identifier: Vec<u8>,
+}
+
+impl UniqueFileIdentifierFrame {
+	/// Read a [`UniqueFileIdentifierFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is empty
+	pub fn parse(data: &[u8]) -> Result<Self> {
+		if data.is_empty() {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let reader = &mut &*data;
+
+		let owner = decode_text(reader, TextEncoding::Latin1, true)?.unwrap_or_default();
+
+		Ok(Self {
+			owner,
+			identifier: reader.to_vec(),
+		})
+	}
+
+	/// Convert a [`UniqueFileIdentifierFrame`] into an ID3v2 UFID frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	///
+	/// # Errors
+	///
+	/// * `identifier` is longer than 64 bytes
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		if self.identifier.len() > MAX_IDENTIFIER_LEN {
+			return Err(
+				Id3v2Error::new(Id3v2ErrorKind::Other("UFID identifier exceeds 64 bytes")).into(),
+			);
+		}
+
+		let mut content = encode_text(&self.owner, TextEncoding::Latin1, true);
+		content.extend_from_slice(&self.identifier);
+
+		Ok(content)
+	}
+}
+
+impl PartialEq for UniqueFileIdentifierFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.owner == other.owner
+	}
+}
+
+impl Hash for UniqueFileIdentifierFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.owner.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::UniqueFileIdentifierFrame;
+
+	const EXPECTED_BYTES: &[u8] = b"http://musicbrainz.org\x00f0b0e1c4-6f3e-4c43-8a4e-1f1c1b2a3d4e";
+
+	#[test]
+	fn ufid_decode() {
+		let parsed = UniqueFileIdentifierFrame::parse(EXPECTED_BYTES).unwrap();
+
+		assert_eq!(parsed.owner, "http://musicbrainz.org");
+		assert_eq!(parsed.identifier, b"f0b0e1c4-6f3e-4c43-8a4e-1f1c1b2a3d4e");
+
+		assert!(UniqueFileIdentifierFrame::parse(&[]).is_err());
+	}
+
+	#[test]
+	fn ufid_encode() {
+		let mut frame = UniqueFileIdentifierFrame {
+			owner: String::from("http://musicbrainz.org"),
+			identifier: b"f0b0e1c4-6f3e-4c43-8a4e-1f1c1b2a3d4e".to_vec(),
+		};
+
+		assert_eq!(frame.as_bytes().unwrap(), EXPECTED_BYTES);
+
+		// The identifier can't exceed 64 bytes
+		frame.identifier = vec![b'a'; 65];
+		assert!(frame.as_bytes().is_err());
+	}
+}
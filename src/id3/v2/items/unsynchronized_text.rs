@@ -0,0 +1,155 @@
+use crate::error::Result;
+use crate::id3::v2::items::language_text::{self, LanguageText};
+use crate::id3::v2::util::text_utils::TextEncoding;
+use crate::id3::v2::Id3v2Version;
+
+use std::hash::{Hash, Hasher};
+
+/// The contents of an unsynchronized lyrics/text transcription ("USLT") frame
+///
+/// A tag can contain multiple "USLT" frames, but there must only be
+/// one with the same language and description.
+#[derive(Clone, Debug, Eq)]
+pub struct UnsynchronizedTextFrame {
+	/// The encoding of the description and content
+	///
+	/// NOTE: When writing, [`TextEncoding::Latin1`] will be upgraded to [`TextEncoding::UTF16`]
+	/// if the description or content contain characters outside of the Latin-1 range.
+	pub encoding: TextEncoding,
+	/// ISO-639-2 language code (3 bytes)
+	pub language: [u8; 3],
+	/// Unique content description
+	pub description: String,
+	/// The lyrics/text itself
+	pub content: String,
+}
+
+impl PartialEq for UnsynchronizedTextFrame {
+	fn eq(&self, other: &Self) -> bool {
+		self.language == other.language && self.description == other.description
+	}
+}
+
+impl Hash for UnsynchronizedTextFrame {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.language.hash(state);
+		self.description.hash(state);
+	}
+}
+
+impl UnsynchronizedTextFrame {
+	/// Create a new [`UnsynchronizedTextFrame`] with no description
+	///
+	/// This will use [`TextEncoding::Latin1`] if possible, otherwise [`TextEncoding::UTF16`].
+	pub fn new(language: [u8; 3], content: String) -> Self {
+		Self {
+			encoding: language_text::encoding_for(&content),
+			language,
+			description: String::new(),
+			content,
+		}
+	}
+
+	/// Read an [`UnsynchronizedTextFrame`] from a slice
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is too short to contain the encoding and language
+	/// * The encoding is invalid (or not supported by `version`)
+	/// * The description or content are not valid for the encoding
+	pub fn parse(data: &[u8], version: Id3v2Version) -> Result<Self> {
+		let LanguageText {
+			encoding,
+			language,
+			description,
+			content,
+		} = language_text::parse(data, version)?;
+
+		Ok(Self {
+			encoding,
+			language,
+			description,
+			content,
+		})
+	}
+
+	/// Convert an [`UnsynchronizedTextFrame`] into an ID3v2 USLT frame byte Vec
+	///
+	/// NOTE: This does not include a frame header
+	///
+	/// # Errors
+	///
+	/// * `language` contains non-alphabetic characters
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		language_text::as_bytes(
+			self.encoding,
+			self.language,
+			&self.description,
+			&self.content,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{Id3v2Version, TextEncoding, UnsynchronizedTextFrame};
+
+	#[test]
+	fn uslt_decode() {
+		let data = b"\x00engFoo\0Bar lyrics";
+
+		let uslt = UnsynchronizedTextFrame::parse(data, Id3v2Version::V4).unwrap();
+
+		assert_eq!(uslt.encoding, TextEncoding::Latin1);
+		assert_eq!(&uslt.language, b"eng");
+		assert_eq!(uslt.description, "Foo");
+		assert_eq!(uslt.content, "Bar lyrics");
+	}
+
+	#[test]
+	fn uslt_decode_utf16() {
+		let mut data = vec![1];
+		data.extend(b"eng");
+		// Description, BOM + "A" + terminator
+		data.extend([0xFF, 0xFE, b'A', 0, 0, 0]);
+		// Content, BOM + "B"
+		data.extend([0xFF, 0xFE, b'B', 0]);
+
+		let uslt = UnsynchronizedTextFrame::parse(&data, Id3v2Version::V4).unwrap();
+
+		assert_eq!(uslt.encoding, TextEncoding::UTF16);
+		assert_eq!(uslt.description, "A");
+		assert_eq!(uslt.content, "B");
+	}
+
+	#[test]
+	#[allow(clippy::non_ascii_literal)]
+	fn uslt_encode() {
+		let latin1 = UnsynchronizedTextFrame::new(*b"eng", String::from("Foo lyrics"));
+		assert_eq!(latin1.encoding, TextEncoding::Latin1);
+		assert_eq!(latin1.as_bytes().unwrap(), b"\x00eng\0Foo lyrics");
+
+		let utf16 = UnsynchronizedTextFrame::new(*b"jpn", String::from("日本語"));
+		assert_eq!(utf16.encoding, TextEncoding::UTF16);
+
+		let parsed =
+			UnsynchronizedTextFrame::parse(&utf16.as_bytes().unwrap(), Id3v2Version::V4).unwrap();
+		assert_eq!(parsed.content, utf16.content);
+
+		// Latin-1 can't represent this, so it must be upgraded
+		let upgraded = UnsynchronizedTextFrame {
+			encoding: TextEncoding::Latin1,
+			..utf16
+		};
+
+		assert_eq!(upgraded.as_bytes().unwrap()[0], TextEncoding::UTF16 as u8);
+	}
+
+	#[test]
+	fn uslt_bad_language() {
+		let uslt = UnsynchronizedTextFrame::new(*b"e\0g", String::from("Foo lyrics"));
+		assert!(uslt.as_bytes().is_err());
+	}
+}
@@ -26,10 +26,19 @@ cfg_if::cfg_if! {
 		pub use tag::Id3v2Tag;
 
 		mod items;
+		pub use items::chapter::ChapterFrame;
+		pub use items::comment_frame::CommentFrame;
 		pub use items::encoded_text_frame::EncodedTextFrame;
-		pub use items::language_frame::LanguageFrame;
+		pub use items::key_value_frame::KeyValueFrame;
+		pub use items::popularimeter::Popularimeter;
+		pub use items::private_frame::PrivateFrame;
+		pub use items::relative_volume_adjustment::{ChannelInformation, ChannelType, RelativeVolumeAdjustmentFrame};
 		pub use items::encapsulated_object::{GEOBInformation, GeneralEncapsulatedObject};
 		pub use items::sync_text::{SyncTextContentType, SyncTextInformation, SynchronizedText, TimestampFormat};
+		pub use items::table_of_contents::TableOfContentsFrame;
+		pub use items::timestamp::Timestamp;
+		pub use items::unique_file_identifier::UniqueFileIdentifierFrame;
+		pub use items::unsynchronized_text::UnsynchronizedTextFrame;
 
 		mod frame;
 		pub use frame::id::FrameID;
@@ -38,6 +47,7 @@ cfg_if::cfg_if! {
 		pub use frame::FrameValue;
 
 		pub(crate) mod read;
+
 		pub(crate) mod write;
 	}
 }
@@ -90,6 +100,11 @@ pub(crate) struct Id3v2Header {
 	pub flags: Id3v2TagFlags,
 	pub size: u32,
 	pub extended_size: u32,
+	#[cfg_attr(not(feature = "id3v2"), allow(dead_code))]
+	pub crc: Option<u32>,
+	// ID3v2.3 only, the padding is excluded from the CRC-32
+	#[cfg_attr(not(feature = "id3v2"), allow(dead_code))]
+	pub padding_size: u32,
 }
 
 pub(crate) fn read_id3v2_header<R>(bytes: &mut R) -> Result<Id3v2Header>
@@ -129,20 +144,52 @@ where
 		unsynchronisation: flags & 0x80 == 0x80,
 		experimental: (version == Id3v2Version::V4 || version == Id3v2Version::V3)
 			&& flags & 0x20 == 0x20,
-		footer: (version == Id3v2Version::V4 || version == Id3v2Version::V3)
-			&& flags & 0x10 == 0x10,
+		// The footer was introduced in ID3v2.4
+		footer: version == Id3v2Version::V4 && flags & 0x10 == 0x10,
 		crc: false, // Retrieved later if applicable
+		padding: 0,
 		#[cfg(feature = "id3v2_restrictions")]
 		restrictions: (false, TagRestrictions::default()), // Retrieved later if applicable
 	};
 
 	let size = unsynch_u32(BigEndian::read_u32(&header[6..]));
 	let mut extended_size = 0;
+	let mut crc = None;
+	let mut padding_size = 0;
 
 	let extended_header =
 		(version == Id3v2Version::V4 || version == Id3v2Version::V3) && flags & 0x40 == 0x40;
 
-	if extended_header {
+	// ID3v2.3 extended headers are laid out differently, and their size doesn't include itself
+	if extended_header && version == Id3v2Version::V3 {
+		let ext_size = bytes.read_u32::<BigEndian>()?;
+
+		if ext_size < 6 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+				"Found an extended header with an invalid size (< 6)",
+			))
+			.into());
+		}
+
+		let extended_flags = bytes.read_u16::<BigEndian>()?;
+		padding_size = bytes.read_u32::<BigEndian>()?;
+
+		let mut remaining = ext_size - 6;
+
+		if extended_flags & 0x8000 == 0x8000 && remaining >= 4 {
+			flags_parsed.crc = true;
+			crc = Some(bytes.read_u32::<BigEndian>()?);
+
+			remaining -= 4;
+		}
+
+		std::io::copy(
+			&mut bytes.by_ref().take(u64::from(remaining)),
+			&mut std::io::sink(),
+		)?;
+
+		extended_size = ext_size.saturating_add(4);
+	} else if extended_header {
 		extended_size = unsynch_u32(bytes.read_u32::<BigEndian>()?);
 
 		if extended_size < 6 {
@@ -162,9 +209,18 @@ where
 		if extended_flags & 0x20 == 0x20 {
 			flags_parsed.crc = true;
 
-			// We don't care about the existing CRC (5) or its length byte (1)
-			let mut crc = [0; 6];
-			bytes.read_exact(&mut crc)?;
+			// We don't care about the length byte, it is always 5
+			let _data_length = bytes.read_u8()?;
+
+			let mut encoded_crc = [0; 5];
+			bytes.read_exact(&mut encoded_crc)?;
+
+			// The CRC-32 is stored as a 35 bit synchsafe integer, the upper four bits are always zeroed
+			crc = Some(
+				encoded_crc
+					.iter()
+					.fold(0, |crc, b| (crc << 7) | u32::from(b & 0x7F)),
+			);
 		}
 
 		#[cfg(feature = "id3v2_restrictions")]
@@ -188,5 +244,7 @@ where
 		flags: flags_parsed,
 		size,
 		extended_size,
+		crc,
+		padding_size,
 	})
 }
@@ -1,30 +1,154 @@
+use super::frame::read::ParsedFrame;
 use super::frame::Frame;
 use super::tag::Id3v2Tag;
-use super::Id3v2Header;
-use crate::error::Result;
+use super::util::convert::frames_to_v4;
+use super::util::{crc_32, unsynch_content};
+use super::{Id3v2Header, Id3v2Version};
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result, WarningCategory};
 use crate::macros::try_vec;
+use crate::probe::ParseContext;
 
 use std::io::Read;
 
-pub(crate) fn parse_id3v2<R>(bytes: &mut R, header: Id3v2Header) -> Result<Id3v2Tag>
+pub(crate) fn parse_id3v2<R>(
+	bytes: &mut R,
+	header: Id3v2Header,
+	context: &mut ParseContext,
+) -> Result<Id3v2Tag>
 where
 	R: Read,
 {
+	let lenient = context.lenient_id3v2;
+
 	let mut tag_bytes = try_vec![0; (header.size - header.extended_size) as usize];
 	bytes.read_exact(&mut tag_bytes)?;
 
+	// In ID3v2.4, the CRC-32 is calculated on the frames as they're stored, prior to any unsynchronisation being removed
+	if let (Some(expected_crc), Id3v2Version::V4) = (header.crc, header.version) {
+		verify_crc(expected_crc, &tag_bytes, context)?;
+	}
+
 	let mut tag = Id3v2Tag::default();
 	tag.original_version = header.version;
 	tag.set_flags(header.flags);
 
+	// Prior to ID3v2.4, unsynchronisation applied to the entire tag, rather than each frame
+	if header.flags.unsynchronisation && header.version != Id3v2Version::V4 {
+		tag_bytes = unsynch_content(&tag_bytes)?;
+	}
+
+	// In ID3v2.3, the CRC-32 is calculated after unsynchronisation is removed, and excludes the padding
+	if let (Some(expected_crc), Id3v2Version::V3) = (header.crc, header.version) {
+		let end = tag_bytes.len().saturating_sub(header.padding_size as usize);
+		verify_crc(expected_crc, &tag_bytes[..end], context)?;
+	}
+
 	let reader = &mut &*tag_bytes;
+	let mut frames = Vec::new();
 
 	loop {
-		match Frame::read(reader, header.version)? {
-			None => break,
-			Some(f) => drop(tag.insert(f)),
+		match Frame::read(reader, header.version, context) {
+			Ok(ParsedFrame::Next(f)) => frames.push(f),
+			Ok(ParsedFrame::Skip) => {},
+			Ok(ParsedFrame::Eof) => break,
+			// The entire frame was read, so we can move on to the next one
+			Ok(ParsedFrame::Corrupt(id, err)) if lenient => {
+				context.warn(
+					WarningCategory::Tag,
+					format!("Skipping corrupt \"{}\" frame: {}", id.as_str(), err),
+				);
+			},
+			// The frame header (or content) couldn't be read, there's no way to find the next frame
+			Err(err) if lenient => {
+				context.warn(
+					WarningCategory::Tag,
+					format!(
+						"Discarding the remaining frames, unable to read frame: {}",
+						err
+					),
+				);
+				break;
+			},
+			Ok(ParsedFrame::Corrupt(_, err)) | Err(err) => return Err(err),
 		}
 	}
 
+	// The frame IDs have already been upgraded, but ID3v2.3 splits the recording time across multiple frames
+	if header.version == Id3v2Version::V3 {
+		frames = frames_to_v4(frames);
+	}
+
+	for frame in frames {
+		drop(tag.insert(frame));
+	}
+
 	Ok(tag)
 }
+
+// When parsing leniently, a mismatch doesn't prevent the frames from being read, they may still be usable
+fn verify_crc(expected_crc: u32, content: &[u8], context: &mut ParseContext) -> Result<()> {
+	let calculated_crc = crc_32(content);
+
+	if calculated_crc != expected_crc {
+		let kind = Id3v2ErrorKind::CrcMismatch(expected_crc, calculated_crc);
+
+		if !context.lenient_id3v2 {
+			return Err(Id3v2Error::new(kind).into());
+		}
+
+		context.warn(WarningCategory::Tag, kind.to_string());
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::parse_id3v2;
+	use crate::id3::v2::read_id3v2_header;
+	use crate::probe::ParseContext;
+
+	fn frame(id: [u8; 4], content: &[u8]) -> Vec<u8> {
+		let mut frame = id.to_vec();
+		frame.extend((content.len() as u32).to_be_bytes());
+		frame.extend([0, 0]);
+		frame.extend(content);
+		frame
+	}
+
+	fn tag_with_corrupt_frame() -> Vec<u8> {
+		let mut frames = frame(*b"TIT2", b"\x00Foo title");
+		// Too short to contain the encoding and language
+		frames.extend(frame(*b"COMM", b"\x00en"));
+		frames.extend(frame(*b"TALB", b"\x00Bar album"));
+
+		let mut tag = b"ID3\x04\x00\x00\x00\x00\x00".to_vec();
+		tag.push(frames.len() as u8);
+		tag.extend(frames);
+		tag
+	}
+
+	#[test]
+	fn lenient_corrupt_frame() {
+		let bytes = tag_with_corrupt_frame();
+
+		let reader = &mut &bytes[..];
+		let header = read_id3v2_header(reader).unwrap();
+		assert!(parse_id3v2(reader, header, &mut ParseContext::default()).is_err());
+
+		let mut context = ParseContext::collecting();
+		context.lenient_id3v2 = true;
+
+		let reader = &mut &bytes[..];
+		let header = read_id3v2_header(reader).unwrap();
+		let tag = parse_id3v2(reader, header, &mut context).unwrap();
+
+		assert!(tag.get("TIT2").is_some());
+		assert!(tag.get("TALB").is_some());
+		assert!(tag.get("COMM").is_none());
+
+		let warnings = context.into_warnings();
+		assert_eq!(warnings.len(), 1);
+		assert!(warnings[0].message.contains("\"COMM\""));
+	}
+}
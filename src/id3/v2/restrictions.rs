@@ -1,3 +1,10 @@
+#[cfg(feature = "id3v2")]
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+#[cfg(feature = "id3v2")]
+use crate::id3::v2::{Frame, FrameValue, TextEncoding};
+#[cfg(feature = "id3v2")]
+use crate::picture::{MimeType, Picture};
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[allow(non_camel_case_types)]
 /// Restrictions on the tag size
@@ -12,6 +19,18 @@ pub enum TagSizeRestrictions {
 	S_32F_4K,
 }
 
+impl TagSizeRestrictions {
+	/// The maximum number of frames and tag size in bytes
+	fn limits(self) -> (usize, usize) {
+		match self {
+			Self::S_128F_1M => (128, 1024 * 1024),
+			Self::S_64F_128K => (64, 128 * 1024),
+			Self::S_32F_40K => (32, 40 * 1024),
+			Self::S_32F_4K => (32, 4 * 1024),
+		}
+	}
+}
+
 impl Default for TagSizeRestrictions {
 	fn default() -> Self {
 		Self::S_128F_1M
@@ -89,7 +108,7 @@ impl TagRestrictions {
 		let restriction_flags = byte;
 
 		// xx000000
-		match restriction_flags & 0x0C {
+		match restriction_flags & 0xC0 {
 			64 => restrictions.size = TagSizeRestrictions::S_64F_128K,
 			128 => restrictions.size = TagSizeRestrictions::S_32F_40K,
 			192 => restrictions.size = TagSizeRestrictions::S_32F_4K,
@@ -134,7 +153,7 @@ impl TagRestrictions {
 			TagSizeRestrictions::S_128F_1M => {},
 			TagSizeRestrictions::S_64F_128K => byte |= 0x40,
 			TagSizeRestrictions::S_32F_40K => byte |= 0x80,
-			TagSizeRestrictions::S_32F_4K => byte |= 0x0C,
+			TagSizeRestrictions::S_32F_4K => byte |= 0xC0,
 		}
 
 		if self.text_encoding {
@@ -162,3 +181,159 @@ impl TagRestrictions {
 		byte
 	}
 }
+
+#[cfg(feature = "id3v2")]
+impl TagRestrictions {
+	// Verifies the size of an entire tag, `tag_size` being the size of the tag once written
+	pub(crate) fn verify_tag_size(self, frame_count: usize, tag_size: usize) -> Result<()> {
+		let (max_frames, max_size) = self.size.limits();
+
+		if frame_count > max_frames {
+			return Err(violation(format!(
+				"Tag has {} frames, the maximum is {}",
+				frame_count, max_frames
+			)));
+		}
+
+		if tag_size > max_size {
+			return Err(violation(format!(
+				"Tag is {} bytes, the maximum is {}",
+				tag_size, max_size
+			)));
+		}
+
+		Ok(())
+	}
+
+	pub(crate) fn verify_frames(self, frames: &[Frame]) -> Result<()> {
+		for frame in frames {
+			let id = frame.id_str();
+
+			match frame.content() {
+				FrameValue::Text { encoding, value } => {
+					self.verify_text(id, *encoding, &[value])?;
+				},
+				FrameValue::UserText(content) | FrameValue::UserURL(content) => {
+					self.verify_text(
+						id,
+						content.encoding,
+						&[&content.description, &content.content],
+					)?;
+				},
+				// The pairs are measured as they're encoded, a single null separated field
+				FrameValue::KeyValueList(content) => {
+					self.verify_text(id, content.encoding, &[&content.to_null_separated()])?;
+				},
+				FrameValue::Comment(content) => {
+					self.verify_text(
+						id,
+						content.encoding,
+						&[&content.description, &content.content],
+					)?;
+				},
+				FrameValue::UnSyncText(content) => {
+					self.verify_text(
+						id,
+						content.encoding,
+						&[&content.description, &content.content],
+					)?;
+				},
+				FrameValue::Picture { encoding, picture } => {
+					let description = picture.description().unwrap_or_default();
+
+					self.verify_text(id, *encoding, &[description])?;
+					self.verify_picture(picture)?;
+				},
+				FrameValue::Chapter(chapter) => self.verify_frames(&chapter.embedded_frames)?,
+				FrameValue::TableOfContents(toc) => self.verify_frames(&toc.embedded_frames)?,
+				_ => {},
+			}
+		}
+
+		Ok(())
+	}
+
+	// If a text field is made up of multiple strings, the restriction applies to their sum
+	fn verify_text(self, id: &str, encoding: TextEncoding, text: &[&str]) -> Result<()> {
+		if self.text_encoding && !matches!(encoding, TextEncoding::Latin1 | TextEncoding::UTF8) {
+			return Err(violation(format!(
+				"Frame \"{}\" uses a disallowed text encoding ({:?})",
+				id, encoding
+			)));
+		}
+
+		let max_len = match self.text_fields_size {
+			TextSizeRestrictions::None => return Ok(()),
+			TextSizeRestrictions::C_1024 => 1024,
+			TextSizeRestrictions::C_128 => 128,
+			TextSizeRestrictions::C_30 => 30,
+		};
+
+		let len = text.iter().map(|t| t.chars().count()).sum::<usize>();
+		if len > max_len {
+			return Err(violation(format!(
+				"Frame \"{}\" has {} characters, the maximum is {}",
+				id, len, max_len
+			)));
+		}
+
+		Ok(())
+	}
+
+	fn verify_picture(self, picture: &Picture) -> Result<()> {
+		if self.image_encoding && !matches!(picture.mime_type(), MimeType::Png | MimeType::Jpeg) {
+			return Err(violation(format!(
+				"Picture has a disallowed format ({}), expected PNG or JPEG",
+				picture.mime_type().as_str()
+			)));
+		}
+
+		let valid_size = |(width, height): (u32, u32)| match self.image_size {
+			ImageSizeRestrictions::None => true,
+			ImageSizeRestrictions::P_256 => width <= 256 && height <= 256,
+			ImageSizeRestrictions::P_64 => width <= 64 && height <= 64,
+			ImageSizeRestrictions::P_64_64 => width == 64 && height == 64,
+		};
+
+		match picture.dimensions() {
+			Some(dimensions) if valid_size(dimensions) => Ok(()),
+			Some((width, height)) => Err(violation(format!(
+				"Picture is {}x{}, which violates {:?}",
+				width, height, self.image_size
+			))),
+			None if self.image_size == ImageSizeRestrictions::None => Ok(()),
+			None => Err(violation(String::from(
+				"Unable to determine the dimensions of a picture",
+			))),
+		}
+	}
+}
+
+#[cfg(feature = "id3v2")]
+fn violation(message: String) -> crate::error::LoftyError {
+	Id3v2Error::new(Id3v2ErrorKind::RestrictionViolation(message)).into()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		ImageSizeRestrictions, TagRestrictions, TagSizeRestrictions, TextSizeRestrictions,
+	};
+
+	#[test]
+	fn restrictions_re_read() {
+		let restrictions = TagRestrictions {
+			size: TagSizeRestrictions::S_32F_4K,
+			text_encoding: true,
+			text_fields_size: TextSizeRestrictions::C_128,
+			image_encoding: false,
+			image_size: ImageSizeRestrictions::P_64,
+		};
+
+		assert_eq!(restrictions.as_bytes(), 0b1111_0010);
+		assert_eq!(
+			TagRestrictions::from_byte(restrictions.as_bytes()),
+			restrictions
+		);
+	}
+}
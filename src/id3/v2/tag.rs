@@ -1,57 +1,100 @@
 use super::flags::Id3v2TagFlags;
 use super::frame::id::FrameID;
 use super::frame::{Frame, FrameFlags, FrameValue};
-use super::util::text_utils::TextEncoding;
+use super::util::convert::{frames_to_v3, frames_to_v4, merge_timestamp, text_value};
+use super::util::text_utils::{decode_latin1_lenient, TextEncoding};
 use super::Id3v2Version;
-use crate::error::{LoftyError, Result};
+use crate::error::{Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
+use crate::id3::genre::resolve_id3v2_genre;
 use crate::id3::v2::frame::FrameRef;
+use crate::id3::v2::items::comment_frame::CommentFrame;
 use crate::id3::v2::items::encoded_text_frame::EncodedTextFrame;
-use crate::id3::v2::items::language_frame::LanguageFrame;
+use crate::id3::v2::items::key_value_frame::KeyValueFrame;
+use crate::id3::v2::items::popularimeter::Popularimeter;
+use crate::id3::v2::items::private_frame::PrivateFrame;
+use crate::id3::v2::items::timestamp::Timestamp;
+use crate::id3::v2::items::unique_file_identifier::{
+	UniqueFileIdentifierFrame, MUSICBRAINZ_UFID_OWNER,
+};
+use crate::id3::v2::items::unsynchronized_text::UnsynchronizedTextFrame;
 use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 
 use std::borrow::Cow;
 use std::convert::TryInto;
-use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
 macro_rules! impl_accessor {
 	($($name:ident, $id:literal;)+) => {
 		paste::paste! {
-			impl Accessor for Id3v2Tag {
-				$(
-					fn $name(&self) -> Option<&str> {
-						if let Some(f) = self.get($id) {
-							if let FrameValue::Text {
-								ref value,
-								..
-							} = f.content() {
-								return Some(value)
-							}
+			$(
+				fn $name(&self) -> Option<&str> {
+					if let Some(f) = self.get($id) {
+						if let FrameValue::Text {
+							ref value,
+							..
+						} = f.content() {
+							return Some(value)
 						}
-
-						None
 					}
 
-					fn [<set_ $name>](&mut self, value: String) {
-						self.insert(Frame {
-							id: FrameID::Valid(String::from($id)),
-							value: FrameValue::Text {
-								encoding: TextEncoding::UTF8,
-								value,
-							},
-							flags: FrameFlags::default()
-						});
-					}
+					None
+				}
 
-					fn [<remove_ $name>](&mut self) {
-						self.remove($id)
-					}
-				)+
-			}
+				fn [<set_ $name>](&mut self, value: String) {
+					self.insert(Frame {
+						id: FrameID::Valid(String::from($id)),
+						value: FrameValue::Text {
+							encoding: TextEncoding::UTF8,
+							value,
+						},
+						flags: FrameFlags::default()
+					});
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove($id)
+				}
+			)+
+		}
+	}
+}
+
+macro_rules! impl_pair_accessor {
+	($($name:ident, $total_name:ident, $id:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<u32> {
+					self.num_pair($id).0
+				}
+
+				fn [<set_ $name>](&mut self, value: u32) {
+					let total = self.num_pair($id).1;
+					self.insert_num_pair($id, Some(value), total)
+				}
+
+				/// NOTE: The total will be removed as well, as it can't be stored on its own
+				fn [<remove_ $name>](&mut self) {
+					self.remove($id)
+				}
+
+				fn $total_name(&self) -> Option<u32> {
+					self.num_pair($id).1
+				}
+
+				fn [<set_ $total_name>](&mut self, value: u32) {
+					let current = self.num_pair($id).0;
+					self.insert_num_pair($id, current, Some(value))
+				}
+
+				fn [<remove_ $total_name>](&mut self) {
+					let current = self.num_pair($id).0;
+					self.insert_num_pair($id, current, None)
+				}
+			)+
 		}
 	}
 }
@@ -62,9 +105,12 @@ macro_rules! impl_accessor {
 /// ## Supported file types
 ///
 /// * [`FileType::MP3`](crate::FileType::MP3)
+/// * [`FileType::Aac`](crate::FileType::Aac)
 /// * [`FileType::WAV`](crate::FileType::WAV)
 /// * [`FileType::AIFF`](crate::FileType::AIFF)
+/// * [`FileType::Dsf`](crate::FileType::Dsf)
 /// * [`FileType::APE`](crate::FileType::APE) **(READ ONLY)**
+/// * [`FileType::FLAC`](crate::FileType::FLAC) **(READ ONLY)**, see [`FlacFile::save_id3v2_to`](crate::flac::FlacFile::save_id3v2_to)
 ///
 /// ## Conversions
 ///
@@ -74,17 +120,22 @@ macro_rules! impl_accessor {
 ///
 /// When converting from a [`Tag`](crate::Tag) to an `Id3v2Tag`, some frames may need editing.
 ///
-/// * [`ItemKey::Comment`](crate::ItemKey::Comment) and [`ItemKey::Lyrics`](crate::ItemKey::Lyrics) - Unlike a normal text frame, these require a [`LanguageFrame`].
-/// An attempt is made to create this information, but it may be incorrect.
+/// * [`ItemKey::Comment`](crate::ItemKey::Comment) and [`ItemKey::Lyrics`](crate::ItemKey::Lyrics) - Unlike a normal text frame, these require a
+/// [`CommentFrame`] and [`UnsynchronizedTextFrame`] respectively. An attempt is made to create this information, but it may be incorrect.
 ///    * `language` - Assumed to be "eng"
 ///    * `description` - Left empty, which is invalid if there are more than one of these frames. These frames can only be identified
 ///    by their descriptions, and as such they are expected to be unique for each.
 /// * [`ItemKey::Unknown("WXXX" | "TXXX")`](crate::ItemKey::Unknown) - These frames are also identified by their descriptions.
+/// * [`ItemKey::FlagPodcast`](crate::ItemKey::FlagPodcast) - The "PCST" frame is only a marker (4 zero bytes), so it will only be written
+/// if the value is "1" (or "true"). When reading, its presence is converted to "1".
 ///
 /// ### To `Tag`
 ///
 /// Converting an `Id3v2Tag` to a [`Tag`](crate::Tag) will not retain any frame-specific information, due
-/// to ID3v2 being the only format that requires such information. This includes things like [`TextEncoding`] and [`LanguageFrame`].
+/// to ID3v2 being the only format that requires such information. This includes things like [`TextEncoding`] and [`CommentFrame`].
+///
+/// Only comments with an empty description are converted to [`ItemKey::Comment`](crate::ItemKey::Comment).
+/// The others are converted to [`ItemKey::Unknown("COMM:<description>")`](crate::ItemKey::Unknown), losing their language.
 ///
 /// ## Special Frames
 ///
@@ -96,15 +147,92 @@ macro_rules! impl_accessor {
 pub struct Id3v2Tag {
 	flags: Id3v2TagFlags,
 	pub(super) original_version: Id3v2Version,
+	version: Id3v2Version,
 	frames: Vec<Frame>,
 }
 
-impl_accessor!(
-	title,        "TIT2";
-	artist,       "TPE1";
-	album,        "TALB";
-	genre,        "TCON";
-);
+impl Accessor for Id3v2Tag {
+	impl_accessor!(
+		title,        "TIT2";
+		artist,       "TPE1";
+		album,        "TALB";
+	);
+
+	impl_pair_accessor!(
+		track, track_total, "TRCK";
+		disk,  disk_total,  "TPOS";
+	);
+
+	/// Returns the genre of the "TCON" frame
+	///
+	/// ID3v1 genre references, such as "(17)" or "17", will be resolved to their names.
+	fn genre(&self) -> Option<&str> {
+		if let Some(FrameValue::Text { value, .. }) = self.get("TCON").map(Frame::content) {
+			return Some(resolve_id3v2_genre(value));
+		}
+
+		None
+	}
+
+	fn set_genre(&mut self, value: String) {
+		self.insert(Frame {
+			id: FrameID::Valid(String::from("TCON")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value,
+			},
+			flags: FrameFlags::default(),
+		});
+	}
+
+	fn remove_genre(&mut self) {
+		self.remove("TCON")
+	}
+
+	/// Returns the rating of the first "POPM" frame
+	///
+	/// See [`Popularimeter::star_rating`] for how the rating is mapped.
+	fn rating(&self) -> Option<u8> {
+		self.popularimeters().next().map(Popularimeter::star_rating)
+	}
+
+	/// Sets the rating of the first "POPM" frame, inserting a new frame if necessary
+	///
+	/// See [`Popularimeter::set_star_rating`] for how the rating is mapped.
+	fn set_rating(&mut self, rating: u8) {
+		let existing = self.frames.iter_mut().find_map(|f| match f {
+			Frame {
+				id: FrameID::Valid(id),
+				value: FrameValue::Popularimeter(popm),
+				..
+			} if id == "POPM" => Some(popm),
+			_ => None,
+		});
+
+		if let Some(popm) = existing {
+			popm.set_star_rating(rating);
+			return;
+		}
+
+		let mut popm = Popularimeter {
+			email: String::new(),
+			rating: 0,
+			counter: 0,
+		};
+		popm.set_star_rating(rating);
+
+		self.frames.push(Frame {
+			id: FrameID::Valid(String::from("POPM")),
+			value: FrameValue::Popularimeter(popm),
+			flags: FrameFlags::default(),
+		});
+	}
+
+	/// Removes **all** "POPM" frames
+	fn remove_rating(&mut self) {
+		self.remove("POPM")
+	}
+}
 
 impl IntoIterator for Id3v2Tag {
 	type Item = Frame;
@@ -115,11 +243,30 @@ impl IntoIterator for Id3v2Tag {
 	}
 }
 
+impl<'a> IntoIterator for &'a Id3v2Tag {
+	type Item = &'a Frame;
+	type IntoIter = std::slice::Iter<'a, Frame>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.frames.iter()
+	}
+}
+
+impl<'a> IntoIterator for &'a mut Id3v2Tag {
+	type Item = &'a mut Frame;
+	type IntoIter = std::slice::IterMut<'a, Frame>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.frames.iter_mut()
+	}
+}
+
 impl Default for Id3v2Tag {
 	fn default() -> Self {
 		Self {
 			flags: Id3v2TagFlags::default(),
 			original_version: Id3v2Version::V4,
+			version: Id3v2Version::V4,
 			frames: Vec::new(),
 		}
 	}
@@ -136,6 +283,41 @@ impl Id3v2Tag {
 		self.flags = flags
 	}
 
+	#[cfg(feature = "id3v2_restrictions")]
+	/// Verify that the tag conforms to its [`TagRestrictions`](crate::id3::v2::TagRestrictions)
+	///
+	/// Restrictions are **not** enforced when writing, so this should be called prior to saving
+	/// if conformance is required. If the restrictions flag isn't set, this will do nothing.
+	///
+	/// # Errors
+	///
+	/// * The tag has too many frames, or its frames are too large once encoded (padding is not included)
+	/// * A text field uses a disallowed [`TextEncoding`], or is too long
+	/// * A picture is not a PNG or JPEG, or its dimensions are too large
+	/// * The tag fails to be written, see [`TagExt::dump_to`]
+	pub fn validate_restrictions(&self) -> Result<()> {
+		let (enabled, restrictions) = self.flags.restrictions;
+		if !enabled {
+			return Ok(());
+		}
+
+		restrictions.verify_frames(&self.frames)?;
+
+		// The tag size is the sum of all of the encoded frames
+		let mut frame_count = 0;
+		let mut encoded = Vec::new();
+		for frame in self.frames.iter().filter_map(Frame::as_opt_ref) {
+			super::write::frame::create_items(
+				&mut encoded,
+				&mut std::iter::once(frame),
+				self.version,
+			)?;
+			frame_count += 1;
+		}
+
+		restrictions.verify_tag_size(frame_count, encoded.len())
+	}
+
 	/// The original version of the tag
 	///
 	/// This is here, since the tag is upgraded to `ID3v2.4`, but a `v2.2` or `v2.3`
@@ -143,14 +325,123 @@ impl Id3v2Tag {
 	pub fn original_version(&self) -> Id3v2Version {
 		self.original_version
 	}
+
+	/// The version the tag will be written as
+	///
+	/// This is always `ID3v2.4`, unless the tag was created with [`Id3v2Tag::to_version`].
+	pub fn version(&self) -> Id3v2Version {
+		self.version
+	}
+
+	/// Convert the tag to another version
+	///
+	/// This will convert the frames to their equivalents in the target version, which will be used
+	/// when writing the tag. Frames that have no equivalent are dropped.
+	///
+	/// Converting to `ID3v2.3`:
+	///
+	/// * "TDRC" is split into "TYER", "TDAT", and "TIME"
+	/// * "TDOR" becomes "TORY", keeping only the year
+	/// * "TIPL" and "TMCL" are merged into "IPLS"
+	/// * "TSOP", "TSOA", and "TSOT" become "XSOP", "XSOA", and "XSOT"
+	/// * Frame sizes are written as plain integers, rather than synchsafe integers
+	/// * UTF-8 and UTF-16 BE text is written as UTF-16, as they are not supported
+	///
+	/// Converting to `ID3v2.4` reverses these.
+	///
+	/// NOTE: An `ID3v2.3` tag will not have an extended header or footer, so [`Id3v2TagFlags::crc`] and
+	/// [`Id3v2TagFlags::footer`] will be ignored when writing.
+	///
+	/// # Errors
+	///
+	/// * Attempting to convert to `ID3v2.2`, which cannot be written
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Frame, FrameFlags, FrameValue, Id3v2Tag, Id3v2Version, TextEncoding};
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// let mut tag = Id3v2Tag::default();
+	/// tag.insert(
+	/// 	Frame::new(
+	/// 		"TDRC",
+	/// 		FrameValue::Text {
+	/// 			encoding: TextEncoding::UTF8,
+	/// 			value: String::from("2022-05-14T13:37"),
+	/// 		},
+	/// 		FrameFlags::default(),
+	/// 	)
+	/// 	.unwrap(),
+	/// );
+	///
+	/// let v3_tag = tag.to_version(Id3v2Version::V3)?;
+	///
+	/// assert_eq!(v3_tag.version(), Id3v2Version::V3);
+	/// assert!(v3_tag.get("TDRC").is_none());
+	/// assert!(v3_tag.get("TYER").is_some());
+	/// # Ok(()) }
+	/// ```
+	pub fn to_version(&self, version: Id3v2Version) -> Result<Self> {
+		let frames = match (self.version, version) {
+			(_, Id3v2Version::V2) => {
+				return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
+					"ID3v2.2 tags cannot be written",
+				))
+				.into())
+			},
+			(current, target) if current == target => self.frames.clone(),
+			(_, Id3v2Version::V3) => frames_to_v3(self.frames.clone()),
+			(_, Id3v2Version::V4) => frames_to_v4(self.frames.clone()),
+		};
+
+		Ok(Self {
+			flags: self.flags,
+			original_version: self.original_version,
+			version,
+			frames,
+		})
+	}
 }
 
 impl Id3v2Tag {
 	/// Returns an iterator over the tag's frames
+	///
+	/// The frames are in the order they were read, followed by any inserted frames.
+	///
+	/// Each frame retains the [`FrameFlags`] it was read with, see [`Frame::flags`].
 	pub fn iter(&self) -> impl Iterator<Item = &Frame> {
 		self.frames.iter()
 	}
 
+	/// Returns an iterator over mutable references to the tag's frames
+	///
+	/// See [`Id3v2Tag::iter`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{FrameFlags, Id3v2Tag};
+	/// use lofty::Accessor;
+	///
+	/// let mut tag = Id3v2Tag::default();
+	/// tag.set_title(String::from("Foo title"));
+	/// tag.set_artist(String::from("Bar artist"));
+	///
+	/// // Mark every frame as read only
+	/// for frame in tag.iter_mut() {
+	/// 	frame.set_flags(FrameFlags {
+	/// 		read_only: true,
+	/// 		..FrameFlags::default()
+	/// 	});
+	/// }
+	///
+	/// assert!(tag.iter().all(|frame| frame.flags().read_only));
+	/// ```
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Frame> {
+		self.frames.iter_mut()
+	}
+
 	/// Returns the number of frames in the tag
 	pub fn len(&self) -> usize {
 		self.frames.len()
@@ -248,7 +539,7 @@ impl Id3v2Tag {
 	}
 
 	/// Returns all `USLT` frames
-	pub fn unsync_text(&self) -> impl Iterator<Item = &LanguageFrame> {
+	pub fn unsync_text(&self) -> impl Iterator<Item = &UnsynchronizedTextFrame> {
 		self.frames.iter().filter_map(|f| match f {
 			Frame {
 				id: FrameID::Valid(id),
@@ -259,535 +550,2034 @@ impl Id3v2Tag {
 		})
 	}
 
-	/// Returns all `COMM` frames
-	pub fn comments(&self) -> impl Iterator<Item = &LanguageFrame> {
+	/// Returns all `POPM` frames
+	pub fn popularimeters(&self) -> impl Iterator<Item = &Popularimeter> {
 		self.frames.iter().filter_map(|f| match f {
 			Frame {
 				id: FrameID::Valid(id),
-				value: FrameValue::Comment(val),
+				value: FrameValue::Popularimeter(val),
 				..
-			} if id == "COMM" => Some(val),
+			} if id == "POPM" => Some(val),
 			_ => None,
 		})
 	}
-}
-
-impl TagExt for Id3v2Tag {
-	type Err = LoftyError;
 
-	fn is_empty(&self) -> bool {
-		self.frames.is_empty()
+	/// Returns all `PRIV` frames
+	pub fn private_frames(&self) -> impl Iterator<Item = &PrivateFrame> {
+		self.frames.iter().filter_map(|f| match f {
+			Frame {
+				id: FrameID::Valid(id),
+				value: FrameValue::Private(val),
+				..
+			} if id == "PRIV" => Some(val),
+			_ => None,
+		})
 	}
 
-	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+	/// Returns all `UFID` frames
+	pub fn unique_file_identifiers(&self) -> impl Iterator<Item = &UniqueFileIdentifierFrame> {
+		self.frames.iter().filter_map(|f| match f {
+			Frame {
+				id: FrameID::Valid(id),
+				value: FrameValue::UniqueFileIdentifier(val),
+				..
+			} if id == "UFID" => Some(val),
+			_ => None,
+		})
 	}
 
-	/// Writes the tag to a file
+	/// Gets the content of a "TXXX" frame by its description
 	///
-	/// # Errors
+	/// If the frame has multiple values, this will only return the first. See [`Id3v2Tag::get_user_text_values`].
 	///
-	/// * Attempting to write the tag to a format that does not support it
-	/// * Attempting to write an encrypted frame without a valid method symbol or data length indicator
-	/// * Attempting to write an invalid [`FrameID`]/[`FrameValue`] pairing
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
-		Id3v2TagRef {
-			flags: self.flags,
-			frames: self.frames.iter().filter_map(Frame::as_opt_ref),
-		}
-		.write_to(file)
+	/// NOTE: The description is *not* case-sensitive
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	///
+	/// let mut tag = Id3v2Tag::default();
+	/// tag.set_user_text("REPLAYGAIN_TRACK_GAIN", String::from("-6.20 dB"));
+	///
+	/// assert_eq!(
+	/// 	tag.get_user_text("replaygain_track_gain"),
+	/// 	Some("-6.20 dB")
+	/// );
+	/// ```
+	pub fn get_user_text(&self, description: &str) -> Option<&str> {
+		self.get_user_text_values(description).next()
 	}
 
-	/// Dumps the tag to a writer
+	/// Gets all values of a "TXXX" frame by its description
 	///
-	/// # Errors
+	/// In `ID3v2.4`, a frame can store multiple values separated by null terminators.
 	///
-	/// * [`std::io::Error`]
-	/// * [`ErrorKind::TooMuchData`](crate::error::ErrorKind::TooMuchData)
-	fn dump_to<W: Write>(&self, writer: &mut W) -> std::result::Result<(), Self::Err> {
-		Id3v2TagRef {
-			flags: self.flags,
-			frames: self.frames.iter().filter_map(Frame::as_opt_ref),
-		}
-		.dump_to(writer)
+	/// NOTE: The description is *not* case-sensitive
+	pub fn get_user_text_values(&self, description: &str) -> impl Iterator<Item = &str> {
+		self.user_frame("TXXX", description)
+			.into_iter()
+			.flat_map(|frame| frame.content.split('\0'))
 	}
 
-	fn remove_from_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		TagType::Id3v2.remove_from_path(path)
+	/// Sets the content of a "TXXX" frame, replacing any frame with the same description
+	///
+	/// Multiple values can be stored by separating them with null terminators (`ID3v2.4` only).
+	///
+	/// NOTE: The description is *not* case-sensitive
+	pub fn set_user_text(&mut self, description: &str, content: String) {
+		self.set_user_frame("TXXX", description, content)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
-		TagType::Id3v2.remove_from(file)
+	/// Removes a "TXXX" frame by its description
+	///
+	/// NOTE: The description is *not* case-sensitive
+	pub fn remove_user_text(&mut self, description: &str) {
+		self.remove_user_frame("TXXX", description)
 	}
 
-	fn clear(&mut self) {
-		self.frames.clear();
+	/// Gets the URL of a "WXXX" frame by its description
+	///
+	/// NOTE: The description is *not* case-sensitive
+	pub fn get_user_url(&self, description: &str) -> Option<&str> {
+		self.user_frame("WXXX", description)
+			.map(|frame| frame.content.as_str())
 	}
-}
 
-impl From<Id3v2Tag> for Tag {
-	fn from(input: Id3v2Tag) -> Self {
-		fn split_pair(
-			content: &str,
-			tag: &mut Tag,
-			current_key: ItemKey,
-			total_key: ItemKey,
-		) -> Option<()> {
-			let mut split = content.splitn(2, &['\0', '/'][..]);
-			let current = split.next()?.to_string();
-			tag.items
-				.push(TagItem::new(current_key, ItemValue::Text(current)));
+	/// Sets the URL of a "WXXX" frame, replacing any frame with the same description
+	///
+	/// NOTE: The description is *not* case-sensitive
+	pub fn set_user_url(&mut self, description: &str, url: String) {
+		self.set_user_frame("WXXX", description, url)
+	}
 
-			if let Some(total) = split.next() {
-				tag.items
-					.push(TagItem::new(total_key, ItemValue::Text(total.to_string())))
-			}
+	/// Removes a "WXXX" frame by its description
+	///
+	/// NOTE: The description is *not* case-sensitive
+	pub fn remove_user_url(&mut self, description: &str) {
+		self.remove_user_frame("WXXX", description)
+	}
 
-			Some(())
+	/// Gets the recording time as a [`Timestamp`]
+	///
+	/// This is stored in a "TDRC" frame, or in "TYER", "TDAT", and "TIME" frames if the tag
+	/// was converted to `ID3v2.3` (see [`Id3v2Tag::to_version`]).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Id3v2Tag, Timestamp};
+	///
+	/// let mut tag = Id3v2Tag::default();
+	/// tag.set_recording_date(Timestamp {
+	/// 	year: 2022,
+	/// 	month: Some(5),
+	/// 	..Timestamp::default()
+	/// });
+	///
+	/// let recording_date = tag.recording_date().unwrap();
+	/// assert_eq!(recording_date.year, 2022);
+	/// assert_eq!(recording_date.month, Some(5));
+	/// assert_eq!(recording_date.day, None);
+	/// ```
+	pub fn recording_date(&self) -> Option<Timestamp> {
+		if self.version == Id3v2Version::V3 {
+			let year = text_value(&self.frames, "TYER")?;
+			let date = text_value(&self.frames, "TDAT");
+			let time = text_value(&self.frames, "TIME");
+
+			return Timestamp::parse(&merge_timestamp(year, date, time));
 		}
 
-		let mut tag = Self::new(TagType::Id3v2);
-
-		for frame in input.frames {
-			let id = frame.id_str();
+		Timestamp::parse(text_value(&self.frames, "TDRC")?)
+	}
 
-			// The text pairs need some special treatment
-			match (id, frame.content()) {
-				("TRCK", FrameValue::Text { value: content, .. })
-					if split_pair(content, &mut tag, ItemKey::TrackNumber, ItemKey::TrackTotal)
-						.is_some() =>
-				{
-					continue
-				},
-				("TPOS", FrameValue::Text { value: content, .. })
-					if split_pair(content, &mut tag, ItemKey::DiscNumber, ItemKey::DiscTotal)
-						.is_some() =>
-				{
-					continue
-				},
-				_ => {},
-			}
+	/// Sets the recording time, replacing any existing recording time
+	///
+	/// Only the components present in the [`Timestamp`] will be written.
+	pub fn set_recording_date(&mut self, timestamp: Timestamp) {
+		self.remove_recording_date();
 
-			let item_key = ItemKey::from_key(TagType::Id3v2, id);
+		let frame = Frame {
+			id: FrameID::Valid(String::from("TDRC")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: timestamp.to_string(),
+			},
+			flags: FrameFlags::default(),
+		};
 
-			let item_value = match frame.value {
-				FrameValue::Comment(LanguageFrame { content, .. })
-				| FrameValue::UnSyncText(LanguageFrame { content, .. })
-				| FrameValue::Text { value: content, .. }
-				| FrameValue::UserText(EncodedTextFrame { content, .. }) => ItemValue::Text(content),
-				FrameValue::URL(content)
-				| FrameValue::UserURL(EncodedTextFrame { content, .. }) => ItemValue::Locator(content),
-				FrameValue::Picture { picture, .. } => {
-					tag.push_picture(picture);
-					continue;
-				},
-				FrameValue::Popularimeter(_) => continue,
-				FrameValue::Binary(binary) => ItemValue::Binary(binary),
-			};
+		if self.version == Id3v2Version::V3 {
+			self.frames.extend(frames_to_v3(vec![frame]));
+		} else {
+			self.frames.push(frame);
+		}
+	}
 
-			tag.items.push(TagItem::new(item_key, item_value));
+	/// Removes the recording time
+	pub fn remove_recording_date(&mut self) {
+		for id in ["TDRC", "TYER", "TDAT", "TIME"] {
+			self.remove(id);
 		}
+	}
 
-		tag
+	fn user_frame(&self, id: &str, description: &str) -> Option<&EncodedTextFrame> {
+		self.frames.iter().find_map(|f| match f {
+			Frame {
+				id: FrameID::Valid(frame_id),
+				value: FrameValue::UserText(content) | FrameValue::UserURL(content),
+				..
+			} if frame_id == id && content.description.eq_ignore_ascii_case(description) => Some(content),
+			_ => None,
+		})
 	}
-}
 
-impl From<Tag> for Id3v2Tag {
-	fn from(input: Tag) -> Self {
-		let mut id3v2_tag = Id3v2Tag {
-			frames: Vec::with_capacity(input.item_count() as usize),
-			..Id3v2Tag::default()
-		};
+	fn set_user_frame(&mut self, id: &str, description: &str, content: String) {
+		self.remove_user_frame(id, description);
 
-		for item in input.items {
-			let frame: Frame = match item.try_into() {
-				Ok(frame) => frame,
-				Err(_) => continue,
-			};
+		let content = EncodedTextFrame {
+			encoding: TextEncoding::UTF8,
+			description: String::from(description),
+			content,
+		};
+
+		self.frames.push(Frame {
+			id: FrameID::Valid(String::from(id)),
+			value: if id == "TXXX" {
+				FrameValue::UserText(content)
+			} else {
+				FrameValue::UserURL(content)
+			},
+			flags: FrameFlags::default(),
+		});
+	}
+
+	fn remove_user_frame(&mut self, id: &str, description: &str) {
+		self.frames.retain(|f| {
+			!matches!(f, Frame {
+					id: FrameID::Valid(frame_id),
+					value: FrameValue::UserText(content) | FrameValue::UserURL(content),
+					..
+				} if frame_id == id && content.description.eq_ignore_ascii_case(description))
+		})
+	}
+
+	// Splits a "TRCK" or "TPOS" frame in the form "current/total"
+	fn num_pair(&self, id: &str) -> (Option<u32>, Option<u32>) {
+		let content = match self.get(id).map(Frame::content) {
+			Some(FrameValue::Text { value, .. }) => value,
+			_ => return (None, None),
+		};
+
+		// A value of 0 is used as a placeholder, see `insert_num_pair`
+		let parse = |n: Option<&str>| n.and_then(|n| n.trim().parse().ok()).filter(|n| *n != 0);
+
+		let mut split = content.splitn(2, &['\0', '/'][..]);
+		(parse(split.next()), parse(split.next()))
+	}
+
+	// A total can't be stored without a current value, so it will be stored as "0/total"
+	fn insert_num_pair(&mut self, id: &str, current: Option<u32>, total: Option<u32>) {
+		let value = match (current, total) {
+			(None, None) => return self.remove(id),
+			(current, None) => current.unwrap_or_default().to_string(),
+			(current, Some(total)) => format!("{}/{}", current.unwrap_or_default(), total),
+		};
+
+		self.insert(Frame {
+			id: FrameID::Valid(String::from(id)),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value,
+			},
+			flags: FrameFlags::default(),
+		});
+	}
 
-			id3v2_tag.insert(frame);
+	/// Repairs text that was incorrectly declared as Latin-1
+	///
+	/// Some taggers will declare text as Latin-1, but actually store it as UTF-8 or Windows-1252.
+	/// Text is always decoded strictly according to its declared encoding, so this must be opted into after reading.
+	///
+	/// For each frame with Latin-1 text:
+	///
+	/// * If the original bytes are valid UTF-8, the text is decoded as UTF-8.
+	/// * Otherwise, the bytes in the range `0x80..=0x9F` are decoded as Windows-1252.
+	///
+	/// The encoding of any repaired frame is changed to [`TextEncoding::UTF8`], so the text is preserved when writing.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Frame, FrameFlags, FrameValue, Id3v2Tag, TextEncoding};
+	/// use lofty::Accessor;
+	///
+	/// let mut tag = Id3v2Tag::default();
+	///
+	/// // "Café" stored as UTF-8, but declared as Latin-1
+	/// tag.insert(Frame::new(
+	/// 	"TIT2",
+	/// 	FrameValue::Text {
+	/// 		encoding: TextEncoding::Latin1,
+	/// 		value: String::from("Caf\u{C3}\u{A9}"),
+	/// 	},
+	/// 	FrameFlags::default(),
+	/// ).unwrap());
+	///
+	/// tag.repair_latin1_text();
+	/// assert_eq!(tag.title().as_deref(), Some("Café"));
+	/// ```
+	pub fn repair_latin1_text(&mut self) {
+		// Latin-1 decoding maps each byte to a char, so the original bytes can be recovered
+		fn repair(text: &mut String) -> bool {
+			if text.chars().any(|c| u32::from(c) > 0xFF) {
+				return false;
+			}
+
+			let bytes = text.chars().map(|c| c as u8).collect::<Vec<u8>>();
+			let repaired = decode_latin1_lenient(&bytes);
+
+			if repaired == *text {
+				return false;
+			}
+
+			*text = repaired;
+			true
 		}
 
-		for picture in input.pictures {
-			id3v2_tag.frames.push(Frame {
-				id: FrameID::Valid(String::from("APIC")),
-				value: FrameValue::Picture {
-					encoding: TextEncoding::UTF8,
-					picture,
-				},
-				flags: FrameFlags::default(),
-			})
+		for frame in &mut self.frames {
+			let (encoding, text) = match &mut frame.value {
+				FrameValue::Text { encoding, value } => (encoding, vec![value]),
+				FrameValue::UserText(EncodedTextFrame {
+					encoding,
+					description,
+					content,
+				})
+				| FrameValue::UserURL(EncodedTextFrame {
+					encoding,
+					description,
+					content,
+				})
+				| FrameValue::Comment(CommentFrame {
+					encoding,
+					description,
+					content,
+					..
+				})
+				| FrameValue::UnSyncText(UnsynchronizedTextFrame {
+					encoding,
+					description,
+					content,
+					..
+				}) => (encoding, vec![description, content]),
+				FrameValue::KeyValueList(KeyValueFrame {
+					encoding,
+					key_value_pairs,
+				}) => (
+					encoding,
+					key_value_pairs
+						.iter_mut()
+						.flat_map(|(key, value)| [key, value])
+						.collect(),
+				),
+				_ => continue,
+			};
+
+			if *encoding != TextEncoding::Latin1 {
+				continue;
+			}
+
+			// Every field is repaired, even after one has changed
+			if text
+				.into_iter()
+				.fold(false, |repaired, t| repair(t) | repaired)
+			{
+				*encoding = TextEncoding::UTF8;
+			}
 		}
+	}
 
-		id3v2_tag
+	/// Returns all `COMM` frames
+	pub fn comments(&self) -> impl Iterator<Item = &CommentFrame> {
+		self.frames.iter().filter_map(|f| match f {
+			Frame {
+				id: FrameID::Valid(id),
+				value: FrameValue::Comment(val),
+				..
+			} if id == "COMM" => Some(val),
+			_ => None,
+		})
 	}
-}
 
-pub(crate) struct Id3v2TagRef<'a, I: Iterator<Item = FrameRef<'a>> + 'a> {
-	pub(crate) flags: Id3v2TagFlags,
-	pub(crate) frames: I,
-}
+	/// Gets a `COMM` frame by its description
+	///
+	/// NOTE: If there are comments with the same description in multiple languages, the first is returned
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{CommentFrame, Id3v2Tag};
+	///
+	/// let mut tag = Id3v2Tag::default();
+	/// tag.insert_comment(CommentFrame::new(*b"eng", String::from("Foo comment")));
+	/// tag.insert_comment(CommentFrame {
+	/// 	description: String::from("ID3v1 Comment"),
+	/// 	..CommentFrame::new(*b"eng", String::from("Bar comment"))
+	/// });
+	///
+	/// assert_eq!(tag.comments().count(), 2);
+	/// assert_eq!(tag.get_comment("").unwrap().content, "Foo comment");
+	/// assert_eq!(tag.get_comment("ID3v1 Comment").unwrap().content, "Bar comment");
+	/// ```
+	pub fn get_comment(&self, description: &str) -> Option<&CommentFrame> {
+		self.comments().find(|c| c.description == description)
+	}
 
-impl<'a> Id3v2TagRef<'a, std::iter::Empty<FrameRef<'a>>> {
-	pub(crate) fn empty() -> Self {
-		Self {
-			flags: Id3v2TagFlags::default(),
-			frames: std::iter::empty(),
+	/// Inserts a `COMM` frame, replacing any comment with the same language and description
+	pub fn insert_comment(&mut self, comment: CommentFrame) -> Option<Frame> {
+		self.insert(Frame {
+			id: FrameID::Valid(String::from("COMM")),
+			value: FrameValue::Comment(comment),
+			flags: FrameFlags::default(),
+		})
+	}
+
+	/// Removes all `COMM` frames with the description, regardless of their language
+	pub fn remove_comment(&mut self, description: &str) {
+		self.frames.retain(|f| {
+			!matches!(f, Frame {
+					id: FrameID::Valid(id),
+					value: FrameValue::Comment(c),
+					..
+				} if id == "COMM" && c.description == description)
+		})
+	}
+
+	pub(crate) fn as_tag_ref(&self) -> Id3v2TagRef<'_, impl Iterator<Item = FrameRef<'_>> + '_> {
+		Id3v2TagRef {
+			flags: self.flags,
+			version: self.version,
+			frames: self.frames.iter().filter_map(Frame::as_opt_ref),
 		}
 	}
 }
 
-// Create an iterator of FrameRef from a Tag's items for Id3v2TagRef::new
-pub(crate) fn tag_frames(tag: &Tag) -> impl Iterator<Item = FrameRef<'_>> + '_ {
-	let items = tag
-		.items()
-		.iter()
-		.map(TryInto::<FrameRef<'_>>::try_into)
-		.filter_map(Result::ok);
+impl TagExt for Id3v2Tag {
+	type Err = LoftyError;
 
-	let pictures = tag.pictures().iter().map(|p| FrameRef {
-		id: "APIC",
-		value: Cow::Owned(FrameValue::Picture {
-			encoding: TextEncoding::UTF8,
-			picture: p.clone(),
-		}),
-		flags: FrameFlags::default(),
-	});
+	fn is_empty(&self) -> bool {
+		self.frames.is_empty()
+	}
 
-	items.chain(pictures)
-}
+	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
+	}
 
-impl<'a, I: Iterator<Item = FrameRef<'a>> + 'a> Id3v2TagRef<'a, I> {
-	pub(crate) fn write_to(&mut self, file: &mut File) -> Result<()> {
-		super::write::write_id3v2(file, self)
+	/// Writes the tag to a file
+	///
+	/// # Errors
+	///
+	/// * Attempting to write the tag to a format that does not support it
+	/// * Attempting to write an encrypted frame without a valid method symbol or data length indicator
+	/// * Attempting to write an invalid [`FrameID`]/[`FrameValue`] pairing
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
+		self.as_tag_ref().write_to(file)
 	}
 
-	pub(crate) fn dump_to<W: Write>(&mut self, writer: &mut W) -> Result<()> {
-		let temp = super::write::create_tag(self)?;
-		writer.write_all(&*temp)?;
+	/// Dumps the tag to a writer
+	///
+	/// # Errors
+	///
+	/// * [`std::io::Error`]
+	/// * [`ErrorKind::TooMuchData`](crate::error::ErrorKind::TooMuchData)
+	fn dump_to<W: Write>(&self, writer: &mut W) -> std::result::Result<(), Self::Err> {
+		self.as_tag_ref().dump_to(writer)
+	}
+
+	fn remove_from_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
+		TagType::Id3v2.remove_from_path(path)
+	}
+
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
+		TagType::Id3v2.remove_from(file)
+	}
+
+	fn clear(&mut self) {
+		self.frames.clear();
+	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		self.frames
+			.iter()
+			.filter_map(|f| match f {
+				Frame {
+					id: FrameID::Valid(id),
+					value: FrameValue::Picture { picture, .. },
+					..
+				} if id == "APIC" => Some(picture.clone()),
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn replace_picture(&mut self, picture: Picture) -> std::result::Result<(), Self::Err> {
+		self.remove_picture_type(picture.pic_type);
+		Id3v2Tag::insert_picture(self, picture);
+
+		Ok(())
+	}
+
+	fn remove_picture_type(&mut self, picture_type: PictureType) {
+		self.remove_picture_type(picture_type)
+	}
+}
+
+impl From<Id3v2Tag> for Tag {
+	fn from(input: Id3v2Tag) -> Self {
+		fn split_pair(
+			content: &str,
+			tag: &mut Tag,
+			current_key: ItemKey,
+			total_key: ItemKey,
+		) -> Option<()> {
+			let mut split = content.splitn(2, &['\0', '/'][..]);
+			let current = split.next()?.to_string();
+			tag.items
+				.push(TagItem::new(current_key, ItemValue::Text(current)));
+
+			if let Some(total) = split.next() {
+				tag.items
+					.push(TagItem::new(total_key, ItemValue::Text(total.to_string())))
+			}
+
+			Some(())
+		}
+
+		let mut tag = Self::new(TagType::Id3v2);
+
+		for frame in input.frames {
+			let id = frame.id_str();
+
+			// The text pairs need some special treatment
+			match (id, frame.content()) {
+				("TRCK", FrameValue::Text { value: content, .. })
+					if split_pair(content, &mut tag, ItemKey::TrackNumber, ItemKey::TrackTotal)
+						.is_some() =>
+				{
+					continue
+				},
+				("TPOS", FrameValue::Text { value: content, .. })
+					if split_pair(content, &mut tag, ItemKey::DiscNumber, ItemKey::DiscTotal)
+						.is_some() =>
+				{
+					continue
+				},
+				("MVIN", FrameValue::Text { value: content, .. })
+					if split_pair(
+						content,
+						&mut tag,
+						ItemKey::MovementNumber,
+						ItemKey::MovementTotal,
+					)
+					.is_some() =>
+				{
+					continue
+				},
+				("TCON", FrameValue::Text { value: content, .. }) => {
+					let genre = resolve_id3v2_genre(content).to_string();
+					tag.items
+						.push(TagItem::new(ItemKey::Genre, ItemValue::Text(genre)));
+					continue;
+				},
+				// "PCST" has no meaningful content, its presence marks the file as a podcast
+				("PCST", _) => {
+					tag.items.push(TagItem::new(
+						ItemKey::FlagPodcast,
+						ItemValue::Text(String::from("1")),
+					));
+					continue;
+				},
+				// Some keys are stored in "TXXX" frames, identified by their descriptions
+				(
+					"TXXX",
+					FrameValue::UserText(EncodedTextFrame {
+						description,
+						content,
+						..
+					}),
+				) => {
+					if let Some(item_key) = ItemKey::from_id3v2_user_text(description) {
+						tag.items
+							.push(TagItem::new(item_key, ItemValue::Text(content.clone())));
+						continue;
+					}
+				},
+				// Roles with an equivalent key are split into their own items
+				("TIPL", FrameValue::KeyValueList(key_value)) => {
+					let mut remaining = Vec::new();
+					for (role, name) in &key_value.key_value_pairs {
+						match ItemKey::from_id3v2_involved_people_role(role) {
+							Some(item_key) => tag
+								.items
+								.push(TagItem::new(item_key, ItemValue::Text(name.clone()))),
+							None => remaining.push((role.clone(), name.clone())),
+						}
+					}
+
+					if !remaining.is_empty() {
+						let remaining = KeyValueFrame {
+							encoding: key_value.encoding,
+							key_value_pairs: remaining,
+						};
+
+						tag.items.push(TagItem::new(
+							ItemKey::InvolvedPeople,
+							ItemValue::Text(remaining.to_null_separated()),
+						));
+					}
+
+					continue;
+				},
+				// Comments with a description are application specific, and are kept
+				// under their description
+				("COMM", FrameValue::Comment(comment)) if !comment.description.is_empty() => {
+					tag.items.push(TagItem::new(
+						ItemKey::Unknown(format!("COMM:{}", comment.description)),
+						ItemValue::Text(comment.content.clone()),
+					));
+					continue;
+				},
+				("UFID", FrameValue::UniqueFileIdentifier(ufid))
+					if ufid.owner == MUSICBRAINZ_UFID_OWNER =>
+				{
+					let recording_id = String::from_utf8_lossy(&ufid.identifier).into_owned();
+					tag.items.push(TagItem::new(
+						ItemKey::MusicBrainzRecordingId,
+						ItemValue::Text(recording_id),
+					));
+					continue;
+				},
+				_ => {},
+			}
+
+			let item_key = ItemKey::from_key(TagType::Id3v2, id);
+
+			let item_value = match frame.value {
+				FrameValue::Comment(CommentFrame { content, .. })
+				| FrameValue::UnSyncText(UnsynchronizedTextFrame { content, .. })
+				| FrameValue::Text { value: content, .. }
+				| FrameValue::UserText(EncodedTextFrame { content, .. }) => ItemValue::Text(content),
+				FrameValue::KeyValueList(key_value) => {
+					ItemValue::Text(key_value.to_null_separated())
+				},
+				FrameValue::URL(content)
+				| FrameValue::UserURL(EncodedTextFrame { content, .. }) => ItemValue::Locator(content),
+				FrameValue::Picture { picture, .. } => {
+					tag.push_picture(picture);
+					continue;
+				},
+				FrameValue::Popularimeter(_)
+				| FrameValue::Chapter(_)
+				| FrameValue::TableOfContents(_)
+				| FrameValue::RelativeVolumeAdjustment(_)
+				| FrameValue::Private(_)
+				| FrameValue::UniqueFileIdentifier(_) => continue,
+				FrameValue::Binary(binary) => ItemValue::Binary(binary),
+			};
+
+			tag.items.push(TagItem::new(item_key, item_value));
+		}
+
+		tag
+	}
+}
+
+impl From<Tag> for Id3v2Tag {
+	fn from(input: Tag) -> Self {
+		let mut id3v2_tag = Id3v2Tag {
+			frames: Vec::with_capacity(input.item_count() as usize),
+			..Id3v2Tag::default()
+		};
+
+		let mut involved_people = Vec::new();
+
+		for (id, value) in num_pair_frames(&input) {
+			id3v2_tag.insert(Frame {
+				id: FrameID::Valid(String::from(id)),
+				value: FrameValue::Text {
+					encoding: TextEncoding::UTF8,
+					value,
+				},
+				flags: FrameFlags::default(),
+			});
+		}
+
+		for item in input.items {
+			if is_num_pair_key(item.key()) {
+				continue;
+			}
+
+			let frame: Frame = match item.try_into() {
+				Ok(frame) => frame,
+				Err(_) => continue,
+			};
+
+			// All of the roles are merged into a single "TIPL" frame
+			match frame {
+				Frame {
+					id: FrameID::Valid(ref id),
+					value: FrameValue::KeyValueList(mut key_value),
+					..
+				} if id == "TIPL" => involved_people.append(&mut key_value.key_value_pairs),
+				frame => {
+					id3v2_tag.insert(frame);
+				},
+			}
+		}
+
+		if !involved_people.is_empty() {
+			id3v2_tag.insert(Frame {
+				id: FrameID::Valid(String::from("TIPL")),
+				value: FrameValue::KeyValueList(KeyValueFrame {
+					encoding: TextEncoding::UTF8,
+					key_value_pairs: involved_people,
+				}),
+				flags: FrameFlags::default(),
+			});
+		}
+
+		for picture in input.pictures {
+			id3v2_tag.frames.push(Frame {
+				id: FrameID::Valid(String::from("APIC")),
+				value: FrameValue::Picture {
+					encoding: TextEncoding::UTF8,
+					picture,
+				},
+				flags: FrameFlags::default(),
+			})
+		}
+
+		id3v2_tag
+	}
+}
+
+pub(crate) struct Id3v2TagRef<'a, I: Iterator<Item = FrameRef<'a>> + 'a> {
+	pub(crate) flags: Id3v2TagFlags,
+	pub(crate) version: Id3v2Version,
+	pub(crate) frames: I,
+}
+
+impl<'a> Id3v2TagRef<'a, std::iter::Empty<FrameRef<'a>>> {
+	pub(crate) fn empty() -> Self {
+		Self {
+			flags: Id3v2TagFlags::default(),
+			version: Id3v2Version::V4,
+			frames: std::iter::empty(),
+		}
+	}
+}
+
+// These frames store both the current value and the total, in the form "current/total"
+const NUM_PAIRS: [(&str, ItemKey, ItemKey); 3] = [
+	("TRCK", ItemKey::TrackNumber, ItemKey::TrackTotal),
+	("TPOS", ItemKey::DiscNumber, ItemKey::DiscTotal),
+	("MVIN", ItemKey::MovementNumber, ItemKey::MovementTotal),
+];
+
+fn is_num_pair_key(key: &ItemKey) -> bool {
+	NUM_PAIRS
+		.iter()
+		.any(|(_, current, total)| key == current || key == total)
+}
+
+// Both items of a pair are merged into a single frame, otherwise one would replace the other.
+// A total can't be stored without a current value, so it will be stored as "0/total"
+fn num_pair_frames(tag: &Tag) -> impl Iterator<Item = (&'static str, String)> + '_ {
+	NUM_PAIRS.iter().filter_map(|(id, current, total)| {
+		let value = match (tag.get_string(current), tag.get_string(total)) {
+			(None, None) => return None,
+			(Some(current), None) => current.to_string(),
+			(current, Some(total)) => format!("{}/{}", current.unwrap_or("0"), total),
+		};
+
+		Some((*id, value))
+	})
+}
+
+// Create an iterator of FrameRef from a Tag's items for Id3v2TagRef::new
+pub(crate) fn tag_frames(tag: &Tag) -> impl Iterator<Item = FrameRef<'_>> + '_ {
+	let mut items = Vec::new();
+	let mut involved_people = Vec::new();
+
+	for (id, value) in num_pair_frames(tag) {
+		items.push(FrameRef {
+			id,
+			value: Cow::Owned(FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value,
+			}),
+			flags: FrameFlags::default(),
+		});
+	}
+
+	for frame in tag
+		.items()
+		.iter()
+		.filter(|item| !is_num_pair_key(item.key()))
+		.map(TryInto::<FrameRef<'_>>::try_into)
+		.filter_map(Result::ok)
+	{
+		// All of the roles are merged into a single "TIPL" frame
+		if let ("TIPL", FrameValue::KeyValueList(key_value)) = (frame.id, frame.value.as_ref()) {
+			involved_people.extend(key_value.key_value_pairs.iter().cloned());
+			continue;
+		}
+
+		items.push(frame);
+	}
+
+	let involved_people = (!involved_people.is_empty()).then(|| FrameRef {
+		id: "TIPL",
+		value: Cow::Owned(FrameValue::KeyValueList(KeyValueFrame {
+			encoding: TextEncoding::UTF8,
+			key_value_pairs: involved_people,
+		})),
+		flags: FrameFlags::default(),
+	});
+
+	let pictures = tag.pictures().iter().map(|p| FrameRef {
+		id: "APIC",
+		value: Cow::Owned(FrameValue::Picture {
+			encoding: TextEncoding::UTF8,
+			picture: p.clone(),
+		}),
+		flags: FrameFlags::default(),
+	});
+
+	items.into_iter().chain(involved_people).chain(pictures)
+}
+
+impl<'a, I: Iterator<Item = FrameRef<'a>> + 'a> Id3v2TagRef<'a, I> {
+	pub(crate) fn write_to<F: FileLike>(&mut self, file: &mut F) -> Result<()> {
+		super::write::write_id3v2(file, self)
+	}
+
+	pub(crate) fn write_to_flac<F: FileLike>(&mut self, file: &mut F) -> Result<()> {
+		super::write::write_id3v2_to_flac(file, self)
+	}
+
+	pub(crate) fn dump_to<W: Write>(&mut self, writer: &mut W) -> Result<()> {
+		let temp = super::write::create_tag(self)?;
+		writer.write_all(&*temp)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{
+		read_id3v2_header, ChapterFrame, CommentFrame, EncodedTextFrame, Frame, FrameFlags,
+		FrameID, FrameValue, Id3v2Tag, Id3v2TagFlags, Id3v2Version, KeyValueFrame, Popularimeter,
+		PrivateFrame, TableOfContentsFrame, TextEncoding, Timestamp, UniqueFileIdentifierFrame,
+	};
+	use crate::probe::ParseContext;
+	use crate::tag::utils::test_utils::read_path;
+	use crate::{
+		Accessor, ItemKey, ItemValue, MimeType, Picture, PictureType, Tag, TagExt, TagItem, TagType,
+	};
+
+	fn read_tag(path: &str) -> Id3v2Tag {
+		let tag_bytes = crate::tag::utils::test_utils::read_path(path);
+
+		let mut reader = std::io::Cursor::new(&tag_bytes[..]);
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		crate::id3::v2::read::parse_id3v2(&mut reader, header, &mut ParseContext::default())
+			.unwrap()
+	}
+
+	#[test]
+	fn parse_id3v2() {
+		let mut expected_tag = Id3v2Tag::default();
+
+		let encoding = TextEncoding::Latin1;
+		let flags = FrameFlags::default();
+
+		expected_tag.insert(
+			Frame::new(
+				"TPE1",
+				FrameValue::Text {
+					encoding,
+					value: String::from("Bar artist"),
+				},
+				flags,
+			)
+			.unwrap(),
+		);
+
+		expected_tag.insert(
+			Frame::new(
+				"TIT2",
+				FrameValue::Text {
+					encoding,
+					value: String::from("Foo title"),
+				},
+				flags,
+			)
+			.unwrap(),
+		);
+
+		expected_tag.insert(
+			Frame::new(
+				"TALB",
+				FrameValue::Text {
+					encoding,
+					value: String::from("Baz album"),
+				},
+				flags,
+			)
+			.unwrap(),
+		);
+
+		expected_tag.insert(
+			Frame::new(
+				"COMM",
+				FrameValue::Comment(CommentFrame {
+					encoding,
+					language: *b"eng",
+					description: String::new(),
+					content: String::from("Qux comment"),
+				}),
+				flags,
+			)
+			.unwrap(),
+		);
+
+		expected_tag.insert(
+			Frame::new(
+				"TDRC",
+				FrameValue::Text {
+					encoding,
+					value: String::from("1984"),
+				},
+				flags,
+			)
+			.unwrap(),
+		);
+
+		expected_tag.insert(
+			Frame::new(
+				"TRCK",
+				FrameValue::Text {
+					encoding,
+					value: String::from("1"),
+				},
+				flags,
+			)
+			.unwrap(),
+		);
+
+		expected_tag.insert(
+			Frame::new(
+				"TCON",
+				FrameValue::Text {
+					encoding,
+					value: String::from("Classical"),
+				},
+				flags,
+			)
+			.unwrap(),
+		);
+
+		let parsed_tag = read_tag("tests/tags/assets/id3v2/test.id3v24");
+
+		assert_eq!(expected_tag, parsed_tag);
+	}
+
+	#[test]
+	fn id3v2_re_read() {
+		let parsed_tag = read_tag("tests/tags/assets/id3v2/test.id3v24");
+
+		let mut writer = Vec::new();
+		parsed_tag.dump_to(&mut writer).unwrap();
+
+		let temp_reader = &mut &*writer;
+
+		let temp_header = read_id3v2_header(temp_reader).unwrap();
+		let temp_parsed_tag = crate::id3::v2::read::parse_id3v2(
+			temp_reader,
+			temp_header,
+			&mut ParseContext::default(),
+		)
+		.unwrap();
+
+		assert_eq!(parsed_tag, temp_parsed_tag);
+	}
+
+	#[test]
+	fn id3v2_to_tag() {
+		let id3v2 = read_tag("tests/tags/assets/id3v2/test.id3v24");
+
+		let tag: Tag = id3v2.into();
+
+		crate::tag::utils::test_utils::verify_tag(&tag, true, true);
+	}
+
+	#[test]
+	fn fail_write_bad_frame() {
+		let mut tag = Id3v2Tag::default();
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("ABCD")),
+			value: FrameValue::URL(String::from("FOO URL")),
+			flags: FrameFlags::default(),
+		});
+
+		let res = tag.dump_to(&mut Vec::<u8>::new());
+
+		assert!(res.is_err());
+		assert_eq!(
+			res.unwrap_err().to_string(),
+			String::from(
+				"ID3v2: Attempted to write an invalid frame. ID: \"ABCD\", Value: \"URL\""
+			)
+		);
+	}
+
+	#[test]
+	fn fail_write_bad_frame_id() {
+		// Invalid IDs can't be created through `FrameID::new`, but the variants can be constructed directly
+		for id in ["TIT", "tit2", "TIT2 "] {
+			let mut tag = Id3v2Tag::default();
+			tag.insert(Frame {
+				id: FrameID::Valid(String::from(id)),
+				value: FrameValue::Binary(vec![1, 2, 3]),
+				flags: FrameFlags::default(),
+			});
+
+			let res = tag.dump_to(&mut Vec::<u8>::new());
+			assert!(res.is_err(), "{}", id);
+		}
+
+		let err = FrameID::new("TI-2").unwrap_err();
+		assert_eq!(
+			err.to_string(),
+			"ID3v2: invalid frame ID \"TI-2\", expected 4 characters (3 for ID3v2.2) within 'A'..='Z' \
+			 or '0'..='9'"
+		);
+
+		// Lowercase IDs are normalized
+		let frame = Frame::new(
+			"tit2",
+			FrameValue::Binary(vec![1, 2, 3]),
+			FrameFlags::default(),
+		);
+		assert_eq!(frame.unwrap().id_str(), "TIT2");
+	}
+
+	#[test]
+	fn tag_to_id3v2() {
+		fn verify_frame(tag: &Id3v2Tag, id: &str, value: &str) {
+			let frame = tag.get(id);
+
+			assert!(frame.is_some());
+
+			let frame = frame.unwrap();
+
+			assert_eq!(
+				frame.content(),
+				&FrameValue::Text {
+					encoding: TextEncoding::UTF8,
+					value: String::from(value)
+				}
+			);
+		}
+
+		let tag = crate::tag::utils::test_utils::create_tag(TagType::Id3v2);
+
+		let id3v2_tag: Id3v2Tag = tag.into();
+
+		verify_frame(&id3v2_tag, "TIT2", "Foo title");
+		verify_frame(&id3v2_tag, "TPE1", "Bar artist");
+		verify_frame(&id3v2_tag, "TALB", "Baz album");
+
+		let frame = id3v2_tag.get("COMM").unwrap();
+		assert_eq!(
+			frame.content(),
+			&FrameValue::Comment(CommentFrame {
+				encoding: TextEncoding::Latin1,
+				language: *b"eng",
+				description: String::new(),
+				content: String::from("Qux comment")
+			})
+		);
+
+		verify_frame(&id3v2_tag, "TRCK", "1");
+		verify_frame(&id3v2_tag, "TCON", "Classical");
+	}
+
+	#[allow(clippy::field_reassign_with_default)]
+	fn create_full_test_tag(version: Id3v2Version) -> Id3v2Tag {
+		let mut tag = Id3v2Tag::default();
+		tag.original_version = version;
+
+		let encoding = TextEncoding::UTF16;
+		let flags = FrameFlags::default();
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TIT2")),
+			value: FrameValue::Text {
+				encoding,
+				value: String::from("TempleOS Hymn Risen (Remix)"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TPE1")),
+			value: FrameValue::Text {
+				encoding,
+				value: String::from("Dave Eddy"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TRCK")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::Latin1,
+				value: String::from("1"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TALB")),
+			value: FrameValue::Text {
+				encoding,
+				value: String::from("Summer"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TDRC")),
+			value: FrameValue::Text {
+				encoding,
+				value: String::from("2017"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TCON")),
+			value: FrameValue::Text {
+				encoding,
+				value: String::from("Electronic"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("TLEN")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF16,
+				value: String::from("213017"),
+			},
+			flags,
+		});
+
+		tag.insert(Frame {
+			id: FrameID::Valid(String::from("APIC")),
+			value: FrameValue::Picture {
+				encoding: TextEncoding::Latin1,
+				picture: Picture {
+					pic_type: PictureType::CoverFront,
+					mime_type: MimeType::Png,
+					description: None,
+					data: read_path("tests/tags/assets/id3v2/test_full_cover.png").into(),
+				},
+			},
+			flags,
+		});
+
+		tag
+	}
+
+	#[test]
+	fn id3v24_full() {
+		let tag = create_full_test_tag(Id3v2Version::V4);
+		let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v24");
+
+		assert_eq!(tag, parsed_tag);
+	}
+
+	#[test]
+	fn id3v23_full() {
+		let tag = create_full_test_tag(Id3v2Version::V3);
+		let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v23");
+
+		assert_eq!(tag, parsed_tag);
+	}
+
+	#[test]
+	fn id3v22_full() {
+		let tag = create_full_test_tag(Id3v2Version::V2);
+		let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v22");
+
+		assert_eq!(tag, parsed_tag);
+	}
+
+	#[test]
+	fn id3v24_footer() {
+		let mut tag = create_full_test_tag(Id3v2Version::V4);
+		tag.flags.footer = true;
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		assert!(
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default()).is_ok()
+		);
+
+		assert_eq!(writer[3..10], writer[writer.len() - 7..])
+	}
+
+	#[test]
+	fn issue_36() {
+		let picture_data = vec![0; 200];
+
+		let picture = Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Jpeg,
+			Some(String::from("cover")),
+			picture_data,
+		);
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.push_picture(picture.clone());
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let tag = crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+			.unwrap();
+
+		assert_eq!(tag.len(), 1);
+		assert_eq!(
+			tag.frames.first(),
+			Some(&Frame {
+				id: FrameID::Valid(String::from("APIC")),
+				value: FrameValue::Picture {
+					encoding: TextEncoding::UTF8,
+					picture
+				},
+				flags: FrameFlags::default()
+			})
+		);
+	}
+
+	#[test]
+	fn chapter_frames_re_read() {
+		let title = Frame {
+			id: FrameID::Valid(String::from("TIT2")),
+			value: FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("Chapter 1"),
+			},
+			flags: FrameFlags::default(),
+		};
+
+		let chapter = ChapterFrame {
+			element_id: String::from("chp0"),
+			start_time: 0,
+			end_time: 30000,
+			start_offset: None,
+			end_offset: None,
+			embedded_frames: vec![title],
+		};
+
+		let toc = TableOfContentsFrame {
+			element_id: String::from("toc"),
+			top_level: true,
+			ordered: true,
+			child_element_ids: vec![String::from("chp0")],
+			embedded_frames: Vec::new(),
+		};
+
+		let mut tag = Id3v2Tag::default();
+		tag.insert(
+			Frame::new(
+				"CHAP",
+				FrameValue::Chapter(chapter.clone()),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+		tag.insert(
+			Frame::new(
+				"CTOC",
+				FrameValue::TableOfContents(toc.clone()),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
 
-		Ok(())
-	}
-}
+		let mut reader = &mut &writer[..];
 
-#[cfg(test)]
-mod tests {
-	use crate::id3::v2::items::popularimeter::Popularimeter;
-	use crate::id3::v2::{
-		read_id3v2_header, Frame, FrameFlags, FrameID, FrameValue, Id3v2Tag, Id3v2Version,
-		LanguageFrame, TextEncoding,
-	};
-	use crate::tag::utils::test_utils::read_path;
-	use crate::{MimeType, Picture, PictureType, Tag, TagExt, TagType};
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
 
-	fn read_tag(path: &str) -> Id3v2Tag {
-		let tag_bytes = crate::tag::utils::test_utils::read_path(path);
+		assert_eq!(parsed_tag.len(), 2);
 
-		let mut reader = std::io::Cursor::new(&tag_bytes[..]);
+		match parsed_tag.get("CHAP").map(Frame::content) {
+			Some(FrameValue::Chapter(parsed_chapter)) => {
+				assert_eq!(parsed_chapter.end_time, chapter.end_time);
+				assert_eq!(parsed_chapter.start_offset, None);
+				assert_eq!(parsed_chapter.embedded_frames, chapter.embedded_frames);
+			},
+			_ => panic!("Expected a chapter frame"),
+		}
 
-		let header = read_id3v2_header(&mut reader).unwrap();
-		crate::id3::v2::read::parse_id3v2(&mut reader, header).unwrap()
+		match parsed_tag.get("CTOC").map(Frame::content) {
+			Some(FrameValue::TableOfContents(parsed_toc)) => {
+				assert!(parsed_toc.top_level);
+				assert_eq!(parsed_toc.child_element_ids, toc.child_element_ids);
+			},
+			_ => panic!("Expected a table of contents frame"),
+		}
 	}
 
 	#[test]
-	fn parse_id3v2() {
-		let mut expected_tag = Id3v2Tag::default();
-
-		let encoding = TextEncoding::Latin1;
-		let flags = FrameFlags::default();
-
-		expected_tag.insert(
-			Frame::new(
-				"TPE1",
-				FrameValue::Text {
-					encoding,
-					value: String::from("Bar artist"),
-				},
-				flags,
-			)
-			.unwrap(),
-		);
+	fn frame_flags_re_read() {
+		let flags = FrameFlags {
+			read_only: true,
+			grouping_identity: (true, 5),
+			data_length_indicator: (true, 0),
+			..FrameFlags::default()
+		};
 
-		expected_tag.insert(
+		let mut tag = Id3v2Tag::default();
+		tag.insert(
 			Frame::new(
 				"TIT2",
 				FrameValue::Text {
-					encoding,
+					encoding: TextEncoding::UTF8,
 					value: String::from("Foo title"),
 				},
 				flags,
 			)
 			.unwrap(),
 		);
-
-		expected_tag.insert(
+		tag.insert(
 			Frame::new(
 				"TALB",
 				FrameValue::Text {
-					encoding,
+					encoding: TextEncoding::UTF8,
 					value: String::from("Baz album"),
 				},
-				flags,
+				FrameFlags {
+					tag_alter_preservation: true,
+					grouping_identity: (true, 1),
+					..FrameFlags::default()
+				},
 			)
 			.unwrap(),
 		);
 
-		expected_tag.insert(
-			Frame::new(
-				"COMM",
-				FrameValue::Comment(LanguageFrame {
-					encoding,
-					language: String::from("eng"),
-					description: String::new(),
-					content: String::from("Qux comment"),
-				}),
-				flags,
-			)
-			.unwrap(),
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert_eq!(parsed_tag.title(), Some("Foo title"));
+		assert_eq!(parsed_tag.album(), Some("Baz album"));
+
+		// The data length indicator is calculated when writing
+		let title_flags = parsed_tag.get("TIT2").map(Frame::flags).unwrap();
+		assert_eq!(
+			*title_flags,
+			FrameFlags {
+				data_length_indicator: (true, 10),
+				..flags
+			}
 		);
 
-		expected_tag.insert(
-			Frame::new(
-				"TDRC",
-				FrameValue::Text {
-					encoding,
-					value: String::from("1984"),
-				},
-				flags,
-			)
-			.unwrap(),
+		let album_flags = parsed_tag.get("TALB").map(Frame::flags).unwrap();
+		assert!(album_flags.tag_alter_preservation);
+		assert_eq!(album_flags.grouping_identity, (true, 1));
+		assert_eq!(album_flags.data_length_indicator, (false, 0));
+	}
+
+	#[test]
+	fn unknown_frame_re_read() {
+		#[rustfmt::skip]
+		let tag_bytes = [
+			b'I', b'D', b'3', 4, 0, 0, 0, 0, 0, 29,
+			// A known frame
+			b'T', b'I', b'T', b'2', 0, 0, 0, 4, 0, 0,
+			3, b'F', b'o', b'o',
+			// An unknown frame, marked as read only
+			b'Z', b'Z', b'Z', b'Z', 0, 0, 0, 5, 0x10, 0,
+			0xDE, 0xAD, 0xBE, 0xEF, 0,
+		];
+
+		let mut reader = &mut &tag_bytes[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		let unknown = parsed_tag.get("ZZZZ").unwrap();
+		assert_eq!(
+			unknown.content(),
+			&FrameValue::Binary(vec![0xDE, 0xAD, 0xBE, 0xEF, 0])
 		);
+		assert!(unknown.flags().read_only);
 
-		expected_tag.insert(
+		// The unknown frame is written back unchanged
+		let mut writer = Vec::new();
+		parsed_tag.dump_to(&mut writer).unwrap();
+
+		assert_eq!(writer, tag_bytes);
+	}
+
+	#[test]
+	fn compressed_frame_re_read() {
+		let flags = FrameFlags {
+			compression: true,
+			..FrameFlags::default()
+		};
+
+		let mut tag = Id3v2Tag::default();
+		tag.insert(
 			Frame::new(
-				"TRCK",
+				"TIT2",
 				FrameValue::Text {
-					encoding,
-					value: String::from("1"),
+					encoding: TextEncoding::UTF8,
+					value: String::from("Foo title"),
 				},
 				flags,
 			)
 			.unwrap(),
 		);
 
-		expected_tag.insert(
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert_eq!(parsed_tag.title(), Some("Foo title"));
+
+		let title_flags = parsed_tag.get("TIT2").map(Frame::flags).unwrap();
+		assert!(title_flags.compression);
+		assert_eq!(title_flags.data_length_indicator, (true, 10));
+	}
+
+	#[test]
+	fn skip_bad_compressed_frame() {
+		let mut frames = Vec::new();
+
+		// A "compressed" frame with a data length indicator of 10
+		frames.extend(b"TIT2");
+		frames.extend(8_u32.to_be_bytes());
+		frames.extend([0x00, 0x09]);
+		frames.extend(10_u32.to_be_bytes());
+		frames.extend([1, 2, 3, 4]);
+
+		frames.extend(b"TALB");
+		frames.extend(10_u32.to_be_bytes());
+		frames.extend([0x00, 0x00]);
+		frames.push(TextEncoding::UTF8 as u8);
+		frames.extend(b"Baz album");
+
+		let mut tag_bytes = b"ID3\x04\x00\x00".to_vec();
+		tag_bytes.extend((frames.len() as u32).to_be_bytes());
+		tag_bytes.extend(frames);
+
+		let mut reader = &mut &tag_bytes[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert_eq!(parsed_tag.len(), 1);
+		assert_eq!(parsed_tag.title(), None);
+		assert_eq!(parsed_tag.album(), Some("Baz album"));
+	}
+
+	#[test]
+	fn unsynchronised_re_read() {
+		let mut tag = Id3v2Tag::default();
+		tag.set_title(String::from("Foo title"));
+		tag.insert(
 			Frame::new(
-				"TCON",
-				FrameValue::Text {
-					encoding,
-					value: String::from("Classical"),
-				},
-				flags,
+				"PRIV",
+				FrameValue::Private(PrivateFrame {
+					owner: String::from("Foo"),
+					data: vec![0xFF, 0xFB, 0xFF, 0x00, 0x01, 0xFF],
+				}),
+				FrameFlags::default(),
 			)
 			.unwrap(),
 		);
 
-		let parsed_tag = read_tag("tests/tags/assets/id3v2/test.id3v24");
+		tag.set_flags(Id3v2TagFlags {
+			unsynchronisation: true,
+			..Id3v2TagFlags::default()
+		});
 
-		assert_eq!(expected_tag, parsed_tag);
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		// The tag header should have the unsynchronisation flag set
+		assert_eq!(writer[5] & 0x80, 0x80);
+
+		// There should be no false synchronisations in the tag
+		assert!(!writer.windows(2).any(|w| w[0] == 0xFF && w[1] >= 0xE0));
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert!(parsed_tag.flags().unsynchronisation);
+		assert_eq!(parsed_tag.len(), 2);
+		assert!(parsed_tag
+			.iter()
+			.all(|frame| frame.flags().unsynchronisation));
+		assert_eq!(
+			parsed_tag.frames.iter().collect::<Vec<_>>(),
+			tag.frames.iter().collect::<Vec<_>>()
+		);
 	}
 
 	#[test]
-	fn id3v2_re_read() {
-		let parsed_tag = read_tag("tests/tags/assets/id3v2/test.id3v24");
+	fn multiple_private_frames() {
+		let private_frame = |owner: &str, data: &[u8]| {
+			Frame::new(
+				"PRIV",
+				FrameValue::Private(PrivateFrame {
+					owner: String::from(owner),
+					data: data.to_vec(),
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap()
+		};
+
+		let mut tag = Id3v2Tag::default();
+		tag.insert(private_frame("WM/MediaClassPrimaryID", &[1, 2, 3]));
+		tag.insert(private_frame("WM/MediaClassSecondaryID", &[4, 5, 6]));
+		// Identical to the first frame, so it should replace it
+		assert!(tag
+			.insert(private_frame("WM/MediaClassPrimaryID", &[1, 2, 3]))
+			.is_some());
 
 		let mut writer = Vec::new();
-		parsed_tag.dump_to(&mut writer).unwrap();
+		tag.dump_to(&mut writer).unwrap();
 
-		let temp_reader = &mut &*writer;
+		let mut reader = &mut &writer[..];
 
-		let temp_header = read_id3v2_header(temp_reader).unwrap();
-		let temp_parsed_tag = crate::id3::v2::read::parse_id3v2(temp_reader, temp_header).unwrap();
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
 
-		assert_eq!(parsed_tag, temp_parsed_tag);
+		let mut owners = parsed_tag
+			.private_frames()
+			.map(|f| f.owner.as_str())
+			.collect::<Vec<_>>();
+		owners.sort_unstable();
+
+		assert_eq!(
+			owners,
+			vec!["WM/MediaClassPrimaryID", "WM/MediaClassSecondaryID"]
+		);
+		assert!(parsed_tag
+			.private_frames()
+			.any(|f| f.owner == "WM/MediaClassSecondaryID" && f.data == [4, 5, 6]));
 	}
 
 	#[test]
-	fn id3v2_to_tag() {
-		let id3v2 = read_tag("tests/tags/assets/id3v2/test.id3v24");
+	fn musicbrainz_recording_id() {
+		const RECORDING_ID: &str = "f0b0e1c4-6f3e-4c43-8a4e-1f1c1b2a3d4e";
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.insert_text(ItemKey::MusicBrainzRecordingId, String::from(RECORDING_ID));
+
+		let id3v2: Id3v2Tag = tag.into();
+
+		let mut writer = Vec::new();
+		id3v2.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		let ufid = parsed_tag.unique_file_identifiers().next().unwrap();
+		assert_eq!(ufid.owner, "http://musicbrainz.org");
+		assert_eq!(ufid.identifier, RECORDING_ID.as_bytes());
+
+		let tag: Tag = parsed_tag.into();
+		assert_eq!(
+			tag.get_string(&ItemKey::MusicBrainzRecordingId),
+			Some(RECORDING_ID)
+		);
+
+		// Identifiers from other owners aren't converted
+		let mut id3v2 = Id3v2Tag::default();
+		id3v2.insert(
+			Frame::new(
+				"UFID",
+				FrameValue::UniqueFileIdentifier(UniqueFileIdentifierFrame {
+					owner: String::from("http://www.id3.org/dummy/ufid.html"),
+					identifier: vec![1, 2, 3],
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
 
 		let tag: Tag = id3v2.into();
+		assert!(tag.is_empty());
+		assert!(TagItem::new(
+			ItemKey::MusicBrainzRecordingId,
+			ItemValue::Text(String::new())
+		)
+		.re_map(TagType::Id3v2));
+	}
 
-		crate::tag::utils::test_utils::verify_tag(&tag, true, true);
+	#[test]
+	fn involved_people() {
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.insert_text(ItemKey::Producer, String::from("Foo"));
+		tag.insert_text(ItemKey::Engineer, String::from("Bar"));
+		tag.insert_text(ItemKey::InvolvedPeople, String::from("vocals\0Baz"));
+		tag.insert_text(ItemKey::MusicianCredits, String::from("guitar\0Qux"));
+
+		let id3v2: Id3v2Tag = tag.into();
+
+		// The roles are merged into a single frame
+		assert_eq!(
+			id3v2.get("TIPL").map(Frame::content),
+			Some(&FrameValue::KeyValueList(KeyValueFrame {
+				encoding: TextEncoding::UTF8,
+				key_value_pairs: vec![
+					(String::from("producer"), String::from("Foo")),
+					(String::from("engineer"), String::from("Bar")),
+					(String::from("vocals"), String::from("Baz")),
+				],
+			}))
+		);
+
+		let mut writer = Vec::new();
+		id3v2.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert_eq!(parsed_tag, id3v2);
+
+		let tag: Tag = parsed_tag.into();
+		assert_eq!(tag.get_string(&ItemKey::Producer), Some("Foo"));
+		assert_eq!(tag.get_string(&ItemKey::Engineer), Some("Bar"));
+		assert_eq!(
+			tag.get_string(&ItemKey::InvolvedPeople),
+			Some("vocals\0Baz")
+		);
+		assert_eq!(
+			tag.get_string(&ItemKey::MusicianCredits),
+			Some("guitar\0Qux")
+		);
+
+		// Both lists become a single "IPLS" frame in ID3v2.3
+		let v3_tag = id3v2.to_version(Id3v2Version::V3).unwrap();
+		assert!(v3_tag.get("TIPL").is_none());
+		assert!(v3_tag.get("TMCL").is_none());
+
+		match v3_tag.get("IPLS").map(Frame::content) {
+			Some(FrameValue::KeyValueList(key_value)) => {
+				assert_eq!(key_value.key_value_pairs.len(), 4);
+				assert!(key_value
+					.key_value_pairs
+					.contains(&(String::from("guitar"), String::from("Qux"))));
+			},
+			_ => panic!("Expected an \"IPLS\" frame"),
+		}
+	}
+
+	fn text_frame(id: &str, value: &str) -> Frame {
+		Frame::new(
+			id,
+			FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from(value),
+			},
+			FrameFlags::default(),
+		)
+		.unwrap()
+	}
+
+	fn text_value<'a>(tag: &'a Id3v2Tag, id: &str) -> Option<&'a str> {
+		match tag.get(id).map(Frame::content) {
+			Some(FrameValue::Text { value, .. }) => Some(value.as_str()),
+			_ => None,
+		}
 	}
 
 	#[test]
-	fn fail_write_bad_frame() {
+	fn id3v24_to_id3v23_re_read() {
 		let mut tag = Id3v2Tag::default();
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("ABCD")),
-			value: FrameValue::URL(String::from("FOO URL")),
-			flags: FrameFlags::default(),
-		});
+		tag.insert(text_frame("TDRC", "2022-05-14T13:37:00"));
+		tag.insert(text_frame("TSOP", "Artist, Foo"));
+		tag.insert(text_frame("TMOO", "Cheerful"));
+		// Large enough that its size will differ between synchsafe and plain integers
+		tag.insert(text_frame("TIT2", &"A".repeat(200)));
+
+		let v3_tag = tag.to_version(Id3v2Version::V3).unwrap();
+
+		assert_eq!(v3_tag.version(), Id3v2Version::V3);
+		assert_eq!(text_value(&v3_tag, "TYER"), Some("2022"));
+		assert_eq!(text_value(&v3_tag, "TDAT"), Some("1405"));
+		assert_eq!(text_value(&v3_tag, "TIME"), Some("1337"));
+		assert_eq!(text_value(&v3_tag, "XSOP"), Some("Artist, Foo"));
+		assert!(v3_tag.get("TDRC").is_none());
+		assert!(v3_tag.get("TSOP").is_none());
+		// "TMOO" has no ID3v2.3 equivalent
+		assert!(v3_tag.get("TMOO").is_none());
 
-		let res = tag.dump_to(&mut Vec::<u8>::new());
+		let mut writer = Vec::new();
+		v3_tag.dump_to(&mut writer).unwrap();
 
-		assert!(res.is_err());
+		// Version 3, rev 0
+		assert_eq!(&writer[3..5], &[3, 0]);
+
+		// UTF-8 isn't supported in ID3v2.3, so the text is re-encoded, bringing it to
+		// 1 (encoding) + 2 (BOM) + 2 * 200 (text) bytes
+		let title_pos = writer.windows(4).position(|w| w == b"TIT2").unwrap();
 		assert_eq!(
-			res.unwrap_err().to_string(),
-			String::from(
-				"ID3v2: Attempted to write an invalid frame. ID: \"ABCD\", Value: \"URL\""
-			)
+			&writer[title_pos + 4..title_pos + 8],
+			&403_u32.to_be_bytes()
 		);
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert_eq!(parsed_tag.original_version(), Id3v2Version::V3);
+		assert_eq!(parsed_tag.version(), Id3v2Version::V4);
+		assert_eq!(text_value(&parsed_tag, "TDRC"), Some("2022-05-14T13:37"));
+		assert_eq!(text_value(&parsed_tag, "TSOP"), Some("Artist, Foo"));
+		assert_eq!(text_value(&parsed_tag, "TIT2"), Some(&*"A".repeat(200)));
+		assert!(parsed_tag.get("TDAT").is_none());
+		assert!(parsed_tag.get("TIME").is_none());
+		assert!(parsed_tag.get("TMOO").is_none());
 	}
 
 	#[test]
-	fn tag_to_id3v2() {
-		fn verify_frame(tag: &Id3v2Tag, id: &str, value: &str) {
-			let frame = tag.get(id);
+	fn recording_date() {
+		let timestamp = Timestamp {
+			year: 2022,
+			month: Some(5),
+			day: Some(14),
+			hour: Some(13),
+			minute: Some(37),
+			second: None,
+		};
 
-			assert!(frame.is_some());
+		let mut tag = Id3v2Tag::default();
+		tag.set_recording_date(timestamp);
+		assert_eq!(text_value(&tag, "TDRC"), Some("2022-05-14T13:37"));
+		assert_eq!(tag.recording_date(), Some(timestamp));
 
-			let frame = frame.unwrap();
+		// ID3v2.3 tags store the components separately
+		let mut v3_tag = tag.to_version(Id3v2Version::V3).unwrap();
+		assert_eq!(v3_tag.recording_date(), Some(timestamp));
 
-			assert_eq!(
-				frame.content(),
-				&FrameValue::Text {
-					encoding: TextEncoding::UTF8,
-					value: String::from(value)
-				}
-			);
-		}
+		let year_only = Timestamp {
+			year: 2021,
+			..Timestamp::default()
+		};
 
-		let tag = crate::tag::utils::test_utils::create_tag(TagType::Id3v2);
+		v3_tag.set_recording_date(year_only);
+		assert_eq!(text_value(&v3_tag, "TYER"), Some("2021"));
+		assert!(v3_tag.get("TDAT").is_none());
+		assert!(v3_tag.get("TIME").is_none());
+		assert!(v3_tag.get("TDRC").is_none());
+		assert_eq!(v3_tag.recording_date(), Some(year_only));
+
+		v3_tag.remove_recording_date();
+		assert!(v3_tag.recording_date().is_none());
+	}
+
+	#[test]
+	fn id3v23_to_id3v24() {
+		let mut tag = Id3v2Tag::default();
+		tag.insert(text_frame("TDRC", "2022-05-14"));
+		tag.insert(text_frame("TIPL", "Producer\0Foo"));
+		tag.insert(text_frame("TMCL", "Guitar\0Bar"));
 
-		let id3v2_tag: Id3v2Tag = tag.into();
+		let v3_tag = tag.to_version(Id3v2Version::V3).unwrap();
+		assert_eq!(
+			text_value(&v3_tag, "IPLS"),
+			Some("Producer\0Foo\0Guitar\0Bar")
+		);
 
-		verify_frame(&id3v2_tag, "TIT2", "Foo title");
-		verify_frame(&id3v2_tag, "TPE1", "Bar artist");
-		verify_frame(&id3v2_tag, "TALB", "Baz album");
+		let v4_tag = v3_tag.to_version(Id3v2Version::V4).unwrap();
 
-		let frame = id3v2_tag.get("COMM").unwrap();
+		assert_eq!(v4_tag.version(), Id3v2Version::V4);
+		assert_eq!(text_value(&v4_tag, "TDRC"), Some("2022-05-14"));
 		assert_eq!(
-			frame.content(),
-			&FrameValue::Comment(LanguageFrame {
-				encoding: TextEncoding::Latin1,
-				language: String::from("eng"),
-				description: String::new(),
-				content: String::from("Qux comment")
-			})
+			text_value(&v4_tag, "TIPL"),
+			Some("Producer\0Foo\0Guitar\0Bar")
 		);
+		assert!(v4_tag.get("TYER").is_none());
+		assert!(v4_tag.get("TDAT").is_none());
+		assert!(v4_tag.get("IPLS").is_none());
 
-		verify_frame(&id3v2_tag, "TRCK", "1");
-		verify_frame(&id3v2_tag, "TCON", "Classical");
+		assert!(tag.to_version(Id3v2Version::V2).is_err());
 	}
 
-	#[allow(clippy::field_reassign_with_default)]
-	fn create_full_test_tag(version: Id3v2Version) -> Id3v2Tag {
+	#[test]
+	#[cfg(feature = "id3v2_restrictions")]
+	fn validate_restrictions() {
+		use crate::id3::v2::{
+			ImageSizeRestrictions, TagRestrictions, TagSizeRestrictions, TextSizeRestrictions,
+		};
+
+		fn validate(tag: &Id3v2Tag, restrictions: TagRestrictions) -> bool {
+			let mut tag = tag.clone();
+			tag.set_flags(Id3v2TagFlags {
+				restrictions: (true, restrictions),
+				..Id3v2TagFlags::default()
+			});
+
+			tag.validate_restrictions().is_ok()
+		}
+
 		let mut tag = Id3v2Tag::default();
-		tag.original_version = version;
+		tag.insert(
+			Frame::new(
+				"TIT2",
+				FrameValue::Text {
+					encoding: TextEncoding::UTF16,
+					value: String::from("A title that is longer than thirty characters"),
+				},
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
 
-		let encoding = TextEncoding::UTF16;
-		let flags = FrameFlags::default();
+		// A GIF header describing a 640x628 image
+		tag.insert_picture(Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Gif,
+			None,
+			b"GIF89a\x80\x02\x74\x02\x00\x00\x00".to_vec(),
+		));
+
+		// Restrictions are only checked if the flag is set
+		assert!(tag.validate_restrictions().is_ok());
+		assert!(validate(&tag, TagRestrictions::default()));
+
+		assert!(!validate(
+			&tag,
+			TagRestrictions {
+				text_encoding: true,
+				..TagRestrictions::default()
+			}
+		));
+		assert!(!validate(
+			&tag,
+			TagRestrictions {
+				text_fields_size: TextSizeRestrictions::C_30,
+				..TagRestrictions::default()
+			}
+		));
+		assert!(validate(
+			&tag,
+			TagRestrictions {
+				text_fields_size: TextSizeRestrictions::C_128,
+				..TagRestrictions::default()
+			}
+		));
+		assert!(!validate(
+			&tag,
+			TagRestrictions {
+				image_encoding: true,
+				..TagRestrictions::default()
+			}
+		));
+		assert!(!validate(
+			&tag,
+			TagRestrictions {
+				image_size: ImageSizeRestrictions::P_256,
+				..TagRestrictions::default()
+			}
+		));
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TIT2")),
-			value: FrameValue::Text {
-				encoding,
-				value: String::from("TempleOS Hymn Risen (Remix)"),
-			},
-			flags,
-		});
+		let size_restriction = TagRestrictions {
+			size: TagSizeRestrictions::S_32F_4K,
+			..TagRestrictions::default()
+		};
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TPE1")),
-			value: FrameValue::Text {
-				encoding,
-				value: String::from("Dave Eddy"),
-			},
-			flags,
+		// Padding doesn't count towards the tag size
+		let mut tag = Id3v2Tag::default();
+		tag.set_title(String::from("Foo title"));
+		tag.set_flags(Id3v2TagFlags {
+			restrictions: (true, size_restriction),
+			padding: 8192,
+			..Id3v2TagFlags::default()
 		});
+		assert!(tag.validate_restrictions().is_ok());
+
+		// Each frame is small enough, but their sum is too large
+		for id in ["TIT2", "TALB", "TPE1", "TCOM", "TEXT"] {
+			tag.insert(
+				Frame::new(
+					id,
+					FrameValue::Text {
+						encoding: TextEncoding::Latin1,
+						value: "a".repeat(1000),
+					},
+					FrameFlags::default(),
+				)
+				.unwrap(),
+			);
+		}
+		assert!(tag.validate_restrictions().is_err());
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TRCK")),
-			value: FrameValue::Text {
-				encoding: TextEncoding::Latin1,
-				value: String::from("1"),
-			},
-			flags,
-		});
+		// Too many frames
+		let mut tag = Id3v2Tag::default();
+		for i in 0..33 {
+			tag.set_user_text(&format!("Foo {}", i), String::from("Bar"));
+		}
+		assert!(validate(&tag, TagRestrictions::default()));
+		assert!(!validate(&tag, size_restriction));
+	}
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TALB")),
-			value: FrameValue::Text {
-				encoding,
-				value: String::from("Summer"),
-			},
-			flags,
-		});
+	#[test]
+	fn user_defined_frames() {
+		let mut tag = Id3v2Tag::default();
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TDRC")),
-			value: FrameValue::Text {
-				encoding,
-				value: String::from("2017"),
-			},
-			flags,
-		});
+		tag.set_user_text("REPLAYGAIN_TRACK_GAIN", String::from("-6.20 dB"));
+		tag.set_user_text("Artists", String::from("Foo\0Bar"));
+		tag.set_user_url("Homepage", String::from("https://example.com"));
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TCON")),
-			value: FrameValue::Text {
-				encoding,
-				value: String::from("Electronic"),
-			},
-			flags,
-		});
+		assert_eq!(tag.len(), 3);
+		assert_eq!(tag.get_user_text("replaygain_track_gain"), Some("-6.20 dB"));
+		assert_eq!(tag.get_user_text("Homepage"), None);
+		assert_eq!(tag.get_user_url("HOMEPAGE"), Some("https://example.com"));
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("TLEN")),
-			value: FrameValue::Text {
-				encoding: TextEncoding::UTF16,
-				value: String::from("213017"),
-			},
-			flags,
-		});
+		// Only the first value is returned
+		assert_eq!(tag.get_user_text("Artists"), Some("Foo"));
+		assert_eq!(
+			tag.get_user_text_values("artists").collect::<Vec<_>>(),
+			vec!["Foo", "Bar"]
+		);
 
-		tag.insert(Frame {
-			id: FrameID::Valid(String::from("APIC")),
-			value: FrameValue::Picture {
-				encoding: TextEncoding::Latin1,
-				picture: Picture {
-					pic_type: PictureType::CoverFront,
-					mime_type: MimeType::Png,
-					description: None,
-					data: read_path("tests/tags/assets/id3v2/test_full_cover.png").into(),
-				},
-			},
-			flags,
-		});
+		// Descriptions differing only in case are replaced
+		tag.set_user_text("replaygain_track_gain", String::from("-3.10 dB"));
+		assert_eq!(tag.len(), 3);
+		assert_eq!(tag.get_user_text("REPLAYGAIN_TRACK_GAIN"), Some("-3.10 dB"));
 
-		tag
-	}
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
 
-	#[test]
-	fn id3v24_full() {
-		let tag = create_full_test_tag(Id3v2Version::V4);
-		let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v24");
+		let mut reader = &mut &writer[..];
 
-		assert_eq!(tag, parsed_tag);
-	}
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
 
-	#[test]
-	fn id3v23_full() {
-		let tag = create_full_test_tag(Id3v2Version::V3);
-		let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v23");
+		assert_eq!(
+			parsed_tag.get_user_text("replaygain_track_gain"),
+			Some("-3.10 dB")
+		);
+		assert_eq!(
+			parsed_tag
+				.get_user_text_values("Artists")
+				.collect::<Vec<_>>(),
+			vec!["Foo", "Bar"]
+		);
+		assert_eq!(
+			parsed_tag.get_user_url("homepage"),
+			Some("https://example.com")
+		);
 
-		assert_eq!(tag, parsed_tag);
+		tag.remove_user_text("artists");
+		tag.remove_user_url("homepage");
+
+		assert_eq!(tag.len(), 1);
+		assert_eq!(tag.get_user_text("Artists"), None);
+		assert_eq!(tag.get_user_url("Homepage"), None);
 	}
 
 	#[test]
-	fn id3v22_full() {
-		let tag = create_full_test_tag(Id3v2Version::V2);
-		let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v22");
+	fn popm_rating_accessor() {
+		let mut tag = Id3v2Tag::default();
+		assert_eq!(tag.rating(), None);
 
-		assert_eq!(tag, parsed_tag);
-	}
+		tag.set_rating(3);
+		assert_eq!(tag.rating(), Some(3));
+		assert_eq!(tag.popularimeters().next().unwrap().rating, 128);
 
-	#[test]
-	fn id3v24_footer() {
-		let mut tag = create_full_test_tag(Id3v2Version::V4);
-		tag.flags.footer = true;
+		// The existing frame should be updated, keeping its email and counter
+		tag.insert(
+			Frame::new(
+				"POPM",
+				FrameValue::Popularimeter(Popularimeter {
+					email: String::new(),
+					rating: 1,
+					counter: 5,
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+		tag.set_rating(5);
+
+		assert_eq!(tag.len(), 1);
+		let popm = tag.popularimeters().next().unwrap();
+		assert_eq!(popm.rating, 255);
+		assert_eq!(popm.counter, 5);
 
 		let mut writer = Vec::new();
 		tag.dump_to(&mut writer).unwrap();
@@ -795,24 +2585,37 @@ mod tests {
 		let mut reader = &mut &writer[..];
 
 		let header = read_id3v2_header(&mut reader).unwrap();
-		assert!(crate::id3::v2::read::parse_id3v2(reader, header).is_ok());
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
 
-		assert_eq!(writer[3..10], writer[writer.len() - 7..])
+		assert_eq!(parsed_tag.rating(), Some(5));
+		assert_eq!(parsed_tag.popularimeters().next().unwrap().counter, 5);
+
+		tag.remove_rating();
+		assert_eq!(tag.rating(), None);
 	}
 
 	#[test]
-	fn issue_36() {
-		let picture_data = vec![0; 200];
-
-		let picture = Picture::new_unchecked(
-			PictureType::CoverFront,
-			MimeType::Jpeg,
-			Some(String::from("cover")),
-			picture_data,
+	fn track_disk_accessors() {
+		let mut tag = Id3v2Tag::default();
+		tag.insert(
+			Frame::new(
+				"TRCK",
+				FrameValue::Text {
+					encoding: TextEncoding::UTF8,
+					value: String::from("4/12"),
+				},
+				FrameFlags::default(),
+			)
+			.unwrap(),
 		);
 
-		let mut tag = Tag::new(TagType::Id3v2);
-		tag.push_picture(picture.clone());
+		assert_eq!(tag.track(), Some(4));
+		assert_eq!(tag.track_total(), Some(12));
+
+		tag.set_track(5);
+		tag.set_disk_total(2);
 
 		let mut writer = Vec::new();
 		tag.dump_to(&mut writer).unwrap();
@@ -820,20 +2623,26 @@ mod tests {
 		let mut reader = &mut &writer[..];
 
 		let header = read_id3v2_header(&mut reader).unwrap();
-		let tag = crate::id3::v2::read::parse_id3v2(reader, header).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
 
-		assert_eq!(tag.len(), 1);
-		assert_eq!(
-			tag.frames.first(),
-			Some(&Frame {
-				id: FrameID::Valid(String::from("APIC")),
-				value: FrameValue::Picture {
-					encoding: TextEncoding::UTF8,
-					picture
-				},
-				flags: FrameFlags::default()
-			})
-		);
+		let text = |id| match parsed_tag.get(id).map(Frame::content) {
+			Some(FrameValue::Text { value, .. }) => Some(value.as_str()),
+			_ => None,
+		};
+
+		assert_eq!(text("TRCK"), Some("5/12"));
+		assert_eq!(text("TPOS"), Some("0/2"));
+		assert_eq!(parsed_tag.disk(), None);
+		assert_eq!(parsed_tag.disk_total(), Some(2));
+
+		tag.remove_track_total();
+		assert_eq!(tag.track(), Some(5));
+		assert_eq!(tag.track_total(), None);
+
+		tag.remove_track();
+		assert!(tag.get("TRCK").is_none());
 	}
 
 	#[test]
@@ -853,4 +2662,132 @@ mod tests {
 			})
 		)
 	}
+
+	#[test]
+	fn podcast_frames() {
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.insert_text(ItemKey::FlagPodcast, String::from("1"));
+		tag.insert_text(ItemKey::PodcastGlobalUniqueID, String::from("Foo id"));
+		tag.insert_text(ItemKey::PodcastDescription, String::from("Bar description"));
+		tag.insert_text(ItemKey::PodcastKeywords, String::from("Baz, Qux"));
+		tag.insert_item(TagItem::new(
+			ItemKey::PodcastURL,
+			ItemValue::Locator(String::from("https://example.com/feed")),
+		));
+
+		let id3v2 = Id3v2Tag::from(tag);
+		assert_eq!(
+			id3v2.get("PCST").map(Frame::content),
+			Some(&FrameValue::Binary(vec![0; 4]))
+		);
+
+		let mut writer = Vec::new();
+		id3v2.dump_to(&mut writer).unwrap();
+
+		let mut reader = &mut &writer[..];
+
+		let header = read_id3v2_header(&mut reader).unwrap();
+		let parsed_tag =
+			crate::id3::v2::read::parse_id3v2(reader, header, &mut ParseContext::default())
+				.unwrap();
+
+		assert_eq!(parsed_tag.len(), 5);
+		assert_eq!(
+			text_value(&parsed_tag, "WFED"),
+			Some("https://example.com/feed")
+		);
+
+		let tag: Tag = parsed_tag.into();
+		assert_eq!(tag.get_string(&ItemKey::FlagPodcast), Some("1"));
+		assert_eq!(
+			tag.get_string(&ItemKey::PodcastGlobalUniqueID),
+			Some("Foo id")
+		);
+		assert_eq!(
+			tag.get_string(&ItemKey::PodcastDescription),
+			Some("Bar description")
+		);
+		assert_eq!(tag.get_string(&ItemKey::PodcastKeywords), Some("Baz, Qux"));
+		assert_eq!(
+			tag.get_string(&ItemKey::PodcastURL),
+			Some("https://example.com/feed")
+		);
+
+		// An unset flag has no frame
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.insert_text(ItemKey::FlagPodcast, String::from("0"));
+		assert!(Id3v2Tag::from(tag).get("PCST").is_none());
+	}
+
+	#[test]
+	fn movement_frames() {
+		let mut id3v2 = Id3v2Tag::default();
+		id3v2.insert(
+			Frame::new(
+				"MVIN",
+				FrameValue::Text {
+					encoding: TextEncoding::UTF8,
+					value: String::from("2/4"),
+				},
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+		id3v2.insert(
+			Frame::new(
+				"TXXX",
+				FrameValue::UserText(EncodedTextFrame {
+					encoding: TextEncoding::UTF8,
+					description: String::from("WORK"),
+					content: String::from("Foo work"),
+				}),
+				FrameFlags::default(),
+			)
+			.unwrap(),
+		);
+
+		let tag: Tag = id3v2.into();
+		assert_eq!(tag.get_string(&ItemKey::MovementNumber), Some("2"));
+		assert_eq!(tag.get_string(&ItemKey::MovementTotal), Some("4"));
+		assert_eq!(tag.get_string(&ItemKey::Work), Some("Foo work"));
+
+		let id3v2: Id3v2Tag = tag.into();
+		assert_eq!(id3v2.get_user_text("WORK"), Some("Foo work"));
+	}
+
+	#[test]
+	fn num_pairs_from_tag() {
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.insert_text(ItemKey::MovementNumber, String::from("2"));
+		tag.insert_text(ItemKey::MovementTotal, String::from("4"));
+		tag.insert_text(ItemKey::TrackNumber, String::from("5"));
+		tag.insert_text(ItemKey::TrackTotal, String::from("12"));
+		tag.insert_text(ItemKey::DiscTotal, String::from("3"));
+
+		let text = |tag: &Id3v2Tag, id| match tag.get(id).map(Frame::content) {
+			Some(FrameValue::Text { value, .. }) => Some(value.clone()),
+			_ => None,
+		};
+
+		let id3v2: Id3v2Tag = tag.clone().into();
+		assert_eq!(text(&id3v2, "MVIN").as_deref(), Some("2/4"));
+		assert_eq!(text(&id3v2, "TRCK").as_deref(), Some("5/12"));
+		assert_eq!(text(&id3v2, "TPOS").as_deref(), Some("0/3"));
+
+		// Writing the `Tag` directly should do the same
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let temp_reader = &mut &*writer;
+		let temp_header = read_id3v2_header(temp_reader).unwrap();
+		let id3v2 = crate::id3::v2::read::parse_id3v2(
+			temp_reader,
+			temp_header,
+			&mut ParseContext::default(),
+		)
+		.unwrap();
+		assert_eq!(text(&id3v2, "MVIN").as_deref(), Some("2/4"));
+		assert_eq!(text(&id3v2, "TRCK").as_deref(), Some("5/12"));
+		assert_eq!(text(&id3v2, "TPOS").as_deref(), Some("0/3"));
+	}
 }
@@ -0,0 +1,343 @@
+use crate::id3::v2::frame::{Frame, FrameFlags, FrameValue};
+use crate::id3::v2::{FrameID, KeyValueFrame, TextEncoding, Timestamp};
+
+// Frames that only exist in ID3v2.4, and have no ID3v2.3 equivalent
+const V4_ONLY_FRAMES: [&str; 11] = [
+	"ASPI", "EQU2", "RVA2", "SEEK", "SIGN", "TDEN", "TDRL", "TDTG", "TMOO", "TPRO", "TSST",
+];
+
+// ID3v2.3 has no sort order frames (other than the non-standard "TSO2" and "TSOC", which are shared),
+// so the "X" prefixed frames used by most software are substituted
+const SORT_ORDER_FRAMES: [(&str, &str); 3] = [("TSOP", "XSOP"), ("TSOA", "XSOA"), ("TSOT", "XSOT")];
+
+fn text_frame(id: &str, encoding: TextEncoding, value: String, flags: FrameFlags) -> Frame {
+	Frame {
+		id: FrameID::Valid(String::from(id)),
+		value: FrameValue::Text { encoding, value },
+		flags,
+	}
+}
+
+pub(in crate::id3::v2) fn text_value<'a>(frames: &'a [Frame], id: &str) -> Option<&'a str> {
+	frames.iter().find_map(|f| match f {
+		Frame {
+			id: FrameID::Valid(frame_id),
+			value: FrameValue::Text { value, .. },
+			..
+		} if frame_id == id => Some(value.as_str()),
+		_ => None,
+	})
+}
+
+// "yyyy-MM-ddTHH:mm:ss" => ("yyyy", "ddMM", "HHmm")
+fn split_timestamp(timestamp: &str) -> (Option<String>, Option<String>, Option<String>) {
+	let timestamp = match Timestamp::parse(timestamp) {
+		Some(timestamp) => timestamp,
+		None => return (None, None, None),
+	};
+
+	let year = format!("{:04}", timestamp.year);
+	let date = timestamp
+		.month
+		.zip(timestamp.day)
+		.map(|(month, day)| format!("{:02}{:02}", day, month));
+	let time = timestamp
+		.hour
+		.zip(timestamp.minute)
+		.map(|(hour, minute)| format!("{:02}{:02}", hour, minute));
+
+	(Some(year), date, time)
+}
+
+// ("yyyy", "ddMM", "HHmm") => "yyyy-MM-ddTHH:mm"
+pub(in crate::id3::v2) fn merge_timestamp(
+	year: &str,
+	date: Option<&str>,
+	time: Option<&str>,
+) -> String {
+	// "ddMM" or "HHmm"
+	let pair = |value: Option<&str>| {
+		value
+			.filter(|v| v.len() == 4)
+			.and_then(|v| Some((v.get(..2)?.parse().ok()?, v.get(2..)?.parse().ok()?)))
+	};
+
+	let (year, (day, month)) = match (Timestamp::parse(year), pair(date)) {
+		(Some(timestamp), Some(date)) => (timestamp.year, date),
+		_ => return String::from(year),
+	};
+
+	let mut timestamp = Timestamp {
+		year,
+		month: Some(month),
+		day: Some(day),
+		..Timestamp::default()
+	};
+
+	if let Some((hour, minute)) = pair(time) {
+		timestamp.hour = Some(hour);
+		timestamp.minute = Some(minute);
+	}
+
+	timestamp.to_string()
+}
+
+pub(in crate::id3::v2) fn frames_to_v3(frames: Vec<Frame>) -> Vec<Frame> {
+	let mut converted = Vec::with_capacity(frames.len());
+	let mut involved_people: Option<Frame> = None;
+
+	for mut frame in frames {
+		let id = match &frame.id {
+			FrameID::Valid(id) => id.as_str(),
+			FrameID::Outdated(_) => {
+				converted.push(frame);
+				continue;
+			},
+		};
+
+		if V4_ONLY_FRAMES.contains(&id) {
+			continue;
+		}
+
+		match (id, frame.value) {
+			// The recording time is split into the year, date, and time
+			("TDRC", FrameValue::Text { encoding, value }) => {
+				let (year, date, time) = split_timestamp(&value);
+
+				let year = match year {
+					Some(year) => year,
+					None => continue,
+				};
+
+				converted.push(text_frame("TYER", encoding, year, frame.flags));
+
+				if let Some(date) = date {
+					converted.push(text_frame("TDAT", encoding, date, frame.flags));
+				}
+
+				if let Some(time) = time {
+					converted.push(text_frame("TIME", encoding, time, frame.flags));
+				}
+			},
+			// ID3v2.3 only has an original release year
+			("TDOR", FrameValue::Text { encoding, value }) => {
+				if let (Some(year), _, _) = split_timestamp(&value) {
+					converted.push(text_frame("TORY", encoding, year, frame.flags));
+				}
+			},
+			// Both the involved people and musician credits lists become a single "IPLS" frame
+			("TIPL" | "TMCL", FrameValue::KeyValueList(mut key_value)) => {
+				match &mut involved_people {
+					Some(Frame {
+						value: FrameValue::KeyValueList(people),
+						..
+					}) => people
+						.key_value_pairs
+						.append(&mut key_value.key_value_pairs),
+					Some(Frame {
+						value: FrameValue::Text { value: people, .. },
+						..
+					}) => {
+						people.push('\0');
+						people.push_str(&key_value.to_null_separated());
+					},
+					_ => {
+						involved_people = Some(Frame {
+							id: FrameID::Valid(String::from("IPLS")),
+							value: FrameValue::KeyValueList(key_value),
+							flags: frame.flags,
+						})
+					},
+				}
+			},
+			("TIPL" | "TMCL", FrameValue::Text { encoding, value }) => match &mut involved_people {
+				Some(Frame {
+					value: FrameValue::Text { value: people, .. },
+					..
+				}) => {
+					people.push('\0');
+					people.push_str(&value);
+				},
+				Some(Frame {
+					value: FrameValue::KeyValueList(people),
+					..
+				}) => people.key_value_pairs.append(
+					&mut KeyValueFrame::from_null_separated(encoding, &value).key_value_pairs,
+				),
+				_ => involved_people = Some(text_frame("IPLS", encoding, value, frame.flags)),
+			},
+			(_, FrameValue::Chapter(mut chapter)) => {
+				chapter.embedded_frames = frames_to_v3(chapter.embedded_frames);
+				frame.value = FrameValue::Chapter(chapter);
+				converted.push(frame);
+			},
+			(_, FrameValue::TableOfContents(mut toc)) => {
+				toc.embedded_frames = frames_to_v3(toc.embedded_frames);
+				frame.value = FrameValue::TableOfContents(toc);
+				converted.push(frame);
+			},
+			(id, value) => {
+				if let Some((_, v3_id)) = SORT_ORDER_FRAMES.iter().find(|(v4_id, _)| *v4_id == id) {
+					frame.id = FrameID::Valid(String::from(*v3_id));
+				}
+
+				frame.value = value;
+				converted.push(frame);
+			},
+		}
+	}
+
+	converted.extend(involved_people);
+
+	// ID3v2.3 has no concept of frame-level unsynchronisation or data length indicators
+	for frame in &mut converted {
+		frame.flags.unsynchronisation = false;
+		frame.flags.data_length_indicator = (false, 0);
+	}
+
+	converted
+}
+
+pub(in crate::id3::v2) fn frames_to_v4(frames: Vec<Frame>) -> Vec<Frame> {
+	// The year may already be stored in "TDRC", as "TYER" is upgraded when reading
+	let year = text_value(&frames, "TYER").or_else(|| text_value(&frames, "TDRC"));
+	let date = text_value(&frames, "TDAT");
+	let time = text_value(&frames, "TIME");
+
+	let recording_time = year.map(|year| merge_timestamp(year, date, time));
+
+	let mut converted = Vec::with_capacity(frames.len());
+
+	for mut frame in frames {
+		let id = match &frame.id {
+			FrameID::Valid(id) => id.as_str(),
+			FrameID::Outdated(_) => {
+				converted.push(frame);
+				continue;
+			},
+		};
+
+		match (id, frame.value) {
+			("TDAT" | "TIME", _) => {},
+			("TYER" | "TDRC", FrameValue::Text { encoding, .. }) => {
+				// Only a single "TDRC" frame is created, even if both "TYER" and "TDRC" are present
+				if let Some(recording_time) = recording_time.as_ref() {
+					if text_value(&converted, "TDRC").is_none() {
+						converted.push(text_frame(
+							"TDRC",
+							encoding,
+							recording_time.clone(),
+							frame.flags,
+						));
+					}
+				}
+			},
+			(_, FrameValue::Chapter(mut chapter)) => {
+				chapter.embedded_frames = frames_to_v4(chapter.embedded_frames);
+				frame.value = FrameValue::Chapter(chapter);
+				converted.push(frame);
+			},
+			(_, FrameValue::TableOfContents(mut toc)) => {
+				toc.embedded_frames = frames_to_v4(toc.embedded_frames);
+				frame.value = FrameValue::TableOfContents(toc);
+				converted.push(frame);
+			},
+			(id, value) => {
+				if let Some(v4_id) = crate::id3::v2::upgrade_v3(id) {
+					frame.id = FrameID::Valid(String::from(v4_id));
+				}
+
+				frame.value = value;
+				converted.push(frame);
+			},
+		}
+	}
+
+	converted
+}
+
+// ID3v2.3 only supports Latin-1 and UTF-16 (with a BOM)
+pub(in crate::id3::v2) fn v3_encoding(encoding: TextEncoding) -> TextEncoding {
+	match encoding {
+		TextEncoding::UTF8 | TextEncoding::UTF16BE => TextEncoding::UTF16,
+		encoding => encoding,
+	}
+}
+
+// Creates a copy of the frame value if any of its text needs to be re-encoded for ID3v2.3
+pub(in crate::id3::v2) fn v3_value(value: &FrameValue) -> Option<FrameValue> {
+	let needs_conversion = |encoding: TextEncoding| v3_encoding(encoding) != encoding;
+
+	match value {
+		FrameValue::Text { encoding, value } if needs_conversion(*encoding) => {
+			Some(FrameValue::Text {
+				encoding: v3_encoding(*encoding),
+				value: value.clone(),
+			})
+		},
+		FrameValue::KeyValueList(content) if needs_conversion(content.encoding) => {
+			let mut content = content.clone();
+			content.encoding = v3_encoding(content.encoding);
+			Some(FrameValue::KeyValueList(content))
+		},
+		FrameValue::UserText(content) if needs_conversion(content.encoding) => {
+			let mut content = content.clone();
+			content.encoding = v3_encoding(content.encoding);
+			Some(FrameValue::UserText(content))
+		},
+		FrameValue::UserURL(content) if needs_conversion(content.encoding) => {
+			let mut content = content.clone();
+			content.encoding = v3_encoding(content.encoding);
+			Some(FrameValue::UserURL(content))
+		},
+		FrameValue::Comment(content) if needs_conversion(content.encoding) => {
+			let mut content = content.clone();
+			content.encoding = v3_encoding(content.encoding);
+			Some(FrameValue::Comment(content))
+		},
+		FrameValue::UnSyncText(content) if needs_conversion(content.encoding) => {
+			let mut content = content.clone();
+			content.encoding = v3_encoding(content.encoding);
+			Some(FrameValue::UnSyncText(content))
+		},
+		FrameValue::Picture { encoding, picture } if needs_conversion(*encoding) => {
+			Some(FrameValue::Picture {
+				encoding: v3_encoding(*encoding),
+				picture: picture.clone(),
+			})
+		},
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{merge_timestamp, split_timestamp};
+
+	#[test]
+	fn timestamps() {
+		assert_eq!(
+			split_timestamp("2022-05-14T13:37:00"),
+			(
+				Some(String::from("2022")),
+				Some(String::from("1405")),
+				Some(String::from("1337"))
+			)
+		);
+		assert_eq!(
+			split_timestamp("2022"),
+			(Some(String::from("2022")), None, None)
+		);
+		assert_eq!(split_timestamp("Foo"), (None, None, None));
+
+		assert_eq!(
+			merge_timestamp("2022", Some("1405"), Some("1337")),
+			"2022-05-14T13:37"
+		);
+		assert_eq!(merge_timestamp("2022", Some("1405"), None), "2022-05-14");
+		// A time is meaningless without a date
+		assert_eq!(merge_timestamp("2022", None, Some("1337")), "2022");
+		// An existing timestamp is left alone
+		assert_eq!(merge_timestamp("2022-05-14", None, None), "2022-05-14");
+	}
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "id3v2")]
+pub(crate) mod convert;
 pub(crate) mod text_utils;
 #[cfg(feature = "id3v2")]
 pub(crate) mod upgrade;
@@ -5,6 +7,35 @@ pub(crate) mod upgrade;
 #[cfg(feature = "id3v2")]
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 
+#[cfg(feature = "id3v2")]
+use std::ops::Not;
+
+#[cfg(feature = "id3v2")]
+// Used for the optional CRC-32 in the extended header
+static CRC_32_TABLE: once_cell::sync::Lazy<[u32; 256]> = once_cell::sync::Lazy::new(|| {
+	let mut crc32_table = [0; 256];
+
+	for n in 0..256 {
+		crc32_table[n as usize] = (0..8).fold(n as u32, |acc, _| match acc & 1 {
+			1 => 0xEDB8_8320 ^ (acc >> 1),
+			_ => acc >> 1,
+		});
+	}
+
+	crc32_table
+});
+
+#[cfg(feature = "id3v2")]
+// https://github.com/rstemmer/id3edit/blob/0246f3dc1a7a80a64461eeeb7b9ee88379003eb1/encoding/crc.c#L6:6
+pub(in crate::id3::v2) fn crc_32(content: &[u8]) -> u32 {
+	content
+		.iter()
+		.fold(!0, |crc, octet| {
+			(crc >> 8) ^ CRC_32_TABLE[(((crc & 0xFF) ^ u32::from(*octet)) & 0xFF) as usize]
+		})
+		.not()
+}
+
 #[cfg(feature = "id3v2")]
 pub(in crate::id3::v2) fn unsynch_content(content: &[u8]) -> Result<Vec<u8>> {
 	let mut unsynch_content = Vec::new();
@@ -50,7 +81,32 @@ pub(in crate::id3::v2) fn unsynch_content(content: &[u8]) -> Result<Vec<u8>> {
 	Ok(unsynch_content)
 }
 
-#[cfg(test)]
+#[cfg(feature = "id3v2")]
+pub(in crate::id3::v2) fn synch_content(content: &[u8]) -> Vec<u8> {
+	let mut synch_content = Vec::with_capacity(content.len());
+
+	let mut iter = content.iter().copied().peekable();
+
+	// Replace (0xFF, 0b111xxxxx) with (0xFF, 0x00, 0b111xxxxx), and (0xFF, 0x00) with (0xFF, 0x00, 0x00)
+	while let Some(byte) = iter.next() {
+		synch_content.push(byte);
+
+		if byte != 0xFF {
+			continue;
+		}
+
+		match iter.peek().copied() {
+			// Content ending in 0xFF also needs a zero, as it could run into an MPEG frame
+			Some(0) | None => synch_content.push(0),
+			Some(next) if next >= 0xE0 => synch_content.push(0),
+			_ => {},
+		}
+	}
+
+	synch_content
+}
+
+#[cfg(all(test, feature = "id3v2"))]
 mod tests {
 	#[test]
 	fn unsynchronisation() {
@@ -67,4 +123,20 @@ mod tests {
 
 		assert!(super::unsynch_content(invalid_unsynch.as_slice()).is_err());
 	}
+
+	#[test]
+	fn synchronisation() {
+		let content = vec![0xFF, 0x00, 0xFF, 0xE0, 0xFF, 0x12, 0xB0, 0x05, 0xFF];
+		let synch_content = super::synch_content(content.as_slice());
+
+		assert_eq!(
+			synch_content,
+			vec![0xFF, 0x00, 0x00, 0xFF, 0x00, 0xE0, 0xFF, 0x12, 0xB0, 0x05, 0xFF, 0x00]
+		);
+
+		assert_eq!(
+			super::unsynch_content(synch_content.as_slice()).unwrap(),
+			content
+		);
+	}
 }
@@ -96,6 +96,32 @@ where
 	Ok(None)
 }
 
+// The characters Windows-1252 assigns to 0x80..=0x9F, with the undefined bytes kept as their C1 control codes
+const WINDOWS_1252: [char; 32] = [
+	'\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+	'\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+	'\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+	'\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+// Decodes text that claims to be Latin-1, but may have been written by a tagger using another encoding
+//
+// If the bytes are valid UTF-8, they are decoded as such. Otherwise, they are decoded as Windows-1252,
+// which is a superset of the printable Latin-1 characters.
+pub(crate) fn decode_latin1_lenient(bytes: &[u8]) -> String {
+	if let Ok(utf8) = std::str::from_utf8(bytes) {
+		return String::from(utf8);
+	}
+
+	bytes
+		.iter()
+		.map(|b| match b {
+			0x80..=0x9F => WINDOWS_1252[(b - 0x80) as usize],
+			_ => *b as char,
+		})
+		.collect()
+}
+
 pub(crate) fn read_to_terminator<R>(reader: &mut R, encoding: TextEncoding) -> Option<Vec<u8>>
 where
 	R: Read,
@@ -144,6 +170,10 @@ pub(crate) fn utf16_decode(reader: &[u8], endianness: fn([u8; 2]) -> u16) -> Res
 		.map_err(|_| LoftyError::new(ErrorKind::TextDecode("Given an invalid UTF-16 string")))
 }
 
+pub(crate) fn is_latin1(text: &str) -> bool {
+	text.chars().all(|c| u32::from(c) <= 0xFF)
+}
+
 pub(crate) fn encode_text(text: &str, text_encoding: TextEncoding, terminated: bool) -> Vec<u8> {
 	match text_encoding {
 		TextEncoding::Latin1 => {
@@ -192,7 +222,7 @@ fn utf16_encode(
 	encoded
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "id3v2"))]
 mod tests {
 	use crate::id3::v2::TextEncoding;
 	use std::io::Cursor;
@@ -238,6 +268,24 @@ mod tests {
 		assert_eq!(utf8_decode.unwrap(), Some(TEST_STRING.to_string()));
 	}
 
+	#[test]
+	fn lenient_latin1_decode() {
+		// Valid UTF-8 is kept as-is
+		assert_eq!(
+			super::decode_latin1_lenient(TEST_STRING.as_bytes()),
+			TEST_STRING
+		);
+
+		// Otherwise, 0x80..=0x9F are mapped using Windows-1252
+		assert_eq!(
+			super::decode_latin1_lenient(&[0x93, 0x6C, 0xF8, 0x66, 0x74, 0x94, 0x20, 0x80]),
+			"\u{201C}løft\u{201D} \u{20AC}"
+		);
+
+		// Undefined bytes are left alone
+		assert_eq!(super::decode_latin1_lenient(&[0x81, 0xA5]), "\u{81}¥");
+	}
+
 	#[test]
 	fn text_encode() {
 		// No BOM
@@ -123,6 +123,11 @@ gen_upgrades!(
 		// Standard frames
 		"TORY" => "TDOR",
 		"TYER" => "TDRC",
-		"IPLS" => "TIPL"
+		"IPLS" => "TIPL",
+
+		// Non-standard sort order frames
+		"XSOA" => "TSOA",
+		"XSOP" => "TSOP",
+		"XSOT" => "TSOT"
 	]
 );
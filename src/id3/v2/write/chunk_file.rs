@@ -1,12 +1,15 @@
 use crate::error::Result;
 use crate::iff::chunk::Chunks;
+use crate::traits::FileLike;
 
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::SeekFrom;
 
 use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 
-pub(in crate::id3::v2) fn write_to_chunk_file<B>(data: &mut File, tag: &[u8]) -> Result<()>
+pub(in crate::id3::v2) fn write_to_chunk_file<B, F: FileLike>(
+	data: &mut F,
+	tag: &[u8],
+) -> Result<()>
 where
 	B: ByteOrder,
 {
@@ -46,28 +49,36 @@ where
 		);
 
 		data.rewind()?;
-		data.set_len(0)?;
+		data.truncate(0)?;
 		data.write_all(&*file_bytes)?;
 	}
 
-	if !tag.is_empty() {
-		data.seek(SeekFrom::End(0))?;
-		data.write_all(b"ID3 ")?;
-		data.write_u32::<B>(tag.len() as u32)?;
-		data.write_all(tag)?;
+	// The RIFF/FORM size has to be updated, even if the tag was only removed
+	if tag.is_empty() {
+		let total_size = data.seek(SeekFrom::End(0))? - 8;
 
-		// It is required an odd length chunk be padded with a 0
-		// The 0 isn't included in the chunk size, however
-		if tag.len() % 2 != 0 {
-			data.write_u8(0)?;
-		}
+		data.seek(SeekFrom::Start(4))?;
+		data.write_u32::<B>(total_size as u32)?;
 
-		let total_size = data.stream_position()? - 8;
+		return Ok(());
+	}
 
-		data.seek(SeekFrom::Start(4))?;
+	data.seek(SeekFrom::End(0))?;
+	data.write_all(b"ID3 ")?;
+	data.write_u32::<B>(tag.len() as u32)?;
+	data.write_all(tag)?;
 
-		data.write_u32::<B>(total_size as u32)?;
+	// It is required an odd length chunk be padded with a 0
+	// The 0 isn't included in the chunk size, however
+	if tag.len() % 2 != 0 {
+		data.write_u8(0)?;
 	}
 
+	let total_size = data.stream_position()? - 8;
+
+	data.seek(SeekFrom::Start(4))?;
+
+	data.write_u32::<B>(total_size as u32)?;
+
 	Ok(())
 }
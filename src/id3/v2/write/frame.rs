@@ -1,37 +1,66 @@
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 use crate::id3::v2::frame::{FrameFlags, FrameRef, FrameValue};
-use crate::id3::v2::synch_u32;
+use crate::id3::v2::util::convert::v3_value;
+use crate::id3::v2::util::synch_content;
+use crate::id3::v2::FrameID;
+use crate::id3::v2::{synch_u32, Id3v2Version};
 
+use std::borrow::Cow;
 use std::io::Write;
 
 use byteorder::{BigEndian, WriteBytesExt};
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
 
 pub(in crate::id3::v2) fn create_items<'a, W>(
 	writer: &mut W,
 	frames: &mut dyn Iterator<Item = FrameRef<'a>>,
+	version: Id3v2Version,
 ) -> Result<()>
 where
 	W: Write,
 {
 	for frame in frames {
 		verify_frame(&frame)?;
-		let value = frame.value.as_bytes()?;
 
-		write_frame(writer, frame.id, frame.flags, &value)?;
+		let value = match version {
+			Id3v2Version::V3 => match v3_value(&frame.value) {
+				Some(converted) => converted.as_bytes(version)?,
+				None => frame.value.as_bytes(version)?,
+			},
+			_ => frame.value.as_bytes(version)?,
+		};
+
+		write_frame(writer, frame.id, frame.flags, &value, version)?;
 	}
 
 	Ok(())
 }
 
 fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
+	// The ID may not have been created through `FrameID::new`
+	if frame.id.len() != 4 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameID(frame.id.to_string())).into());
+	}
+
+	FrameID::verify_id(frame.id)?;
+
 	match (frame.id, frame.value.as_ref()) {
 		("APIC", FrameValue::Picture { .. })
 		| ("USLT", FrameValue::UnSyncText(_))
 		| ("COMM", FrameValue::Comment(_))
 		| ("TXXX", FrameValue::UserText(_))
 		| ("WXXX", FrameValue::UserURL(_))
+		| ("POPM", FrameValue::Popularimeter(_))
+		| ("CHAP", FrameValue::Chapter(_))
+		| ("CTOC", FrameValue::TableOfContents(_))
+		| ("RVA2", FrameValue::RelativeVolumeAdjustment(_))
+		| ("PRIV", FrameValue::Private(_))
+		| ("UFID", FrameValue::UniqueFileIdentifier(_))
+		| ("TIPL" | "TMCL" | "IPLS", FrameValue::KeyValueList(_))
 		| (_, FrameValue::Binary(_))
-		| ("WFED" | "GRP1" | "MVNM" | "MVIN", FrameValue::Text { .. }) => Ok(()),
+		| ("WFED" | "GRP1" | "MVNM" | "MVIN", FrameValue::Text { .. })
+		| ("IPLS" | "XSOA" | "XSOP" | "XSOT", FrameValue::Text { .. }) => Ok(()),
 		(id, FrameValue::Text { .. }) if id.starts_with('T') => Ok(()),
 		(id, FrameValue::URL(_)) if id.starts_with('W') => Ok(()),
 		(id, frame_value) => Err(Id3v2Error::new(Id3v2ErrorKind::BadFrame(
@@ -41,10 +70,16 @@ fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 				FrameValue::UnSyncText(_) => "UnSyncText",
 				FrameValue::Text { .. } => "Text",
 				FrameValue::UserText(_) => "UserText",
+				FrameValue::KeyValueList(_) => "KeyValueList",
 				FrameValue::URL(_) => "URL",
 				FrameValue::UserURL(_) => "UserURL",
 				FrameValue::Picture { .. } => "Picture",
 				FrameValue::Popularimeter(_) => "Popularimeter",
+				FrameValue::Chapter(_) => "Chapter",
+				FrameValue::TableOfContents(_) => "TableOfContents",
+				FrameValue::RelativeVolumeAdjustment(_) => "RelativeVolumeAdjustment",
+				FrameValue::Private(_) => "Private",
+				FrameValue::UniqueFileIdentifier(_) => "UniqueFileIdentifier",
 				FrameValue::Binary(_) => "Binary",
 			},
 		))
@@ -52,54 +87,105 @@ fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 	}
 }
 
-fn write_frame<W>(writer: &mut W, name: &str, flags: FrameFlags, value: &[u8]) -> Result<()>
+fn write_frame<W>(
+	writer: &mut W,
+	name: &str,
+	mut flags: FrameFlags,
+	value: &[u8],
+	version: Id3v2Version,
+) -> Result<()>
 where
 	W: Write,
 {
+	let mut value = Cow::Borrowed(value);
+
 	if flags.encryption.0 {
-		write_encrypted(writer, name, value, flags)?;
-		return Ok(());
+		verify_encrypted(flags)?;
+	} else {
+		// The data length indicator is the size of the frame's content, prior to compression
+		flags.data_length_indicator.1 = value.len() as u32;
+
+		if flags.compression {
+			// Compressed frames are required to have a data length indicator
+			flags.data_length_indicator.0 = true;
+			value = Cow::Owned(compress(&value)?);
+		}
 	}
 
-	let len = value.len() as u32;
-	let is_grouping_identity = flags.grouping_identity.0;
+	let header_additions = if version == Id3v2Version::V3 {
+		v3_header_additions(flags)?
+	} else {
+		if flags.unsynchronisation {
+			value = Cow::Owned(synch_content(&value));
+		}
+
+		v4_header_additions(flags)?
+	};
 
 	write_frame_header(
 		writer,
 		name,
-		if is_grouping_identity { len + 1 } else { len },
+		(header_additions.len() + value.len()) as u32,
 		flags,
+		version,
 	)?;
 
-	if is_grouping_identity {
-		writer.write_u8(flags.grouping_identity.1)?;
+	writer.write_all(&header_additions)?;
+	writer.write_all(&value)?;
+
+	Ok(())
+}
+
+// The additional information is written in the same order as the flags
+fn v4_header_additions(flags: FrameFlags) -> Result<Vec<u8>> {
+	let mut header_additions = Vec::new();
+
+	if flags.grouping_identity.0 {
+		header_additions.push(flags.grouping_identity.1);
+	}
+
+	if flags.encryption.0 {
+		header_additions.push(flags.encryption.1);
 	}
 
-	writer.write_all(value)?;
+	if flags.data_length_indicator.0 {
+		header_additions.write_u32::<BigEndian>(synch_u32(flags.data_length_indicator.1)?)?;
+	}
 
-	Ok(())
+	Ok(header_additions)
 }
 
-fn write_encrypted<W>(writer: &mut W, name: &str, value: &[u8], flags: FrameFlags) -> Result<()>
-where
-	W: Write,
-{
+// ID3v2.3 only stores the decompressed size for compressed frames, and it comes first
+fn v3_header_additions(flags: FrameFlags) -> Result<Vec<u8>> {
+	let mut header_additions = Vec::new();
+
+	if flags.compression {
+		header_additions.write_u32::<BigEndian>(flags.data_length_indicator.1)?;
+	}
+
+	if flags.encryption.0 {
+		header_additions.push(flags.encryption.1);
+	}
+
+	if flags.grouping_identity.0 {
+		header_additions.push(flags.grouping_identity.1);
+	}
+
+	Ok(header_additions)
+}
+
+fn verify_encrypted(flags: FrameFlags) -> Result<()> {
 	let method_symbol = flags.encryption.1;
 	let data_length_indicator = flags.data_length_indicator;
 
-	if method_symbol > 0x80 {
+	if method_symbol < 0x80 {
 		return Err(Id3v2Error::new(Id3v2ErrorKind::Other(
-			"Attempted to write an encrypted frame with an invalid method symbol (> 0x80)",
+			"Attempted to write an encrypted frame with an invalid method symbol (< 0x80)",
 		))
 		.into());
 	}
 
 	if data_length_indicator.0 && data_length_indicator.1 > 0 {
-		write_frame_header(writer, name, (value.len() + 1) as u32, flags)?;
-		writer.write_u32::<BigEndian>(synch_u32(data_length_indicator.1)?)?;
-		writer.write_u8(method_symbol)?;
-		writer.write_all(value)?;
-
 		return Ok(());
 	}
 
@@ -109,17 +195,67 @@ where
 	.into())
 }
 
-fn write_frame_header<W>(writer: &mut W, name: &str, len: u32, flags: FrameFlags) -> Result<()>
+fn compress(value: &[u8]) -> Result<Vec<u8>> {
+	let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+	encoder.write_all(value)?;
+
+	Ok(encoder.finish()?)
+}
+
+fn write_frame_header<W>(
+	writer: &mut W,
+	name: &str,
+	len: u32,
+	flags: FrameFlags,
+	version: Id3v2Version,
+) -> Result<()>
 where
 	W: Write,
 {
 	writer.write_all(name.as_bytes())?;
-	writer.write_u32::<BigEndian>(synch_u32(len)?)?;
-	writer.write_u16::<BigEndian>(get_flags(flags))?;
+
+	// Only ID3v2.4 uses synchsafe integers for the frame size
+	if version == Id3v2Version::V3 {
+		writer.write_u32::<BigEndian>(len)?;
+		writer.write_u16::<BigEndian>(get_v3_flags(flags))?;
+	} else {
+		writer.write_u32::<BigEndian>(synch_u32(len)?)?;
+		writer.write_u16::<BigEndian>(get_flags(flags))?;
+	}
 
 	Ok(())
 }
 
+fn get_v3_flags(tag_flags: FrameFlags) -> u16 {
+	let mut flags = 0;
+
+	if tag_flags.tag_alter_preservation {
+		flags |= 0x8000
+	}
+
+	if tag_flags.file_alter_preservation {
+		flags |= 0x4000
+	}
+
+	if tag_flags.read_only {
+		flags |= 0x2000
+	}
+
+	if tag_flags.compression {
+		flags |= 0x0080
+	}
+
+	if tag_flags.encryption.0 {
+		flags |= 0x0040
+	}
+
+	if tag_flags.grouping_identity.0 {
+		flags |= 0x0020
+	}
+
+	flags
+}
+
 fn get_flags(tag_flags: FrameFlags) -> u16 {
 	let mut flags = 0;
 
@@ -1,38 +1,24 @@
 mod chunk_file;
-mod frame;
+pub(super) mod frame;
 
 use super::Id3v2TagFlags;
-use crate::error::{ErrorKind, LoftyError, Result};
+use crate::error::{ErrorKind, FileEncodingError, LoftyError, Result};
 use crate::file::FileType;
-use crate::id3::find_id3v2;
 use crate::id3::v2::frame::FrameRef;
-use crate::id3::v2::synch_u32;
 use crate::id3::v2::tag::Id3v2TagRef;
+use crate::id3::v2::util::{crc_32, synch_content};
+use crate::id3::v2::{synch_u32, Id3v2Version};
+use crate::id3::{find_id3v2, ID3FindResults};
 use crate::probe::Probe;
+use crate::traits::FileLike;
 
-use std::fs::File;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
-use std::ops::Not;
 
 use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
 
-// In the very rare chance someone wants to write a CRC in their extended header
-static CRC_32_TABLE: once_cell::sync::Lazy<[u32; 256]> = once_cell::sync::Lazy::new(|| {
-	let mut crc32_table = [0; 256];
-
-	for n in 0..256 {
-		crc32_table[n as usize] = (0..8).fold(n as u32, |acc, _| match acc & 1 {
-			1 => 0xEDB8_8320 ^ (acc >> 1),
-			_ => acc >> 1,
-		});
-	}
-
-	crc32_table
-});
-
 #[allow(clippy::shadow_unrelated)]
-pub(crate) fn write_id3v2<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
-	data: &mut File,
+pub(crate) fn write_id3v2<'a, I: Iterator<Item = FrameRef<'a>> + 'a, F: FileLike>(
+	data: &mut F,
 	tag: &mut Id3v2TagRef<'a, I>,
 ) -> Result<()> {
 	let probe = Probe::new(data).guess_file_type()?;
@@ -41,23 +27,83 @@ pub(crate) fn write_id3v2<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	let data = probe.into_inner();
 
 	match file_type {
-		Some(FileType::APE | FileType::MP3 | FileType::FLAC) => {},
+		Some(FileType::Aac | FileType::APE | FileType::MP3) => {},
+		// ID3v2 in FLAC is non-standard, so this is only used to strip the tag.
+		// Writing needs to be opted into with `FlacFile::save_id3v2_to`.
+		Some(FileType::FLAC) => {
+			let id3v2 = create_tag(tag)?;
+
+			if !id3v2.is_empty() {
+				return Err(LoftyError::new(ErrorKind::UnsupportedTag));
+			}
+
+			return write_to_start(data, id3v2);
+		},
 		// Formats such as WAV and AIFF store the ID3v2 tag in an 'ID3 ' chunk rather than at the beginning of the file
 		Some(FileType::WAV) => {
 			tag.flags.footer = false;
-			return chunk_file::write_to_chunk_file::<LittleEndian>(data, &create_tag(tag)?);
+			return chunk_file::write_to_chunk_file::<LittleEndian, _>(data, &create_tag(tag)?);
 		},
 		Some(FileType::AIFF) => {
 			tag.flags.footer = false;
-			return chunk_file::write_to_chunk_file::<BigEndian>(data, &create_tag(tag)?);
+			return chunk_file::write_to_chunk_file::<BigEndian, _>(data, &create_tag(tag)?);
+		},
+		// DSF stores the ID3v2 tag at the end of the file, pointed to by the "DSD " chunk
+		Some(FileType::Dsf) => {
+			tag.flags.footer = false;
+			return crate::dsf::write::write_id3v2_to_dsf(data, &create_tag(tag)?);
 		},
 		_ => return Err(LoftyError::new(ErrorKind::UnsupportedTag)),
 	}
 
 	let id3v2 = create_tag(tag)?;
+	write_to_start(data, id3v2)
+}
+
+pub(crate) fn write_id3v2_to_flac<'a, I: Iterator<Item = FrameRef<'a>> + 'a, F: FileLike>(
+	data: &mut F,
+	tag: &mut Id3v2TagRef<'a, I>,
+) -> Result<()> {
+	let probe = Probe::new(data).guess_file_type()?;
+
+	if probe.file_type() != Some(FileType::FLAC) {
+		return Err(FileEncodingError::new(
+			FileType::FLAC,
+			"Attempted to write an ID3v2 tag to a non-FLAC file",
+		)
+		.into());
+	}
+
+	let id3v2 = create_tag(tag)?;
+	write_to_start(probe.into_inner(), id3v2)
+}
 
+// Replaces any existing ID3v2 tag at the start of the file, the rest of the file is left untouched
+fn write_to_start<F: FileLike>(data: &mut F, mut id3v2: Vec<u8>) -> Result<()> {
 	// find_id3v2 will seek us to the end of the tag
-	find_id3v2(data, false)?;
+	let ID3FindResults(header, _) = find_id3v2(data, false)?;
+
+	if let Some(header) = header {
+		let mut available = 10 + header.size as usize;
+
+		if header.flags.footer {
+			available += 10;
+		}
+
+		// If the new tag fits in the space of the old one, we can avoid shifting the rest of the file
+		if can_pad(&id3v2, available) {
+			id3v2.resize(available, 0);
+
+			// The size doesn't include the header
+			let size = synch_u32((available - 10) as u32)?;
+			id3v2[6..10].copy_from_slice(&size.to_be_bytes());
+
+			data.rewind()?;
+			data.write_all(&id3v2)?;
+
+			return Ok(());
+		}
+	}
 
 	let mut file_bytes = Vec::new();
 	data.read_to_end(&mut file_bytes)?;
@@ -65,23 +111,47 @@ pub(crate) fn write_id3v2<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	file_bytes.splice(0..0, id3v2);
 
 	data.rewind()?;
-	data.set_len(0)?;
+	data.truncate(0)?;
 	data.write_all(&*file_bytes)?;
 
 	Ok(())
 }
 
+// A tag can only be padded if it doesn't have a footer, as the two are mutually exclusive.
+//
+// Tags with an extended header are always rewritten, since padding would be included in the CRC
+// when reading it back.
+fn can_pad(tag: &[u8], available: usize) -> bool {
+	const EXTENDED_HEADER_FLAG: u8 = 0x40;
+	const FOOTER_FLAG: u8 = 0x10;
+
+	!tag.is_empty() && tag.len() <= available && tag[5] & (EXTENDED_HEADER_FLAG | FOOTER_FLAG) == 0
+}
+
 pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	tag: &mut Id3v2TagRef<'a, I>,
 ) -> Result<Vec<u8>> {
+	let unsynchronisation = tag.flags.unsynchronisation;
+
 	let frames = &mut tag.frames;
-	let mut peek = frames.peekable();
+	let mut peek = frames
+		.map(|mut frame| {
+			// ID3v2.4 unsynchronisation is done on a per-frame basis, the tag flag only indicates
+			// that it has been applied to every frame
+			frame.flags.unsynchronisation |= unsynchronisation;
+			frame
+		})
+		.peekable();
 
 	// We are stripping the tag
 	if peek.peek().is_none() {
 		return Ok(Vec::new());
 	}
 
+	if tag.version == Id3v2Version::V3 {
+		return create_v3_tag(tag.flags, &mut peek);
+	}
+
 	let has_footer = tag.flags.footer;
 	let needs_crc = tag.flags.crc;
 	#[cfg(feature = "id3v2_restrictions")]
@@ -91,13 +161,20 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	let header_len = id3v2.get_ref().len();
 
 	// Write the items
-	frame::create_items(&mut id3v2, &mut peek)?;
+	frame::create_items(&mut id3v2, &mut peek, Id3v2Version::V4)?;
 
 	let len = id3v2.get_ref().len() - header_len;
 
+	// Padding is excluded from the CRC, and can't be used alongside a footer
+	let padding = if has_footer || needs_crc {
+		0
+	} else {
+		tag.flags.padding
+	};
+
 	// Go back to the start and write the final size
 	id3v2.seek(SeekFrom::Start(6))?;
-	id3v2.write_u32::<BigEndian>(synch_u32(extended_header_len + len as u32)?)?;
+	id3v2.write_u32::<BigEndian>(synch_u32(extended_header_len + len as u32 + padding)?)?;
 
 	if needs_crc {
 		// The CRC is calculated on all the data between the header and footer
@@ -105,9 +182,11 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 		// Past the CRC
 		let mut content_start_idx = 22;
 
+		// Restrictions length byte (1)
+		// Restrictions (1)
 		#[cfg(feature = "id3v2_restrictions")]
 		if has_restrictions {
-			content_start_idx += 3;
+			content_start_idx += 2;
 		}
 
 		// Skip 16 bytes
@@ -117,7 +196,7 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 		id3v2.seek(SeekFrom::Start(16))?;
 
 		let tag_contents = &id3v2.get_ref()[content_start_idx..];
-		let encoded_crc = calculate_crc(tag_contents);
+		let encoded_crc = encode_crc(crc_32(tag_contents));
 
 		id3v2.write_u8(5)?;
 		id3v2.write_all(&encoded_crc)?;
@@ -135,7 +214,45 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 		id3v2.write_all(&header_without_identifier)?;
 	}
 
-	Ok(id3v2.into_inner())
+	let mut id3v2 = id3v2.into_inner();
+	id3v2.resize(id3v2.len() + padding as usize, 0);
+
+	Ok(id3v2)
+}
+
+// ID3v2.3 tags are written without an extended header or footer, as neither are required,
+// so padding can always be added
+fn create_v3_tag<'a>(
+	flags: Id3v2TagFlags,
+	frames: &mut dyn Iterator<Item = FrameRef<'a>>,
+) -> Result<Vec<u8>> {
+	let mut content = Vec::new();
+	frame::create_items(&mut content, frames, Id3v2Version::V3)?;
+
+	// Prior to ID3v2.4, unsynchronisation applied to the entire tag, rather than each frame
+	if flags.unsynchronisation {
+		content = synch_content(&content);
+	}
+
+	let mut tag_flags = 0;
+
+	if flags.unsynchronisation {
+		tag_flags |= 0x80
+	}
+
+	if flags.experimental {
+		tag_flags |= 0x20
+	}
+
+	let mut id3v2 = Vec::with_capacity(content.len() + 10);
+
+	// Version 3, rev 0
+	id3v2.write_all(&[b'I', b'D', b'3', 3, 0, tag_flags])?;
+	id3v2.write_u32::<BigEndian>(synch_u32(content.len() as u32 + flags.padding)?)?;
+	id3v2.append(&mut content);
+	id3v2.resize(id3v2.len() + flags.padding as usize, 0);
+
+	Ok(id3v2)
 }
 
 fn create_tag_header(flags: Id3v2TagFlags) -> Result<(Cursor<Vec<u8>>, u32)> {
@@ -154,6 +271,10 @@ fn create_tag_header(flags: Id3v2TagFlags) -> Result<(Cursor<Vec<u8>>, u32)> {
 	#[cfg(feature = "id3v2_restrictions")]
 	let extended_header = flags.crc || flags.restrictions.0;
 
+	if flags.unsynchronisation {
+		tag_flags |= 0x80
+	}
+
 	if flags.footer {
 		tag_flags |= 0x10
 	}
@@ -213,17 +334,9 @@ fn create_tag_header(flags: Id3v2TagFlags) -> Result<(Cursor<Vec<u8>>, u32)> {
 	Ok((header, extended_header_size))
 }
 
-// https://github.com/rstemmer/id3edit/blob/0246f3dc1a7a80a64461eeeb7b9ee88379003eb1/encoding/crc.c#L6:6
-fn calculate_crc(content: &[u8]) -> [u8; 5] {
-	let crc: u32 = content
-		.iter()
-		.fold(!0, |crc, octet| {
-			(crc >> 8) ^ CRC_32_TABLE[(((crc & 0xFF) ^ u32::from(*octet)) & 0xFF) as usize]
-		})
-		.not();
-
-	// The CRC-32 is stored as an 35 bit synchsafe integer, leaving the upper
-	// four bits always zeroed.
+// The CRC-32 is stored as an 35 bit synchsafe integer, leaving the upper
+// four bits always zeroed.
+fn encode_crc(crc: u32) -> [u8; 5] {
 	let mut encoded_crc = [0; 5];
 	let mut b;
 
@@ -239,9 +352,16 @@ fn calculate_crc(content: &[u8]) -> [u8; 5] {
 
 #[cfg(test)]
 mod tests {
-	use crate::id3::v2::{Id3v2Tag, Id3v2TagFlags};
+	use crate::error::{ErrorKind, Id3v2ErrorKind};
+	use crate::id3::v2::read::parse_id3v2;
+	use crate::id3::v2::util::crc_32;
+	use crate::id3::v2::{read_id3v2_header, Id3v2Tag, Id3v2TagFlags};
+	use crate::probe::ParseContext;
 	use crate::{Accessor, TagExt};
 
+	#[cfg(feature = "id3v2_restrictions")]
+	use crate::id3::v2::TagRestrictions;
+
 	#[test]
 	fn id3v2_write_crc32() {
 		let mut tag = Id3v2Tag::default();
@@ -272,4 +392,143 @@ mod tests {
 
 		assert_eq!(unsynch_crc, 0x66BA_7E94);
 	}
+
+	#[test]
+	fn id3v2_read_crc32() {
+		let mut tag = Id3v2Tag::default();
+		tag.set_artist(String::from("Foo artist"));
+
+		let flags = Id3v2TagFlags {
+			crc: true,
+			#[cfg(feature = "id3v2_restrictions")]
+			restrictions: (true, TagRestrictions::default()),
+			..Id3v2TagFlags::default()
+		};
+		tag.set_flags(flags);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let header = read_id3v2_header(&mut &writer[..]).unwrap();
+		assert_eq!(
+			header.crc,
+			Some(crc_32(&writer[header.extended_size as usize + 10..]))
+		);
+
+		let reader = &mut &writer[..];
+		let header = read_id3v2_header(reader).unwrap();
+		let parsed_tag = parse_id3v2(reader, header, &mut ParseContext::default()).unwrap();
+
+		assert_eq!(parsed_tag.artist(), Some("Foo artist"));
+
+		// Changing the content should cause the CRC to no longer match
+		let last = writer.len() - 1;
+		writer[last] = b'X';
+
+		let reader = &mut &writer[..];
+		let header = read_id3v2_header(reader).unwrap();
+		let err = parse_id3v2(reader, header, &mut ParseContext::default()).unwrap_err();
+		assert!(matches!(
+			err.kind(),
+			ErrorKind::Id3v2(e) if matches!(e.kind(), Id3v2ErrorKind::CrcMismatch(..))
+		));
+
+		// Which is only a warning when parsing leniently
+		let mut context = ParseContext::collecting();
+		context.lenient_id3v2 = true;
+
+		let reader = &mut &writer[..];
+		let header = read_id3v2_header(reader).unwrap();
+		let parsed_tag = parse_id3v2(reader, header, &mut context).unwrap();
+
+		assert_eq!(parsed_tag.artist(), Some("Foo artisX"));
+		assert_eq!(context.into_warnings().len(), 1);
+	}
+
+	#[test]
+	fn id3v2_read_v3_crc32() {
+		// ID3v2.3 frame sizes aren't synchsafe
+		let frame = b"TPE1\x00\x00\x00\x0B\x00\x00\x00Foo artist";
+		let padding = [0; 4];
+
+		let build_tag = |crc: u32| {
+			// Extended header size (excluding itself), flags (CRC-32 present), padding size, and the CRC-32
+			let mut extended_header = 10_u32.to_be_bytes().to_vec();
+			extended_header.extend_from_slice(&[0x80, 0x00]);
+			extended_header.extend_from_slice(&(padding.len() as u32).to_be_bytes());
+			extended_header.extend_from_slice(&crc.to_be_bytes());
+
+			let size = extended_header.len() + frame.len() + padding.len();
+
+			let mut tag = b"ID3\x03\x00\x40\x00\x00\x00".to_vec();
+			tag.push(size as u8);
+			tag.extend_from_slice(&extended_header);
+			tag.extend_from_slice(frame);
+			tag.extend_from_slice(&padding);
+			tag
+		};
+
+		// The CRC-32 only covers the frames, not the padding
+		let tag = build_tag(crc_32(frame) ^ 1);
+
+		let reader = &mut &tag[..];
+		let header = read_id3v2_header(reader).unwrap();
+		assert!(parse_id3v2(reader, header, &mut ParseContext::default()).is_err());
+
+		for (crc, expected_warnings) in [(crc_32(frame), 0), (crc_32(frame) ^ 1, 1)] {
+			let tag = build_tag(crc);
+
+			let mut context = ParseContext::collecting();
+			context.lenient_id3v2 = true;
+
+			let reader = &mut &tag[..];
+			let header = read_id3v2_header(reader).unwrap();
+			assert_eq!(header.crc, Some(crc));
+
+			let parsed_tag = parse_id3v2(reader, header, &mut context).unwrap();
+
+			assert!(parsed_tag.flags().crc);
+			assert_eq!(parsed_tag.artist(), Some("Foo artist"));
+			assert_eq!(context.into_warnings().len(), expected_warnings);
+		}
+	}
+
+	#[test]
+	fn id3v2_write_padding() {
+		let mut tag = Id3v2Tag::default();
+		tag.set_artist(String::from("Foo artist"));
+
+		let mut unpadded = Vec::new();
+		tag.dump_to(&mut unpadded).unwrap();
+
+		tag.set_flags(Id3v2TagFlags {
+			padding: 1024,
+			..Id3v2TagFlags::default()
+		});
+
+		let mut padded = Vec::new();
+		tag.dump_to(&mut padded).unwrap();
+
+		assert_eq!(padded.len(), unpadded.len() + 1024);
+		assert!(padded[unpadded.len()..].iter().all(|b| *b == 0));
+
+		let reader = &mut &padded[..];
+		let header = read_id3v2_header(reader).unwrap();
+		assert_eq!(header.size as usize, padded.len() - 10);
+
+		let parsed_tag = parse_id3v2(reader, header, &mut ParseContext::default()).unwrap();
+		assert_eq!(parsed_tag.artist(), Some("Foo artist"));
+
+		// Padding isn't allowed alongside a footer
+		tag.set_flags(Id3v2TagFlags {
+			padding: 1024,
+			footer: true,
+			..Id3v2TagFlags::default()
+		});
+
+		let mut with_footer = Vec::new();
+		tag.dump_to(&mut with_footer).unwrap();
+
+		assert_eq!(with_footer.len(), unpadded.len() + 10);
+	}
 }
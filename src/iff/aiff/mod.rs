@@ -1,13 +1,15 @@
 mod properties;
-mod read;
+pub(crate) mod read;
 pub(crate) mod write;
 
 use crate::error::Result;
 use crate::file::{AudioFile, FileType, TaggedFile};
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::probe::ParseContext;
 use crate::properties::FileProperties;
 use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
 
 use std::io::{Read, Seek};
 
@@ -19,6 +21,12 @@ cfg_if::cfg_if! {
 }
 
 /// An AIFF file
+///
+/// ## Tags
+///
+/// AIFF files can contain both an `ID3 ` chunk and text chunks (`NAME`, `AUTH`, `(c) `, `ANNO`, `COMT`).
+/// When both are present, ID3v2 is the primary tag. They are stored separately, so each can
+/// be written or removed without affecting the other.
 pub struct AiffFile {
 	#[cfg(feature = "aiff_text_chunks")]
 	/// Any text chunks included in the file
@@ -43,6 +51,8 @@ impl From<AiffFile> for TaggedFile {
 		Self {
 			ty: FileType::AIFF,
 			properties: input.properties,
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			tags: tags.into_iter().flatten().collect(),
 		}
 	}
@@ -56,13 +66,24 @@ impl AudioFile for AiffFile {
 		R: Read + Seek,
 		Self: Sized,
 	{
-		read::read_from(reader, read_properties)
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
 	}
 
 	fn properties(&self) -> &Self::Properties {
 		&self.properties
 	}
 
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v2")]
+			id3v2_tag,
+
+			#[cfg(feature = "aiff_text_chunks")]
+			text_chunks
+		}
+	}
+
 	#[allow(unreachable_code)]
 	fn contains_tag(&self) -> bool {
 		#[cfg(feature = "id3v2")]
@@ -6,6 +6,7 @@ use crate::file::FileType;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::iff::chunk::Chunks;
+use crate::probe::ParseContext;
 use crate::properties::FileProperties;
 
 use std::io::{Read, Seek};
@@ -21,7 +22,7 @@ where
 	let mut id = [0; 12];
 	data.read_exact(&mut id)?;
 
-	if !(&id[..4] == b"FORM" && (&id[8..] == b"AIFF" || &id[..8] == b"AIFC")) {
+	if !(&id[..4] == b"FORM" && (&id[8..] == b"AIFF" || &id[8..] == b"AIFC")) {
 		return Err(LoftyError::new(ErrorKind::UnknownFormat));
 	}
 
@@ -30,7 +31,13 @@ where
 	))
 }
 
-pub(crate) fn read_from<R>(data: &mut R, read_properties: bool) -> Result<AiffFile>
+#[allow(unused_variables)]
+pub(crate) fn read_from<R>(
+	data: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<AiffFile>
 where
 	R: Read + Seek,
 {
@@ -54,7 +61,7 @@ where
 	while chunks.next(data).is_ok() {
 		match &chunks.fourcc {
 			#[cfg(feature = "id3v2")]
-			b"ID3 " | b"id3 " => id3v2_tag = Some(chunks.id3_chunk(data)?),
+			b"ID3 " | b"id3 " if read_tags => id3v2_tag = Some(chunks.id3_chunk(data, context)?),
 			b"COMM" if read_properties && comm.is_none() => {
 				if chunks.size < 18 {
 					return Err(FileDecodingError::new(
@@ -72,13 +79,13 @@ where
 				chunks.skip(data)?;
 			},
 			#[cfg(feature = "aiff_text_chunks")]
-			b"ANNO" => {
+			b"ANNO" if read_tags => {
 				annotations.push(chunks.read_pstring(data, None)?);
 			},
 			// These four chunks are expected to appear at most once per file,
 			// so there's no need to replace anything we already read
 			#[cfg(feature = "aiff_text_chunks")]
-			b"COMT" if comments.is_empty() => {
+			b"COMT" if read_tags && comments.is_empty() => {
 				let num_comments = data.read_u16::<BigEndian>()?;
 
 				for _ in 0..num_comments {
@@ -98,15 +105,15 @@ where
 				chunks.correct_position(data)?;
 			},
 			#[cfg(feature = "aiff_text_chunks")]
-			b"NAME" if text_chunks.name.is_none() => {
+			b"NAME" if read_tags && text_chunks.name.is_none() => {
 				text_chunks.name = Some(chunks.read_pstring(data, None)?);
 			},
 			#[cfg(feature = "aiff_text_chunks")]
-			b"AUTH" if text_chunks.author.is_none() => {
+			b"AUTH" if read_tags && text_chunks.author.is_none() => {
 				text_chunks.author = Some(chunks.read_pstring(data, None)?);
 			},
 			#[cfg(feature = "aiff_text_chunks")]
-			b"(c) " if text_chunks.copyright.is_none() => {
+			b"(c) " if read_tags && text_chunks.copyright.is_none() => {
 				text_chunks.copyright = Some(chunks.read_pstring(data, None)?);
 			},
 			_ => chunks.skip(data)?,
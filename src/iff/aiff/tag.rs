@@ -1,12 +1,12 @@
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::iff::chunk::Chunks;
+use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 
 use std::convert::TryFrom;
-use std::fs::{File, OpenOptions};
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::Write;
 use std::path::Path;
 
 use byteorder::BigEndian;
@@ -141,10 +141,10 @@ impl TagExt for AiffTextChunks {
 	/// * `path` does not exist
 	/// * See [`AiffTextChunks::save_to`]
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		AiffTextChunksRef {
 			name: self.name.as_deref(),
 			author: self.author.as_deref(),
@@ -170,13 +170,28 @@ impl TagExt for AiffTextChunks {
 		TagType::AiffText.remove_from_path(path)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		TagType::AiffText.remove_from(file)
 	}
 
 	fn clear(&mut self) {
 		*self = Self::default();
 	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		Vec::new()
+	}
+
+	/// AIFF text chunks cannot store pictures
+	///
+	/// # Errors
+	///
+	/// This will always error with [`ErrorKind::UnsupportedPicture`]
+	fn replace_picture(&mut self, _: Picture) -> std::result::Result<(), Self::Err> {
+		Err(LoftyError::new(ErrorKind::UnsupportedPicture))
+	}
+
+	fn remove_picture_type(&mut self, _: PictureType) {}
 }
 
 impl From<AiffTextChunks> for Tag {
@@ -245,7 +260,7 @@ where
 	T: AsRef<str>,
 	AI: IntoIterator<Item = T>,
 {
-	pub(crate) fn write_to(self, file: &mut File) -> Result<()> {
+	pub(crate) fn write_to<F: FileLike>(self, file: &mut F) -> Result<()> {
 		AiffTextChunksRef::write_to_inner(file, self)
 	}
 
@@ -336,27 +351,31 @@ where
 		Ok(text_chunks)
 	}
 
-	fn write_to_inner(data: &mut File, mut tag: AiffTextChunksRef<'_, T, AI>) -> Result<()> {
+	fn write_to_inner<F: FileLike>(
+		data: &mut F,
+		mut tag: AiffTextChunksRef<'_, T, AI>,
+	) -> Result<()> {
 		let file_size = super::read::verify_aiff(data)?;
 
 		let text_chunks = Self::create_text_chunks(&mut tag)?;
 
 		let mut chunks_remove = Vec::new();
+		// New text chunks are placed after the "COMM" chunk, or after the FORM header if it's missing
+		let mut comm_end = 12;
 
 		let mut chunks = Chunks::<BigEndian>::new(file_size);
 
 		while chunks.next(data).is_ok() {
-			match &chunks.fourcc {
-				b"NAME" | b"AUTH" | b"(c) " | b"ANNO" | b"COMT" => {
-					let start = (data.stream_position()? - 8) as usize;
-					let mut end = start + 8 + chunks.size as usize;
+			let start = (data.stream_position()? - 8) as usize;
+			let mut end = start + 8 + chunks.size as usize;
 
-					if chunks.size % 2 != 0 {
-						end += 1
-					}
+			if chunks.size % 2 != 0 {
+				end += 1
+			}
 
-					chunks_remove.push((start, end))
-				},
+			match &chunks.fourcc {
+				b"NAME" | b"AUTH" | b"(c) " | b"ANNO" | b"COMT" => chunks_remove.push((start, end)),
+				b"COMM" => comm_end = end,
 				_ => {},
 			}
 
@@ -369,12 +388,6 @@ where
 		data.read_to_end(&mut file_bytes)?;
 
 		if chunks_remove.is_empty() {
-			data.seek(SeekFrom::Start(16))?;
-
-			let mut size = [0; 4];
-			data.read_exact(&mut size)?;
-
-			let comm_end = (20 + u32::from_le_bytes(size)) as usize;
 			file_bytes.splice(comm_end..comm_end, text_chunks);
 		} else {
 			chunks_remove.sort_unstable();
@@ -393,7 +406,7 @@ where
 		file_bytes.splice(4..8, total_size.to_vec());
 
 		data.rewind()?;
-		data.set_len(0)?;
+		data.truncate(0)?;
 		data.write_all(&*file_bytes)?;
 
 		Ok(())
@@ -403,6 +416,7 @@ where
 #[cfg(test)]
 mod tests {
 	use crate::iff::{AiffTextChunks, Comment};
+	use crate::probe::ParseContext;
 	use crate::{ItemKey, ItemValue, Tag, TagExt, TagItem, TagType};
 
 	use std::io::Cursor;
@@ -433,10 +447,15 @@ mod tests {
 
 		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.aiff_text");
 
-		let parsed_tag = super::super::read::read_from(&mut Cursor::new(tag), false)
-			.unwrap()
-			.text_chunks
-			.unwrap();
+		let parsed_tag = super::super::read::read_from(
+			&mut Cursor::new(tag),
+			true,
+			false,
+			&mut ParseContext::default(),
+		)
+		.unwrap()
+		.text_chunks
+		.unwrap();
 
 		assert_eq!(expected_tag, parsed_tag);
 	}
@@ -444,10 +463,15 @@ mod tests {
 	#[test]
 	fn aiff_text_re_read() {
 		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.aiff_text");
-		let parsed_tag = super::super::read::read_from(&mut Cursor::new(tag), false)
-			.unwrap()
-			.text_chunks
-			.unwrap();
+		let parsed_tag = super::super::read::read_from(
+			&mut Cursor::new(tag),
+			true,
+			false,
+			&mut ParseContext::default(),
+		)
+		.unwrap()
+		.text_chunks
+		.unwrap();
 
 		// Create a fake AIFF signature
 		let mut writer = vec![
@@ -455,10 +479,15 @@ mod tests {
 		];
 		parsed_tag.dump_to(&mut writer).unwrap();
 
-		let temp_parsed_tag = super::super::read::read_from(&mut Cursor::new(writer), false)
-			.unwrap()
-			.text_chunks
-			.unwrap();
+		let temp_parsed_tag = super::super::read::read_from(
+			&mut Cursor::new(writer),
+			true,
+			false,
+			&mut ParseContext::default(),
+		)
+		.unwrap()
+		.text_chunks
+		.unwrap();
 
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
@@ -468,10 +497,15 @@ mod tests {
 		let tag_bytes =
 			crate::tag::utils::test_utils::read_path("tests/tags/assets/test.aiff_text");
 
-		let aiff_text = super::super::read::read_from(&mut Cursor::new(tag_bytes), false)
-			.unwrap()
-			.text_chunks
-			.unwrap();
+		let aiff_text = super::super::read::read_from(
+			&mut Cursor::new(tag_bytes),
+			true,
+			false,
+			&mut ParseContext::default(),
+		)
+		.unwrap()
+		.text_chunks
+		.unwrap();
 
 		let tag: Tag = aiff_text.into();
 
@@ -2,6 +2,8 @@ use crate::error::{ErrorKind, LoftyError, Result};
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::macros::try_vec;
+#[cfg(feature = "id3v2")]
+use crate::probe::ParseContext;
 
 use std::io::{Read, Seek, SeekFrom};
 use std::marker::PhantomData;
@@ -90,7 +92,7 @@ impl<B: ByteOrder> Chunks<B> {
 	}
 
 	#[cfg(feature = "id3v2")]
-	pub fn id3_chunk<R>(&mut self, data: &mut R) -> Result<Id3v2Tag>
+	pub fn id3_chunk<R>(&mut self, data: &mut R, context: &mut ParseContext) -> Result<Id3v2Tag>
 	where
 		R: Read + Seek,
 	{
@@ -103,7 +105,7 @@ impl<B: ByteOrder> Chunks<B> {
 		let reader = &mut &*value;
 
 		let header = read_id3v2_header(reader)?;
-		let id3v2 = parse_id3v2(reader, header)?;
+		let id3v2 = parse_id3v2(reader, header, context)?;
 
 		// Skip over the footer
 		if id3v2.flags().footer {
@@ -6,7 +6,7 @@ pub(crate) mod wav;
 // Exports
 
 pub use aiff::AiffFile;
-pub use wav::{WavFile, WavFormat, WavProperties};
+pub use wav::{BroadcastExtension, WavFile, WavFormat, WavProperties};
 
 cfg_if::cfg_if! {
 	if #[cfg(feature = "aiff_text_chunks")] {
@@ -0,0 +1,338 @@
+use super::read::verify_wav;
+use crate::error::{ErrorKind, FileDecodingError, FileEncodingError, LoftyError, Result};
+use crate::file::FileType;
+use crate::iff::chunk::Chunks;
+use crate::traits::FileLike;
+
+use std::io::{Read, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+// The size of everything prior to the coding history
+const FIXED_SIZE: usize = 602;
+
+/// A Broadcast Wave Format extension (`bext`) chunk
+///
+/// See [EBU Tech 3285](https://tech.ebu.ch/docs/tech/tech3285.pdf) for more information.
+///
+/// NOTE: All text fields are ASCII, and are written with a fixed width padded with nulls.
+/// Writing a field that exceeds its width will fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastExtension {
+	/// A description of the sound sequence (256 bytes)
+	pub description: String,
+	/// The name of the originator (32 bytes)
+	pub originator: String,
+	/// A unique reference, allocated by the originator (32 bytes)
+	pub originator_reference: String,
+	/// The date of creation, formatted as `yyyy-mm-dd` (10 bytes)
+	pub origination_date: String,
+	/// The time of creation, formatted as `hh:mm:ss` (8 bytes)
+	pub origination_time: String,
+	/// The first sample count since midnight
+	pub time_reference: u64,
+	/// The version of the BWF
+	pub version: u16,
+	/// A SMPTE UMID (version 1+)
+	pub umid: [u8; 64],
+	/// The integrated loudness, in LUFS multiplied by 100 (version 2+)
+	pub loudness_value: i16,
+	/// The loudness range, in LU multiplied by 100 (version 2+)
+	pub loudness_range: i16,
+	/// The maximum true peak level, in dBTP multiplied by 100 (version 2+)
+	pub max_true_peak_level: i16,
+	/// The highest momentary loudness, in LUFS multiplied by 100 (version 2+)
+	pub max_momentary_loudness: i16,
+	/// The highest short-term loudness, in LUFS multiplied by 100 (version 2+)
+	pub max_short_term_loudness: i16,
+	/// The coding history, a series of CR/LF terminated lines
+	pub coding_history: String,
+}
+
+impl Default for BroadcastExtension {
+	fn default() -> Self {
+		Self {
+			description: String::new(),
+			originator: String::new(),
+			originator_reference: String::new(),
+			origination_date: String::new(),
+			origination_time: String::new(),
+			time_reference: 0,
+			version: 0,
+			umid: [0; 64],
+			loudness_value: 0,
+			loudness_range: 0,
+			max_true_peak_level: 0,
+			max_momentary_loudness: 0,
+			max_short_term_loudness: 0,
+			coding_history: String::new(),
+		}
+	}
+}
+
+impl BroadcastExtension {
+	pub(crate) fn parse(content: &[u8]) -> Result<Self> {
+		if content.len() < FIXED_SIZE {
+			return Err(
+				FileDecodingError::new(FileType::WAV, "\"bext\" chunk is too short").into(),
+			);
+		}
+
+		let reader = &mut &content[..];
+
+		let description = read_string(reader, 256)?;
+		let originator = read_string(reader, 32)?;
+		let originator_reference = read_string(reader, 32)?;
+		let origination_date = read_string(reader, 10)?;
+		let origination_time = read_string(reader, 8)?;
+
+		// The time reference is split into the low and high 32 bits
+		let time_reference_low = reader.read_u32::<LittleEndian>()?;
+		let time_reference_high = reader.read_u32::<LittleEndian>()?;
+		let time_reference = (u64::from(time_reference_high) << 32) | u64::from(time_reference_low);
+
+		let version = reader.read_u16::<LittleEndian>()?;
+
+		let mut umid = [0; 64];
+		reader.read_exact(&mut umid)?;
+
+		let loudness_value = reader.read_i16::<LittleEndian>()?;
+		let loudness_range = reader.read_i16::<LittleEndian>()?;
+		let max_true_peak_level = reader.read_i16::<LittleEndian>()?;
+		let max_momentary_loudness = reader.read_i16::<LittleEndian>()?;
+		let max_short_term_loudness = reader.read_i16::<LittleEndian>()?;
+
+		// Reserved
+		*reader = &reader[180..];
+
+		let coding_history = decode_text(reader);
+
+		Ok(Self {
+			description,
+			originator,
+			originator_reference,
+			origination_date,
+			origination_time,
+			time_reference,
+			version,
+			umid,
+			loudness_value,
+			loudness_range,
+			max_true_peak_level,
+			max_momentary_loudness,
+			max_short_term_loudness,
+			coding_history,
+		})
+	}
+
+	pub(crate) fn as_bytes(&self) -> Result<Vec<u8>> {
+		let mut bytes = Vec::with_capacity(FIXED_SIZE + self.coding_history.len());
+
+		write_string(&mut bytes, &self.description, 256)?;
+		write_string(&mut bytes, &self.originator, 32)?;
+		write_string(&mut bytes, &self.originator_reference, 32)?;
+		write_string(&mut bytes, &self.origination_date, 10)?;
+		write_string(&mut bytes, &self.origination_time, 8)?;
+
+		bytes.write_u32::<LittleEndian>(self.time_reference as u32)?;
+		bytes.write_u32::<LittleEndian>((self.time_reference >> 32) as u32)?;
+		bytes.write_u16::<LittleEndian>(self.version)?;
+		bytes.extend_from_slice(&self.umid);
+		bytes.write_i16::<LittleEndian>(self.loudness_value)?;
+		bytes.write_i16::<LittleEndian>(self.loudness_range)?;
+		bytes.write_i16::<LittleEndian>(self.max_true_peak_level)?;
+		bytes.write_i16::<LittleEndian>(self.max_momentary_loudness)?;
+		bytes.write_i16::<LittleEndian>(self.max_short_term_loudness)?;
+		bytes.extend_from_slice(&[0; 180]);
+		bytes.extend_from_slice(self.coding_history.as_bytes());
+
+		Ok(bytes)
+	}
+
+	/// Writes the chunk to a WAV file, replacing any existing `bext` chunk
+	///
+	/// # Errors
+	///
+	/// * `file` is not a valid WAV file
+	/// * A text field exceeds its fixed width
+	/// * [`std::io::Error`]
+	pub fn write_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		replace_chunk(file, Some(&self.as_bytes()?))
+	}
+
+	/// Removes the `bext` chunk from a WAV file
+	///
+	/// # Errors
+	///
+	/// * `file` is not a valid WAV file
+	/// * [`std::io::Error`]
+	pub fn remove_from<F: FileLike>(file: &mut F) -> Result<()> {
+		replace_chunk(file, None)
+	}
+}
+
+fn read_string(reader: &mut &[u8], size: usize) -> Result<String> {
+	let mut content = vec![0; size];
+	reader.read_exact(&mut content)?;
+
+	Ok(decode_text(&content))
+}
+
+// The fields are null padded, but aren't required to be null terminated
+fn decode_text(content: &[u8]) -> String {
+	let end = content
+		.iter()
+		.position(|b| *b == 0)
+		.unwrap_or(content.len());
+
+	content[..end].iter().map(|b| *b as char).collect()
+}
+
+fn write_string(bytes: &mut Vec<u8>, value: &str, size: usize) -> Result<()> {
+	if value.len() > size || !value.is_ascii() {
+		return Err(FileEncodingError::new(
+			FileType::WAV,
+			"\"bext\" text field is too long, or contains non-ASCII characters",
+		)
+		.into());
+	}
+
+	bytes.extend_from_slice(value.as_bytes());
+	bytes.resize(bytes.len() + (size - value.len()), 0);
+
+	Ok(())
+}
+
+fn replace_chunk<F: FileLike>(file: &mut F, content: Option<&[u8]>) -> Result<()> {
+	let file_size = verify_wav(file)?;
+
+	let mut existing = None;
+
+	let mut chunks = Chunks::<LittleEndian>::new(file_size);
+	while chunks.next(file).is_ok() {
+		if &chunks.fourcc == b"bext" {
+			let start = file.stream_position()? as usize - 8;
+			let end = start + 8 + chunks.size as usize + (chunks.size % 2) as usize;
+
+			if end as u64 > file.seek(SeekFrom::End(0))? {
+				return Err(FileDecodingError::new(
+					FileType::WAV,
+					"\"bext\" chunk size exceeds the file length",
+				)
+				.into());
+			}
+
+			existing = Some(start..end);
+			break;
+		}
+
+		chunks.skip(file)?;
+	}
+
+	let mut chunk = Vec::new();
+	if let Some(content) = content {
+		if content.len() > u32::MAX as usize {
+			return Err(LoftyError::new(ErrorKind::TooMuchData));
+		}
+
+		chunk.extend_from_slice(b"bext");
+		chunk.write_u32::<LittleEndian>(content.len() as u32)?;
+		chunk.extend_from_slice(content);
+
+		if content.len() % 2 != 0 {
+			chunk.push(0);
+		}
+	}
+
+	// New chunks are placed directly after the RIFF header, so they precede the "fmt " chunk
+	let range = match existing {
+		Some(range) => range,
+		None if chunk.is_empty() => return Ok(()),
+		None => 12..12,
+	};
+
+	// The chunk can be replaced in place if the size is unchanged
+	if range.len() == chunk.len() {
+		file.seek(SeekFrom::Start(range.start as u64))?;
+		file.write_all(&chunk)?;
+
+		return Ok(());
+	}
+
+	file.rewind()?;
+
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes)?;
+
+	let _ = file_bytes.splice(range, chunk);
+
+	let total_size = (file_bytes.len() - 8) as u32;
+	let _ = file_bytes.splice(4..8, total_size.to_le_bytes());
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&file_bytes)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BroadcastExtension, FIXED_SIZE};
+
+	use std::io::Cursor;
+
+	#[test]
+	fn bext_round_trip() {
+		let bext = BroadcastExtension {
+			description: String::from("Foo description"),
+			originator: String::from("Bar originator"),
+			originator_reference: String::from("BAZREF0001"),
+			origination_date: String::from("2022-05-14"),
+			origination_time: String::from("13:37:00"),
+			// Requires the high 32 bits
+			time_reference: 0x0001_0000_0000 + 48000,
+			version: 2,
+			umid: [0x0A; 64],
+			loudness_value: -2300,
+			loudness_range: 500,
+			max_true_peak_level: -100,
+			max_momentary_loudness: -1800,
+			max_short_term_loudness: -2000,
+			coding_history: String::from("A=PCM,F=48000,W=24,M=stereo,T=Qux\r\n"),
+		};
+
+		let bytes = bext.as_bytes().unwrap();
+		assert_eq!(bytes.len(), FIXED_SIZE + bext.coding_history.len());
+
+		// Fixed width fields are null padded
+		assert_eq!(&bytes[..15], b"Foo description");
+		assert!(bytes[15..256].iter().all(|b| *b == 0));
+		assert_eq!(&bytes[338..346], &[0x80, 0xBB, 0, 0, 1, 0, 0, 0]);
+
+		assert_eq!(BroadcastExtension::parse(&bytes).unwrap(), bext);
+
+		let too_long = BroadcastExtension {
+			origination_date: String::from("2022-05-14T13:37"),
+			..BroadcastExtension::default()
+		};
+		assert!(too_long.as_bytes().is_err());
+
+		assert!(BroadcastExtension::parse(&bytes[..FIXED_SIZE - 1]).is_err());
+	}
+
+	#[test]
+	fn bext_chunk_exceeding_file() {
+		let mut file = Vec::new();
+		file.extend_from_slice(b"RIFF");
+		file.extend_from_slice(&22_u32.to_le_bytes());
+		file.extend_from_slice(b"WAVE");
+		file.extend_from_slice(b"bext");
+		file.extend_from_slice(&5000_u32.to_le_bytes());
+		file.extend_from_slice(&[0; 10]);
+
+		let bext = BroadcastExtension::default();
+		assert!(bext.write_to(&mut Cursor::new(file.clone())).is_err());
+		assert!(BroadcastExtension::remove_from(&mut Cursor::new(file)).is_err());
+	}
+}
@@ -1,13 +1,16 @@
+mod bext;
 mod properties;
-mod read;
+pub(crate) mod read;
 pub(crate) mod write;
 
 use crate::error::Result;
 use crate::file::{AudioFile, FileType, TaggedFile};
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::probe::ParseContext;
 use crate::properties::FileProperties;
 use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
 
 use std::io::{Read, Seek};
 
@@ -19,6 +22,7 @@ cfg_if::cfg_if! {
 }
 
 // Exports
+pub use crate::iff::wav::bext::BroadcastExtension;
 pub use crate::iff::wav::properties::{WavFormat, WavProperties};
 
 /// A WAV file
@@ -29,6 +33,8 @@ pub struct WavFile {
 	#[cfg(feature = "id3v2")]
 	/// An ID3v2 tag
 	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// A Broadcast Wave Format extension chunk
+	pub(crate) bext: Option<BroadcastExtension>,
 	/// The file's audio properties
 	pub(crate) properties: WavProperties,
 }
@@ -46,6 +52,8 @@ impl From<WavFile> for TaggedFile {
 		Self {
 			ty: FileType::WAV,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			tags: tags.into_iter().flatten().collect(),
 		}
 	}
@@ -59,13 +67,29 @@ impl AudioFile for WavFile {
 		R: Read + Seek,
 		Self: Sized,
 	{
-		read::read_from(reader, read_properties)
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
 	}
 
 	fn properties(&self) -> &Self::Properties {
 		&self.properties
 	}
 
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		if let Some(ref bext) = self.bext {
+			file.rewind()?;
+			bext.write_to(file)?;
+		}
+
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v2")]
+			id3v2_tag,
+
+			#[cfg(feature = "riff_info_list")]
+			riff_info
+		}
+	}
+
 	#[allow(unreachable_code)]
 	fn contains_tag(&self) -> bool {
 		#[cfg(feature = "id3v2")]
@@ -94,6 +118,16 @@ impl WavFile {
 		id3v2_tag, Id3v2Tag;
 
 		#[cfg(feature = "riff_info_list")]
-		riff_info, RiffInfoList
+		riff_info, RiffInfoList;
+
+		bext, BroadcastExtension
+	}
+
+	/// Sets the [`BroadcastExtension`], returning the old one if it exists
+	///
+	/// NOTE: The chunk will be written by [`AudioFile::save_to`], use [`BroadcastExtension::remove_from`]
+	/// to remove it from a file.
+	pub fn set_bext(&mut self, bext: BroadcastExtension) -> Option<BroadcastExtension> {
+		self.bext.replace(bext)
 	}
 }
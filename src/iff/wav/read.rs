@@ -1,12 +1,14 @@
+use super::bext::BroadcastExtension;
 use super::properties::WavProperties;
 #[cfg(feature = "riff_info_list")]
 use super::tag::RiffInfoList;
 use super::WavFile;
-use crate::error::{FileDecodingError, Result};
+use crate::error::{ErrorKind, FileDecodingError, Result, WarningCategory};
 use crate::file::FileType;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::iff::chunk::Chunks;
+use crate::probe::ParseContext;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -36,7 +38,12 @@ where
 	))
 }
 
-pub(crate) fn read_from<R>(data: &mut R, read_properties: bool) -> Result<WavFile>
+pub(crate) fn read_from<R>(
+	data: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<WavFile>
 where
 	R: Read + Seek,
 {
@@ -51,6 +58,8 @@ where
 	#[cfg(feature = "id3v2")]
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
 
+	let mut bext = None;
+
 	let mut chunks = Chunks::<LittleEndian>::new(file_size);
 
 	while chunks.next(data).is_ok() {
@@ -82,7 +91,7 @@ where
 
 				match &list_type {
 					#[cfg(feature = "riff_info_list")]
-					b"INFO" => {
+					b"INFO" if read_tags => {
 						let end = data.stream_position()? + u64::from(chunks.size - 4);
 						super::tag::read::parse_riff_info(data, &mut chunks, end, &mut riff_info)?;
 					},
@@ -92,8 +101,40 @@ where
 					},
 				}
 			},
+			// A broken "bext" chunk shouldn't prevent the rest of the file from being read
+			b"bext" if read_tags && bext.is_none() => {
+				if chunks.size.saturating_add(4) > file_size {
+					context.warn(
+						WarningCategory::Structure,
+						"Skipping \"bext\" chunk, it extends past the end of the file",
+					);
+					break;
+				}
+
+				let content = match chunks.content(data) {
+					Ok(content) => content,
+					Err(err) if matches!(err.kind(), ErrorKind::Io(_)) => {
+						context.warn(
+							WarningCategory::Structure,
+							"Skipping \"bext\" chunk, it extends past the end of the file",
+						);
+						break;
+					},
+					Err(err) => return Err(err),
+				};
+
+				match BroadcastExtension::parse(&content) {
+					Ok(parsed) => bext = Some(parsed),
+					Err(_) => context.warn(
+						WarningCategory::Structure,
+						"Skipping \"bext\" chunk, it is too short",
+					),
+				}
+
+				chunks.correct_position(data)?;
+			},
 			#[cfg(feature = "id3v2")]
-			b"ID3 " | b"id3 " => id3v2_tag = Some(chunks.id3_chunk(data)?),
+			b"ID3 " | b"id3 " if read_tags => id3v2_tag = Some(chunks.id3_chunk(data, context)?),
 			_ => chunks.skip(data)?,
 		}
 	}
@@ -128,5 +169,6 @@ where
 		riff_info: (!riff_info.items.is_empty()).then(|| riff_info),
 		#[cfg(feature = "id3v2")]
 		id3v2_tag,
+		bext,
 	})
 }
@@ -1,12 +1,12 @@
 pub(super) mod read;
 mod write;
 
-use crate::error::{LoftyError, Result};
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 
-use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
@@ -41,6 +41,11 @@ macro_rules! impl_accessor {
 ///
 /// ## Conversions
 ///
+/// ## To `Tag`
+///
+/// Chunks without an [`ItemKey`] mapping will be stored as [`ItemKey::Unknown`], with
+/// the chunk ID preserved as-is.
+///
 /// ## From `Tag`
 ///
 /// Two conditions must be met:
@@ -107,10 +112,10 @@ impl TagExt for RiffInfoList {
 	}
 
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		RiffInfoListRef::new(self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())))
 			.write_to(file)
 	}
@@ -124,13 +129,28 @@ impl TagExt for RiffInfoList {
 		TagType::RiffInfo.remove_from_path(path)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		TagType::RiffInfo.remove_from(file)
 	}
 
 	fn clear(&mut self) {
 		self.items.clear();
 	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		Vec::new()
+	}
+
+	/// RIFF INFO cannot store pictures
+	///
+	/// # Errors
+	///
+	/// This will always error with [`ErrorKind::UnsupportedPicture`]
+	fn replace_picture(&mut self, _: Picture) -> std::result::Result<(), Self::Err> {
+		Err(LoftyError::new(ErrorKind::UnsupportedPicture))
+	}
+
+	fn remove_picture_type(&mut self, _: PictureType) {}
 }
 
 impl From<RiffInfoList> for Tag {
@@ -190,7 +210,7 @@ where
 		RiffInfoListRef { items }
 	}
 
-	pub(crate) fn write_to(&mut self, file: &mut File) -> Result<()> {
+	pub(crate) fn write_to<F: FileLike>(&mut self, file: &mut F) -> Result<()> {
 		write::write_riff_info(file, self)
 	}
 
@@ -313,6 +333,41 @@ mod tests {
 		assert_eq!(riff_info.get("IART"), Some("Bar artist"));
 		assert_eq!(riff_info.get("IPRD"), Some("Baz album"));
 		assert_eq!(riff_info.get("ICMT"), Some("Qux comment"));
-		assert_eq!(riff_info.get("IPRT"), Some("1"));
+		assert_eq!(riff_info.get("ITRK"), Some("1"));
+	}
+
+	#[test]
+	fn riff_info_round_trip_all_fields() {
+		const FIELDS: [&str; 24] = [
+			"IPRD", "INAM", "IART", "IWRI", "IMUS", "IPRO", "IENG", "ISTR", "ITRK", "IFRM", "IRTD",
+			"ICRD", "ISRF", "ITCH", "ISFT", "IGNR", "ICOP", "ICMT", "ISBJ", "ILNG",
+			// Chunks without a mapping
+			"IARL", "IKEY", "IMED", "ISRC",
+		];
+
+		let mut riff_info = RiffInfoList::default();
+		for (i, field) in FIELDS.iter().enumerate() {
+			riff_info.insert(String::from(*field), format!("Value {}", i));
+		}
+
+		let tag: Tag = riff_info.clone().into();
+		assert_eq!(tag.items().len(), FIELDS.len());
+
+		let converted: RiffInfoList = tag.into();
+		assert_eq!(converted, riff_info);
+
+		let mut writer = Vec::new();
+		converted.dump_to(&mut writer).unwrap();
+
+		let mut parsed_tag = RiffInfoList::default();
+		super::read::parse_riff_info(
+			&mut Cursor::new(&writer[12..]),
+			&mut Chunks::<LittleEndian>::new((writer.len() - 12) as u32),
+			(writer.len() - 12) as u64,
+			&mut parsed_tag,
+		)
+		.unwrap();
+
+		assert_eq!(parsed_tag, riff_info);
 	}
 }
@@ -2,14 +2,14 @@ use super::RiffInfoListRef;
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::iff::chunk::Chunks;
 use crate::iff::wav::read::verify_wav;
+use crate::traits::FileLike;
 
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{LittleEndian, WriteBytesExt};
 
-pub(in crate::iff::wav) fn write_riff_info<'a, I>(
-	data: &mut File,
+pub(in crate::iff::wav) fn write_riff_info<'a, I, F: FileLike>(
+	data: &mut F,
 	tag: &mut RiffInfoListRef<'a, I>,
 ) -> Result<()>
 where
@@ -35,7 +35,7 @@ where
 		let _ = file_bytes.splice(4..8, total_size.to_le_bytes());
 
 		data.rewind()?;
-		data.set_len(0)?;
+		data.truncate(0)?;
 		data.write_all(&*file_bytes)?;
 	} else {
 		data.seek(SeekFrom::End(0))?;
@@ -8,18 +8,25 @@
 //!
 //! | File Format | Metadata Format(s)                   |
 //! |-------------|--------------------------------------|
+//! | AAC (ADTS)  | `ID3v2`, `ID3v1`                     |
 //! | Ape         | `APEv2`, `APEv1`, `ID3v2`\*, `ID3v1` |
+//! | ASF (WMA)   | `ASF attributes`\*\*                 |
 //! | AIFF        | `ID3v2`, `Text Chunks`               |
+//! | DSF         | `ID3v2`                              |
 //! | FLAC        | `Vorbis Comments`, `ID3v2`\*         |
 //! | MP3         | `ID3v2`, `ID3v1`, `APEv2`, `APEv1`   |
 //! | MP4         | `iTunes-style ilst`                  |
+//! | Musepack    | `APEv2`, `APEv1`, `ID3v1`            |
 //! | Opus        | `Vorbis Comments`                    |
 //! | Ogg Vorbis  | `Vorbis Comments`                    |
 //! | Speex       | `Vorbis Comments`                    |
 //! | WAV         | `ID3v2`, `RIFF INFO`                 |
+//! | WavPack     | `APEv2`, `APEv1`, `ID3v1`            |
 //!
 //! \* The tag will be **read only**, due to lack of official support
 //!
+//! \*\* The tag will be **read only**, as writing is not yet supported
+//!
 //! # Examples
 //!
 //! ## Reading a generic file
@@ -65,6 +72,21 @@
 //! # }
 //! ```
 //!
+//! ### Using an in-memory buffer
+//!
+//! ```rust
+//! # use lofty::LoftyError;
+//! # fn main() -> Result<(), LoftyError> {
+//! use lofty::read_from_bytes;
+//!
+//! let bytes = std::fs::read("tests/files/assets/minimal/full_test.mp3")?;
+//!
+//! // As with an existing reader, the file type is guessed from the content
+//! let tagged_file = read_from_bytes(&bytes, false)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ### Accessing tags
 //!
 //! ```rust
@@ -121,7 +143,13 @@
 //! * `vorbis_comments`
 //!
 //! ## Utilities
+//! * `id3v1_encodings` - Allows for ID3v1 tags to be read and written with an encoding other than Latin-1
 //! * `id3v2_restrictions` - Parses ID3v2 extended headers and exposes flags for fine grained control
+//! * `serde` - Implements `Serialize` and `Deserialize` for [`Tag`], [`TagItem`], [`Picture`], [`FileProperties`], and their
+//!   related types. Binary data is (de)serialized as base64, and the unknown items of a [`Tag`] (see [`Tag::re_map`])
+//!   are skipped.
+//! * `tokio` - Provides `read_from_async` and `read_from_path_async`. The content is read asynchronously, and then
+//!   parsed in memory, so the entire file is buffered (see `set_max_async_buffer_size`).
 //!
 //! # Important format-specific notes
 //!
@@ -164,33 +192,48 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+pub mod aac;
 pub mod ape;
+pub mod asf;
+pub mod dsf;
 pub mod error;
 pub(crate) mod file;
 pub mod flac;
 pub mod id3;
 pub mod iff;
+mod limits;
 pub(crate) mod macros;
 pub mod mp3;
 pub mod mp4;
+pub mod mpc;
 pub mod ogg;
 pub(crate) mod picture;
 mod probe;
 pub(crate) mod properties;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_utils;
 pub(crate) mod tag;
 mod traits;
+pub mod wavpack;
 
 pub use crate::error::{LoftyError, Result};
 
-pub use crate::probe::{read_from, read_from_path, Probe};
+pub use crate::probe::{
+	read_from, read_from_bytes, read_from_path, read_from_path_properties_only,
+	read_from_path_with_warnings, Probe,
+};
+#[cfg(feature = "tokio")]
+pub use crate::probe::{read_from_async, read_from_path_async};
 
 pub use crate::file::{AudioFile, FileType, TaggedFile};
-pub use crate::picture::{MimeType, Picture, PictureType};
+pub use crate::limits::{max_allocation, set_max_allocation};
+#[cfg(feature = "tokio")]
+pub use crate::limits::{max_async_buffer_size, set_max_async_buffer_size};
+pub use crate::picture::{MimeType, Picture, PictureInformation, PictureType};
 pub use crate::properties::FileProperties;
-pub use crate::tag::{Tag, TagType};
+pub use crate::tag::editor::TagEditor;
+pub use crate::tag::merged::MergedTag;
+pub use crate::tag::{remove_tags_from, remove_tags_from_path, Tag, TagType};
 pub use tag::item::{ItemKey, ItemValue, TagItem};
 
-pub use crate::traits::{Accessor, TagExt};
-
-#[cfg(feature = "vorbis_comments")]
-pub use picture::PictureInformation;
+pub use crate::traits::{Accessor, FileLike, TagExt, Truncate};
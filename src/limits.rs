@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static MAX_ALLOCATION: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Set the maximum size (in bytes) of a single allocation made while reading
+///
+/// Sizes are read directly from files (ex. ID3v2 frame sizes, MP4 atom lengths, APE item sizes),
+/// so a malformed or malicious file can declare a size far larger than the file itself.
+/// When a size exceeds this limit, the read will fail with [`ErrorKind::TooMuchData`](crate::error::ErrorKind::TooMuchData),
+/// rather than attempting the allocation.
+///
+/// This also applies when writing, as some allocations are sized from the existing file (ex. the
+/// leftover padding in an MP4 file).
+///
+/// This applies globally, and there is no limit by default.
+///
+/// # Examples
+///
+/// ```rust
+/// // Limit allocations to 16 MiB
+/// lofty::set_max_allocation(16 * 1024 * 1024);
+/// assert_eq!(lofty::max_allocation(), 16 * 1024 * 1024);
+/// ```
+pub fn set_max_allocation(limit: usize) {
+	MAX_ALLOCATION.store(limit, Ordering::Relaxed);
+}
+
+/// Get the maximum size (in bytes) of a single allocation made while reading
+///
+/// See [`set_max_allocation`].
+pub fn max_allocation() -> usize {
+	MAX_ALLOCATION.load(Ordering::Relaxed)
+}
+
+static MAX_ATOM_DEPTH: AtomicUsize = AtomicUsize::new(20);
+
+/// Set the maximum nesting depth of MP4 atoms
+///
+/// The depth is counted from the top-level atoms (depth 0), so an item in `moov.udta.meta.ilst`
+/// is at depth 4. Some atoms, such as freeform identifiers (`----`), contain other atoms that need to be parsed.
+/// A crafted file can nest these indefinitely, so when the depth exceeds this limit, the read will fail
+/// with [`ErrorKind::BadAtom`](crate::error::ErrorKind::BadAtom).
+///
+/// This applies globally, and the default is 20.
+pub fn set_max_atom_depth(depth: usize) {
+	MAX_ATOM_DEPTH.store(depth, Ordering::Relaxed);
+}
+
+/// Get the maximum nesting depth of MP4 atoms
+///
+/// See [`set_max_atom_depth`].
+pub fn max_atom_depth() -> usize {
+	MAX_ATOM_DEPTH.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "tokio")]
+static MAX_ASYNC_BUFFER_SIZE: AtomicUsize = AtomicUsize::new(256 * 1024 * 1024);
+
+#[cfg(feature = "tokio")]
+/// Set the maximum size (in bytes) of the content buffered by the async readers
+///
+/// The parsers are synchronous, so [`read_from_async`](crate::read_from_async) and
+/// [`read_from_path_async`](crate::read_from_path_async) read the entire file into memory before
+/// parsing it. When a file is larger than this limit (or [`max_allocation`], whichever is smaller),
+/// the read will fail with [`ErrorKind::TooMuchData`](crate::error::ErrorKind::TooMuchData),
+/// rather than buffering the rest of it.
+///
+/// This applies globally, and the default is 256 MiB.
+pub fn set_max_async_buffer_size(limit: usize) {
+	MAX_ASYNC_BUFFER_SIZE.store(limit, Ordering::Relaxed);
+}
+
+#[cfg(feature = "tokio")]
+/// Get the maximum size (in bytes) of the content buffered by the async readers
+///
+/// See [`set_max_async_buffer_size`].
+pub fn max_async_buffer_size() -> usize {
+	MAX_ASYNC_BUFFER_SIZE.load(Ordering::Relaxed)
+}
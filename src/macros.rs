@@ -30,6 +30,29 @@ macro_rules! tag_methods {
 	}
 }
 
+// Writes all of the tags that are present, for use in `AudioFile::save_to`
+macro_rules! save_tags {
+	(
+		$self:ident, $file:ident;
+		$(
+			$(#[cfg($meta:meta)])?
+			$name:ident
+		),*
+	) => {{
+		$(
+			$(#[cfg($meta)])?
+			if let Some(ref tag) = $self.$name {
+				std::io::Seek::rewind($file)?;
+				crate::traits::TagExt::save_to(tag, $file)?;
+			}
+		)*
+
+		// All of the tags may be disabled
+		let _ = $file;
+		Ok(())
+	}};
+}
+
 // See cfg-if comment in `Cargo.toml`
 //
 // macro_rules! feature_locked {
@@ -44,8 +67,13 @@ macro_rules! tag_methods {
 // 	}
 // }
 
+// Allocates a `Vec`, checking the size against `crate::max_allocation`
 macro_rules! try_vec {
 	($elem:expr; $size:expr) => {{
+		if $size > crate::max_allocation() {
+			return Err(crate::error::LoftyError::new(crate::error::ErrorKind::TooMuchData).into());
+		}
+
 		let mut v = Vec::new();
 		v.try_reserve($size)?;
 		v.resize($size, $elem);
@@ -54,4 +82,4 @@ macro_rules! try_vec {
 	}};
 }
 
-pub(crate) use {tag_methods, try_vec};
+pub(crate) use {save_tags, tag_methods, try_vec};
@@ -6,8 +6,9 @@ use std::io::Read;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+// A layer of 0 is reserved, and is used by ADTS headers instead
 pub(crate) fn verify_frame_sync(frame_sync: [u8; 2]) -> bool {
-	frame_sync[0] == 0xFF && frame_sync[1] >> 5 == 0b111
+	frame_sync[0] == 0xFF && frame_sync[1] >> 5 == 0b111 && (frame_sync[1] >> 1) & 0b11 != 0
 }
 
 // Searches for a frame sync (11 set bits) in the reader.
@@ -210,6 +211,10 @@ impl Header {
 		} else {
 			match layer {
 				Layer::Layer1 => (bitrate * 12000 / sample_rate + padding) * 4,
+				// MPEG 2/2.5 layer 3 frames have half the samples of an MPEG 1 frame
+				Layer::Layer3 if version != MpegVersion::V1 => {
+					bitrate * 72_000 / sample_rate + padding
+				},
 				Layer::Layer2 | Layer::Layer3 => bitrate * 144_000 / sample_rate + padding,
 			}
 		};
@@ -238,9 +243,15 @@ impl Header {
 	}
 }
 
+// "Xing" (4) + flags (4) + frames (4) + size (4) + TOC (100) + quality (4) + LAME tag (36)
+pub(crate) const XING_HEADER_MAX_SIZE: u64 = 156;
+
 pub(crate) struct XingHeader {
 	pub(crate) frames: u32,
 	pub(crate) size: u32,
+	// LAME writes an "Info" header for CBR files, which is otherwise identical to a "Xing" header
+	pub(crate) vbr: bool,
+	pub(crate) lame: Option<LameHeader>,
 }
 
 impl XingHeader {
@@ -274,7 +285,23 @@ impl XingHeader {
 				let frames = reader.read_u32::<BigEndian>()?;
 				let size = reader.read_u32::<BigEndian>()?;
 
-				Ok(Some(Self { frames, size }))
+				// The LAME tag follows the optional TOC (0x0004) and quality indicator (0x0008)
+				let mut lame_start = 0;
+				if flags[3] & 0x04 == 0x04 {
+					lame_start += 100;
+				}
+				if flags[3] & 0x08 == 0x08 {
+					lame_start += 4;
+				}
+
+				let lame = reader.get(lame_start..).and_then(LameHeader::read);
+
+				Ok(Some(Self {
+					frames,
+					size,
+					vbr: &header == b"Xing",
+					lame,
+				}))
 			},
 			b"VBRI" => {
 				if reader_len < 32 {
@@ -294,13 +321,102 @@ impl XingHeader {
 				let size = reader.read_u32::<BigEndian>()?;
 				let frames = reader.read_u32::<BigEndian>()?;
 
-				Ok(Some(Self { frames, size }))
+				Ok(Some(Self {
+					frames,
+					size,
+					vbr: true,
+					lame: None,
+				}))
 			},
 			_ => Ok(None),
 		}
 	}
 }
 
+/// A LAME tag, stored after a Xing header
+///
+/// This is written by LAME, as well as FFmpeg (`Lavf`/`Lavc`), and holds the information
+/// necessary for gapless playback.
+///
+/// NOTE: The CRCs of the tag and the music data are not verified
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct LameHeader {
+	pub(crate) encoder: [u8; 9],
+	pub(crate) revision: u8,
+	pub(crate) vbr_method: u8,
+	pub(crate) encoding_flags: u8,
+	pub(crate) encoder_delay: u16,
+	pub(crate) encoder_padding: u16,
+}
+
+impl LameHeader {
+	fn read(content: &[u8]) -> Option<Self> {
+		if content.len() < 24 {
+			return None;
+		}
+
+		let mut encoder = [0; 9];
+		encoder.copy_from_slice(&content[..9]);
+
+		if !encoder.is_ascii() || !matches!(&encoder[..4], b"LAME" | b"Lavf" | b"Lavc") {
+			return None;
+		}
+
+		// Skip 9 bytes
+		// Lowpass filter (1)
+		// Peak signal amplitude (4)
+		// Radio replay gain (2)
+		// Audiophile replay gain (2)
+		let encoding_flags = content[19] >> 4;
+
+		// The delay and padding are two 12-bit values, following the bitrate (1)
+		let encoder_delay = (u16::from(content[21]) << 4) | u16::from(content[22] >> 4);
+		let encoder_padding = (u16::from(content[22] & 0x0F) << 8) | u16::from(content[23]);
+
+		Some(Self {
+			encoder,
+			revision: content[9] >> 4,
+			vbr_method: content[9] & 0x0F,
+			encoding_flags,
+			encoder_delay,
+			encoder_padding,
+		})
+	}
+
+	/// The encoder version string (Ex. "LAME3.100")
+	pub fn encoder(&self) -> &str {
+		// The encoder is verified to be ASCII when reading
+		std::str::from_utf8(&self.encoder)
+			.unwrap_or_default()
+			.trim_end_matches(['\0', ' '])
+	}
+
+	/// The revision of the LAME tag
+	pub fn revision(&self) -> u8 {
+		self.revision
+	}
+
+	/// The VBR method used (Ex. 1 for CBR, 3 for VBR old/rh)
+	pub fn vbr_method(&self) -> u8 {
+		self.vbr_method
+	}
+
+	/// The encoding flags (nspsytune, nssafejoint, nogap next, and nogap previous)
+	pub fn encoding_flags(&self) -> u8 {
+		self.encoding_flags
+	}
+
+	/// The number of samples added to the start of the stream by the encoder
+	pub fn encoder_delay(&self) -> u16 {
+		self.encoder_delay
+	}
+
+	/// The number of samples added to the end of the stream by the encoder
+	pub fn encoder_padding(&self) -> u16 {
+		self.encoder_padding
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#[test]
@@ -314,4 +430,95 @@ mod tests {
 		test(&[0x00, 0x00, 0x01, 0xFF, 0xFB], Some(3));
 		test(&[0x01, 0xFF], None);
 	}
+
+	#[test]
+	fn layer3_frame_len() {
+		use super::Header;
+
+		// MPEG 1, 128 kbps, 44100 Hz, mono
+		let header = Header::read(0xFFFB_90C4).unwrap();
+		assert_eq!(header.samples, 1152);
+		assert_eq!(header.len, 417);
+
+		// MPEG 2, 64 kbps, 22050 Hz, mono
+		// These frames hold half the samples, so they are half the size of an MPEG 1 frame
+		let header = Header::read(0xFFF3_80C4).unwrap();
+		assert_eq!(header.samples, 576);
+		assert_eq!(header.len, 208);
+
+		// MPEG 2.5, 32 kbps, 11025 Hz, mono
+		let header = Header::read(0xFFE3_40C4).unwrap();
+		assert_eq!(header.samples, 576);
+		assert_eq!(header.len, 208);
+	}
+
+	#[test]
+	fn xing_header_vbr() {
+		use super::XingHeader;
+
+		fn header(ident: [u8; 4]) -> Vec<u8> {
+			let mut header = ident.to_vec();
+			// Frames and size flags
+			header.extend(3_u32.to_be_bytes());
+			header.extend(100_u32.to_be_bytes());
+			header.extend(10_000_u32.to_be_bytes());
+
+			header
+		}
+
+		let xing = XingHeader::read(&mut &*header(*b"Xing")).unwrap().unwrap();
+		assert!(xing.vbr);
+		assert_eq!(xing.frames, 100);
+		assert_eq!(xing.size, 10_000);
+
+		// "Info" headers are used for CBR files
+		let info = XingHeader::read(&mut &*header(*b"Info")).unwrap().unwrap();
+		assert!(!info.vbr);
+		assert!(info.lame.is_none());
+	}
+
+	#[test]
+	fn lame_header() {
+		use super::XingHeader;
+
+		let mut header = b"Xing".to_vec();
+		// Frames, size, and quality flags
+		header.extend(0x0B_u32.to_be_bytes());
+		header.extend(100_u32.to_be_bytes());
+		header.extend(10_000_u32.to_be_bytes());
+		// Quality indicator
+		header.extend(50_u32.to_be_bytes());
+
+		header.extend(b"LAME3.100");
+		// Revision 1, VBR method 3
+		header.push(0x13);
+		// Lowpass, peak, and replay gain
+		header.extend([0; 9]);
+		// nogap previous, ATH type 5
+		header.push(0x15);
+		// Bitrate
+		header.push(128);
+		// A 576 sample delay, and 1150 samples of padding
+		header.extend([0x24, 0x04, 0x7E]);
+
+		let lame = XingHeader::read(&mut &*header)
+			.unwrap()
+			.unwrap()
+			.lame
+			.unwrap();
+		assert_eq!(lame.encoder(), "LAME3.100");
+		assert_eq!(lame.revision(), 1);
+		assert_eq!(lame.vbr_method(), 3);
+		assert_eq!(lame.encoding_flags(), 1);
+		assert_eq!(lame.encoder_delay(), 576);
+		assert_eq!(lame.encoder_padding(), 1150);
+
+		// Not enough data for the LAME tag
+		header.truncate(header.len() - 1);
+		assert!(XingHeader::read(&mut &*header)
+			.unwrap()
+			.unwrap()
+			.lame
+			.is_none());
+	}
 }
@@ -2,10 +2,10 @@
 mod constants;
 pub(crate) mod header;
 mod properties;
-mod read;
+pub(crate) mod read;
 pub(crate) mod write;
 
-pub use header::{ChannelMode, Emphasis, Layer, MpegVersion};
+pub use header::{ChannelMode, Emphasis, LameHeader, Layer, MpegVersion};
 pub use properties::Mp3Properties;
 
 #[cfg(feature = "ape")]
@@ -16,8 +16,10 @@ use crate::file::{AudioFile, FileType, TaggedFile};
 use crate::id3::v1::tag::Id3v1Tag;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::probe::ParseContext;
 use crate::properties::FileProperties;
 use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
 
 use std::io::{Read, Seek};
 
@@ -54,6 +56,8 @@ impl From<Mp3File> for TaggedFile {
 		Self {
 			ty: FileType::MP3,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			tags: tags.into_iter().flatten().collect(),
 		}
 	}
@@ -66,13 +70,33 @@ impl AudioFile for Mp3File {
 	where
 		R: Read + Seek,
 	{
-		read::read_from(reader, read_properties)
+		read::read_from(
+			reader,
+			true,
+			read_properties,
+			false,
+			&mut ParseContext::default(),
+		)
 	}
 
 	fn properties(&self) -> &Self::Properties {
 		&self.properties
 	}
 
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v2")]
+			id3v2_tag,
+
+			#[cfg(feature = "id3v1")]
+			id3v1_tag,
+
+			#[cfg(feature = "ape")]
+			ape_tag
+		}
+	}
+
 	#[allow(unreachable_code)]
 	fn contains_tag(&self) -> bool {
 		#[cfg(feature = "id3v2")]
@@ -99,6 +123,45 @@ impl AudioFile for Mp3File {
 }
 
 impl Mp3File {
+	/// Read an [`Mp3File`], scanning every frame to determine its properties
+	///
+	/// Without a Xing or VBRI header, [`AudioFile::read_from`] estimates the properties from the first frame,
+	/// which will be inaccurate for VBR streams. This instead reads every frame header to get the exact
+	/// duration and average bitrate, at the cost of reading through the whole stream.
+	///
+	/// # Errors
+	///
+	/// * No valid MPEG frame is found
+	/// * See [`AudioFile::read_from`]
+	pub fn read_from_accurate<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		read::read_from(reader, true, true, true, &mut ParseContext::default())
+	}
+
+	/// Read an [`Mp3File`] from a reader that can't seek, such as a network stream
+	///
+	/// Unlike [`AudioFile::read_from`], only the ID3v2 tag(s) at the start of the stream will be read,
+	/// as the ID3v1 and APE tags are found by seeking to the end.
+	///
+	/// The properties are estimated from the first frame, as the length of the stream is unknown.
+	/// See [`Mp3Properties::is_approximate`].
+	///
+	/// NOTE: If the stream has no Xing or VBRI header, the remainder of the stream will be read (and discarded)
+	/// to determine its length.
+	///
+	/// # Errors
+	///
+	/// * `read_properties` is true and no valid MPEG frame is found
+	/// * See [`AudioFile::read_from`]
+	pub fn read_from_stream<R>(reader: &mut R, read_properties: bool) -> Result<Self>
+	where
+		R: Read,
+	{
+		read::read_from_stream(reader, read_properties, &mut ParseContext::default())
+	}
+
 	crate::macros::tag_methods! {
 		#[cfg(feature = "id3v2")]
 		id3v2_tag, Id3v2Tag;
@@ -1,10 +1,16 @@
-use super::header::{ChannelMode, Emphasis, Header, Layer, MpegVersion, XingHeader};
+use super::header::{
+	search_for_frame_sync, verify_frame_sync, ChannelMode, Emphasis, Header, LameHeader, Layer,
+	MpegVersion, XingHeader,
+};
+use crate::error::Result;
 use crate::properties::FileProperties;
 
+use std::io::{Read, Seek, SeekFrom};
 use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
 /// An MP3 file's audio properties
 pub struct Mp3Properties {
 	pub(crate) version: MpegVersion,
@@ -19,6 +25,9 @@ pub struct Mp3Properties {
 	pub(crate) copyright: bool,
 	pub(crate) original: bool,
 	pub(crate) emphasis: Emphasis,
+	pub(crate) vbr: bool,
+	pub(crate) approximate: bool,
+	pub(crate) lame_header: Option<LameHeader>,
 }
 
 impl From<Mp3Properties> for FileProperties {
@@ -46,6 +55,8 @@ impl Mp3Properties {
 	}
 
 	/// Audio bitrate (kbps)
+	///
+	/// For VBR files, this is the average bitrate of the stream.
 	pub fn audio_bitrate(&self) -> u32 {
 		self.audio_bitrate
 	}
@@ -94,6 +105,47 @@ impl Mp3Properties {
 	pub fn emphasis(&self) -> Emphasis {
 		self.emphasis
 	}
+
+	/// Whether the stream is variable bitrate
+	///
+	/// This is determined by the presence of a Xing or VBRI header. Note that
+	/// an "Info" header (a Xing header written for CBR streams) **will not** be considered VBR.
+	///
+	/// For files read with [`Mp3File::read_from_accurate`](crate::mp3::Mp3File::read_from_accurate),
+	/// this will also be the case if the bitrate changes between frames.
+	pub fn is_vbr(&self) -> bool {
+		self.vbr
+	}
+
+	/// Whether the properties are an estimate
+	///
+	/// This will only be the case for files read with [`Mp3File::read_from_stream`](crate::mp3::Mp3File::read_from_stream),
+	/// as the length of the file is unknown.
+	pub fn is_approximate(&self) -> bool {
+		self.approximate
+	}
+
+	/// The LAME tag, if one follows the Xing header
+	///
+	/// See [`LameHeader`]
+	pub fn lame_header(&self) -> Option<&LameHeader> {
+		self.lame_header.as_ref()
+	}
+
+	/// The encoder version string from the LAME tag (Ex. "LAME3.100")
+	pub fn encoder(&self) -> Option<&str> {
+		self.lame_header.as_ref().map(LameHeader::encoder)
+	}
+
+	/// The number of samples added to the start of the stream by the encoder, according to the LAME tag
+	pub fn encoder_delay(&self) -> Option<u16> {
+		self.lame_header.map(|lame| lame.encoder_delay)
+	}
+
+	/// The number of samples added to the end of the stream by the encoder, according to the LAME tag
+	pub fn encoder_padding(&self) -> Option<u16> {
+		self.lame_header.map(|lame| lame.encoder_padding)
+	}
 }
 
 pub(super) fn read_properties(
@@ -118,6 +170,11 @@ pub(super) fn read_properties(
 		sample_rate: first_frame_header.sample_rate,
 		channels: first_frame_header.channels,
 		emphasis: first_frame_header.emphasis,
+		vbr: false,
+		approximate: false,
+		lame_header: xing_header
+			.as_ref()
+			.and_then(|xing_header| xing_header.lame),
 	};
 
 	match xing_header {
@@ -126,9 +183,14 @@ pub(super) fn read_properties(
 				u32::from(first_frame_header.samples) * 1000 / first_frame_header.sample_rate;
 			let length = u64::from(frame_time) * u64::from(xing_header.frames);
 
-			properties.duration = Duration::from_millis(length);
-			properties.overall_bitrate = ((file_length * 8) / length) as u32;
-			properties.audio_bitrate = ((u64::from(xing_header.size) * 8) / length) as u32;
+			properties.vbr = xing_header.vbr;
+
+			if length > 0 {
+				properties.duration = Duration::from_millis(length);
+				properties.overall_bitrate = ((file_length * 8) / length) as u32;
+				// The average bitrate, derived from the size of the stream
+				properties.audio_bitrate = ((u64::from(xing_header.size) * 8) / length) as u32;
+			}
 		},
 		_ if first_frame_header.bitrate > 0 => {
 			let audio_bitrate = first_frame_header.bitrate;
@@ -146,3 +208,78 @@ pub(super) fn read_properties(
 
 	properties
 }
+
+// Walks every frame between the first and last frame offsets, rather than estimating from the first frame
+pub(super) fn read_properties_accurate<R>(
+	reader: &mut R,
+	first_frame: (Header, u64),
+	last_frame_offset: u64,
+	xing_header: Option<XingHeader>,
+	file_length: u64,
+) -> Result<Mp3Properties>
+where
+	R: Read + Seek,
+{
+	let (first_frame_header, first_frame_offset) = first_frame;
+
+	// The Xing header is stored in a frame that holds no audio
+	let skip_first_frame = xing_header.is_some();
+
+	let mut properties = read_properties(first_frame, last_frame_offset, xing_header, file_length);
+
+	let mut frames = 0_u64;
+	let mut samples = 0_u64;
+	let mut stream_length = 0_u64;
+	let mut vbr = false;
+
+	let mut pos = first_frame_offset;
+	let mut header = [0; 4];
+
+	while pos + 4 <= last_frame_offset {
+		reader.seek(SeekFrom::Start(pos))?;
+		reader.read_exact(&mut header)?;
+
+		let frame_header = if verify_frame_sync([header[0], header[1]]) {
+			Header::read(u32::from_be_bytes(header)).ok()
+		} else {
+			None
+		};
+
+		match frame_header {
+			Some(frame_header)
+				if frame_header.len > 0
+					&& frame_header.sample_rate == first_frame_header.sample_rate =>
+			{
+				if !(skip_first_frame && pos == first_frame_offset) {
+					frames += 1;
+					samples += u64::from(frame_header.samples);
+					stream_length += u64::from(frame_header.len);
+					vbr |= frame_header.bitrate != first_frame_header.bitrate;
+				}
+
+				pos += u64::from(frame_header.len);
+			},
+			// Skip over any junk between frames
+			_ => {
+				reader.seek(SeekFrom::Start(pos + 1))?;
+
+				match search_for_frame_sync(&mut reader.by_ref().take(last_frame_offset - pos - 1))?
+				{
+					Some(offset) => pos += 1 + offset,
+					None => break,
+				}
+			},
+		}
+	}
+
+	let length = samples * 1000 / u64::from(first_frame_header.sample_rate);
+
+	if frames > 0 && length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = ((file_length * 8) / length) as u32;
+		properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+		properties.vbr |= vbr;
+	}
+
+	Ok(properties)
+}
@@ -1,21 +1,30 @@
-use super::header::{search_for_frame_sync, Header, XingHeader};
+use super::header::{
+	search_for_frame_sync, verify_frame_sync, Header, XingHeader, XING_HEADER_MAX_SIZE,
+};
 use super::{Mp3File, Mp3Properties};
 use crate::ape::constants::APE_PREAMBLE;
 use crate::ape::header::read_ape_header;
 #[cfg(feature = "ape")]
 use crate::ape::tag::read::read_ape_tag;
-use crate::error::{FileDecodingError, Result};
+use crate::error::{FileDecodingError, Result, WarningCategory};
 use crate::file::FileType;
 #[cfg(feature = "id3v2")]
 use crate::id3::v2::read::parse_id3v2;
 use crate::id3::v2::read_id3v2_header;
 use crate::id3::{find_id3v1, find_lyrics3v2, ID3FindResults};
+use crate::probe::ParseContext;
 
 use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{BigEndian, ReadBytesExt};
 
-pub(super) fn read_from<R>(reader: &mut R, read_properties: bool) -> Result<Mp3File>
+pub(crate) fn read_from<R>(
+	reader: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	accurate: bool,
+	context: &mut ParseContext,
+) -> Result<Mp3File>
 where
 	R: Read + Seek,
 {
@@ -41,11 +50,20 @@ where
 				let skip_footer = header.flags.footer;
 
 				#[cfg(feature = "id3v2")]
-				{
-					let id3v2 = parse_id3v2(reader, header)?;
+				if read_tags {
+					let id3v2 = parse_id3v2(reader, header, context)?;
 					file.id3v2_tag = Some(id3v2);
+				} else {
+					reader.seek(SeekFrom::Current(i64::from(
+						header.size - header.extended_size,
+					)))?;
 				}
 
+				#[cfg(not(feature = "id3v2"))]
+				reader.seek(SeekFrom::Current(i64::from(
+					header.size - header.extended_size,
+				)))?;
+
 				// Skip over the footer
 				if skip_footer {
 					reader.seek(SeekFrom::Current(10))?;
@@ -60,16 +78,20 @@ where
 				if &header_remaining == b"AGEX" {
 					let ape_header = read_ape_header(reader, false)?;
 
+					// The header has already been read
 					#[cfg(not(feature = "ape"))]
 					{
-						let size = ape_header.size;
+						let size = ape_header.size - 32;
 						reader.seek(SeekFrom::Current(size as i64))?;
 					}
 
 					#[cfg(feature = "ape")]
-					{
-						file.ape_tag =
-							Some(crate::ape::tag::read::read_ape_tag(reader, ape_header)?);
+					if read_tags {
+						file.ape_tag = Some(crate::ape::tag::read::read_ape_tag(
+							reader, ape_header, context,
+						)?);
+					} else {
+						reader.seek(SeekFrom::Current(i64::from(ape_header.size - 32)))?;
 					}
 
 					continue;
@@ -89,6 +111,12 @@ where
 					// Seek back to the start of the frame and read the header
 					reader.seek(SeekFrom::Start(first_mp3_frame_start_absolute))?;
 					let header = Header::read(reader.read_u32::<BigEndian>()?)?;
+					if header.sample_rate == 0 {
+						context.warn(
+							WarningCategory::Properties,
+							"The first MPEG frame has an invalid sample rate index",
+						);
+					}
 
 					file.first_frame_offset = first_mp3_frame_start_absolute;
 					first_frame_header = Some(header);
@@ -101,7 +129,7 @@ where
 	}
 
 	#[allow(unused_variables)]
-	let ID3FindResults(header, id3v1) = find_id3v1(reader, true)?;
+	let ID3FindResults(header, id3v1) = find_id3v1(reader, read_tags)?;
 
 	#[cfg(feature = "id3v1")]
 	if header.is_some() {
@@ -120,8 +148,8 @@ where
 		let size = ape_header.size;
 
 		#[cfg(feature = "ape")]
-		{
-			let ape = read_ape_tag(reader, ape_header)?;
+		if read_tags {
+			let ape = read_ape_tag(reader, ape_header, context)?;
 			file.ape_tag = Some(ape);
 		}
 
@@ -155,17 +183,29 @@ where
 
 		reader.seek(SeekFrom::Start(xing_header_location))?;
 
-		let mut xing_reader = [0; 32];
-		reader.read_exact(&mut xing_reader)?;
+		let mut xing_reader = Vec::new();
+		reader
+			.take(XING_HEADER_MAX_SIZE)
+			.read_to_end(&mut xing_reader)?;
 
 		let xing_header = XingHeader::read(&mut &xing_reader[..])?;
 
-		super::properties::read_properties(
-			(first_frame_header, first_frame_offset),
-			file.last_frame_offset,
-			xing_header,
-			file_length,
-		)
+		if accurate {
+			super::properties::read_properties_accurate(
+				reader,
+				(first_frame_header, first_frame_offset),
+				file.last_frame_offset,
+				xing_header,
+				file_length,
+			)?
+		} else {
+			super::properties::read_properties(
+				(first_frame_header, first_frame_offset),
+				file.last_frame_offset,
+				xing_header,
+				file_length,
+			)
+		}
 	} else {
 		Mp3Properties::default()
 	};
@@ -173,11 +213,161 @@ where
 	Ok(file)
 }
 
+pub(super) fn read_from_stream<R>(
+	reader: &mut R,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<Mp3File>
+where
+	R: Read,
+{
+	let mut file = Mp3File::default();
+
+	// The number of bytes read from the stream
+	let mut offset = 0_u64;
+
+	// Skip any invalid padding
+	let mut first_byte = reader.read_u8()?;
+	while first_byte == 0 {
+		first_byte = reader.read_u8()?;
+		offset += 1;
+	}
+
+	let mut header = [first_byte, 0, 0, 0];
+	reader.read_exact(&mut header[1..])?;
+
+	// [I, D, 3, ver_major, ver_minor, flags, size (4 bytes)]
+	while &header[..3] == b"ID3" {
+		let id3v2_header = read_id3v2_header(&mut Read::chain(&header[..], &mut *reader))?;
+		let skip_footer = id3v2_header.flags.footer;
+
+		offset += 10 + u64::from(id3v2_header.size);
+
+		#[cfg(feature = "id3v2")]
+		{
+			let id3v2 = parse_id3v2(reader, id3v2_header, context)?;
+			file.id3v2_tag = Some(id3v2);
+		}
+
+		#[cfg(not(feature = "id3v2"))]
+		skip(
+			reader,
+			u64::from(id3v2_header.size - id3v2_header.extended_size),
+		)?;
+
+		if skip_footer {
+			skip(reader, 10)?;
+			offset += 10;
+		}
+
+		if reader.read_exact(&mut header).is_err() {
+			// The stream only consists of tags
+			return if read_properties {
+				Err(FileDecodingError::new(FileType::MP3, "File contains an invalid frame").into())
+			} else {
+				Ok(file)
+			};
+		}
+	}
+
+	if !read_properties {
+		return Ok(file);
+	}
+
+	// Tags might be followed by junk bytes before the first MP3 frame begins
+	let mut search = Read::chain(&header[..], &mut *reader).bytes();
+	let mut frame_sync = [0; 2];
+
+	let first_frame_header = loop {
+		let byte = match search.next() {
+			Some(byte) => byte?,
+			None => {
+				// The search for sync bits was unsuccessful
+				return Err(FileDecodingError::new(
+					FileType::MP3,
+					"File contains an invalid frame",
+				)
+				.into());
+			},
+		};
+
+		frame_sync = [frame_sync[1], byte];
+		offset += 1;
+
+		if verify_frame_sync(frame_sync) {
+			let mut remaining = [0; 2];
+			for b in &mut remaining {
+				*b = search.next().transpose()?.unwrap_or_default();
+			}
+
+			file.first_frame_offset = offset - 2;
+			offset += 2;
+
+			break Header::read(u32::from_be_bytes([
+				frame_sync[0],
+				frame_sync[1],
+				remaining[0],
+				remaining[1],
+			]))?;
+		}
+	};
+
+	if first_frame_header.sample_rate == 0 {
+		return Err(FileDecodingError::new(FileType::MP3, "Sample rate is 0").into());
+	}
+
+	// The Xing header starts after the side information
+	skip(reader, u64::from(first_frame_header.data_start) - 4)?;
+	offset += u64::from(first_frame_header.data_start) - 4;
+
+	let mut xing_reader = Vec::new();
+	reader
+		.take(XING_HEADER_MAX_SIZE)
+		.read_to_end(&mut xing_reader)?;
+	offset += xing_reader.len() as u64;
+
+	let xing_header = XingHeader::read(&mut &xing_reader[..])?;
+
+	// Without a Xing header, the rest of the stream needs to be read to determine its length.
+	// Any trailing tags will be counted as part of the stream.
+	let stream_length = match xing_header {
+		Some(ref xing_header) => file.first_frame_offset + u64::from(xing_header.size),
+		None => offset + skip(reader, u64::MAX)?,
+	};
+
+	file.last_frame_offset = stream_length;
+
+	let mut properties = super::properties::read_properties(
+		(first_frame_header, file.first_frame_offset),
+		file.last_frame_offset,
+		xing_header,
+		stream_length,
+	);
+	properties.approximate = true;
+
+	file.properties = properties;
+
+	Ok(file)
+}
+
+// Discards up to `amount` bytes, returning the number of bytes skipped
+fn skip<R>(reader: &mut R, amount: u64) -> Result<u64>
+where
+	R: Read,
+{
+	Ok(std::io::copy(
+		&mut reader.by_ref().take(amount),
+		&mut std::io::sink(),
+	)?)
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::file::AudioFile;
 	use crate::mp3::Mp3File;
 	use std::fs::File;
+	use std::io::Cursor;
+	use std::time::Duration;
 
 	#[test]
 	fn issue_39() {
@@ -188,4 +378,41 @@ mod tests {
 		)
 		.is_err());
 	}
+
+	#[test]
+	fn accurate_vbr_without_xing() {
+		// MPEG 1 layer 3 frames, 44.1 KHz, no padding
+		fn frame(bitrate_index: u8, len: usize) -> Vec<u8> {
+			let mut frame = vec![0; len];
+			frame[..4].copy_from_slice(&[0xFF, 0xFB, bitrate_index << 4, 0x00]);
+			frame
+		}
+
+		// 10 128 kbps frames, followed by 30 64 kbps frames
+		let mut stream = Vec::new();
+		for _ in 0..10 {
+			stream.extend(frame(0b1001, 417));
+		}
+		// With some junk in between
+		stream.extend_from_slice(&[0x01, 0x02, 0x03]);
+		for _ in 0..30 {
+			stream.extend(frame(0b0101, 208));
+		}
+
+		let estimated = Mp3File::read_from(&mut Cursor::new(&stream), true).unwrap();
+		let accurate = Mp3File::read_from_accurate(&mut Cursor::new(&stream)).unwrap();
+
+		// The estimate assumes every frame is 128 kbps
+		assert_eq!(estimated.properties.audio_bitrate, 128);
+		assert!(!estimated.properties.vbr);
+
+		// 40 frames * 1152 samples / 44100 Hz
+		assert_eq!(accurate.properties.duration, Duration::from_millis(1044));
+		assert_eq!(
+			accurate.properties.audio_bitrate,
+			(10 * 417 + 30 * 208) * 8 / 1044
+		);
+		assert!(accurate.properties.vbr);
+		assert!(!accurate.properties.approximate);
+	}
 }
@@ -1,5 +1,7 @@
-use crate::error::{ErrorKind, LoftyError, Result};
+use crate::error::{ErrorKind, LoftyError, Result, WarningCategory};
+use crate::limits::max_atom_depth;
 use crate::macros::try_vec;
+use crate::probe::ParseContext;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -36,39 +38,80 @@ pub(crate) struct AtomInfo {
 	pub(crate) len: u64,
 	pub(crate) extended: bool,
 	pub(crate) ident: AtomIdent,
+	// The number of atoms containing this one, 0 for top-level atoms
+	pub(crate) depth: usize,
 }
 
 impl AtomInfo {
-	pub(crate) fn read<R>(data: &mut R) -> Result<Self>
+	// `reader_size` is the number of bytes available to the atom, starting at the current position.
+	// This is either the remaining length of its parent, or of the stream for top-level atoms.
+	pub(crate) fn read<R>(data: &mut R, reader_size: u64) -> Result<Self>
 	where
 		R: Read + Seek,
 	{
-		let start = data.stream_position()?;
-
-		let len = data.read_u32::<BigEndian>()?;
+		Self::read_nested(data, reader_size, 0, None)
+	}
 
-		let mut ident = [0; 4];
-		data.read_exact(&mut ident)?;
+	// Same as `read`, for an atom nested in a parent at `parent_depth`
+	pub(crate) fn read_child<R>(data: &mut R, reader_size: u64, parent_depth: usize) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		Self::read_nested(data, reader_size, parent_depth + 1, None)
+	}
 
-		let mut atom_ident = AtomIdent::Fourcc(ident);
+	// Same as `read`, except a (32-bit) length past the end of the stream is clamped to the end.
+	// This is only used for top-level atoms, where it is likely that the file was truncated,
+	// or the length is slightly off.
+	pub(crate) fn read_top_level<R>(
+		data: &mut R,
+		reader_size: u64,
+		context: &mut ParseContext,
+	) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		Self::read_nested(data, reader_size, 0, Some(context))
+	}
 
-		// Encountered a freeform identifier
-		if &ident == b"----" {
-			atom_ident = parse_freeform(data)?;
+	// `depth` is the number of atoms containing this one
+	//
+	// `top_level` is only provided for top-level atoms, which are the only ones that can be clamped
+	fn read_nested<R>(
+		data: &mut R,
+		reader_size: u64,
+		depth: usize,
+		top_level: Option<&mut ParseContext>,
+	) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		if depth >= max_atom_depth() {
+			return Err(LoftyError::new(ErrorKind::BadAtom(
+				"Exceeded the maximum atom nesting depth",
+			)));
 		}
 
-		let (len, extended) = match len {
-			// The atom extends to the end of the file
-			0 => {
-				let pos = data.stream_position()?;
-				let end = data.seek(SeekFrom::End(0))?;
+		let start = data.stream_position()?;
 
-				data.seek(SeekFrom::Start(pos))?;
+		let len = data.read_u32::<BigEndian>()?;
 
-				(end - pos, false)
-			},
+		let mut ident = [0; 4];
+		data.read_exact(&mut ident)?;
+
+		let (mut len, extended) = match len {
+			// The atom extends to the end of its parent, or the file
+			0 => (reader_size, false),
 			// There's an extended length
-			1 => (data.read_u64::<BigEndian>()?, true),
+			1 => match data.read_u64::<BigEndian>()? {
+				// The extended length includes itself (8) and the rest of the header (8)
+				ext_len if ext_len < 16 => {
+					return Err(LoftyError::new(ErrorKind::BadAtom(
+						"Found an invalid extended length (< 16)",
+					)))
+				},
+				ext_len => (ext_len, true),
+			},
 			_ if len < 8 => {
 				return Err(LoftyError::new(ErrorKind::BadAtom(
 					"Found an invalid length (< 8)",
@@ -77,32 +120,71 @@ impl AtomInfo {
 			_ => (u64::from(len), false),
 		};
 
+		if len > reader_size {
+			// A top-level atom can extend past the end of a truncated file,
+			// but an extended length this large is almost certainly garbage
+			let context = match top_level {
+				Some(context) if !extended => context,
+				_ => {
+					return Err(LoftyError::new(ErrorKind::BadAtom(
+						"Found an atom with a length exceeding the remaining data",
+					)))
+				},
+			};
+
+			// The atom is treated as extending to the end of the file, as with a length of 0
+			context.warn(
+				WarningCategory::Structure,
+				"Found an atom extending past the end of the file",
+			);
+			len = reader_size;
+		}
+
+		let mut atom_ident = AtomIdent::Fourcc(ident);
+
+		// Encountered a freeform identifier
+		if &ident == b"----" {
+			let header_len = if extended { 16 } else { 8 };
+			atom_ident = parse_freeform(data, len - header_len, depth + 1)?;
+		}
+
 		Ok(Self {
 			start,
 			len,
 			extended,
 			ident: atom_ident,
+			depth,
 		})
 	}
 }
 
-fn parse_freeform<R>(data: &mut R) -> Result<AtomIdent>
+// `reader_size` is the length of the "----" atom's content
+fn parse_freeform<R>(data: &mut R, mut reader_size: u64, depth: usize) -> Result<AtomIdent>
 where
 	R: Read + Seek,
 {
-	let mean = freeform_chunk(data, b"mean")?;
-	let name = freeform_chunk(data, b"name")?;
+	let mean = freeform_chunk(data, b"mean", &mut reader_size, depth)?;
+	let name = freeform_chunk(data, b"name", &mut reader_size, depth)?;
 
 	Ok(AtomIdent::Freeform { mean, name })
 }
 
-fn freeform_chunk<R>(data: &mut R, name: &[u8]) -> Result<String>
+fn freeform_chunk<R>(
+	data: &mut R,
+	name: &[u8],
+	reader_size: &mut u64,
+	depth: usize,
+) -> Result<String>
 where
 	R: Read + Seek,
 {
-	let atom = AtomInfo::read(data)?;
+	let atom = AtomInfo::read_nested(data, *reader_size, depth, None)?;
+	*reader_size -= atom.len;
 
 	match atom.ident {
+		AtomIdent::Fourcc(ref fourcc) if fourcc == name && atom.len < 12 => Err(LoftyError::new(
+			ErrorKind::BadAtom("Found a freeform identifier chunk with an invalid length (< 12)"),
+		)),
 		AtomIdent::Fourcc(ref fourcc) if fourcc == name => {
 			// Version (1)
 			// Flags (3)
@@ -123,3 +205,129 @@ where
 		))),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::error::ErrorKind;
+	use crate::limits::max_atom_depth;
+	use crate::mp4::atom_info::{AtomIdent, AtomInfo};
+	use crate::mp4::read::nested_atom;
+	use crate::probe::ParseContext;
+
+	use std::io::Cursor;
+
+	fn moov(len: u64, depth: usize) -> AtomInfo {
+		AtomInfo {
+			start: 0,
+			len,
+			extended: false,
+			ident: AtomIdent::Fourcc(*b"moov"),
+			depth,
+		}
+	}
+
+	fn is_bad_atom<T>(result: crate::error::Result<T>) -> bool {
+		match result {
+			Err(e) => matches!(e.kind(), ErrorKind::BadAtom(_)),
+			Ok(_) => false,
+		}
+	}
+
+	#[test]
+	fn atom_len_bounds() {
+		let truncated = b"\x00\x00\x00\x20free\x00\x00\x00\x00";
+		assert!(is_bad_atom(AtomInfo::read(&mut Cursor::new(truncated), 12)));
+
+		// A top-level atom with a length past the end of the data is treated as extending to the end
+		let atom = AtomInfo::read_top_level(
+			&mut Cursor::new(truncated),
+			12,
+			&mut ParseContext::default(),
+		)
+		.unwrap();
+		assert_eq!(atom.ident, AtomIdent::Fourcc(*b"free"));
+		assert_eq!(atom.len, 12);
+
+		// Unless it's an extended length
+		let extended = b"\x00\x00\x00\x01free\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF";
+		assert!(is_bad_atom(AtomInfo::read(&mut Cursor::new(extended), 16)));
+		assert!(is_bad_atom(AtomInfo::read_top_level(
+			&mut Cursor::new(extended),
+			16,
+			&mut ParseContext::default(),
+		)));
+
+		// The extended length includes the entire header
+		let extended = b"\x00\x00\x00\x01free\x00\x00\x00\x00\x00\x00\x00\x08";
+		assert!(is_bad_atom(AtomInfo::read(&mut Cursor::new(extended), 16)));
+
+		let extended = b"\x00\x00\x00\x01free\x00\x00\x00\x00\x00\x00\x00\x10";
+		let atom = AtomInfo::read(&mut Cursor::new(extended), 16).unwrap();
+		assert!(atom.extended);
+		assert_eq!(atom.len, 16);
+
+		// A length of 0 extends to the end of the parent
+		let atom = AtomInfo::read(
+			&mut Cursor::new(b"\x00\x00\x00\x00free\x00\x00\x00\x00"),
+			10,
+		)
+		.unwrap();
+		assert_eq!(atom.len, 10);
+	}
+
+	#[test]
+	fn child_exceeds_parent() {
+		// A 24 byte parent (including its header), with children "free" (8) and "skip" (16)
+		let children = b"\x00\x00\x00\x08free\x00\x00\x00\x10skip\x00\x00\x00\x00\x00\x00\x00\x00";
+
+		assert!(is_bad_atom(nested_atom(
+			&mut Cursor::new(children),
+			&moov(24, 0),
+			b"udta"
+		)));
+
+		// Within the parent's bounds
+		assert!(
+			nested_atom(&mut Cursor::new(children), &moov(32, 0), b"udta")
+				.unwrap()
+				.is_none()
+		);
+	}
+
+	#[test]
+	fn child_depth() {
+		let children = b"\x00\x00\x00\x08free\x00\x00\x00\x08udta";
+
+		let udta = nested_atom(&mut Cursor::new(children), &moov(24, 0), b"udta")
+			.unwrap()
+			.unwrap();
+		assert_eq!(udta.depth, 1);
+
+		// Atoms found while traversing the tree count towards the limit, not just freeform identifiers
+		let parent = moov(24, max_atom_depth() - 1);
+		assert!(is_bad_atom(nested_atom(
+			&mut Cursor::new(children),
+			&parent,
+			b"udta"
+		)));
+	}
+
+	#[test]
+	fn pathological_nesting() {
+		// Freeform identifiers all the way down, each expecting a "mean" atom
+		let depth = 1000_u32;
+
+		let mut nested = Vec::new();
+		for i in 0..depth {
+			nested.extend((8 * (depth - i)).to_be_bytes());
+			nested.extend(b"----");
+		}
+
+		let len = nested.len() as u64;
+		let err = AtomInfo::read(&mut Cursor::new(nested), len).err().unwrap();
+		assert!(matches!(
+			err.kind(),
+			ErrorKind::BadAtom("Exceeded the maximum atom nesting depth")
+		));
+	}
+}
@@ -0,0 +1,509 @@
+use super::atom_info::{AtomIdent, AtomInfo};
+use super::read::skip_unneeded;
+use super::trak::Trak;
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::id3::v2::util::text_utils::utf16_decode;
+use crate::macros::try_vec;
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// A chapter in an MP4 file
+///
+/// These can come from either a Nero (`moov.udta.chpl`) chapter list, or a QuickTime chapter track.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chapter {
+	/// The start of the chapter
+	pub start: Duration,
+	/// The chapter's title
+	pub title: String,
+}
+
+// Reads the contents of an atom, expects the reader to be positioned right after the atom's header
+fn atom_content<R>(data: &mut R, atom: &AtomInfo) -> Result<Vec<u8>>
+where
+	R: Read + Seek,
+{
+	let header_len = if atom.extended { 16 } else { 8 };
+
+	let content_len = atom.len.checked_sub(header_len).ok_or_else(|| {
+		LoftyError::new(ErrorKind::BadAtom("Found an atom smaller than its header"))
+	})?;
+
+	let mut content = try_vec![0; content_len as usize];
+	data.read_exact(&mut content)?;
+
+	Ok(content)
+}
+
+// Collects the atoms nested in `parent`, leaving the reader at the end of `parent`
+fn child_atoms<R>(data: &mut R, parent: &AtomInfo) -> Result<Vec<AtomInfo>>
+where
+	R: Read + Seek,
+{
+	let header_len = if parent.extended { 16 } else { 8 };
+	let end = parent.start + parent.len;
+
+	let mut pos = parent.start + header_len;
+	data.seek(SeekFrom::Start(pos))?;
+
+	let mut children = Vec::new();
+
+	while pos < end {
+		let atom = AtomInfo::read_child(data, end - pos, parent.depth)?;
+		skip_unneeded(data, atom.extended, atom.len)?;
+
+		pos += atom.len;
+		children.push(atom);
+	}
+
+	Ok(children)
+}
+
+fn find_child<R>(data: &mut R, parent: &AtomInfo, ident: [u8; 4]) -> Result<Option<Vec<u8>>>
+where
+	R: Read + Seek,
+{
+	for atom in child_atoms(data, parent)? {
+		if atom.ident == AtomIdent::Fourcc(ident) {
+			let header_len = if atom.extended { 16 } else { 8 };
+			data.seek(SeekFrom::Start(atom.start + header_len))?;
+
+			return atom_content(data, &atom).map(Some);
+		}
+	}
+
+	Ok(None)
+}
+
+// https://github.com/FFmpeg/FFmpeg/blob/n5.0/libavformat/mov.c#L5618
+pub(super) fn read_nero_chapters<R>(data: &mut R, chpl: &AtomInfo) -> Result<Vec<Chapter>>
+where
+	R: Read + Seek,
+{
+	let content = atom_content(data, chpl)?;
+	let reader = &mut &*content;
+
+	let version = reader.read_u8()?;
+	let _flags = reader.read_u24::<BigEndian>()?;
+
+	if version > 0 {
+		let _reserved = reader.read_u32::<BigEndian>()?;
+	}
+
+	let chapter_count = reader.read_u8()?;
+
+	let mut chapters = Vec::with_capacity(chapter_count as usize);
+
+	for _ in 0..chapter_count {
+		// The start time is stored in 100 nanosecond units
+		let start = reader.read_u64::<BigEndian>()?;
+
+		let title_len = reader.read_u8()?;
+		let mut title = try_vec![0; title_len as usize];
+		reader.read_exact(&mut title)?;
+
+		chapters.push(Chapter {
+			start: Duration::from_nanos(start.saturating_mul(100)),
+			title: String::from_utf8_lossy(&title).into_owned(),
+		});
+	}
+
+	Ok(chapters)
+}
+
+// QuickTime chapters are stored as the samples of a text track, referenced by another track's `tref.chap` atom
+pub(super) fn read_quicktime_chapters<R>(data: &mut R, traks: &[Trak]) -> Result<Vec<Chapter>>
+where
+	R: Read + Seek,
+{
+	let chapter_trak = traks
+		.iter()
+		.flat_map(|trak| trak.chapter_ids.iter())
+		.find_map(|id| traks.iter().find(|trak| trak.id == Some(*id)));
+
+	let mdia = match chapter_trak.and_then(|trak| trak.mdia.as_ref()) {
+		Some(mdia) => mdia,
+		None => return Ok(Vec::new()),
+	};
+
+	let mut timescale = None;
+	let mut stbl = None;
+
+	for atom in child_atoms(data, mdia)? {
+		match atom.ident {
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"mdhd" => {
+				let header_len = if atom.extended { 16 } else { 8 };
+				data.seek(SeekFrom::Start(atom.start + header_len))?;
+
+				let version = data.read_u8()?;
+				let _flags = data.read_u24::<BigEndian>()?;
+
+				// Skip the creation and modification times
+				if version == 1 {
+					data.seek(SeekFrom::Current(16))?;
+				} else {
+					data.seek(SeekFrom::Current(8))?;
+				}
+
+				timescale = Some(data.read_u32::<BigEndian>()?);
+			},
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"minf" => {
+				stbl = child_atoms(data, &atom)?
+					.into_iter()
+					.find(|atom| atom.ident == AtomIdent::Fourcc(*b"stbl"));
+			},
+			_ => {},
+		}
+	}
+
+	let (timescale, stbl) = match (timescale, stbl) {
+		(Some(timescale), Some(stbl)) if timescale > 0 => (timescale, stbl),
+		_ => return Ok(Vec::new()),
+	};
+
+	let sample_table = SampleTable::read(data, &stbl)?;
+
+	let mut chapters = Vec::new();
+	let mut time = 0_u64;
+	let mut durations = sample_table
+		.time_to_sample
+		.iter()
+		.flat_map(|(count, delta)| std::iter::repeat(*delta).take(*count as usize));
+
+	for (offset, size) in sample_table.samples() {
+		// Each sample is a 16-bit length followed by the text
+		data.seek(SeekFrom::Start(offset))?;
+
+		let text_len = data.read_u16::<BigEndian>()?;
+		let text_len = std::cmp::min(u64::from(text_len), u64::from(size.saturating_sub(2)));
+
+		let mut text = try_vec![0; text_len as usize];
+		data.read_exact(&mut text)?;
+
+		let title = match text.as_slice() {
+			[0xFE, 0xFF, rest @ ..] => utf16_decode(rest, u16::from_be_bytes)?,
+			[0xFF, 0xFE, rest @ ..] => utf16_decode(rest, u16::from_le_bytes)?,
+			_ => String::from_utf8_lossy(&text).into_owned(),
+		};
+
+		chapters.push(Chapter {
+			start: Duration::from_nanos(
+				(u128::from(time) * 1_000_000_000 / u128::from(timescale)) as u64,
+			),
+			title,
+		});
+
+		time += u64::from(durations.next().unwrap_or(0));
+	}
+
+	Ok(chapters)
+}
+
+// Reads one of the tables in `stbl`, skipping the version (1) and flags (3)
+fn read_table<R>(data: &mut R, stbl: &AtomInfo, ident: [u8; 4]) -> Result<Option<Vec<u8>>>
+where
+	R: Read + Seek,
+{
+	Ok(find_child(data, stbl, ident)?.map(|mut content| {
+		content.drain(..std::cmp::min(4, content.len()));
+		content
+	}))
+}
+
+// The parts of `stbl` needed to locate and time each sample
+struct SampleTable {
+	// (sample count, sample delta)
+	time_to_sample: Vec<(u32, u32)>,
+	// (first chunk, samples per chunk)
+	sample_to_chunk: Vec<(u32, u32)>,
+	// Either a single size shared by every sample, or the size of each sample
+	sample_size: u32,
+	sample_sizes: Vec<u32>,
+	sample_count: u32,
+	chunk_offsets: Vec<u64>,
+}
+
+impl SampleTable {
+	fn read<R>(data: &mut R, stbl: &AtomInfo) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		let mut table = Self {
+			time_to_sample: Vec::new(),
+			sample_to_chunk: Vec::new(),
+			sample_size: 0,
+			sample_sizes: Vec::new(),
+			sample_count: 0,
+			chunk_offsets: Vec::new(),
+		};
+
+		if let Some(stts) = read_table(data, stbl, *b"stts")? {
+			let reader = &mut &*stts;
+			let entry_count = reader.read_u32::<BigEndian>()?;
+
+			for _ in 0..entry_count {
+				table.time_to_sample.push((
+					reader.read_u32::<BigEndian>()?,
+					reader.read_u32::<BigEndian>()?,
+				));
+			}
+		}
+
+		if let Some(stsc) = read_table(data, stbl, *b"stsc")? {
+			let reader = &mut &*stsc;
+			let entry_count = reader.read_u32::<BigEndian>()?;
+
+			for _ in 0..entry_count {
+				let first_chunk = reader.read_u32::<BigEndian>()?;
+				let samples_per_chunk = reader.read_u32::<BigEndian>()?;
+				let _sample_description_id = reader.read_u32::<BigEndian>()?;
+
+				table.sample_to_chunk.push((first_chunk, samples_per_chunk));
+			}
+		}
+
+		if let Some(stsz) = read_table(data, stbl, *b"stsz")? {
+			let reader = &mut &*stsz;
+			table.sample_size = reader.read_u32::<BigEndian>()?;
+			table.sample_count = reader.read_u32::<BigEndian>()?;
+
+			if table.sample_size == 0 {
+				for _ in 0..table.sample_count {
+					table.sample_sizes.push(reader.read_u32::<BigEndian>()?);
+				}
+			}
+		}
+
+		if let Some(stco) = read_table(data, stbl, *b"stco")? {
+			let reader = &mut &*stco;
+			let entry_count = reader.read_u32::<BigEndian>()?;
+
+			for _ in 0..entry_count {
+				table
+					.chunk_offsets
+					.push(u64::from(reader.read_u32::<BigEndian>()?));
+			}
+		} else if let Some(co64) = read_table(data, stbl, *b"co64")? {
+			let reader = &mut &*co64;
+			let entry_count = reader.read_u32::<BigEndian>()?;
+
+			for _ in 0..entry_count {
+				table.chunk_offsets.push(reader.read_u64::<BigEndian>()?);
+			}
+		}
+
+		Ok(table)
+	}
+
+	// The offset and size of every sample
+	fn samples(&self) -> Vec<(u64, u32)> {
+		let mut samples = Vec::new();
+		let mut sample = 0;
+
+		for (idx, chunk_offset) in self.chunk_offsets.iter().enumerate() {
+			let chunk = idx as u32 + 1;
+
+			// The sample-to-chunk entries apply to every chunk up to the next entry
+			let samples_per_chunk = self
+				.sample_to_chunk
+				.iter()
+				.rev()
+				.find(|(first_chunk, _)| *first_chunk <= chunk)
+				.map_or(0, |(_, samples_per_chunk)| *samples_per_chunk);
+
+			let mut offset = *chunk_offset;
+
+			for _ in 0..samples_per_chunk {
+				if sample >= self.sample_count {
+					return samples;
+				}
+
+				let size = match self.sample_size {
+					0 => self.sample_sizes[sample as usize],
+					size => size,
+				};
+
+				samples.push((offset, size));
+
+				offset += u64::from(size);
+				sample += 1;
+			}
+		}
+
+		samples
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mp4::{Chapter, Mp4File};
+	use crate::AudioFile;
+
+	use std::io::Cursor;
+	use std::time::Duration;
+
+	fn atom(ident: [u8; 4], content: &[u8]) -> Vec<u8> {
+		let mut atom = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+		atom.extend_from_slice(&ident);
+		atom.extend_from_slice(content);
+		atom
+	}
+
+	fn full_atom(ident: [u8; 4], version: u8, content: &[u8]) -> Vec<u8> {
+		atom(ident, &[&[version, 0, 0, 0], content].concat())
+	}
+
+	fn tkhd(id: u32) -> Vec<u8> {
+		// Creation time (4), Modification time (4), Track ID (4)
+		full_atom(*b"tkhd", 0, &[&[0; 8][..], &id.to_be_bytes()].concat())
+	}
+
+	fn table(ident: [u8; 4], entries: &[&[u32]]) -> Vec<u8> {
+		let mut content = (entries.len() as u32).to_be_bytes().to_vec();
+
+		for entry in entries {
+			for value in *entry {
+				content.extend_from_slice(&value.to_be_bytes());
+			}
+		}
+
+		full_atom(ident, 0, &content)
+	}
+
+	fn text_sample(text: &str) -> Vec<u8> {
+		[&(text.len() as u16).to_be_bytes()[..], text.as_bytes()].concat()
+	}
+
+	fn file(quicktime: bool) -> Vec<u8> {
+		let ftyp = atom(*b"ftyp", b"M4A \0\0\0\0M4A ");
+
+		let samples = [text_sample("Intro"), text_sample("Part 2")];
+
+		let moov = |chunk_offset: u32| {
+			let stbl = atom(
+				*b"stbl",
+				&[
+					table(*b"stts", &[&[1, 1500], &[1, 2000]]),
+					table(*b"stsc", &[&[1, 2, 1]]),
+					// Sample size (0), sample count (2), followed by the sizes
+					full_atom(
+						*b"stsz",
+						0,
+						&[0, 2, samples[0].len() as u32, samples[1].len() as u32]
+							.iter()
+							.flat_map(|v| v.to_be_bytes())
+							.collect::<Vec<u8>>(),
+					),
+					table(*b"stco", &[&[chunk_offset]]),
+				]
+				.concat(),
+			);
+
+			// Creation time (4), Modification time (4), Timescale (4), Duration (4)
+			let mdhd = full_atom(
+				*b"mdhd",
+				0,
+				&[&[0; 8][..], &1000_u32.to_be_bytes(), &[0; 4]].concat(),
+			);
+			let mdia = atom(*b"mdia", &[mdhd, atom(*b"minf", &stbl)].concat());
+
+			let mut audio_trak = tkhd(1);
+			if quicktime {
+				audio_trak.extend(atom(*b"tref", &atom(*b"chap", &2_u32.to_be_bytes())));
+			}
+
+			// Version 1 has 4 reserved bytes, followed by the chapter count
+			let mut chpl = vec![0, 0, 0, 0, 2];
+			for (start, title) in [(0_u64, "Nero intro"), (15_000_000, "Nero part 2")] {
+				chpl.extend_from_slice(&start.to_be_bytes());
+				chpl.push(title.len() as u8);
+				chpl.extend_from_slice(title.as_bytes());
+			}
+
+			atom(
+				*b"moov",
+				&[
+					atom(*b"trak", &audio_trak),
+					atom(*b"trak", &[tkhd(2), mdia].concat()),
+					atom(*b"udta", &full_atom(*b"chpl", 1, &chpl)),
+				]
+				.concat(),
+			)
+		};
+
+		// The samples are stored right after the `mdat` header
+		let chunk_offset = ftyp.len() + moov(0).len() + 8;
+
+		[
+			ftyp,
+			moov(chunk_offset as u32),
+			atom(*b"mdat", &samples.concat()),
+		]
+		.concat()
+	}
+
+	#[test]
+	fn quicktime_chapters() {
+		let mp4 = Mp4File::read_from(&mut Cursor::new(file(true)), false).unwrap();
+
+		assert_eq!(
+			mp4.chapters(),
+			&[
+				Chapter {
+					start: Duration::ZERO,
+					title: String::from("Intro"),
+				},
+				Chapter {
+					start: Duration::from_millis(1500),
+					title: String::from("Part 2"),
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn nero_chapters() {
+		// Without a `tref.chap` reference, the text track isn't used
+		let mp4 = Mp4File::read_from(&mut Cursor::new(file(false)), false).unwrap();
+
+		assert_eq!(
+			mp4.chapters(),
+			&[
+				Chapter {
+					start: Duration::ZERO,
+					title: String::from("Nero intro"),
+				},
+				Chapter {
+					start: Duration::from_millis(1500),
+					title: String::from("Nero part 2"),
+				},
+			]
+		);
+	}
+
+	fn find(data: &[u8], ident: [u8; 4]) -> usize {
+		data.windows(4).position(|w| w == ident).unwrap()
+	}
+
+	#[test]
+	fn invalid_chapters() {
+		// A chunk offset past the end of the file, falling back to the Nero chapters
+		let mut quicktime = file(true);
+		let stco = find(&quicktime, *b"stco");
+		quicktime[stco + 12..stco + 16].copy_from_slice(&0xFFFF_FF00_u32.to_be_bytes());
+
+		let mp4 = Mp4File::read_from(&mut Cursor::new(quicktime), false).unwrap();
+		assert_eq!(mp4.chapters()[0].title, "Nero intro");
+
+		// More chapters than the `chpl` atom holds
+		let mut nero = file(false);
+		let chpl = find(&nero, *b"chpl");
+		nero[chpl + 12] = 200;
+
+		let mp4 = Mp4File::read_from(&mut Cursor::new(nero), false).unwrap();
+		assert!(mp4.chapters().is_empty());
+	}
+}
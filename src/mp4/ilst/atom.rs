@@ -51,7 +51,9 @@ pub enum AtomData {
 	///
 	/// NOTE:
 	///
-	/// This will shrink the integer when writing
+	/// This will shrink the integer when writing, unless the atom
+	/// is expected to have a specific size (ex. `tmpo`, `cpil`), in which
+	/// case values that don't fit will fail to write
 	///
 	/// 255 will be written as `[255]` rather than `[0, 0, 0, 255]`
 	///
@@ -0,0 +1,99 @@
+pub(super) const MEAN: &str = "com.apple.iTunes";
+pub(super) const NAME: &str = "iTunSMPB";
+
+/// Gapless playback information, stored in the `----:com.apple.iTunes:iTunSMPB` atom
+///
+/// The atom is a string of 12 space separated hexadecimal fields, of which only the
+/// second, third, and fourth are used:
+///
+/// ```text
+///  00000000 00000840 000001CA 00000000003F31F6 00000000 00000000 ...
+///           ^ delay  ^ padding ^ sample count
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct GaplessInfo {
+	/// The number of priming samples added by the encoder
+	pub encoder_delay: u32,
+	/// The number of samples added to the end of the stream
+	pub padding: u32,
+	/// The number of samples in the original audio, excluding the delay and padding
+	pub original_sample_count: u64,
+}
+
+impl GaplessInfo {
+	/// Parses an `iTunSMPB` string
+	///
+	/// This will return `None` if any of the first four fields are missing or invalid.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::GaplessInfo;
+	///
+	/// let info = GaplessInfo::parse(" 00000000 00000840 000001CA 00000000003F31F6 00000000 00000000 00000000 00000000 00000000 00000000 00000000 00000000").unwrap();
+	///
+	/// assert_eq!(info.encoder_delay, 2112);
+	/// assert_eq!(info.padding, 458);
+	/// assert_eq!(info.original_sample_count, 4_141_558);
+	/// ```
+	pub fn parse(value: &str) -> Option<Self> {
+		let mut fields = value.split_whitespace();
+
+		// The first field is reserved
+		let _ = fields.next()?;
+
+		let encoder_delay = u32::from_str_radix(fields.next()?, 16).ok()?;
+		let padding = u32::from_str_radix(fields.next()?, 16).ok()?;
+		let original_sample_count = u64::from_str_radix(fields.next()?, 16).ok()?;
+
+		Some(Self {
+			encoder_delay,
+			padding,
+			original_sample_count,
+		})
+	}
+
+	/// Creates an `iTunSMPB` string, in the same layout iTunes writes
+	pub fn as_string(&self) -> String {
+		let mut value = format!(
+			" 00000000 {:08X} {:08X} {:016X}",
+			self.encoder_delay, self.padding, self.original_sample_count
+		);
+
+		// The remaining 8 fields are unused
+		for _ in 0..8 {
+			value.push_str(" 00000000");
+		}
+
+		value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::GaplessInfo;
+
+	#[test]
+	fn gapless_info_round_trip() {
+		let info = GaplessInfo {
+			encoder_delay: 2112,
+			padding: 458,
+			original_sample_count: 4_141_558,
+		};
+
+		let value = info.as_string();
+		assert_eq!(value.split(' ').count(), 13);
+		assert!(value.starts_with(" 00000000 00000840 000001CA 00000000003F31F6 00000000"));
+
+		assert_eq!(GaplessInfo::parse(&value), Some(info));
+
+		// Lowercase hex, and missing trailing fields
+		assert_eq!(
+			GaplessInfo::parse("00000000 00000840 000001ca 00000000003f31f6"),
+			Some(info)
+		);
+
+		assert_eq!(GaplessInfo::parse(" 00000000 00000840 000001CA"), None);
+		assert_eq!(GaplessInfo::parse(" 00000000 FOO 000001CA 0"), None);
+	}
+}
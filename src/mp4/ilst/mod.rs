@@ -1,5 +1,6 @@
 pub(super) mod atom;
 pub(super) mod constants;
+pub(super) mod gapless;
 pub(super) mod read;
 pub(crate) mod write;
 
@@ -8,11 +9,11 @@ use crate::error::{LoftyError, Result};
 use crate::picture::{Picture, PictureType};
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 use atom::{AdvisoryRating, Atom, AtomData, AtomDataRef, AtomIdentRef, AtomRef};
+use gapless::GaplessInfo;
 
 use std::convert::TryInto;
-use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
@@ -24,30 +25,64 @@ const GENRE: AtomIdent = AtomIdent::Fourcc(*b"\xa9gen");
 macro_rules! impl_accessor {
 	($($name:ident, $const:ident;)+) => {
 		paste::paste! {
-			impl Accessor for Ilst {
-				$(
-					fn $name(&self) -> Option<&str> {
-						if let Some(atom) = self.atom(&$const) {
-							if let AtomData::UTF8(val) | AtomData::UTF16(val) = atom.data() {
-								return Some(val)
-							}
+			$(
+				fn $name(&self) -> Option<&str> {
+					if let Some(atom) = self.atom(&$const) {
+						if let AtomData::UTF8(val) | AtomData::UTF16(val) = atom.data() {
+							return Some(val)
 						}
-
-						None
 					}
 
-					fn [<set_ $name>](&mut self, value: String) {
-						self.replace_atom(Atom {
-							ident: $const,
-							data: AtomData::UTF8(value),
-						})
-					}
+					None
+				}
 
-					fn [<remove_ $name>](&mut self) {
-						self.remove_atom(&$const)
-					}
-				)+
-			}
+				fn [<set_ $name>](&mut self, value: String) {
+					self.replace_atom(Atom {
+						ident: $const,
+						data: AtomData::UTF8(value),
+					})
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove_atom(&$const)
+				}
+			)+
+		}
+	}
+}
+
+macro_rules! impl_pair_accessor {
+	($($name:ident, $total_name:ident, $fourcc:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<u32> {
+					self.int_pair(*$fourcc).0
+				}
+
+				fn [<set_ $name>](&mut self, value: u32) {
+					let total = self.int_pair(*$fourcc).1;
+					self.insert_int_pair(*$fourcc, Some(value), total)
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					let total = self.int_pair(*$fourcc).1;
+					self.insert_int_pair(*$fourcc, None, total)
+				}
+
+				fn $total_name(&self) -> Option<u32> {
+					self.int_pair(*$fourcc).1
+				}
+
+				fn [<set_ $total_name>](&mut self, value: u32) {
+					let current = self.int_pair(*$fourcc).0;
+					self.insert_int_pair(*$fourcc, current, Some(value))
+				}
+
+				fn [<remove_ $total_name>](&mut self) {
+					let current = self.int_pair(*$fourcc).0;
+					self.insert_int_pair(*$fourcc, current, None)
+				}
+			)+
 		}
 	}
 }
@@ -62,7 +97,9 @@ macro_rules! impl_accessor {
 /// ## Pictures
 ///
 /// Unlike other formats, ilst does not store a [`PictureType`]. All pictures will have
-/// [`PictureType::Other`].
+/// [`PictureType::CoverFront`], with the first being the primary cover.
+///
+/// Multiple pictures are stored together in a single `covr` atom.
 ///
 /// ## Conversions
 ///
@@ -71,7 +108,7 @@ macro_rules! impl_accessor {
 /// When converting to [`Tag`], only atoms with a value of [`AtomData::UTF8`] and [`AtomData::UTF16`],
 /// with the exception of the `trkn` and `disk` atoms, as well as pictures, will be preserved.
 ///
-/// Do note, all pictures will be [`PictureType::Other`](crate::PictureType::Other)
+/// Do note, all pictures will be [`PictureType::CoverFront`](crate::PictureType::CoverFront)
 ///
 /// ### From `Tag`
 ///
@@ -83,12 +120,19 @@ pub struct Ilst {
 	pub(crate) atoms: Vec<Atom>,
 }
 
-impl_accessor!(
-	artist,       ARTIST;
-	title,        TITLE;
-	album,        ALBUM;
-	genre,        GENRE;
-);
+impl Accessor for Ilst {
+	impl_accessor!(
+		artist,       ARTIST;
+		title,        TITLE;
+		album,        ALBUM;
+		genre,        GENRE;
+	);
+
+	impl_pair_accessor!(
+		track, track_total, b"trkn";
+		disk,  disk_total,  b"disk";
+	);
+}
 
 impl Ilst {
 	/// Returns all of the tag's atoms
@@ -146,7 +190,7 @@ impl Ilst {
 	/// Inserts a picture
 	pub fn insert_picture(&mut self, mut picture: Picture) {
 		// This is just for correctness, it doesn't really matter.
-		picture.pic_type = PictureType::Other;
+		picture.pic_type = PictureType::CoverFront;
 
 		self.atoms.push(Atom {
 			ident: AtomIdent::Fourcc(*b"covr"),
@@ -185,41 +229,182 @@ impl Ilst {
 		})
 	}
 
-	/// Returns the track number
-	pub fn track_number(&self) -> Option<u16> {
-		self.extract_number(*b"trkn", 4)
+	/// Removes the advisory rating
+	pub fn remove_advisory_rating(&mut self) {
+		self.remove_atom(&AtomIdent::Fourcc(*b"rtng"))
 	}
 
-	/// Returns the total number of tracks
-	pub fn track_total(&self) -> Option<u16> {
-		self.extract_number(*b"trkn", 6)
+	/// Returns the tempo (BPM) according to the `tmpo` atom
+	pub fn bpm(&self) -> Option<u16> {
+		self.int_atom(*b"tmpo").and_then(|i| u16::try_from(i).ok())
 	}
 
-	/// Returns the disc number
-	pub fn disc_number(&self) -> Option<u16> {
-		self.extract_number(*b"disk", 4)
+	/// Sets the tempo (BPM)
+	pub fn set_bpm(&mut self, bpm: u16) {
+		self.replace_atom(Atom {
+			ident: AtomIdent::Fourcc(*b"tmpo"),
+			data: AtomData::UnsignedInteger(u32::from(bpm)),
+		})
 	}
 
-	/// Returns the total number of discs
-	pub fn disc_total(&self) -> Option<u16> {
-		self.extract_number(*b"disk", 6)
+	/// Removes the tempo (BPM)
+	pub fn remove_bpm(&mut self) {
+		self.remove_atom(&AtomIdent::Fourcc(*b"tmpo"))
 	}
 
-	// Extracts a u16 from an integer pair
-	fn extract_number(&self, fourcc: [u8; 4], expected_size: usize) -> Option<u16> {
-		if let Some(atom) = self.atom(&AtomIdent::Fourcc(fourcc)) {
-			match atom.data() {
-				AtomData::Unknown { code: 0, data } if data.len() >= expected_size => {
-					return Some(u16::from_be_bytes([
-						data[expected_size - 2],
-						data[expected_size - 1],
-					]))
-				},
-				_ => {},
-			}
+	/// Returns whether the track is part of a compilation, according to the `cpil` atom
+	pub fn compilation(&self) -> Option<bool> {
+		self.int_atom(*b"cpil").map(|i| i != 0)
+	}
+
+	/// Sets whether the track is part of a compilation
+	pub fn set_compilation(&mut self, compilation: bool) {
+		self.replace_atom(Atom {
+			ident: AtomIdent::Fourcc(*b"cpil"),
+			data: AtomData::SignedInteger(i32::from(compilation)),
+		})
+	}
+
+	/// Removes the compilation flag
+	pub fn remove_compilation(&mut self) {
+		self.remove_atom(&AtomIdent::Fourcc(*b"cpil"))
+	}
+
+	fn int_atom(&self, fourcc: [u8; 4]) -> Option<i64> {
+		match self.atom(&AtomIdent::Fourcc(fourcc))?.data() {
+			AtomData::SignedInteger(si) => Some(i64::from(*si)),
+			AtomData::UnsignedInteger(ui) => Some(i64::from(*ui)),
+			// Some software stores these as implicit (0) binary data
+			AtomData::Unknown {
+				code: 0 | 21 | 22,
+				data,
+			} if (1..=4).contains(&data.len()) => {
+				Some(data.iter().fold(0, |acc, b| (acc << 8) | i64::from(*b)))
+			},
+			_ => None,
 		}
+	}
 
-		None
+	/// Gets the value of a freeform (`----`) atom
+	///
+	/// This will only return text values, see [`AtomIdent::Freeform`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::Ilst;
+	///
+	/// let mut ilst = Ilst::default();
+	/// ilst.set_freeform(
+	/// 	"com.apple.iTunes",
+	/// 	"MusicBrainz Track Id",
+	/// 	String::from("3f2c1b0d-7d5e-4b9a-9f1c-0e6b8a2d4c11"),
+	/// );
+	///
+	/// assert_eq!(
+	/// 	ilst.get_freeform("com.apple.iTunes", "MusicBrainz Track Id"),
+	/// 	Some("3f2c1b0d-7d5e-4b9a-9f1c-0e6b8a2d4c11")
+	/// );
+	/// ```
+	pub fn get_freeform(&self, mean: &str, name: &str) -> Option<&str> {
+		self.atoms.iter().find_map(|a| match a {
+			Atom {
+				ident: AtomIdent::Freeform { mean: m, name: n },
+				data: AtomData::UTF8(value) | AtomData::UTF16(value),
+			} if m == mean && n == name => Some(value.as_str()),
+			_ => None,
+		})
+	}
+
+	/// Sets the value of a freeform (`----`) atom, replacing any existing value
+	pub fn set_freeform(&mut self, mean: &str, name: &str, value: String) {
+		self.replace_atom(Atom {
+			ident: AtomIdent::Freeform {
+				mean: String::from(mean),
+				name: String::from(name),
+			},
+			data: AtomData::UTF8(value),
+		})
+	}
+
+	/// Removes all freeform (`----`) atoms with the given `mean` and `name`
+	pub fn remove_freeform(&mut self, mean: &str, name: &str) {
+		self.atoms.retain(
+			|a| !matches!(&a.ident, AtomIdent::Freeform { mean: m, name: n } if m == mean && n == name),
+		)
+	}
+
+	/// Returns the gapless playback information according to the `----:com.apple.iTunes:iTunSMPB` atom
+	///
+	/// See [`GaplessInfo::parse`]
+	pub fn gapless_info(&self) -> Option<GaplessInfo> {
+		self.get_freeform(gapless::MEAN, gapless::NAME)
+			.and_then(GaplessInfo::parse)
+	}
+
+	/// Sets the gapless playback information
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::{GaplessInfo, Ilst};
+	///
+	/// let info = GaplessInfo {
+	/// 	encoder_delay: 2112,
+	/// 	padding: 458,
+	/// 	original_sample_count: 4_141_558,
+	/// };
+	///
+	/// let mut ilst = Ilst::default();
+	/// ilst.set_gapless_info(info);
+	///
+	/// assert_eq!(ilst.gapless_info(), Some(info));
+	/// ```
+	pub fn set_gapless_info(&mut self, info: GaplessInfo) {
+		self.set_freeform(gapless::MEAN, gapless::NAME, info.as_string())
+	}
+
+	/// Removes the gapless playback information
+	pub fn remove_gapless_info(&mut self) {
+		self.remove_freeform(gapless::MEAN, gapless::NAME)
+	}
+
+	// Reads the current and total values from the `trkn` and `disk` atoms, where 0 means unset
+	fn int_pair(&self, fourcc: [u8; 4]) -> (Option<u32>, Option<u32>) {
+		match self.atom(&AtomIdent::Fourcc(fourcc)).map(Atom::data) {
+			Some(AtomData::Unknown { code: 0, data }) if data.len() >= 6 => {
+				let number = |pos: usize| {
+					Some(u32::from(u16::from_be_bytes([data[pos], data[pos + 1]])))
+						.filter(|n| *n != 0)
+				};
+
+				(number(2), number(4))
+			},
+			_ => (None, None),
+		}
+	}
+
+	// Values that don't fit in a `u16` are treated as unset
+	fn insert_int_pair(&mut self, fourcc: [u8; 4], current: Option<u32>, total: Option<u32>) {
+		let to_bytes = |n: Option<u32>| {
+			n.and_then(|n| u16::try_from(n).ok())
+				.unwrap_or_default()
+				.to_be_bytes()
+		};
+
+		let (current, total) = (to_bytes(current), to_bytes(total));
+
+		if current == [0; 2] && total == [0; 2] {
+			return self.remove_atom(&AtomIdent::Fourcc(fourcc));
+		}
+
+		self.replace_atom(Atom {
+			ident: AtomIdent::Fourcc(fourcc),
+			data: AtomData::Unknown {
+				code: 0,
+				data: vec![0, 0, current[0], current[1], total[0], total[1], 0, 0],
+			},
+		})
 	}
 }
 
@@ -231,11 +416,10 @@ impl TagExt for Ilst {
 	}
 
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		let mut f = OpenOptions::new().read(true).write(true).open(path)?;
-		self.save_to(&mut f)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		Into::<IlstRef<'_>>::into(self).write_to(file)
 	}
 
@@ -247,13 +431,35 @@ impl TagExt for Ilst {
 		TagType::Mp4Ilst.remove_from_path(path)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		TagType::Mp4Ilst.remove_from(file)
 	}
 
 	fn clear(&mut self) {
 		self.atoms.clear();
 	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		self.pictures().cloned().collect()
+	}
+
+	/// Inserts a [`Picture`]
+	///
+	/// Since ilst does not store a [`PictureType`], this will replace **all** existing pictures.
+	/// To add another picture, use [`Ilst::insert_picture`].
+	fn replace_picture(&mut self, picture: Picture) -> std::result::Result<(), Self::Err> {
+		self.remove_pictures();
+		Ilst::insert_picture(self, picture);
+
+		Ok(())
+	}
+
+	/// Removes all pictures
+	///
+	/// Since ilst does not store a [`PictureType`], `picture_type` is ignored.
+	fn remove_picture_type(&mut self, _: PictureType) {
+		self.remove_pictures()
+	}
 }
 
 impl From<Ilst> for Tag {
@@ -263,6 +469,8 @@ impl From<Ilst> for Tag {
 		for atom in input.atoms {
 			let value = match atom.data {
 				AtomData::UTF8(text) | AtomData::UTF16(text) => ItemValue::Text(text),
+				AtomData::SignedInteger(int) => ItemValue::Text(int.to_string()),
+				AtomData::UnsignedInteger(uint) => ItemValue::Text(uint.to_string()),
 				AtomData::Picture(pic) => {
 					tag.pictures.push(pic);
 					continue;
@@ -359,6 +567,23 @@ impl From<Tag> for Ilst {
 					ItemKey::TrackTotal => convert_to_uint(&mut tracks.1, data.as_str()),
 					ItemKey::DiscNumber => convert_to_uint(&mut discs.0, data.as_str()),
 					ItemKey::DiscTotal => convert_to_uint(&mut discs.1, data.as_str()),
+					ItemKey::BPM => {
+						if let Ok(bpm) = data.parse::<u16>() {
+							ilst.set_bpm(bpm)
+						}
+					},
+					ItemKey::FlagCompilation => {
+						ilst.set_compilation(data == "1" || data.eq_ignore_ascii_case("true"))
+					},
+					// These are written as signed 16-bit integers, so larger values can't be stored
+					ItemKey::MovementNumber | ItemKey::MovementTotal => {
+						if let Ok(num @ 0..) = data.parse::<i16>() {
+							ilst.atoms.push(Atom {
+								ident,
+								data: AtomData::SignedInteger(i32::from(num)),
+							})
+						}
+					},
 					_ => ilst.atoms.push(Atom {
 						ident,
 						data: AtomData::UTF8(data),
@@ -370,7 +595,7 @@ impl From<Tag> for Ilst {
 		for mut picture in input.pictures {
 			// Just for correctness, since we can't actually
 			// assign a picture type in this format
-			picture.pic_type = PictureType::Other;
+			picture.pic_type = PictureType::CoverFront;
 
 			ilst.atoms.push(Atom {
 				ident: AtomIdent::Fourcc([b'c', b'o', b'v', b'r']),
@@ -390,7 +615,7 @@ pub(crate) struct IlstRef<'a> {
 }
 
 impl<'a> IlstRef<'a> {
-	pub(crate) fn write_to(&mut self, file: &mut File) -> Result<()> {
+	pub(crate) fn write_to<F: FileLike>(&mut self, file: &mut F) -> Result<()> {
 		write::write_to(file, self)
 	}
 
@@ -410,25 +635,6 @@ impl<'a> Into<IlstRef<'a>> for &'a Ilst {
 	}
 }
 
-impl<'a> Into<IlstRef<'a>> for &'a Tag {
-	fn into(self) -> IlstRef<'a> {
-		let iter =
-			self.items
-				.iter()
-				.filter_map(|i| match (item_key_to_ident(i.key()), i.value()) {
-					(Some(ident), ItemValue::Text(text)) => Some(AtomRef {
-						ident,
-						data: AtomDataRef::UTF8(text),
-					}),
-					_ => None,
-				});
-
-		IlstRef {
-			atoms: Box::new(iter),
-		}
-	}
-}
-
 fn item_key_to_ident(key: &ItemKey) -> Option<AtomIdentRef<'_>> {
 	key.map_key(TagType::Mp4Ilst, true).and_then(|ident| {
 		if ident.starts_with("----") {
@@ -460,12 +666,14 @@ fn item_key_to_ident(key: &ItemKey) -> Option<AtomIdentRef<'_>> {
 mod tests {
 	use crate::mp4::{AdvisoryRating, Atom, AtomData, AtomIdent, Ilst, Mp4File};
 	use crate::tag::utils::test_utils::read_path;
-	use crate::{Accessor, AudioFile, ItemKey, Tag, TagExt, TagType};
-	use std::io::{Cursor, Read, Seek, Write};
+	use crate::{
+		Accessor, AudioFile, ItemKey, MimeType, Picture, PictureType, Tag, TagExt, TagType,
+	};
+	use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 	fn read_ilst(path: &str) -> Ilst {
 		let tag = crate::tag::utils::test_utils::read_path(path);
-		super::read::parse_ilst(&mut &tag[..], tag.len() as u64).unwrap()
+		super::read::parse_ilst(&mut &tag[..], tag.len() as u64, 0).unwrap()
 	}
 
 	fn verify_atom(ilst: &Ilst, ident: [u8; 4], data: &AtomData) {
@@ -529,7 +737,7 @@ mod tests {
 
 		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/ilst/test.ilst");
 
-		let parsed_tag = super::read::parse_ilst(&mut &tag[..], tag.len() as u64).unwrap();
+		let parsed_tag = super::read::parse_ilst(&mut &tag[..], tag.len() as u64, 0).unwrap();
 
 		assert_eq!(expected_tag, parsed_tag);
 	}
@@ -543,7 +751,7 @@ mod tests {
 
 		// Remove the ilst identifier and size
 		let temp_parsed_tag =
-			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64).unwrap();
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
 
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
@@ -553,7 +761,7 @@ mod tests {
 		let tag_bytes =
 			crate::tag::utils::test_utils::read_path("tests/tags/assets/ilst/test.ilst");
 
-		let ilst = super::read::parse_ilst(&mut &tag_bytes[..], tag_bytes.len() as u64).unwrap();
+		let ilst = super::read::parse_ilst(&mut &tag_bytes[..], tag_bytes.len() as u64, 0).unwrap();
 
 		let tag: Tag = ilst.into();
 
@@ -647,6 +855,233 @@ mod tests {
 		assert_eq!(ilst.advisory_rating(), Some(AdvisoryRating::Explicit));
 	}
 
+	#[test]
+	fn track_disk_accessors() {
+		let mut ilst = read_ilst("tests/tags/assets/ilst/test.ilst");
+
+		assert_eq!(ilst.track(), Some(1));
+		assert_eq!(ilst.track_total(), None);
+		assert_eq!(ilst.disk(), Some(1));
+		assert_eq!(ilst.disk_total(), Some(2));
+
+		ilst.set_track_total(12);
+		ilst.remove_disk();
+
+		verify_atom(
+			&ilst,
+			*b"trkn",
+			&AtomData::Unknown {
+				code: 0,
+				data: vec![0, 0, 0, 1, 0, 12, 0, 0],
+			},
+		);
+		assert_eq!(ilst.disk(), None);
+		assert_eq!(ilst.disk_total(), Some(2));
+
+		ilst.remove_disk_total();
+		assert!(ilst.atom(&AtomIdent::Fourcc(*b"disk")).is_none());
+	}
+
+	#[test]
+	fn covr_mime_type() {
+		const PNG: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+		const JPEG: [u8; 4] = [0xFF, 0xD8, 0xFF, 0xE0];
+
+		let mut ilst = Ilst::default();
+
+		// An unknown mime type should be written with the flag matching the data
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"covr"),
+			AtomData::Picture(Picture::new_unchecked(
+				PictureType::Other,
+				MimeType::None,
+				None,
+				PNG.to_vec(),
+			)),
+		));
+		// An implicit (0) flag should fall back to the magic bytes
+		ilst.insert_atom(Atom::new(
+			AtomIdent::Fourcc(*b"covr"),
+			AtomData::Unknown {
+				code: 0,
+				data: JPEG.to_vec(),
+			},
+		));
+
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer).unwrap();
+
+		let parsed_tag =
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
+
+		let mime_types = parsed_tag
+			.pictures()
+			.map(|p| p.mime_type().clone())
+			.collect::<Vec<_>>();
+		assert_eq!(mime_types, vec![MimeType::Png, MimeType::Jpeg]);
+
+		assert!(writer
+			.windows(8)
+			.any(|w| w == [b'd', b'a', b't', b'a', 0, 0, 0, 14]));
+	}
+
+	#[test]
+	fn multiple_covr() {
+		let front = Picture::new_unchecked(
+			PictureType::CoverFront,
+			MimeType::Png,
+			None,
+			vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A],
+		);
+		let back = Picture::new_unchecked(
+			PictureType::CoverBack,
+			MimeType::Jpeg,
+			None,
+			vec![0xFF, 0xD8, 0xFF, 0xE0],
+		);
+
+		let mut ilst = Ilst::default();
+		ilst.insert_picture(front.clone());
+		ilst.set_title(String::from("Foo title"));
+		ilst.insert_picture(back.clone());
+
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer).unwrap();
+
+		// Both pictures should be stored in the same atom
+		assert_eq!(writer.windows(4).filter(|w| w == b"covr").count(), 1);
+
+		let parsed_tag =
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
+
+		let pictures = parsed_tag.pictures().collect::<Vec<_>>();
+		assert_eq!(pictures.len(), 2);
+		assert_eq!(pictures[0].data(), front.data());
+		assert_eq!(pictures[1].data(), back.data());
+		assert!(pictures
+			.iter()
+			.all(|p| p.pic_type() == PictureType::CoverFront));
+		assert_eq!(parsed_tag.title(), Some("Foo title"));
+	}
+
+	#[test]
+	fn integer_atoms() {
+		let mut ilst = Ilst::default();
+
+		ilst.set_bpm(120);
+		ilst.set_compilation(true);
+		ilst.set_advisory_rating(AdvisoryRating::Inoffensive);
+
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer).unwrap();
+
+		// Each atom must be written with its expected size
+		let tmpo = [
+			0, 0, 0, 0x1A, b't', b'm', b'p', b'o', 0, 0, 0, 0x12, b'd', b'a', b't', b'a', 0, 0, 0,
+			22, 0, 0, 0, 0, 0, 120,
+		];
+		let cpil = [
+			0, 0, 0, 0x19, b'c', b'p', b'i', b'l', 0, 0, 0, 0x11, b'd', b'a', b't', b'a', 0, 0, 0,
+			21, 0, 0, 0, 0, 1,
+		];
+		let rtng = [
+			0, 0, 0, 0x19, b'r', b't', b'n', b'g', 0, 0, 0, 0x11, b'd', b'a', b't', b'a', 0, 0, 0,
+			21, 0, 0, 0, 0, 0,
+		];
+
+		assert!(writer.windows(tmpo.len()).any(|w| w == tmpo));
+		assert!(writer.windows(cpil.len()).any(|w| w == cpil));
+		assert!(writer.windows(rtng.len()).any(|w| w == rtng));
+
+		let mut parsed_tag =
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
+
+		assert_eq!(parsed_tag.bpm(), Some(120));
+		assert_eq!(parsed_tag.compilation(), Some(true));
+		assert_eq!(
+			parsed_tag.advisory_rating(),
+			Some(AdvisoryRating::Inoffensive)
+		);
+
+		parsed_tag.remove_bpm();
+		parsed_tag.remove_compilation();
+		parsed_tag.remove_advisory_rating();
+		assert!(parsed_tag.atoms().is_empty());
+
+		// The full range of the 2 byte `tmpo` atom is usable
+		let mut ilst = Ilst::default();
+		ilst.set_bpm(u16::MAX);
+
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer).unwrap();
+
+		let parsed_tag =
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
+		assert_eq!(parsed_tag.bpm(), Some(u16::MAX));
+
+		// Values that don't fit in the expected size can't be written
+		ilst.replace_atom(Atom {
+			ident: AtomIdent::Fourcc(*b"tmpo"),
+			data: AtomData::SignedInteger(40000),
+		});
+		assert!(ilst.dump_to(&mut Vec::new()).is_err());
+
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.insert_text(ItemKey::BPM, String::from("70000"));
+
+		let ilst: Ilst = tag.into();
+		assert!(ilst.bpm().is_none());
+
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.insert_text(ItemKey::BPM, String::from("98"));
+		tag.insert_text(ItemKey::FlagCompilation, String::from("1"));
+
+		let ilst: Ilst = tag.into();
+		assert_eq!(ilst.bpm(), Some(98));
+		assert_eq!(ilst.compilation(), Some(true));
+
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.get_string(&ItemKey::BPM), Some("98"));
+		assert_eq!(tag.get_string(&ItemKey::FlagCompilation), Some("1"));
+	}
+
+	#[test]
+	fn freeform_accessors() {
+		const MEAN: &str = "com.apple.iTunes";
+
+		let mut ilst = Ilst::default();
+		assert!(ilst.get_freeform(MEAN, "REPLAYGAIN_TRACK_GAIN").is_none());
+
+		ilst.set_freeform(MEAN, "REPLAYGAIN_TRACK_GAIN", String::from("-6.50 dB"));
+		ilst.set_freeform(MEAN, "REPLAYGAIN_TRACK_GAIN", String::from("-7.00 dB"));
+		ilst.set_freeform(MEAN, "MusicBrainz Track Id", String::from("Foo"));
+
+		assert_eq!(ilst.atoms().len(), 2);
+		assert_eq!(
+			ilst.get_freeform(MEAN, "REPLAYGAIN_TRACK_GAIN"),
+			Some("-7.00 dB")
+		);
+
+		// The `mean` and `name` atoms should survive a round trip
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer).unwrap();
+
+		let mut parsed_tag =
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
+
+		assert_eq!(parsed_tag, ilst);
+		assert_eq!(
+			parsed_tag.get_freeform(MEAN, "MusicBrainz Track Id"),
+			Some("Foo")
+		);
+
+		parsed_tag.remove_freeform(MEAN, "MusicBrainz Track Id");
+		assert!(parsed_tag
+			.get_freeform(MEAN, "MusicBrainz Track Id")
+			.is_none());
+		assert_eq!(parsed_tag.atoms().len(), 1);
+	}
+
 	#[test]
 	fn trailing_padding() {
 		const ILST_START: usize = 97;
@@ -662,7 +1097,8 @@ mod tests {
 			u32::from_be_bytes(file_bytes[ILST_END..ILST_END + 4].try_into().unwrap());
 		assert_eq!(old_free_size, PADDING_SIZE as u32);
 
-		let mut ilst = super::read::parse_ilst(&mut &*ilst_bytes, ilst_bytes.len() as u64).unwrap();
+		let mut ilst =
+			super::read::parse_ilst(&mut &*ilst_bytes, ilst_bytes.len() as u64, 0).unwrap();
 
 		let mut file = tempfile::tempfile().unwrap();
 		file.write_all(&file_bytes).unwrap();
@@ -732,4 +1168,93 @@ mod tests {
 			&AtomData::UTF8(String::from("Foo artist")),
 		);
 	}
+
+	#[test]
+	fn write_non_full_meta_atom_padding() {
+		// The `free` atom following the `ilst` is a child of the non-full meta atom,
+		// and should be used as padding
+		let file_bytes = read_path("tests/files/assets/non_full_meta_atom.m4a");
+		let mut file = tempfile::tempfile().unwrap();
+		file.write_all(&file_bytes).unwrap();
+		file.rewind().unwrap();
+
+		let mut tag = Ilst::default();
+		tag.set_title(String::from("Foo title"));
+
+		tag.save_to(&mut file).unwrap();
+
+		// The file shouldn't have grown
+		assert_eq!(
+			file.seek(SeekFrom::End(0)).unwrap(),
+			file_bytes.len() as u64
+		);
+
+		file.rewind().unwrap();
+		let mp4_file = Mp4File::read_from(&mut file, true).unwrap();
+		assert_eq!(mp4_file.ilst.unwrap().title(), Some("Foo title"));
+	}
+
+	#[test]
+	fn movement_atoms() {
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.insert_text(ItemKey::Work, String::from("Foo work"));
+		tag.insert_text(ItemKey::Movement, String::from("Bar movement"));
+		tag.insert_text(ItemKey::MovementNumber, String::from("2"));
+		tag.insert_text(ItemKey::MovementTotal, String::from("4"));
+
+		let ilst: Ilst = tag.clone().into();
+		assert_eq!(
+			ilst.atom(&AtomIdent::Fourcc(*b"\xa9mvi")).map(Atom::data),
+			Some(&AtomData::SignedInteger(2))
+		);
+		assert_eq!(
+			ilst.atom(&AtomIdent::Fourcc(*b"\xa9mvc")).map(Atom::data),
+			Some(&AtomData::SignedInteger(4))
+		);
+
+		// The generic writer must also use integer atoms
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer).unwrap();
+
+		let mvi = [
+			0, 0, 0, 0x1A, 0xA9, b'm', b'v', b'i', 0, 0, 0, 0x12, b'd', b'a', b't', b'a', 0, 0, 0,
+			21, 0, 0, 0, 0, 0, 2,
+		];
+		assert!(writer.windows(mvi.len()).any(|w| w == mvi));
+
+		let parsed_tag =
+			super::read::parse_ilst(&mut &writer[8..], (writer.len() - 8) as u64, 0).unwrap();
+
+		let tag: Tag = parsed_tag.into();
+		assert_eq!(tag.get_string(&ItemKey::Work), Some("Foo work"));
+		assert_eq!(tag.get_string(&ItemKey::Movement), Some("Bar movement"));
+		assert_eq!(tag.get_string(&ItemKey::MovementNumber), Some("2"));
+		assert_eq!(tag.get_string(&ItemKey::MovementTotal), Some("4"));
+	}
+
+	#[test]
+	fn tag_dump_matches_conversion() {
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+		tag.insert_text(ItemKey::TrackNumber, String::from("1"));
+		tag.insert_text(ItemKey::TrackTotal, String::from("2"));
+		tag.insert_text(ItemKey::BPM, String::from("120"));
+		tag.insert_text(ItemKey::FlagCompilation, String::from("1"));
+		tag.insert_text(ItemKey::MovementNumber, String::from("40000"));
+
+		let mut from_tag = Vec::new();
+		tag.dump_to(&mut from_tag).unwrap();
+
+		let ilst: Ilst = tag.into();
+		let mut from_ilst = Vec::new();
+		ilst.dump_to(&mut from_ilst).unwrap();
+
+		assert_eq!(from_tag, from_ilst);
+
+		assert_eq!(ilst.bpm(), Some(120));
+		assert_eq!(ilst.compilation(), Some(true));
+		assert_eq!(ilst.track(), Some(1));
+		// The movement number is too large to be stored
+		assert!(ilst.atom(&AtomIdent::Fourcc(*b"\xa9mvi")).is_none());
+	}
 }
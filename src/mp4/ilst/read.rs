@@ -16,7 +16,8 @@ use std::io::{Cursor, Read, Seek, SeekFrom};
 
 use byteorder::ReadBytesExt;
 
-pub(in crate::mp4) fn parse_ilst<R>(reader: &mut R, len: u64) -> Result<Ilst>
+// `depth` is the depth of the `ilst` atom, and `len` is the length of its content
+pub(in crate::mp4) fn parse_ilst<R>(reader: &mut R, len: u64, depth: usize) -> Result<Ilst>
 where
 	R: Read,
 {
@@ -27,7 +28,16 @@ where
 
 	let mut tag = Ilst::default();
 
-	while let Ok(atom) = AtomInfo::read(&mut cursor) {
+	loop {
+		let remaining = len.saturating_sub(cursor.position());
+		let atom = match AtomInfo::read_child(&mut cursor, remaining, depth) {
+			Ok(atom) => atom,
+			Err(_) => break,
+		};
+
+		// The remaining length of the atom's content, used to verify the `data` atoms
+		let remaining = (atom.start + atom.len).saturating_sub(cursor.position());
+
 		let ident = match atom.ident {
 			AtomIdent::Fourcc(ref fourcc) => match fourcc {
 				b"free" | b"skip" => {
@@ -35,12 +45,12 @@ where
 					continue;
 				},
 				b"covr" => {
-					handle_covr(&mut cursor, &mut tag)?;
+					handle_covr(&mut cursor, &mut tag, &atom)?;
 					continue;
 				},
 				// Upgrade this to a \xa9gen atom
 				b"gnre" => {
-					let content = parse_data(&mut cursor)?;
+					let content = parse_data(&mut cursor, remaining, atom.depth)?;
 
 					if let Some(AtomData::Unknown {
 						code: BE_UNSIGNED_INTEGER | 0,
@@ -64,7 +74,9 @@ where
 				// Special case the "Album ID", as it has the code "BE signed integer" (21), but
 				// must be interpreted as a "BE 64-bit Signed Integer" (74)
 				b"plID" => {
-					if let Some((code, content)) = parse_data_inner(&mut cursor)? {
+					if let Some((code, content)) =
+						parse_data_inner(&mut cursor, remaining, atom.depth)?
+					{
 						if (code == BE_SIGNED_INTEGER || code == BE_64BIT_SIGNED_INTEGER)
 							&& content.len() == 8
 						{
@@ -85,7 +97,7 @@ where
 			ident => ident,
 		};
 
-		if let Some(data) = parse_data(&mut cursor)? {
+		if let Some(data) = parse_data(&mut cursor, remaining, atom.depth)? {
 			tag.atoms.push(Atom { ident, data })
 		}
 	}
@@ -93,11 +105,11 @@ where
 	Ok(tag)
 }
 
-fn parse_data<R>(data: &mut R) -> Result<Option<AtomData>>
+fn parse_data<R>(data: &mut R, reader_size: u64, parent_depth: usize) -> Result<Option<AtomData>>
 where
 	R: Read + Seek,
 {
-	if let Some((flags, content)) = parse_data_inner(data)? {
+	if let Some((flags, content)) = parse_data_inner(data, reader_size, parent_depth)? {
 		// https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/Metadata/Metadata.html#//apple_ref/doc/uid/TP40000939-CH1-SW35
 		let value = match flags {
 			UTF8 => AtomData::UTF8(String::from_utf8(content)?),
@@ -116,11 +128,15 @@ where
 	Ok(None)
 }
 
-fn parse_data_inner<R>(data: &mut R) -> Result<Option<(u32, Vec<u8>)>>
+fn parse_data_inner<R>(
+	data: &mut R,
+	reader_size: u64,
+	parent_depth: usize,
+) -> Result<Option<(u32, Vec<u8>)>>
 where
 	R: Read + Seek,
 {
-	let atom = AtomInfo::read(data)?;
+	let atom = AtomInfo::read_child(data, reader_size, parent_depth)?;
 
 	match atom.ident {
 		AtomIdent::Fourcc(ref name) if name == b"data" => {},
@@ -182,39 +198,45 @@ fn parse_int(bytes: &[u8]) -> Result<i32> {
 	})
 }
 
-fn handle_covr(reader: &mut Cursor<Vec<u8>>, tag: &mut Ilst) -> Result<()> {
-	if let Some(value) = parse_data(reader)? {
-		let (mime_type, data) = match value {
-			AtomData::Unknown { code, data } => match code {
-				// Type 0 is implicit
-				RESERVED => (MimeType::None, data),
-				// GIF is deprecated
-				12 => (MimeType::Gif, data),
-				JPEG => (MimeType::Jpeg, data),
-				PNG => (MimeType::Png, data),
-				BMP => (MimeType::Bmp, data),
+fn handle_covr(reader: &mut Cursor<Vec<u8>>, tag: &mut Ilst, atom: &AtomInfo) -> Result<()> {
+	let end = atom.start + atom.len;
+
+	// Multiple pictures can be stored as separate `data` atoms
+	while reader.position() < end {
+		let remaining = end - reader.position();
+		if let Some(value) = parse_data(reader, remaining, atom.depth)? {
+			let (mime_type, data) = match value {
+				AtomData::Unknown { code, data } => match code {
+					// Type 0 is implicit, so the format has to be guessed from the data
+					RESERVED => (MimeType::from_bytes(&data).unwrap_or(MimeType::None), data),
+					// GIF is deprecated
+					12 => (MimeType::Gif, data),
+					JPEG => (MimeType::Jpeg, data),
+					PNG => (MimeType::Png, data),
+					BMP => (MimeType::Bmp, data),
+					_ => {
+						return Err(LoftyError::new(ErrorKind::BadAtom(
+							"\"covr\" atom has an unknown type",
+						)))
+					},
+				},
 				_ => {
 					return Err(LoftyError::new(ErrorKind::BadAtom(
 						"\"covr\" atom has an unknown type",
 					)))
 				},
-			},
-			_ => {
-				return Err(LoftyError::new(ErrorKind::BadAtom(
-					"\"covr\" atom has an unknown type",
-				)))
-			},
-		};
-
-		tag.atoms.push(Atom {
-			ident: AtomIdent::Fourcc(*b"covr"),
-			data: AtomData::Picture(Picture {
-				pic_type: PictureType::Other,
-				mime_type,
-				description: None,
-				data: Cow::from(data),
-			}),
-		});
+			};
+
+			tag.atoms.push(Atom {
+				ident: AtomIdent::Fourcc(*b"covr"),
+				data: AtomData::Picture(Picture {
+					pic_type: PictureType::CoverFront,
+					mime_type,
+					description: None,
+					data: Cow::from(data),
+				}),
+			});
+		}
 	}
 
 	Ok(())
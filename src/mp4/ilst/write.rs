@@ -7,16 +7,25 @@ use crate::mp4::ilst::{AtomIdentRef, AtomRef};
 use crate::mp4::moov::Moov;
 use crate::mp4::read::{atom_tree, meta_is_full, nested_atom, verify_mp4};
 use crate::picture::{MimeType, Picture};
+use crate::probe::ParseContext;
+use crate::traits::FileLike;
 
-use std::fs::File;
-use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::io::{Cursor, Seek, SeekFrom, Write};
 
 use byteorder::{BigEndian, WriteBytesExt};
 
-pub(in crate) fn write_to(data: &mut File, tag: &mut IlstRef<'_>) -> Result<()> {
-	verify_mp4(data)?;
+pub(crate) fn write_to<F: FileLike>(data: &mut F, tag: &mut IlstRef<'_>) -> Result<()> {
+	// The length is needed to verify the top-level atoms
+	let start = data.stream_position()?;
+	let file_length = data.seek(SeekFrom::End(0))?;
+
+	data.seek(SeekFrom::Start(start))?;
+
+	verify_mp4(data, file_length - start)?;
 
-	let moov = Moov::find(data)?;
+	let pos = data.stream_position()?;
+
+	let moov = Moov::find(data, file_length - pos, &mut ParseContext::default())?;
 	let pos = data.stream_position()?;
 
 	data.rewind()?;
@@ -30,7 +39,7 @@ pub(in crate) fn write_to(data: &mut File, tag: &mut IlstRef<'_>) -> Result<()>
 	let ilst = build_ilst(&mut tag.atoms)?;
 	let remove_tag = ilst.is_empty();
 
-	let udta = nested_atom(&mut cursor, moov.len, b"udta")?;
+	let udta = nested_atom(&mut cursor, &moov, b"udta")?;
 
 	// Nothing to do
 	if remove_tag && udta.is_none() {
@@ -47,16 +56,17 @@ pub(in crate) fn write_to(data: &mut File, tag: &mut IlstRef<'_>) -> Result<()>
 		existing_udta_size = udta.len;
 		new_udta_size = existing_udta_size;
 
-		let meta = nested_atom(&mut cursor, udta.len, b"meta")?;
+		let meta = nested_atom(&mut cursor, &udta, b"meta")?;
 		match meta {
 			Some(meta) => {
 				// We may encounter a non-full `meta` atom
-				meta_is_full(&mut cursor)?;
+				let full_meta_atom = meta_is_full(&mut cursor)?;
 
 				// We can use the existing `udta` and `meta` atoms
 				save_to_existing(
 					&mut cursor,
 					(meta, udta),
+					full_meta_atom,
 					&mut new_udta_size,
 					ilst,
 					remove_tag,
@@ -122,7 +132,7 @@ pub(in crate) fn write_to(data: &mut File, tag: &mut IlstRef<'_>) -> Result<()>
 	)?;
 
 	data.rewind()?;
-	data.set_len(0)?;
+	data.truncate(0)?;
 	data.write_all(&cursor.into_inner())?;
 
 	Ok(())
@@ -131,6 +141,7 @@ pub(in crate) fn write_to(data: &mut File, tag: &mut IlstRef<'_>) -> Result<()>
 fn save_to_existing(
 	cursor: &mut Cursor<Vec<u8>>,
 	(meta, udta): (AtomInfo, AtomInfo),
+	full_meta_atom: bool,
 	new_udta_size: &mut u64,
 	ilst: Vec<u8>,
 	remove_tag: bool,
@@ -138,7 +149,13 @@ fn save_to_existing(
 	let replacement;
 	let range;
 
-	let (ilst_idx, tree) = atom_tree(cursor, meta.len - 4, b"ilst")?;
+	// A full `meta` atom has an extra 4 bytes (version + flags) before its children
+	let tree_len = if full_meta_atom {
+		meta.len - 4
+	} else {
+		meta.len
+	};
+	let (ilst_idx, tree) = atom_tree(cursor, tree_len, meta.depth, b"ilst")?;
 
 	if tree.is_empty() {
 		// Nothing to do
@@ -302,18 +319,29 @@ pub(super) fn build_ilst(atoms: &mut dyn Iterator<Item = AtomRef<'_>>) -> Result
 	let mut writer = Cursor::new(vec![0, 0, 0, 0, b'i', b'l', b's', b't']);
 	writer.seek(SeekFrom::End(0))?;
 
-	for atom in peek {
+	// All pictures have to be stored in a single `covr` atom
+	let (pictures, atoms): (Vec<_>, Vec<_>) =
+		peek.partition(|a| matches!(a.ident, AtomIdentRef::Fourcc(ref f) if f == b"covr"));
+
+	let mut groups = atoms.into_iter().map(|a| vec![a]).collect::<Vec<_>>();
+	if !pictures.is_empty() {
+		groups.push(pictures);
+	}
+
+	for group in groups {
 		let start = writer.stream_position()?;
 
 		// Empty size, we get it later
 		writer.write_all(&[0; 4])?;
 
-		match atom.ident {
+		match group[0].ident {
 			AtomIdentRef::Fourcc(ref fourcc) => writer.write_all(fourcc)?,
 			AtomIdentRef::Freeform { mean, name } => write_freeform(mean, name, &mut writer)?,
 		}
 
-		write_atom_data(&atom.data, &mut writer)?;
+		for atom in &group {
+			write_atom_data(&atom.ident, &atom.data, &mut writer)?;
+		}
 
 		let end = writer.stream_position()?;
 
@@ -357,41 +385,87 @@ fn write_freeform(mean: &str, name: &str, writer: &mut Cursor<Vec<u8>>) -> Resul
 	Ok(())
 }
 
-fn write_atom_data(value: &AtomDataRef<'_>, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
+fn write_atom_data(
+	ident: &AtomIdentRef<'_>,
+	value: &AtomDataRef<'_>,
+	writer: &mut Cursor<Vec<u8>>,
+) -> Result<()> {
 	match value {
 		AtomDataRef::UTF8(text) => write_data(1, text.as_bytes(), writer),
 		AtomDataRef::UTF16(text) => write_data(2, text.as_bytes(), writer),
 		AtomDataRef::Picture(pic) => write_picture(pic, writer),
-		AtomDataRef::SignedInteger(int) => write_signed_int(*int, writer),
-		AtomDataRef::UnsignedInteger(uint) => write_unsigned_int(*uint, writer),
+		AtomDataRef::SignedInteger(int) => {
+			let size = int_size(ident).unwrap_or(match *int {
+				0..=255 => 1,
+				-32768..=32767 => 2,
+				_ => 4,
+			});
+
+			let fits = match size {
+				1 => u8::try_from(*int).is_ok(),
+				2 => i16::try_from(*int).is_ok(),
+				_ => true,
+			};
+
+			write_int(21, int.to_be_bytes(), size, fits, writer)
+		},
+		AtomDataRef::UnsignedInteger(uint) => {
+			let size = int_size(ident).unwrap_or(match *uint {
+				0..=255 => 1,
+				256..=65535 => 2,
+				_ => 4,
+			});
+
+			let fits = match size {
+				1 => u8::try_from(*uint).is_ok(),
+				2 => u16::try_from(*uint).is_ok(),
+				_ => true,
+			};
+
+			write_int(22, uint.to_be_bytes(), size, fits, writer)
+		},
 		AtomDataRef::Unknown { code, data } => write_data(*code, data, writer),
 	}
 }
 
-fn write_signed_int(int: i32, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
-	let start_pos = int.trailing_zeros() as usize;
-	write_int(21, int.to_be_bytes(), start_pos, writer)
-}
-
-fn write_unsigned_int(uint: u32, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
-	let start_pos = uint.trailing_zeros() as usize;
-	write_int(22, uint.to_be_bytes(), start_pos, writer)
+// iTunes expects these atoms to have an exact size, regardless of their value
+fn int_size(ident: &AtomIdentRef<'_>) -> Option<usize> {
+	match ident {
+		AtomIdentRef::Fourcc(fourcc) => match fourcc {
+			b"tmpo" | b"\xa9mvi" | b"\xa9mvc" => Some(2),
+			b"cpil" | b"pgap" | b"pcst" | b"rtng" | b"stik" | b"hdvd" | b"shwm" => Some(1),
+			_ => None,
+		},
+		AtomIdentRef::Freeform { .. } => None,
+	}
 }
 
 fn write_int(
 	flags: u32,
 	bytes: [u8; 4],
-	mut start_pos: usize,
+	size: usize,
+	fits: bool,
 	writer: &mut Cursor<Vec<u8>>,
 ) -> Result<()> {
-	if start_pos == 1 || start_pos == 4 {
-		start_pos = 0;
+	// Truncating the value would silently wrap it
+	if !fits {
+		return Err(FileEncodingError::new(
+			FileType::MP4,
+			"Attempted to write an integer that cannot fit in its atom",
+		)
+		.into());
 	}
-	write_data(flags, &bytes[start_pos..], writer)
+
+	write_data(flags, &bytes[4 - size..], writer)
 }
 
 fn write_picture(picture: &Picture, writer: &mut Cursor<Vec<u8>>) -> Result<()> {
-	match picture.mime_type {
+	let mime_type = match picture.mime_type {
+		MimeType::None => MimeType::from_bytes(&picture.data).unwrap_or(MimeType::None),
+		ref mime_type => mime_type.clone(),
+	};
+
+	match mime_type {
 		// GIF is deprecated
 		MimeType::Gif => write_data(12, &picture.data, writer),
 		MimeType::Jpeg => write_data(13, &picture.data, writer),
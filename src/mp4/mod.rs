@@ -4,15 +4,18 @@
 //!
 //! The only supported tag format is [`Ilst`].
 mod atom_info;
+mod chapter;
 mod moov;
 mod properties;
-mod read;
+pub(crate) mod read;
 mod trak;
 
 use crate::error::Result;
 use crate::file::{AudioFile, FileType, TaggedFile};
+use crate::probe::ParseContext;
 use crate::properties::FileProperties;
 use crate::tag::TagType;
+use crate::traits::FileLike;
 
 use std::io::{Read, Seek};
 
@@ -25,6 +28,7 @@ cfg_if::cfg_if! {
 		pub use atom_info::AtomIdent;
 		pub use ilst::atom::{Atom, AtomData, AdvisoryRating};
 		pub use ilst::Ilst;
+		pub use ilst::gapless::GaplessInfo;
 
 		/// This module contains the codes for all of the [Well-known data types](https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/Metadata/Metadata.html#//apple_ref/doc/uid/TP40000939-CH1-SW34)
 		pub mod constants {
@@ -33,6 +37,8 @@ cfg_if::cfg_if! {
 	}
 }
 
+pub use crate::limits::{max_atom_depth, set_max_atom_depth};
+pub use crate::mp4::chapter::Chapter;
 pub use crate::mp4::properties::{AudioObjectType, Mp4Codec, Mp4Properties};
 
 /// An MP4 file
@@ -42,6 +48,8 @@ pub struct Mp4File {
 	#[cfg(feature = "mp4_ilst")]
 	/// The parsed `ilst` (metadata) atom, if it exists
 	pub(crate) ilst: Option<Ilst>,
+	/// The file's chapters
+	pub(crate) chapters: Vec<Chapter>,
 	/// The file's audio properties
 	pub(crate) properties: Mp4Properties,
 }
@@ -51,6 +59,8 @@ impl From<Mp4File> for TaggedFile {
 		Self {
 			ty: FileType::MP4,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			tags: {
 				#[cfg(feature = "mp4_ilst")]
 				if let Some(ilst) = input.ilst {
@@ -73,13 +83,21 @@ impl AudioFile for Mp4File {
 	where
 		R: Read + Seek,
 	{
-		read::read_from(reader, read_properties)
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
 	}
 
 	fn properties(&self) -> &Self::Properties {
 		&self.properties
 	}
 
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "mp4_ilst")]
+			ilst
+		}
+	}
+
 	#[allow(unreachable_code)]
 	fn contains_tag(&self) -> bool {
 		#[cfg(feature = "mp4_ilst")]
@@ -102,6 +120,31 @@ impl Mp4File {
 	pub fn ftyp(&self) -> &str {
 		self.ftyp.as_ref()
 	}
+
+	/// Returns the file's chapters
+	///
+	/// These are read from a QuickTime chapter track if one exists, otherwise from a Nero `chpl` atom.
+	pub fn chapters(&self) -> &[Chapter] {
+		&self.chapters
+	}
+
+	#[cfg(feature = "mp4_ilst")]
+	/// Returns the gapless playback information, if the file has an [`Ilst`]
+	///
+	/// See [`Ilst::gapless_info`]
+	pub fn gapless_info(&self) -> Option<GaplessInfo> {
+		self.ilst.as_ref().and_then(Ilst::gapless_info)
+	}
+
+	#[cfg(feature = "mp4_ilst")]
+	/// Sets the gapless playback information, creating an [`Ilst`] if necessary
+	///
+	/// The file must still be saved with [`AudioFile::save_to`].
+	pub fn set_gapless_info(&mut self, info: GaplessInfo) {
+		self.ilst
+			.get_or_insert_with(Ilst::default)
+			.set_gapless_info(info)
+	}
 }
 
 impl Mp4File {
@@ -1,4 +1,5 @@
 use super::atom_info::{AtomIdent, AtomInfo};
+use super::chapter::{read_nero_chapters, Chapter};
 use super::read::skip_unneeded;
 use super::trak::Trak;
 #[cfg(feature = "mp4_ilst")]
@@ -6,32 +7,41 @@ use super::{
 	ilst::{read::parse_ilst, Ilst},
 	read::meta_is_full,
 };
-use crate::error::{FileDecodingError, Result};
+use crate::error::{FileDecodingError, Result, WarningCategory};
 use crate::file::FileType;
+use crate::probe::ParseContext;
 
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 
 pub(crate) struct Moov {
 	pub(crate) traks: Vec<Trak>,
 	#[cfg(feature = "mp4_ilst")]
 	// Represents a parsed moov.udta.meta.ilst since we don't need anything else
 	pub(crate) meta: Option<Ilst>,
+	// Nero chapters from moov.udta.chpl
+	pub(crate) chapters: Vec<Chapter>,
 }
 
 impl Moov {
-	pub(crate) fn find<R>(data: &mut R) -> Result<AtomInfo>
+	// `reader_size` is the length of the stream, starting at the current position
+	pub(crate) fn find<R>(
+		data: &mut R,
+		mut reader_size: u64,
+		context: &mut ParseContext,
+	) -> Result<AtomInfo>
 	where
 		R: Read + Seek,
 	{
 		let mut moov = None;
 
-		while let Ok(atom) = AtomInfo::read(data) {
+		while let Ok(atom) = AtomInfo::read_top_level(data, reader_size, context) {
 			if atom.ident == AtomIdent::Fourcc(*b"moov") {
 				moov = Some(atom);
 				break;
 			}
 
 			skip_unneeded(data, atom.extended, atom.len)?;
+			reader_size -= atom.len;
 		}
 
 		if let Some(moov) = moov {
@@ -41,21 +51,59 @@ impl Moov {
 		}
 	}
 
-	pub(crate) fn parse<R>(data: &mut R, read_properties: bool) -> Result<Self>
+	pub(crate) fn parse<R>(
+		data: &mut R,
+		moov: &AtomInfo,
+		read_tags: bool,
+		context: &mut ParseContext,
+	) -> Result<Self>
 	where
 		R: Read + Seek,
 	{
 		let mut traks = Vec::new();
 		#[cfg(feature = "mp4_ilst")]
 		let mut meta = None;
+		let mut chapters = Vec::new();
+
+		let moov_end = moov.start + moov.len;
+		let mut pos = data.stream_position()?;
+
+		while let Ok(atom) = AtomInfo::read_child(data, moov_end.saturating_sub(pos), moov.depth) {
+			pos = atom.start + atom.len;
 
-		while let Ok(atom) = AtomInfo::read(data) {
 			if let AtomIdent::Fourcc(fourcc) = atom.ident {
 				match &fourcc {
-					b"trak" if read_properties => traks.push(Trak::parse(data, &atom)?),
-					#[cfg(feature = "mp4_ilst")]
-					b"udta" => {
-						meta = meta_from_udta(data, atom.len - 8)?;
+					// The tracks are needed for both the properties and chapters
+					b"trak" => traks.push(Trak::parse(data, &atom)?),
+					b"udta" if read_tags => {
+						let udta_end = atom.start + atom.len;
+
+						let mut pos = data.stream_position()?;
+
+						while pos < udta_end {
+							let child = AtomInfo::read_child(data, udta_end - pos, atom.depth)?;
+							pos = child.start + child.len;
+
+							match child.ident {
+								#[cfg(feature = "mp4_ilst")]
+								AtomIdent::Fourcc(ref fourcc) if fourcc == b"meta" => {
+									meta = meta_from_meta(data, &child)?;
+								},
+								AtomIdent::Fourcc(ref fourcc) if fourcc == b"chpl" => {
+									chapters =
+										read_nero_chapters(data, &child).unwrap_or_else(|_| {
+											context.warn(
+											WarningCategory::Tag,
+											"Skipping Nero chapters, the \"chpl\" atom is invalid",
+										);
+											Vec::new()
+										});
+								},
+								_ => {},
+							}
+
+							data.seek(SeekFrom::Start(pos))?;
+						}
 					},
 					_ => skip_unneeded(data, atom.extended, atom.len)?,
 				}
@@ -70,48 +118,26 @@ impl Moov {
 			traks,
 			#[cfg(feature = "mp4_ilst")]
 			meta,
+			chapters,
 		})
 	}
 }
 
 #[cfg(feature = "mp4_ilst")]
-fn meta_from_udta<R>(data: &mut R, len: u64) -> Result<Option<Ilst>>
+fn meta_from_meta<R>(data: &mut R, meta: &AtomInfo) -> Result<Option<Ilst>>
 where
 	R: Read + Seek,
 {
-	let mut read = 8;
-	let mut meta = (false, 0_u64);
-
-	while read < len {
-		let atom = AtomInfo::read(data)?;
-
-		if atom.ident == AtomIdent::Fourcc(*b"meta") {
-			meta = (true, atom.len);
-			break;
-		}
-
-		read += atom.len;
-		skip_unneeded(data, atom.extended, atom.len)?;
-	}
-
-	if !meta.0 {
-		return Ok(None);
-	}
-
 	// It's possible for the `meta` atom to be non-full,
 	// so we have to check for that case
 	let full_meta_atom = meta_is_full(data)?;
 
-	if full_meta_atom {
-		read = 12;
-	} else {
-		read = 8;
-	}
+	let mut read = if full_meta_atom { 12 } else { 8 };
 
 	let mut islt = (false, 0_u64);
 
-	while read < meta.1 {
-		let atom = AtomInfo::read(data)?;
+	while read < meta.len {
+		let atom = AtomInfo::read_child(data, meta.len - read, meta.depth)?;
 
 		if atom.ident == AtomIdent::Fourcc(*b"ilst") {
 			islt = (true, atom.len);
@@ -123,7 +149,7 @@ where
 	}
 
 	if islt.0 {
-		return parse_ilst(data, islt.1 - 8).map(Some);
+		return parse_ilst(data, islt.1 - 8, meta.depth + 1).map(Some);
 	}
 
 	Ok(None)
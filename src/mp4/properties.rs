@@ -239,7 +239,7 @@ where
 
 		let mut read = 8;
 		while read < mdia.len {
-			let atom = AtomInfo::read(data)?;
+			let atom = AtomInfo::read_child(data, mdia.len - read, mdia.depth)?;
 			read += atom.len;
 
 			if let AtomIdent::Fourcc(fourcc) = atom.ident {
@@ -322,12 +322,12 @@ where
 	if let Some(minf) = minf {
 		data.seek(SeekFrom::Start(minf.start + 8))?;
 
-		if let Some(stbl) = nested_atom(data, minf.len, b"stbl")? {
-			if let Some(stsd) = nested_atom(data, stbl.len, b"stsd")? {
-				let mut stsd = try_vec![0; (stsd.len - 8) as usize];
-				data.read_exact(&mut stsd)?;
+		if let Some(stbl) = nested_atom(data, &minf, b"stbl")? {
+			if let Some(stsd) = nested_atom(data, &stbl, b"stsd")? {
+				let mut stsd_content = try_vec![0; (stsd.len - 8) as usize];
+				data.read_exact(&mut stsd_content)?;
 
-				let mut stsd_reader = Cursor::new(&*stsd);
+				let mut stsd_reader = Cursor::new(&*stsd_content);
 
 				// Skipping 8 bytes
 				// Version (1)
@@ -335,12 +335,20 @@ where
 				// Number of entries (4)
 				stsd_reader.seek(SeekFrom::Current(8))?;
 
-				let atom = AtomInfo::read(&mut stsd_reader)?;
+				let atom = AtomInfo::read_child(
+					&mut stsd_reader,
+					(stsd_content.len() as u64).saturating_sub(8),
+					stsd.depth,
+				)?;
 
 				if let AtomIdent::Fourcc(ref fourcc) = atom.ident {
 					match fourcc {
-						b"mp4a" => mp4a_properties(&mut stsd_reader, &mut properties, file_length)?,
-						b"alac" => alac_properties(&mut stsd_reader, &mut properties, file_length)?,
+						b"mp4a" => {
+							mp4a_properties(&mut stsd_reader, &atom, &mut properties, file_length)?
+						},
+						b"alac" => {
+							alac_properties(&mut stsd_reader, &atom, &mut properties, file_length)?
+						},
 						// Maybe do these?
 						// TODO: dfla (https://github.com/xiph/flac/blob/master/doc/isoflac.txt)
 						// TODO: dops
@@ -355,7 +363,12 @@ where
 	Ok(properties)
 }
 
-fn mp4a_properties<R>(stsd: &mut R, properties: &mut Mp4Properties, file_length: u64) -> Result<()>
+fn mp4a_properties<R>(
+	stsd: &mut R,
+	mp4a: &AtomInfo,
+	properties: &mut Mp4Properties,
+	file_length: u64,
+) -> Result<()>
 where
 	R: Read + Seek,
 {
@@ -389,10 +402,11 @@ where
 
 	properties.sample_rate = stsd.read_u32::<BigEndian>()?;
 
-	stsd.seek(SeekFrom::Current(2))?;
+	let pos = stsd.seek(SeekFrom::Current(2))?;
 
 	// This information is often followed by an esds (elementary stream descriptor) atom containing the bitrate
-	if let Ok(esds) = AtomInfo::read(stsd) {
+	let remaining = (mp4a.start + mp4a.len).saturating_sub(pos);
+	if let Ok(esds) = AtomInfo::read_child(stsd, remaining, mp4a.depth) {
 		// There are 4 bytes we expect to be zeroed out
 		// Version (1)
 		// Flags (3)
@@ -524,7 +538,12 @@ where
 	Ok(())
 }
 
-fn alac_properties<R>(data: &mut R, properties: &mut Mp4Properties, file_length: u64) -> Result<()>
+fn alac_properties<R>(
+	data: &mut R,
+	alac: &AtomInfo,
+	properties: &mut Mp4Properties,
+	file_length: u64,
+) -> Result<()>
 where
 	R: Read + Seek,
 {
@@ -542,7 +561,8 @@ where
 	// First alac atom's content (28)
 	data.seek(SeekFrom::Start(44))?;
 
-	if let Ok(alac) = AtomInfo::read(data) {
+	// This leaves 36 bytes for the second alac atom
+	if let Ok(alac) = AtomInfo::read_child(data, 36, alac.depth) {
 		if alac.ident == AtomIdent::Fourcc(*b"alac") {
 			properties.codec = Mp4Codec::ALAC;
 
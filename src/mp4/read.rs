@@ -1,20 +1,22 @@
 use super::atom_info::{AtomIdent, AtomInfo};
+use super::chapter::read_quicktime_chapters;
 use super::moov::Moov;
 use super::properties::Mp4Properties;
 use super::Mp4File;
-use crate::error::{ErrorKind, FileDecodingError, LoftyError, Result};
+use crate::error::{ErrorKind, FileDecodingError, LoftyError, Result, WarningCategory};
 use crate::file::FileType;
+use crate::probe::ParseContext;
 
 use std::io::{Read, Seek, SeekFrom};
 
 #[cfg(feature = "mp4_ilst")]
 use byteorder::{BigEndian, ReadBytesExt};
 
-pub(in crate::mp4) fn verify_mp4<R>(data: &mut R) -> Result<String>
+pub(in crate::mp4) fn verify_mp4<R>(data: &mut R, reader_size: u64) -> Result<String>
 where
 	R: Read + Seek,
 {
-	let atom = AtomInfo::read(data)?;
+	let atom = AtomInfo::read(data, reader_size)?;
 
 	if atom.ident != AtomIdent::Fourcc(*b"ftyp") {
 		return Err(LoftyError::new(ErrorKind::UnknownFormat));
@@ -35,21 +37,51 @@ where
 		.map_err(|_| LoftyError::new(ErrorKind::BadAtom("Unable to parse \"ftyp\"'s major brand")))
 }
 
-pub(crate) fn read_from<R>(data: &mut R, read_properties: bool) -> Result<Mp4File>
+pub(crate) fn read_from<R>(
+	data: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<Mp4File>
 where
 	R: Read + Seek,
 {
-	let ftyp = verify_mp4(data)?;
+	// The length is needed to verify the top-level atoms
+	let start = data.stream_position()?;
+	let file_length = data.seek(SeekFrom::End(0))?;
 
-	Moov::find(data)?;
-	let moov = Moov::parse(data, read_properties)?;
+	data.seek(SeekFrom::Start(start))?;
 
-	let file_length = data.seek(SeekFrom::End(0))?;
+	let ftyp = verify_mp4(data, file_length - start)?;
+
+	let pos = data.stream_position()?;
+
+	let moov = Moov::find(data, file_length - pos, context)?;
+	let moov = Moov::parse(data, &moov, read_tags, context)?;
+
+	// QuickTime chapters are preferred, falling back to Nero chapters
+	let mut chapters = Vec::new();
+
+	if read_tags {
+		// Broken chapters shouldn't prevent the rest of the file from being read
+		chapters = read_quicktime_chapters(data, &moov.traks).unwrap_or_else(|_| {
+			context.warn(
+				WarningCategory::Tag,
+				"Skipping QuickTime chapters, the chapter track is invalid",
+			);
+			Vec::new()
+		});
+	}
+
+	if chapters.is_empty() {
+		chapters = moov.chapters;
+	}
 
 	Ok(Mp4File {
 		ftyp,
 		#[cfg(feature = "mp4_ilst")]
 		ilst: moov.meta,
+		chapters,
 		properties: if read_properties {
 			super::properties::read_properties(data, &moov.traks, file_length)?
 		} else {
@@ -65,7 +97,17 @@ where
 	if ext {
 		let pos = data.stream_position()?;
 
-		if let (pos, false) = pos.overflowing_add(len - 8) {
+		// The extended header is 16 bytes long (size + identifier + extended size)
+		let remaining = match len.checked_sub(16) {
+			Some(remaining) => remaining,
+			None => {
+				return Err(LoftyError::new(ErrorKind::BadAtom(
+					"Found an extended atom with an invalid length (< 16)",
+				)))
+			},
+		};
+
+		if let (pos, false) = pos.overflowing_add(remaining) {
 			data.seek(SeekFrom::Start(pos))?;
 		} else {
 			return Err(LoftyError::new(ErrorKind::TooMuchData));
@@ -77,15 +119,21 @@ where
 	Ok(())
 }
 
-pub(super) fn nested_atom<R>(data: &mut R, len: u64, expected: &[u8]) -> Result<Option<AtomInfo>>
+pub(super) fn nested_atom<R>(
+	data: &mut R,
+	parent: &AtomInfo,
+	expected: &[u8],
+) -> Result<Option<AtomInfo>>
 where
 	R: Read + Seek,
 {
+	let len = parent.len;
+
 	let mut read = 8;
 	let mut ret = None;
 
 	while read < len {
-		let atom = AtomInfo::read(data)?;
+		let atom = AtomInfo::read_child(data, len - read, parent.depth)?;
 
 		match atom.ident {
 			AtomIdent::Fourcc(ref fourcc) if fourcc == expected => {
@@ -104,7 +152,12 @@ where
 
 #[cfg(feature = "mp4_ilst")]
 // Creates a tree of nested atoms
-pub(super) fn atom_tree<R>(data: &mut R, len: u64, up_to: &[u8]) -> Result<(usize, Vec<AtomInfo>)>
+pub(super) fn atom_tree<R>(
+	data: &mut R,
+	len: u64,
+	depth: usize,
+	up_to: &[u8],
+) -> Result<(usize, Vec<AtomInfo>)>
 where
 	R: Read + Seek,
 {
@@ -115,7 +168,7 @@ where
 	let mut i = 0;
 
 	while read < len {
-		let atom = AtomInfo::read(data)?;
+		let atom = AtomInfo::read_child(data, len - read, depth)?;
 
 		skip_unneeded(data, atom.extended, atom.len)?;
 		read += atom.len;
@@ -4,7 +4,13 @@ use crate::error::Result;
 
 use std::io::{Read, Seek, SeekFrom};
 
+use byteorder::{BigEndian, ReadBytesExt};
+
 pub(crate) struct Trak {
+	/// The track ID from `tkhd`
+	pub(crate) id: Option<u32>,
+	/// The IDs of the tracks referenced by `tref.chap`
+	pub(crate) chapter_ids: Vec<u32>,
 	pub(crate) mdia: Option<AtomInfo>,
 }
 
@@ -13,23 +19,62 @@ impl Trak {
 	where
 		R: Read + Seek,
 	{
+		let mut id = None;
+		let mut chapter_ids = Vec::new();
 		let mut mdia = None;
 
 		let mut read = 8;
 
 		while read < trak.len {
-			let atom = AtomInfo::read(data)?;
+			let atom = AtomInfo::read_child(data, trak.len - read, trak.depth)?;
+			read += atom.len;
 
-			if atom.ident == AtomIdent::Fourcc(*b"mdia") {
-				mdia = Some(atom);
-				data.seek(SeekFrom::Current((trak.len - read - 8) as i64))?;
-				break;
-			}
+			let end = atom.start + atom.len;
 
-			skip_unneeded(data, atom.extended, atom.len)?;
-			read += atom.len;
+			match atom.ident {
+				AtomIdent::Fourcc(ref fourcc) if fourcc == b"tkhd" => {
+					let version = data.read_u8()?;
+					let _flags = data.read_u24::<BigEndian>()?;
+
+					// Skip the creation and modification times
+					if version == 1 {
+						data.seek(SeekFrom::Current(16))?;
+					} else {
+						data.seek(SeekFrom::Current(8))?;
+					}
+
+					id = Some(data.read_u32::<BigEndian>()?);
+					data.seek(SeekFrom::Start(end))?;
+				},
+				AtomIdent::Fourcc(ref fourcc) if fourcc == b"tref" => {
+					let mut pos = data.stream_position()?;
+
+					while pos < end {
+						let reference = AtomInfo::read_child(data, end - pos, atom.depth)?;
+						pos = reference.start + reference.len;
+
+						if reference.ident == AtomIdent::Fourcc(*b"chap") {
+							let header_len = if reference.extended { 16 } else { 8 };
+							for _ in 0..(reference.len - header_len) / 4 {
+								chapter_ids.push(data.read_u32::<BigEndian>()?);
+							}
+						}
+
+						data.seek(SeekFrom::Start(pos))?;
+					}
+				},
+				AtomIdent::Fourcc(ref fourcc) if fourcc == b"mdia" => {
+					mdia = Some(atom);
+					data.seek(SeekFrom::Start(end))?;
+				},
+				_ => skip_unneeded(data, atom.extended, atom.len)?,
+			}
 		}
 
-		Ok(Self { mdia })
+		Ok(Self {
+			id,
+			chapter_ids,
+			mdia,
+		})
 	}
 }
@@ -0,0 +1,114 @@
+//! Musepack specific items
+//!
+//! ## File notes
+//!
+//! Stream versions 7 and 8 are supported.
+//!
+//! The only tag allowed by spec is `APEv2`, although `ID3v1` tags are also commonly found.
+mod properties;
+pub(crate) mod read;
+pub(crate) mod write;
+
+#[cfg(feature = "ape")]
+use crate::ape::tag::ApeTag;
+use crate::error::Result;
+use crate::file::{AudioFile, FileType, TaggedFile};
+#[cfg(feature = "id3v1")]
+use crate::id3::v1::tag::Id3v1Tag;
+use crate::probe::ParseContext;
+use crate::properties::FileProperties;
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+use std::io::{Read, Seek};
+
+pub use properties::MpcProperties;
+
+/// A Musepack file
+#[derive(Default)]
+pub struct MpcFile {
+	#[cfg(feature = "id3v1")]
+	/// An ID3v1 tag
+	pub(crate) id3v1_tag: Option<Id3v1Tag>,
+	#[cfg(feature = "ape")]
+	/// An APEv1/v2 tag
+	pub(crate) ape_tag: Option<ApeTag>,
+	/// The file's audio properties
+	pub(crate) properties: MpcProperties,
+}
+
+impl From<MpcFile> for TaggedFile {
+	#[allow(clippy::vec_init_then_push, unused_mut)]
+	fn from(input: MpcFile) -> Self {
+		let mut tags = Vec::<Option<Tag>>::with_capacity(2);
+
+		#[cfg(feature = "ape")]
+		tags.push(input.ape_tag.map(Into::into));
+		#[cfg(feature = "id3v1")]
+		tags.push(input.id3v1_tag.map(Into::into));
+
+		Self {
+			ty: FileType::Mpc,
+			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
+			tags: tags.into_iter().flatten().collect(),
+		}
+	}
+}
+
+impl AudioFile for MpcFile {
+	type Properties = MpcProperties;
+
+	fn read_from<R>(reader: &mut R, read_properties: bool) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		read::read_from(reader, true, read_properties, &mut ParseContext::default())
+	}
+
+	fn properties(&self) -> &Self::Properties {
+		&self.properties
+	}
+
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		crate::macros::save_tags! {
+			self, file;
+			#[cfg(feature = "id3v1")]
+			id3v1_tag,
+
+			#[cfg(feature = "ape")]
+			ape_tag
+		}
+	}
+
+	#[allow(unreachable_code)]
+	fn contains_tag(&self) -> bool {
+		#[cfg(feature = "ape")]
+		return self.ape_tag.is_some();
+		#[cfg(feature = "id3v1")]
+		return self.id3v1_tag.is_some();
+
+		false
+	}
+
+	fn contains_tag_type(&self, tag_type: TagType) -> bool {
+		match tag_type {
+			#[cfg(feature = "ape")]
+			TagType::Ape => self.ape_tag.is_some(),
+			#[cfg(feature = "id3v1")]
+			TagType::Id3v1 => self.id3v1_tag.is_some(),
+			_ => false,
+		}
+	}
+}
+
+impl MpcFile {
+	crate::macros::tag_methods! {
+		#[cfg(feature = "id3v1")]
+		id3v1_tag, Id3v1Tag;
+
+		#[cfg(feature = "ape")]
+		ape_tag, ApeTag
+	}
+}
@@ -0,0 +1,322 @@
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::macros::try_vec;
+use crate::properties::FileProperties;
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
+/// A Musepack file's audio properties
+pub struct MpcProperties {
+	pub(crate) version: u8,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+}
+
+impl From<MpcProperties> for FileProperties {
+	fn from(input: MpcProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: None,
+			channels: Some(input.channels),
+		}
+	}
+}
+
+impl MpcProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Musepack stream version (7 or 8)
+	pub fn version(&self) -> u8 {
+		self.version
+	}
+}
+
+const SAMPLE_RATES: [u32; 4] = [44100, 48000, 37800, 32000];
+
+// The number of samples in an SV7 frame
+const FRAME_LENGTH: u64 = 1152;
+// Used when an SV7 file isn't "true gapless"
+const DECODER_SYNTH_DELAY: u64 = 481;
+
+pub(super) fn read_properties<R>(
+	reader: &mut R,
+	stream_length: u64,
+	file_length: u64,
+) -> Result<MpcProperties>
+where
+	R: Read + Seek,
+{
+	let mut magic = [0; 4];
+	reader.read_exact(&mut magic)?;
+
+	let (mut properties, total_samples) = match magic {
+		[b'M', b'P', b'C', b'K'] => read_sv8(reader, stream_length)?,
+		[b'M', b'P', b'+', version] if version & 0x0F == 7 => read_sv7(reader)?,
+		_ => {
+			return Err(FileDecodingError::new(
+				FileType::Mpc,
+				"Unsupported stream version (only SV7 and SV8 are supported)",
+			)
+			.into())
+		},
+	};
+
+	if properties.sample_rate > 0 {
+		let length = (total_samples * 1000) / u64::from(properties.sample_rate);
+
+		if length > 0 {
+			properties.duration = Duration::from_millis(length);
+			properties.overall_bitrate = ((file_length * 8) / length) as u32;
+			properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+		}
+	}
+
+	Ok(properties)
+}
+
+// SV8 streams are made up of packets, with the stream header being stored in the "SH" packet
+//
+// http://trac.musepack.net/musepack/wiki/SV8Specification
+fn read_sv8<R>(reader: &mut R, stream_length: u64) -> Result<(MpcProperties, u64)>
+where
+	R: Read + Seek,
+{
+	// The magic signature
+	let mut offset = 4;
+
+	while offset < stream_length {
+		let mut key = [0; 2];
+		reader.read_exact(&mut key)?;
+
+		if !key.iter().all(u8::is_ascii_uppercase) {
+			return Err(
+				FileDecodingError::new(FileType::Mpc, "Found an invalid packet key").into(),
+			);
+		}
+
+		let (packet_size, size_len) = read_variable_size(reader)?;
+
+		// The size includes the key and the size itself
+		let content_size = packet_size
+			.checked_sub(2 + u64::from(size_len))
+			.ok_or_else(|| FileDecodingError::new(FileType::Mpc, "Found an invalid packet size"))?;
+
+		match &key {
+			b"SH" => {
+				if content_size > stream_length {
+					return Err(FileDecodingError::new(
+						FileType::Mpc,
+						"Stream header is larger than the stream",
+					)
+					.into());
+				}
+
+				let mut content = try_vec![0; content_size as usize];
+				reader.read_exact(&mut content)?;
+
+				return read_sv8_stream_header(&mut &*content);
+			},
+			// The stream header must come before any audio packets
+			b"AP" | b"SE" => break,
+			_ => {},
+		}
+
+		reader.seek(SeekFrom::Current(content_size as i64))?;
+		offset += packet_size;
+	}
+
+	Err(FileDecodingError::new(FileType::Mpc, "File is missing a stream header").into())
+}
+
+fn read_sv8_stream_header(reader: &mut &[u8]) -> Result<(MpcProperties, u64)> {
+	// Skip 4 bytes
+	// CRC (4)
+	reader.read_u32::<LittleEndian>()?;
+
+	let version = reader.read_u8()?;
+
+	let (sample_count, _) = read_variable_size(reader)?;
+	let (beginning_silence, _) = read_variable_size(reader)?;
+
+	// Sample frequency (3)
+	// Max used bands (5)
+	let sample_frequency_idx = reader.read_u8()? >> 5;
+	let sample_rate = match SAMPLE_RATES.get(sample_frequency_idx as usize) {
+		Some(sample_rate) => *sample_rate,
+		None => {
+			return Err(
+				FileDecodingError::new(FileType::Mpc, "Found an invalid sample frequency").into(),
+			)
+		},
+	};
+
+	// Channel count - 1 (4)
+	// Mid side stereo (1)
+	// Audio block frames (3)
+	let channels = (reader.read_u8()? >> 4) + 1;
+
+	let properties = MpcProperties {
+		version,
+		sample_rate,
+		channels,
+		..MpcProperties::default()
+	};
+
+	Ok((properties, sample_count.saturating_sub(beginning_silence)))
+}
+
+// SV7 streams have a fixed size header following the "MP+" signature
+fn read_sv7<R>(reader: &mut R) -> Result<(MpcProperties, u64)>
+where
+	R: Read,
+{
+	let frame_count = reader.read_u32::<LittleEndian>()?;
+
+	// Max level (16)
+	// Sample frequency (2)
+	// ...
+	let flags = reader.read_u32::<LittleEndian>()?;
+	let sample_rate = SAMPLE_RATES[((flags >> 16) & 0x03) as usize];
+
+	// Skip 8 bytes
+	// Title peak (2)
+	// Title gain (2)
+	// Album peak (2)
+	// Album gain (2)
+	reader.read_u32::<LittleEndian>()?;
+	reader.read_u32::<LittleEndian>()?;
+
+	// True gapless (1)
+	// Last frame length (11)
+	// ...
+	let gapless = reader.read_u32::<LittleEndian>()?;
+
+	let mut total_samples = u64::from(frame_count) * FRAME_LENGTH;
+	if gapless >> 31 == 1 {
+		let last_frame_length = u64::from((gapless >> 20) & 0x07FF);
+		total_samples = total_samples.saturating_sub(FRAME_LENGTH - last_frame_length);
+	} else {
+		total_samples = total_samples.saturating_sub(DECODER_SYNTH_DELAY);
+	}
+
+	let properties = MpcProperties {
+		version: 7,
+		sample_rate,
+		// SV7 only supports stereo
+		channels: 2,
+		..MpcProperties::default()
+	};
+
+	Ok((properties, total_samples))
+}
+
+// Sizes are stored with 7 bits per byte, with the MSB set on all bytes but the last
+//
+// Returns the size and the number of bytes it took up
+fn read_variable_size<R>(reader: &mut R) -> Result<(u64, u8)>
+where
+	R: Read,
+{
+	let mut size = 0_u64;
+	let mut len = 0;
+
+	loop {
+		let byte = reader.read_u8()?;
+		size = (size << 7) | u64::from(byte & 0x7F);
+		len += 1;
+
+		if byte & 0x80 == 0 {
+			break;
+		}
+
+		// A u64 can fit at most 9 bytes worth of size
+		if len == 9 {
+			return Err(FileDecodingError::new(FileType::Mpc, "Found an invalid size").into());
+		}
+	}
+
+	Ok((size, len))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{read_properties, read_variable_size};
+
+	use std::io::Cursor;
+	use std::time::Duration;
+
+	#[test]
+	fn variable_size() {
+		assert_eq!(read_variable_size(&mut &[0x03][..]).unwrap(), (3, 1));
+		assert_eq!(
+			read_variable_size(&mut &[0x84, 0x97, 0x40][..]).unwrap(),
+			(68544, 3)
+		);
+		assert!(read_variable_size(&mut &[0xFF; 10][..]).is_err());
+	}
+
+	#[test]
+	fn sv7_gapless() {
+		let mut stream = Vec::new();
+		stream.extend(b"MP+\x17");
+		// 60 frames
+		stream.extend(60_u32.to_le_bytes());
+		// 48000 Hz
+		stream.extend((1_u32 << 16).to_le_bytes());
+		stream.extend([0; 8]);
+		// True gapless, with a last frame length of 576
+		stream.extend(((1_u32 << 31) | (576 << 20)).to_le_bytes());
+		stream.extend([0; 1000]);
+
+		let len = stream.len() as u64;
+		let properties = read_properties(&mut Cursor::new(stream), len, len).unwrap();
+
+		assert_eq!(properties.version, 7);
+		assert_eq!(properties.sample_rate, 48000);
+		assert_eq!(properties.channels, 2);
+		assert_eq!(properties.duration, Duration::from_millis(1428));
+	}
+
+	#[test]
+	fn unsupported_version() {
+		let mut stream = b"MP+\x16".to_vec();
+		stream.extend([0; 24]);
+
+		let len = stream.len() as u64;
+		assert!(read_properties(&mut Cursor::new(stream), len, len).is_err());
+	}
+}
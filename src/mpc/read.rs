@@ -0,0 +1,92 @@
+use super::properties::MpcProperties;
+use super::MpcFile;
+use crate::ape::constants::APE_PREAMBLE;
+use crate::ape::header::read_ape_header;
+#[cfg(feature = "ape")]
+use crate::ape::tag::read::read_ape_tag;
+use crate::error::Result;
+use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2, ID3FindResults};
+use crate::probe::ParseContext;
+
+use std::io::{Read, Seek, SeekFrom};
+
+pub(crate) fn read_from<R>(
+	reader: &mut R,
+	read_tags: bool,
+	read_properties: bool,
+	context: &mut ParseContext,
+) -> Result<MpcFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let end = reader.seek(SeekFrom::End(0))?;
+
+	let file_length = end - start;
+	let mut stream_length = file_length;
+
+	let mut file = MpcFile::default();
+
+	reader.seek(SeekFrom::Start(start))?;
+
+	// ID3v2 tags are not allowed in Musepack files, but some taggers will write them anyway.
+	// Since reading them isn't supported, they are just skipped.
+	let mut stream_start = start;
+	if let ID3FindResults(Some(header), _) = find_id3v2(reader, false)? {
+		let mut size = u64::from(header.size) + 10;
+
+		if header.flags.footer {
+			size += 10;
+		}
+
+		stream_length -= size;
+		stream_start = reader.stream_position()?;
+	}
+
+	// First see if there's a ID3v1 tag
+	#[allow(unused_variables)]
+	let ID3FindResults(id3v1_header, id3v1) = find_id3v1(reader, read_tags)?;
+
+	if id3v1_header.is_some() {
+		stream_length -= 128;
+
+		#[cfg(feature = "id3v1")]
+		{
+			file.id3v1_tag = id3v1;
+		}
+	}
+
+	// Next, check for a Lyrics3v2 tag, and skip over it, as it's no use to us
+	let ID3FindResults(lyrics3_header, lyrics3v2_size) = find_lyrics3v2(reader)?;
+
+	if lyrics3_header.is_some() {
+		stream_length -= u64::from(lyrics3v2_size)
+	}
+
+	// Finally, search for an APE tag footer
+	reader.seek(SeekFrom::Current(-32))?;
+
+	let mut ape_preamble = [0; 8];
+	reader.read_exact(&mut ape_preamble)?;
+
+	if &ape_preamble == APE_PREAMBLE {
+		let ape_header = read_ape_header(reader, true)?;
+		stream_length -= u64::from(ape_header.size);
+
+		#[cfg(feature = "ape")]
+		if read_tags {
+			let ape = read_ape_tag(reader, ape_header, context)?;
+			file.ape_tag = Some(ape);
+		}
+	}
+
+	reader.seek(SeekFrom::Start(stream_start))?;
+
+	file.properties = if read_properties {
+		super::properties::read_properties(reader, stream_length, file_length)?
+	} else {
+		MpcProperties::default()
+	};
+
+	Ok(file)
+}
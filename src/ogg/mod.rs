@@ -11,6 +11,7 @@ pub(crate) mod vorbis;
 
 use crate::error::{FileDecodingError, Result};
 use crate::file::FileType;
+use crate::properties::FileProperties;
 
 use std::io::{Read, Seek};
 
@@ -34,6 +35,33 @@ pub use speex::SpeexFile;
 pub use vorbis::properties::VorbisProperties;
 pub use vorbis::VorbisFile;
 
+// Reads the properties of an OGG file, skipping over the metadata packet
+pub(crate) fn read_properties<R>(data: &mut R, file_type: FileType) -> Result<FileProperties>
+where
+	R: Read + Seek,
+{
+	Ok(match file_type {
+		FileType::Opus => {
+			let (_, first_page) =
+				read::read_from(data, constants::OPUSHEAD, constants::OPUSTAGS, false)?;
+			opus::properties::read_properties(data, &first_page)?.into()
+		},
+		FileType::Speex => {
+			let (_, first_page) = read::read_from(data, constants::SPEEXHEADER, &[], false)?;
+			speex::properties::read_properties(data, &first_page)?.into()
+		},
+		_ => {
+			let (_, first_page) = read::read_from(
+				data,
+				constants::VORBIS_IDENT_HEAD,
+				constants::VORBIS_COMMENT_HEAD,
+				false,
+			)?;
+			vorbis::properties::read_properties(data, &first_page)?.into()
+		},
+	})
+}
+
 pub(self) fn verify_signature(page: &Page, sig: &[u8]) -> Result<()> {
 	let sig_len = sig.len();
 
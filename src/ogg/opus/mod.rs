@@ -3,14 +3,17 @@ pub(super) mod properties;
 use super::find_last_page;
 #[cfg(feature = "vorbis_comments")]
 use super::tag::VorbisComments;
-use crate::error::Result;
+use crate::error::{FileDecodingError, Result};
 use crate::file::{AudioFile, FileType, TaggedFile};
 use crate::ogg::constants::{OPUSHEAD, OPUSTAGS};
 use crate::properties::FileProperties;
 use crate::tag::TagType;
+use crate::traits::FileLike;
 use properties::OpusProperties;
 
-use std::io::{Read, Seek};
+use ogg_pager::Page;
+
+use std::io::{Read, Seek, SeekFrom};
 
 /// An OGG Opus file
 pub struct OpusFile {
@@ -28,6 +31,8 @@ impl From<OpusFile> for TaggedFile {
 		Self {
 			ty: FileType::Opus,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			#[cfg(feature = "vorbis_comments")]
 			tags: vec![input.vorbis_comments.into()],
 			#[cfg(not(feature = "vorbis_comments"))]
@@ -43,7 +48,7 @@ impl AudioFile for OpusFile {
 	where
 		R: Read + Seek,
 	{
-		let file_information = super::read::read_from(reader, OPUSHEAD, OPUSTAGS)?;
+		let file_information = super::read::read_from(reader, OPUSHEAD, OPUSTAGS, true)?;
 
 		Ok(Self {
 			properties: if read_properties {properties::read_properties(reader, &file_information.1)? } else { OpusProperties::default() },
@@ -57,6 +62,17 @@ impl AudioFile for OpusFile {
 		&self.properties
 	}
 
+	#[allow(unused_variables)]
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		#[cfg(feature = "vorbis_comments")]
+		{
+			std::io::Seek::rewind(file)?;
+			crate::traits::TagExt::save_to(&self.vorbis_comments, file)?;
+		}
+
+		Ok(())
+	}
+
 	fn contains_tag(&self) -> bool {
 		true
 	}
@@ -78,4 +94,53 @@ impl OpusFile {
 	pub fn vorbis_comments_mut(&mut self) -> &mut VorbisComments {
 		&mut self.vorbis_comments
 	}
+
+	/// Rewrites the output gain in the identification header of `file`
+	///
+	/// The rest of the file, including the Vorbis comments, is left untouched.
+	/// See [`OpusProperties::output_gain`] for the format of `output_gain`.
+	///
+	/// # Errors
+	///
+	/// * `file` does not start with an Opus identification header
+	/// * [`std::io::Error`]
+	pub fn set_output_gain<F: FileLike>(&mut self, file: &mut F, output_gain: i16) -> Result<()> {
+		file.rewind()?;
+
+		let page = Page::read(file, false)?;
+		let mut content = page.content().to_vec();
+
+		// Magic signature (8)
+		// Version (1)
+		// Channel count (1)
+		// Pre-skip (2)
+		// Input sample rate (4)
+		// Output gain (2)
+		if content.len() < 19 || !content.starts_with(OPUSHEAD) {
+			return Err(FileDecodingError::new(
+				FileType::Opus,
+				"File doesn't start with an identification header",
+			)
+			.into());
+		}
+
+		content[16..18].copy_from_slice(&output_gain.to_le_bytes());
+
+		let mut new_page = Page::new(
+			page.header_type(),
+			page.abgp,
+			page.serial,
+			page.seq_num,
+			content,
+		)?;
+		new_page.gen_crc()?;
+
+		// The content length is unchanged, so the page can be overwritten in place
+		file.seek(SeekFrom::Start(page.start))?;
+		file.write_all(&new_page.as_bytes()?)?;
+
+		self.properties.output_gain = output_gain;
+
+		Ok(())
+	}
 }
@@ -19,6 +19,9 @@ pub struct OpusProperties {
 	pub(crate) channels: u8,
 	pub(crate) version: u8,
 	pub(crate) input_sample_rate: u32,
+	pub(crate) pre_skip: u16,
+	pub(crate) output_gain: i16,
+	pub(crate) channel_mapping_family: u8,
 }
 
 impl From<OpusProperties> for FileProperties {
@@ -64,6 +67,25 @@ impl OpusProperties {
 	pub fn input_sample_rate(&self) -> u32 {
 		self.input_sample_rate
 	}
+
+	/// The number of samples (at 48 kHz) to discard from the start of the decoder output
+	pub fn pre_skip(&self) -> u16 {
+		self.pre_skip
+	}
+
+	/// The gain to apply to the decoder output, as a Q7.8 fixed point number in dB
+	///
+	/// To get the gain in dB, divide this value by 256.
+	pub fn output_gain(&self) -> i16 {
+		self.output_gain
+	}
+
+	/// The channel mapping family
+	///
+	/// See [RFC 7845, section 5.1.1](https://datatracker.ietf.org/doc/html/rfc7845.html#section-5.1.1)
+	pub fn channel_mapping_family(&self) -> u8 {
+		self.channel_mapping_family
+	}
 }
 
 pub(in crate::ogg) fn read_properties<R>(data: &mut R, first_page: &Page) -> Result<OpusProperties>
@@ -88,17 +110,14 @@ where
 	properties.version = first_page_content.read_u8()?;
 	properties.channels = first_page_content.read_u8()?;
 
-	let pre_skip = first_page_content.read_u16::<LittleEndian>()?;
-
+	properties.pre_skip = first_page_content.read_u16::<LittleEndian>()?;
 	properties.input_sample_rate = first_page_content.read_u32::<LittleEndian>()?;
-
-	let _output_gain = first_page_content.read_u16::<LittleEndian>()?;
-
-	let channel_mapping_family = first_page_content.read_u8()?;
+	properties.output_gain = first_page_content.read_i16::<LittleEndian>()?;
+	properties.channel_mapping_family = first_page_content.read_u8()?;
 
 	// https://datatracker.ietf.org/doc/html/rfc7845.html#section-5.1.1
-	if (channel_mapping_family == 0 && properties.channels > 2)
-		|| (channel_mapping_family == 1 && properties.channels > 8)
+	if (properties.channel_mapping_family == 0 && properties.channels > 2)
+		|| (properties.channel_mapping_family == 1 && properties.channels > 8)
 	{
 		return Err(FileDecodingError::new(
 			FileType::Opus,
@@ -113,7 +132,9 @@ where
 	let last_page = find_last_page(data)?;
 	let last_page_abgp = last_page.abgp;
 
-	if let Some(frame_count) = last_page_abgp.checked_sub(first_page_abgp + u64::from(pre_skip)) {
+	if let Some(frame_count) =
+		last_page_abgp.checked_sub(first_page_abgp + u64::from(properties.pre_skip))
+	{
 		let length = frame_count * 1000 / 48000;
 		properties.duration = Duration::from_millis(length);
 
@@ -3,7 +3,7 @@ use super::tag::VorbisComments;
 use super::verify_signature;
 use crate::error::{ErrorKind, LoftyError, Result};
 #[cfg(feature = "vorbis_comments")]
-use crate::picture::Picture;
+use crate::picture::{MimeType, Picture, PictureInformation, PictureType};
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -44,6 +44,10 @@ where
 
 	let comments_total_len = data.read_u32::<LittleEndian>()?;
 
+	// The legacy cover art fields are paired up once all comments are read
+	let mut cover_art = Vec::new();
+	let mut cover_art_mime = Vec::new();
+
 	for _ in 0..comments_total_len {
 		let comment_len = data.read_u32::<LittleEndian>()?;
 
@@ -61,9 +65,14 @@ where
 		// Make sure there was a separator present, otherwise just move on
 		if let Some(value) = comment_split.next() {
 			match key {
-				"METADATA_BLOCK_PICTURE" => tag
-					.pictures
-					.push(Picture::from_flac_bytes(value.as_bytes(), true)?),
+				// A malformed picture shouldn't prevent the rest of the tag from being read
+				"METADATA_BLOCK_PICTURE" => {
+					if let Ok(picture) = Picture::from_flac_bytes(value.as_bytes(), true) {
+						tag.pictures.push(picture)
+					}
+				},
+				"COVERART" => cover_art.push(value.to_string()),
+				"COVERARTMIME" => cover_art_mime.push(value.to_string()),
 				// The valid range is 0x20..=0x7D not including 0x3D
 				k if k.chars().all(|c| (' '..='}').contains(&c) && c != '=') => {
 					tag.items.push((k.to_string(), value.to_string()))
@@ -73,10 +82,40 @@ where
 		}
 	}
 
+	for (i, data) in cover_art.iter().enumerate() {
+		if let Some(picture) = read_cover_art(data, cover_art_mime.get(i).map(String::as_str)) {
+			tag.pictures.push(picture)
+		}
+	}
+
 	Ok(())
 }
 
-pub(crate) fn read_from<T>(data: &mut T, header_sig: &[u8], comment_sig: &[u8]) -> Result<OGGTags>
+// Before "METADATA_BLOCK_PICTURE", pictures were stored as raw base64 encoded images in "COVERART",
+// with the optional MIME type stored in "COVERARTMIME"
+#[cfg(feature = "vorbis_comments")]
+fn read_cover_art(data: &str, mime_type: Option<&str>) -> Option<(Picture, PictureInformation)> {
+	let data = base64::decode(data).ok()?;
+
+	let mime_type = match mime_type {
+		Some(mime_type) => MimeType::from_str(mime_type),
+		None => Picture::from_reader(&mut &*data).ok()?.mime_type().clone(),
+	};
+
+	// There's no picture type, but this is almost always a front cover
+	let picture = Picture::new_unchecked(PictureType::CoverFront, mime_type, None, data);
+
+	let information = PictureInformation::from_picture(&picture).unwrap_or_default();
+	Some((picture, information))
+}
+
+#[allow(unused_variables)]
+pub(crate) fn read_from<T>(
+	data: &mut T,
+	header_sig: &[u8],
+	comment_sig: &[u8],
+	read_tags: bool,
+) -> Result<OGGTags>
 where
 	T: Read + Seek,
 {
@@ -104,15 +143,14 @@ where
 	}
 
 	#[cfg(feature = "vorbis_comments")]
-	{
+	if read_tags {
 		let mut tag = VorbisComments::default();
 
 		let reader = &mut &md_pages[..];
 		read_comments(reader, &mut tag)?;
 
-		Ok((Some(tag), first_page))
+		return Ok((Some(tag), first_page));
 	}
 
-	#[cfg(not(feature = "vorbis_comments"))]
 	Ok((None, first_page))
 }
@@ -7,6 +7,7 @@ use crate::file::{AudioFile, FileType, TaggedFile};
 use crate::ogg::constants::SPEEXHEADER;
 use crate::properties::FileProperties;
 use crate::tag::TagType;
+use crate::traits::FileLike;
 use properties::SpeexProperties;
 
 use std::io::{Read, Seek};
@@ -27,6 +28,8 @@ impl From<SpeexFile> for TaggedFile {
 		Self {
 			ty: FileType::Speex,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			#[cfg(feature = "vorbis_comments")]
 			tags: vec![input.vorbis_comments.into()],
 			#[cfg(not(feature = "vorbis_comments"))]
@@ -42,7 +45,7 @@ impl AudioFile for SpeexFile {
 	where
 		R: Read + Seek,
 	{
-		let file_information = super::read::read_from(reader, SPEEXHEADER, &[])?;
+		let file_information = super::read::read_from(reader, SPEEXHEADER, &[], true)?;
 
 		Ok(Self {
             properties: if read_properties { properties::read_properties(reader, &file_information.1)? } else { SpeexProperties::default() },
@@ -56,6 +59,17 @@ impl AudioFile for SpeexFile {
 		&self.properties
 	}
 
+	#[allow(unused_variables)]
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		#[cfg(feature = "vorbis_comments")]
+		{
+			std::io::Seek::rewind(file)?;
+			crate::traits::TagExt::save_to(&self.vorbis_comments, file)?;
+		}
+
+		Ok(())
+	}
+
 	fn contains_tag(&self) -> bool {
 		true
 	}
@@ -22,6 +22,7 @@ pub struct SpeexProperties {
 	pub(crate) overall_bitrate: u32,
 	pub(crate) audio_bitrate: u32,
 	pub(crate) nominal_bitrate: i32,
+	pub(crate) frames_per_packet: u32,
 }
 
 impl From<SpeexProperties> for FileProperties {
@@ -54,6 +55,10 @@ impl SpeexProperties {
 	}
 
 	/// Speex encoding mode
+	///
+	/// * 0: Narrowband (8 kHz)
+	/// * 1: Wideband (16 kHz)
+	/// * 2: Ultra-wideband (32 kHz)
 	pub fn mode(&self) -> u32 {
 		self.mode
 	}
@@ -78,10 +83,15 @@ impl SpeexProperties {
 		self.audio_bitrate
 	}
 
-	/// Audio bitrate (bps)
+	/// Nominal bitrate from the header (bps), or -1 if unknown
 	pub fn nominal_bitrate(&self) -> i32 {
 		self.nominal_bitrate
 	}
+
+	/// The number of frames stored in each Ogg packet
+	pub fn frames_per_packet(&self) -> u32 {
+		self.frames_per_packet
+	}
 }
 
 pub(in crate::ogg) fn read_properties<R>(data: &mut R, first_page: &Page) -> Result<SpeexProperties>
@@ -131,6 +141,7 @@ where
 	let _frame_size = first_page_content.read_u32::<LittleEndian>()?;
 
 	properties.vbr = first_page_content.read_u32::<LittleEndian>()? == 1;
+	properties.frames_per_packet = first_page_content.read_u32::<LittleEndian>()?;
 
 	let last_page = find_last_page(data)?;
 	let last_page_abgp = last_page.abgp;
@@ -5,31 +5,48 @@ use crate::picture::{Picture, PictureInformation, PictureType};
 use crate::probe::Probe;
 use crate::tag::item::{ItemKey, ItemValue, TagItem};
 use crate::tag::{Tag, TagType};
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 
 use crate::flac::write;
-use std::fs::{File, OpenOptions};
 use std::io::{Cursor, Write};
 use std::path::Path;
 
 macro_rules! impl_accessor {
 	($($name:ident, $key:literal;)+) => {
 		paste::paste! {
-			impl Accessor for VorbisComments {
-				$(
-					fn $name(&self) -> Option<&str> {
-						self.get($key)
-					}
-
-					fn [<set_ $name>](&mut self, value: String) {
-						self.insert(String::from($key), value, true)
-					}
-
-					fn [<remove_ $name>](&mut self) {
-						let _ = self.remove($key);
-					}
-				)+
-			}
+			$(
+				fn $name(&self) -> Option<&str> {
+					self.get($key)
+				}
+
+				fn [<set_ $name>](&mut self, value: String) {
+					self.set(String::from($key), value)
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					let _ = self.remove($key);
+				}
+			)+
+		}
+	}
+}
+
+macro_rules! impl_num_accessor {
+	($($name:ident, $key:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<u32> {
+					self.get($key).and_then(|n| n.trim().parse().ok())
+				}
+
+				fn [<set_ $name>](&mut self, value: u32) {
+					self.set(String::from($key), value.to_string())
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					let _ = self.remove($key);
+				}
+			)+
 		}
 	}
 }
@@ -45,12 +62,21 @@ pub struct VorbisComments {
 	pub(crate) pictures: Vec<(Picture, PictureInformation)>,
 }
 
-impl_accessor!(
-	artist,       "ARTIST";
-	title,        "TITLE";
-	album,        "ALBUM";
-	genre,        "GENRE";
-);
+impl Accessor for VorbisComments {
+	impl_accessor!(
+		artist,       "ARTIST";
+		title,        "TITLE";
+		album,        "ALBUM";
+		genre,        "GENRE";
+	);
+
+	impl_num_accessor!(
+		track,        "TRACKNUMBER";
+		track_total,  "TRACKTOTAL";
+		disk,         "DISCNUMBER";
+		disk_total,   "DISCTOTAL";
+	);
+}
 
 impl VorbisComments {
 	/// Returns the vendor string
@@ -68,7 +94,7 @@ impl VorbisComments {
 		&self.items
 	}
 
-	/// Gets an item by key
+	/// Gets the first item with a key
 	///
 	/// NOTE: This is case-sensitive
 	pub fn get(&self, key: &str) -> Option<&str> {
@@ -78,17 +104,44 @@ impl VorbisComments {
 			.map(|(_, v)| v.as_str())
 	}
 
+	/// Gets all items with a key
+	///
+	/// NOTE: This is case-sensitive
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisComments;
+	///
+	/// let mut tag = VorbisComments::default();
+	/// tag.insert(String::from("ARTIST"), String::from("Foo artist"));
+	/// tag.insert(String::from("ARTIST"), String::from("Bar artist"));
+	///
+	/// assert_eq!(
+	/// 	tag.get_all("ARTIST").collect::<Vec<_>>(),
+	/// 	vec!["Foo artist", "Bar artist"]
+	/// );
+	/// ```
+	pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+		self.items
+			.iter()
+			.filter(move |(k, _)| k == key)
+			.map(|(_, v)| v.as_str())
+	}
+
 	/// Inserts an item
 	///
-	/// If `replace_all` is true, it will remove all items with the key before insertion
-	pub fn insert(&mut self, key: String, value: String, replace_all: bool) {
-		if replace_all {
-			self.items
-				.iter()
-				.position(|(k, _)| k == &key)
-				.map(|p| self.items.remove(p));
-		}
+	/// This will **not** replace any existing items with the key, as Vorbis comments allow
+	/// a key to appear multiple times. To replace them, see [`VorbisComments::set`].
+	pub fn insert(&mut self, key: String, value: String) {
+		self.items.push((key, value))
+	}
 
+	/// Sets an item, removing all existing items with the key
+	///
+	/// NOTE: This is case-sensitive
+	pub fn set(&mut self, key: String, value: String) {
+		self.items.retain(|(k, _)| k != &key);
 		self.items.push((key, value))
 	}
 
@@ -101,7 +154,8 @@ impl VorbisComments {
 
 		for read_idx in 0..self.items.len() {
 			if self.items[read_idx].0 == key {
-				self.items.swap(split_idx, read_idx);
+				// Rotating (rather than swapping) keeps the remaining items in order
+				self.items[split_idx..=read_idx].rotate_right(1);
 				split_idx += 1;
 			}
 		}
@@ -164,7 +218,7 @@ impl TagExt for VorbisComments {
 	/// * `path` does not exist
 	/// * See [`VorbisComments::save_to`]
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
 	/// Writes the tag to a file
@@ -175,7 +229,7 @@ impl TagExt for VorbisComments {
 	/// * The file does not contain valid packets
 	/// * [`PictureInformation::from_picture`]
 	/// * [`std::io::Error`]
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		VorbisCommentsRef {
 			vendor: self.vendor.as_str(),
 			items: self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())),
@@ -206,7 +260,7 @@ impl TagExt for VorbisComments {
 		TagType::VorbisComments.remove_from_path(path)
 	}
 
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		TagType::VorbisComments.remove_from(file)
 	}
 
@@ -214,6 +268,28 @@ impl TagExt for VorbisComments {
 		self.items.clear();
 		self.pictures.clear();
 	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		self.pictures.iter().map(|(p, _)| p.clone()).collect()
+	}
+
+	/// Inserts a [`Picture`], inferring its [`PictureInformation`]
+	///
+	/// To provide the [`PictureInformation`], use [`VorbisComments::insert_picture`].
+	///
+	/// # Errors
+	///
+	/// * See [`PictureInformation::from_picture`]
+	fn replace_picture(&mut self, picture: Picture) -> std::result::Result<(), Self::Err> {
+		self.remove_picture_type(picture.pic_type);
+		VorbisComments::insert_picture(self, picture, None)?;
+
+		Ok(())
+	}
+
+	fn remove_picture_type(&mut self, picture_type: PictureType) {
+		self.remove_picture_type(picture_type)
+	}
 }
 
 impl From<VorbisComments> for Tag {
@@ -278,9 +354,8 @@ impl From<Tag> for VorbisComments {
 		}
 
 		for picture in input.pictures {
-			if let Ok(information) = PictureInformation::from_picture(&picture) {
-				vorbis_comments.pictures.push((picture, information))
-			}
+			let information = PictureInformation::from_picture(&picture).unwrap_or_default();
+			vorbis_comments.pictures.push((picture, information))
 		}
 
 		vorbis_comments
@@ -303,7 +378,7 @@ where
 	IP: Iterator<Item = (&'a Picture, PictureInformation)>,
 {
 	#[allow(clippy::shadow_unrelated)]
-	fn write_to(&mut self, file: &mut File) -> Result<()> {
+	fn write_to<F: FileLike>(&mut self, file: &mut F) -> Result<()> {
 		let probe = Probe::new(file).guess_file_type()?;
 		let f_ty = probe.file_type();
 
@@ -354,7 +429,9 @@ pub(crate) fn create_vorbis_comments_ref(
 #[cfg(test)]
 mod tests {
 	use crate::ogg::VorbisComments;
-	use crate::{Tag, TagExt, TagType};
+	use crate::{
+		ItemKey, MimeType, Picture, PictureInformation, PictureType, Tag, TagExt, TagType,
+	};
 
 	use std::io::Read;
 
@@ -372,13 +449,13 @@ mod tests {
 
 		expected_tag.set_vendor(String::from("Lavf58.76.100"));
 
-		expected_tag.insert(String::from("ALBUM"), String::from("Baz album"), false);
-		expected_tag.insert(String::from("ARTIST"), String::from("Bar artist"), false);
-		expected_tag.insert(String::from("COMMENT"), String::from("Qux comment"), false);
-		expected_tag.insert(String::from("DATE"), String::from("1984"), false);
-		expected_tag.insert(String::from("GENRE"), String::from("Classical"), false);
-		expected_tag.insert(String::from("TITLE"), String::from("Foo title"), false);
-		expected_tag.insert(String::from("TRACKNUMBER"), String::from("1"), false);
+		expected_tag.insert(String::from("ALBUM"), String::from("Baz album"));
+		expected_tag.insert(String::from("ARTIST"), String::from("Bar artist"));
+		expected_tag.insert(String::from("COMMENT"), String::from("Qux comment"));
+		expected_tag.insert(String::from("DATE"), String::from("1984"));
+		expected_tag.insert(String::from("GENRE"), String::from("Classical"));
+		expected_tag.insert(String::from("TITLE"), String::from("Foo title"));
+		expected_tag.insert(String::from("TRACKNUMBER"), String::from("1"));
 
 		let file_cont = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.vorbis");
 		let parsed_tag = read_tag(&*file_cont);
@@ -402,6 +479,102 @@ mod tests {
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
 
+	#[test]
+	fn multiple_values_re_read() {
+		let mut tag = VorbisComments::default();
+		tag.insert(String::from("GENRE"), String::from("Classical"));
+		tag.insert(String::from("TITLE"), String::from("Foo title"));
+		tag.insert(String::from("GENRE"), String::from("Jazz"));
+		tag.insert(String::from("GENRE"), String::from("Rock"));
+
+		let mut writer = vec![0, 0, 0, 0];
+		tag.dump_to(&mut writer).unwrap();
+
+		let parsed_tag = read_tag(&*writer);
+
+		assert_eq!(
+			parsed_tag.get_all("GENRE").collect::<Vec<_>>(),
+			vec!["Classical", "Jazz", "Rock"]
+		);
+
+		let generic_tag: Tag = parsed_tag.into();
+		assert_eq!(
+			generic_tag
+				.get_items(&ItemKey::Genre)
+				.filter_map(|item| item.value().text())
+				.collect::<Vec<_>>(),
+			vec!["Classical", "Jazz", "Rock"]
+		);
+
+		let mut vorbis_comments: VorbisComments = generic_tag.into();
+		assert_eq!(
+			vorbis_comments.get_all("GENRE").collect::<Vec<_>>(),
+			vec!["Classical", "Jazz", "Rock"]
+		);
+
+		vorbis_comments.set(String::from("GENRE"), String::from("Blues"));
+		assert_eq!(
+			vorbis_comments.get_all("GENRE").collect::<Vec<_>>(),
+			vec!["Blues"]
+		);
+		assert_eq!(vorbis_comments.get("TITLE"), Some("Foo title"));
+	}
+
+	#[test]
+	fn legacy_and_malformed_pictures() {
+		fn push_comment(bytes: &mut Vec<u8>, comment: &str) {
+			bytes.extend((comment.len() as u32).to_le_bytes());
+			bytes.extend(comment.as_bytes());
+		}
+
+		let picture_data =
+			crate::tag::utils::test_utils::read_path("tests/picture/assets/png_640x628.png");
+		let picture = Picture::from_reader(&mut &*picture_data).unwrap();
+		let information = PictureInformation::from_picture(&picture).unwrap();
+
+		let mut truncated_picture = picture.as_flac_bytes(information, false);
+		truncated_picture.truncate(100);
+
+		// Zero-size vendor, followed by the number of comments
+		let mut tag_bytes = vec![0, 0, 0, 0, 5, 0, 0, 0];
+		push_comment(&mut tag_bytes, "TITLE=Foo title");
+		push_comment(&mut tag_bytes, "METADATA_BLOCK_PICTURE=Not base64!");
+		push_comment(
+			&mut tag_bytes,
+			&format!(
+				"METADATA_BLOCK_PICTURE={}",
+				base64::encode(truncated_picture)
+			),
+		);
+		push_comment(
+			&mut tag_bytes,
+			&format!("COVERART={}", base64::encode(&picture_data)),
+		);
+		push_comment(&mut tag_bytes, "COVERARTMIME=image/png");
+
+		let parsed_tag = read_tag(&tag_bytes);
+
+		// Only the legacy picture is valid
+		assert_eq!(
+			parsed_tag.items(),
+			&[(String::from("TITLE"), String::from("Foo title"))]
+		);
+		assert_eq!(parsed_tag.pictures.len(), 1);
+
+		let (legacy_picture, legacy_information) = &parsed_tag.pictures[0];
+		assert_eq!(legacy_picture.pic_type(), PictureType::CoverFront);
+		assert_eq!(legacy_picture.mime_type(), &MimeType::Png);
+		assert_eq!(legacy_picture.data(), &*picture_data);
+		assert_eq!(*legacy_information, information);
+
+		// The legacy picture is written as a "METADATA_BLOCK_PICTURE"
+		let mut writer = vec![0, 0, 0, 0];
+		parsed_tag.dump_to(&mut writer).unwrap();
+
+		assert!(!writer.windows(8).any(|w| w == b"COVERART"));
+		assert_eq!(read_tag(&writer), parsed_tag);
+	}
+
 	#[test]
 	fn vorbis_comments_to_tag() {
 		let mut tag_bytes = Vec::new();
@@ -433,4 +606,23 @@ mod tests {
 		assert_eq!(vorbis_comments.get("TRACKNUMBER"), Some("1"));
 		assert_eq!(vorbis_comments.get("GENRE"), Some("Classical"));
 	}
+
+	#[test]
+	fn tag_to_vorbis_comments_picture_information() {
+		let picture_data =
+			crate::tag::utils::test_utils::read_path("tests/picture/assets/png_640x628.png");
+		let picture = Picture::from_reader(&mut &*picture_data).unwrap();
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.push_picture(picture.clone());
+
+		let vorbis_comments: VorbisComments = tag.into();
+
+		assert_eq!(vorbis_comments.pictures.len(), 1);
+
+		let (converted_picture, information) = &vorbis_comments.pictures[0];
+		assert_eq!(converted_picture, &picture);
+		assert_eq!(information.width, 640);
+		assert_eq!(information.height, 628);
+	}
 }
@@ -10,6 +10,7 @@ use crate::file::{AudioFile, FileType, TaggedFile};
 use crate::ogg::constants::{VORBIS_COMMENT_HEAD, VORBIS_IDENT_HEAD};
 use crate::properties::FileProperties;
 use crate::tag::TagType;
+use crate::traits::FileLike;
 use properties::VorbisProperties;
 
 use std::io::{Read, Seek};
@@ -30,6 +31,8 @@ impl From<VorbisFile> for TaggedFile {
 		Self {
 			ty: FileType::Vorbis,
 			properties: FileProperties::from(input.properties),
+			tag_priority: Vec::new(),
+			removed_tags: Vec::new(),
 			#[cfg(feature = "vorbis_comments")]
 			tags: vec![input.vorbis_comments.into()],
 			#[cfg(not(feature = "vorbis_comments"))]
@@ -46,7 +49,7 @@ impl AudioFile for VorbisFile {
 		R: Read + Seek,
 	{
 		let file_information =
-			super::read::read_from(reader, VORBIS_IDENT_HEAD, VORBIS_COMMENT_HEAD)?;
+			super::read::read_from(reader, VORBIS_IDENT_HEAD, VORBIS_COMMENT_HEAD, true)?;
 
 		Ok(Self {
 			properties: if read_properties { properties::read_properties(reader, &file_information.1)? } else { VorbisProperties::default() },
@@ -60,6 +63,17 @@ impl AudioFile for VorbisFile {
 		&self.properties
 	}
 
+	#[allow(unused_variables)]
+	fn save_to<F: FileLike>(&self, file: &mut F) -> Result<()> {
+		#[cfg(feature = "vorbis_comments")]
+		{
+			std::io::Seek::rewind(file)?;
+			crate::traits::TagExt::save_to(&self.vorbis_comments, file)?;
+		}
+
+		Ok(())
+	}
+
 	fn contains_tag(&self) -> bool {
 		true
 	}
@@ -1,23 +1,18 @@
 use crate::error::{FileEncodingError, Result};
 use crate::file::FileType;
 use crate::ogg::constants::VORBIS_SETUP_HEAD;
+use crate::traits::FileLike;
 
-use std::fs::File;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use ogg_pager::Page;
 
-pub(crate) fn write_to(
-	data: &mut File,
-	writer: &mut Vec<u8>,
+// Reads the setup header that follows the comment header, leaving the reader at the first audio page
+pub(crate) fn read_setup_header<F: FileLike>(
+	data: &mut F,
 	first_md_content: Vec<u8>,
-	pages: &mut [Page],
-) -> Result<()> {
-	let mut remaining = Vec::new();
-
-	let reached_md_end: bool;
-
+) -> Result<Vec<u8>> {
 	// Find the total comment count in the first page's content
 	let mut c = Cursor::new(first_md_content);
 
@@ -34,25 +29,27 @@ pub(crate) fn write_to(
 	c.seek(SeekFrom::End(0))?;
 
 	loop {
-		let p = Page::read(data, false)?;
-
-		if p.header_type() & 0x01 != 1 {
+		let p = match Page::read(data, false) {
+			Ok(p) => p,
+			Err(_) => {
+				return Err(FileEncodingError::new(
+					FileType::Vorbis,
+					"File ends with comment header",
+				)
+				.into())
+			},
+		};
+
+		// The setup header isn't required to share a page with the comment header.
+		// Audio packets can never start with the setup header signature, as they start with a 0 bit.
+		if p.header_type() & 0x01 != 1 && !p.content().starts_with(VORBIS_SETUP_HEAD) {
 			data.seek(SeekFrom::Start(p.start))?;
-			data.read_to_end(&mut remaining)?;
-
-			reached_md_end = true;
 			break;
 		}
 
 		c.write_all(p.content())?;
 	}
 
-	if !reached_md_end {
-		return Err(
-			FileEncodingError::new(FileType::Vorbis, "File ends with comment header").into(),
-		);
-	}
-
 	c.seek(SeekFrom::Start(comments_pos))?;
 
 	for _ in 0..total_comments {
@@ -83,72 +80,5 @@ pub(crate) fn write_to(
 	let mut setup = Vec::new();
 	c.read_to_end(&mut setup)?;
 
-	// Safe to unwrap, since `pages` is guaranteed to not be empty
-	let (last_page, remaining_pages) = pages.split_last_mut().unwrap();
-
-	for p in remaining_pages.iter_mut() {
-		p.gen_crc()?;
-		writer.write_all(&*p.as_bytes()?)?;
-	}
-
-	build_remaining_header(writer, last_page, &*setup)?;
-
-	writer.write_all(&*remaining)?;
-
-	Ok(())
-}
-
-fn build_remaining_header(
-	writer: &mut Vec<u8>,
-	last_page: &mut Page,
-	setup_header: &[u8],
-) -> Result<()> {
-	let mut segment_table = ogg_pager::segment_table(last_page.content().len())?;
-	let seg_table_len = segment_table.len();
-
-	if seg_table_len == 255 {
-		last_page.gen_crc()?;
-
-		let p_bytes = last_page.as_bytes()?;
-		writer.write_all(&*p_bytes)?;
-		return Ok(());
-	}
-
-	// The segment tables of current page and the setup header have to be combined
-	if seg_table_len < 255 {
-		let remaining_segments = 255 - seg_table_len;
-		let setup_segment_table = ogg_pager::segment_table(setup_header.len())?;
-
-		let mut i = 0;
-		for e in setup_segment_table {
-			segment_table.push(e);
-			i += 1;
-
-			if i == remaining_segments {
-				break;
-			}
-		}
-	}
-
-	// Add the number of segments to the front of the table
-	segment_table.insert(0, segment_table.len() as u8);
-
-	let page = last_page.extend(setup_header)?;
-
-	let mut p_bytes = last_page.as_bytes()?;
-	let seg_count = p_bytes[26] as usize;
-
-	// Replace segment table and checksum
-	p_bytes.splice(26..27 + seg_count, segment_table);
-	p_bytes.splice(22..26, ogg_pager::crc32(&*p_bytes).to_le_bytes());
-
-	writer.write_all(&*p_bytes)?;
-
-	if let Some(mut page) = page {
-		page.gen_crc()?;
-
-		writer.write_all(&*page.as_bytes()?)?;
-	}
-
-	Ok(())
+	Ok(setup)
 }
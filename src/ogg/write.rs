@@ -7,9 +7,9 @@ use crate::ogg::constants::{OPUSTAGS, VORBIS_COMMENT_HEAD};
 use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
 use crate::picture::PictureInformation;
 use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
 
 use std::convert::TryFrom;
-use std::fs::File;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
@@ -33,7 +33,7 @@ impl OGGFormat {
 	}
 }
 
-pub(in crate) fn write_to(file: &mut File, tag: &Tag, file_type: FileType) -> Result<()> {
+pub(crate) fn write_to<F: FileLike>(file: &mut F, tag: &Tag, file_type: FileType) -> Result<()> {
 	match tag.tag_type() {
 		#[cfg(feature = "vorbis_comments")]
 		TagType::VorbisComments => {
@@ -147,8 +147,8 @@ where
 }
 
 #[cfg(feature = "vorbis_comments")]
-pub(super) fn write<'a, II, IP>(
-	data: &mut File,
+pub(super) fn write<'a, II, IP, F: FileLike>(
+	data: &mut F,
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
 	format: OGGFormat,
 ) -> Result<()>
@@ -190,60 +190,124 @@ where
 
 	match format {
 		OGGFormat::Vorbis => {
-			super::vorbis::write::write_to(
-				data,
-				&mut writer,
-				first_md_page.take_content(),
-				&mut pages,
-			)?;
-		},
-		OGGFormat::Opus => {
-			replace_packet(data, &mut writer, &mut pages, FileType::Opus)?;
-		},
-		OGGFormat::Speex => {
-			replace_packet(data, &mut writer, &mut pages, FileType::Speex)?;
+			// The setup header needs to be carried over, it gets its own pages following the comments
+			let setup =
+				super::vorbis::write::read_setup_header(data, first_md_page.take_content())?;
+			pages.extend(ogg_pager::paginate(&setup, ser, 0, 0));
 		},
+		OGGFormat::Opus => skip_comment_header(data, FileType::Opus)?,
+		OGGFormat::Speex => skip_comment_header(data, FileType::Speex)?,
 	}
 
+	let mut remaining = Vec::new();
+	data.read_to_end(&mut remaining)?;
+
+	write_pages(
+		&mut writer,
+		&mut pages,
+		first_page.seq_num + 1,
+		&mut remaining,
+	)?;
+
 	data.rewind()?;
-	data.set_len(first_page.end)?;
+	data.truncate(first_page.end)?;
 	data.write_all(&*writer)?;
 
 	Ok(())
 }
 
-fn replace_packet(
-	data: &mut File,
-	writer: &mut Vec<u8>,
-	pages: &mut [Page],
-	file_type: FileType,
-) -> Result<()> {
-	let reached_md_end: bool;
-
+// Seeks to the first page following the comment header
+fn skip_comment_header<F: FileLike>(data: &mut F, file_type: FileType) -> Result<()> {
 	loop {
-		let p = Page::read(data, true)?;
+		let p = match Page::read(data, true) {
+			Ok(p) => p,
+			Err(_) => {
+				return Err(
+					FileEncodingError::new(file_type, "File ends with comment header").into(),
+				)
+			},
+		};
 
 		if p.header_type() & 0x01 != 0x01 {
 			data.seek(SeekFrom::Start(p.start))?;
-			reached_md_end = true;
-			break;
+			return Ok(());
 		}
 	}
+}
 
-	if !reached_md_end {
-		return Err(FileEncodingError::new(file_type, "File ends with comment header").into());
-	}
-
-	let mut remaining = Vec::new();
-	data.read_to_end(&mut remaining)?;
+// Writes the new metadata pages, followed by the rest of the stream
+//
+// Since the number of metadata pages can change, the pages that follow need to be renumbered
+fn write_pages(
+	writer: &mut Vec<u8>,
+	pages: &mut [Page],
+	first_seq_num: u32,
+	remaining: &mut [u8],
+) -> Result<()> {
+	let mut seq_num = first_seq_num;
 
 	for p in pages.iter_mut() {
+		p.seq_num = seq_num;
 		p.gen_crc()?;
 
 		writer.write_all(&*p.as_bytes()?)?;
+
+		seq_num += 1;
+	}
+
+	// The serial of the stream we're writing to, any other (multiplexed) streams are left alone
+	let serial = pages.first().map(|p| p.serial);
+
+	let mut pos = 0;
+
+	// Page header:
+	//
+	// Capture pattern ("OggS", 4)
+	// Version (1)
+	// Header type (1)
+	// Absolute granule position (8)
+	// Stream serial number (4)
+	// Page sequence number (4)
+	// Checksum (4)
+	// Segment count (1)
+	while let Some(header) = remaining.get(pos..pos + 27) {
+		if &header[..4] != b"OggS" {
+			break;
+		}
+
+		let page_serial = u32::from_le_bytes([header[14], header[15], header[16], header[17]]);
+		let page_seq_num = u32::from_le_bytes([header[18], header[19], header[20], header[21]]);
+		let segment_count = header[26] as usize;
+
+		let content_len = match remaining.get(pos + 27..pos + 27 + segment_count) {
+			Some(segment_table) => segment_table.iter().map(|b| *b as usize).sum::<usize>(),
+			None => break,
+		};
+
+		let page_end = pos + 27 + segment_count + content_len;
+
+		let page = match remaining.get_mut(pos..page_end) {
+			Some(page) => page,
+			None => break,
+		};
+
+		if Some(page_serial) == serial {
+			if page_seq_num != seq_num {
+				page[18..22].copy_from_slice(&seq_num.to_le_bytes());
+
+				// The checksum is calculated with the checksum field zeroed
+				page[22..26].copy_from_slice(&[0; 4]);
+				let checksum = ogg_pager::crc32(page);
+				page[22..26].copy_from_slice(&checksum.to_le_bytes());
+			}
+
+			seq_num += 1;
+		}
+
+		pos = page_end;
 	}
 
-	writer.write_all(&*remaining)?;
+	writer.write_all(remaining)?;
 
 	Ok(())
 }
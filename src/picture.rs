@@ -7,20 +7,15 @@ use crate::{
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
-#[cfg(any(feature = "vorbis_comments", feature = "ape", feature = "id3v2"))]
-use std::io::Cursor;
-use std::io::Read;
+use std::fs::File;
 #[cfg(feature = "id3v2")]
 use std::io::Write;
-#[cfg(any(feature = "vorbis_comments", feature = "ape"))]
-use std::io::{Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
 
-#[cfg(any(feature = "vorbis_comments"))]
-use byteorder::BigEndian;
-#[cfg(any(feature = "vorbis_comments", feature = "id3v2", feature = "ape"))]
-use byteorder::ReadBytesExt;
 #[cfg(feature = "id3v2")]
 use byteorder::WriteBytesExt;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, ReadBytesExt};
 
 #[cfg(feature = "ape")]
 /// Common picture item keys for APE
@@ -62,6 +57,12 @@ pub enum MimeType {
 	Bmp,
 	/// GIF image
 	Gif,
+	/// WebP image
+	Webp,
+	/// A link to an image (`-->`)
+	///
+	/// The picture's data is a URL, rather than the image itself. See [`Picture::url`].
+	Url,
 	/// Some unknown mimetype
 	Unknown(String),
 	/// No mimetype
@@ -76,6 +77,8 @@ impl ToString for MimeType {
 			MimeType::Tiff => "image/tiff".to_string(),
 			MimeType::Bmp => "image/bmp".to_string(),
 			MimeType::Gif => "image/gif".to_string(),
+			MimeType::Webp => "image/webp".to_string(),
+			MimeType::Url => "-->".to_string(),
 			MimeType::Unknown(unknown) => unknown.clone(),
 			MimeType::None => String::new(),
 		}
@@ -92,6 +95,8 @@ impl MimeType {
 			"image/tiff" => Self::Tiff,
 			"image/bmp" => Self::Bmp,
 			"image/gif" => Self::Gif,
+			"image/webp" => Self::Webp,
+			"-->" => Self::Url,
 			"" => Self::None,
 			_ => Self::Unknown(mime_type.to_string()),
 		}
@@ -105,15 +110,68 @@ impl MimeType {
 			MimeType::Tiff => "image/tiff",
 			MimeType::Bmp => "image/bmp",
 			MimeType::Gif => "image/gif",
+			MimeType::Webp => "image/webp",
+			MimeType::Url => "-->",
 			MimeType::Unknown(unknown) => &*unknown,
 			MimeType::None => "",
 		}
 	}
+
+	/// Get a `MimeType` from an image's signature
+	///
+	/// This supports JPEG, PNG, GIF, BMP, TIFF, and WebP images.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::MimeType;
+	///
+	/// let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+	/// assert_eq!(MimeType::from_bytes(&png), Some(MimeType::Png));
+	///
+	/// let webp = *b"RIFF\x24\0\0\0WEBPVP8 ";
+	/// assert_eq!(MimeType::from_bytes(&webp), Some(MimeType::Webp));
+	///
+	/// assert_eq!(MimeType::from_bytes(b"Foo"), None);
+	/// ```
+	pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+		match bytes {
+			[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(Self::Png),
+			[0xFF, 0xD8, ..] => Some(Self::Jpeg),
+			[b'G', b'I', b'F', 0x38, 0x37 | 0x39, b'a', ..] => Some(Self::Gif),
+			[b'B', b'M', ..] => Some(Self::Bmp),
+			[b'I', b'I', b'*', 0x00, ..] | [b'M', b'M', 0x00, b'*', ..] => Some(Self::Tiff),
+			[b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::Webp),
+			_ => None,
+		}
+	}
+}
+
+// Mimetypes are (de)serialized as their string representation
+#[cfg(feature = "serde")]
+impl serde::Serialize for MimeType {
+	fn serialize<S: serde::Serializer>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error> {
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MimeType {
+	fn deserialize<D: serde::Deserializer<'de>>(
+		deserializer: D,
+	) -> std::result::Result<Self, D::Error> {
+		let mime_type = String::deserialize(deserializer)?;
+		Ok(Self::from_str(&mime_type))
+	}
 }
 
 /// The picture type, according to ID3v2 APIC
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum PictureType {
 	Other,
@@ -141,9 +199,13 @@ pub enum PictureType {
 }
 
 impl PictureType {
-	// ID3/OGG specific methods
+	// ID3/OGG/ASF specific methods
 
-	#[cfg(any(feature = "id3v2", feature = "vorbis_comments"))]
+	#[cfg(any(
+		feature = "id3v2",
+		feature = "vorbis_comments",
+		feature = "asf_attributes"
+	))]
 	/// Get a u8 from a `PictureType` according to ID3v2 APIC
 	pub fn as_u8(&self) -> u8 {
 		match self {
@@ -172,7 +234,11 @@ impl PictureType {
 		}
 	}
 
-	#[cfg(any(feature = "id3v2", feature = "vorbis_comments"))]
+	#[cfg(any(
+		feature = "id3v2",
+		feature = "vorbis_comments",
+		feature = "asf_attributes"
+	))]
 	/// Get a `PictureType` from a u8 according to ID3v2 APIC
 	pub fn from_u8(byte: u8) -> Self {
 		match byte {
@@ -262,8 +328,8 @@ impl PictureType {
 	}
 }
 
-#[cfg(feature = "vorbis_comments")]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Information about a [`Picture`]
 ///
 /// This information is necessary for FLAC's `METADATA_BLOCK_PICTURE`.
@@ -279,17 +345,19 @@ pub struct PictureInformation {
 	pub num_colors: u32,
 }
 
-#[cfg(feature = "vorbis_comments")]
 impl PictureInformation {
 	/// Attempt to extract [`PictureInformation`] from a [`Picture`]
 	///
-	/// NOTE: This only supports PNG and JPEG images. If another image is provided,
-	/// the `PictureInformation` will be zeroed out.
+	/// The information is derived from the image data itself, so this can be used
+	/// for pictures from any tag format (for example, when converting an ID3v2 "APIC"
+	/// frame or an MP4 "covr" atom to a FLAC `METADATA_BLOCK_PICTURE`).
+	///
+	/// NOTE: This supports PNG, JPEG, GIF, BMP, TIFF, and WebP images. If another image is provided,
+	/// or the image data is invalid, the `PictureInformation` will be zeroed out.
 	///
 	/// # Errors
 	///
 	/// * `picture.data` is less than 8 bytes in length
-	/// * See [`PictureInformation::from_png`] and [`PictureInformation::from_jpeg`]
 	pub fn from_picture(picture: &Picture) -> Result<Self> {
 		let reader = &mut &*picture.data;
 
@@ -297,11 +365,19 @@ impl PictureInformation {
 			return Err(LoftyError::new(ErrorKind::NotAPicture));
 		}
 
-		match reader[..4] {
-			[0x89, b'P', b'N', b'G'] => Ok(Self::from_png(reader).unwrap_or_default()),
-			[0xFF, 0xD8, 0xFF, ..] => Ok(Self::from_jpeg(reader).unwrap_or_default()),
-			_ => Ok(Self::default()),
-		}
+		let information = match *reader {
+			[0x89, b'P', b'N', b'G', ..] => Self::from_png(reader),
+			[0xFF, 0xD8, 0xFF, ..] => Self::from_jpeg(reader),
+			[b'G', b'I', b'F', ..] => Self::from_gif(reader),
+			[b'B', b'M', ..] => Self::from_bmp(reader),
+			[b'I', b'I', b'*', 0x00, ..] | [b'M', b'M', 0x00, b'*', ..] => Self::from_tiff(reader),
+			[b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => {
+				Self::from_webp(reader)
+			},
+			_ => return Ok(Self::default()),
+		};
+
+		Ok(information.unwrap_or_default())
 	}
 
 	/// Attempt to extract [`PictureInformation`] from a PNG
@@ -436,10 +512,286 @@ impl PictureInformation {
 
 		Err(LoftyError::new(ErrorKind::NotAPicture))
 	}
+
+	/// Attempt to extract [`PictureInformation`] from a GIF
+	///
+	/// # Errors
+	///
+	/// * `reader` is not a GIF image
+	pub fn from_gif(mut data: &[u8]) -> Result<Self> {
+		let reader = &mut data;
+
+		let mut sig = [0; 6];
+		reader.read_exact(&mut sig)?;
+
+		if &sig != b"GIF87a" && &sig != b"GIF89a" {
+			return Err(LoftyError::new(ErrorKind::NotAPicture));
+		}
+
+		// Logical screen descriptor
+		let width = u32::from(reader.read_u16::<LittleEndian>()?);
+		let height = u32::from(reader.read_u16::<LittleEndian>()?);
+		let packed = reader.read_u8()?;
+
+		// Bits 4-6 are the color resolution, and bits 0-2 are
+		// the size of the global color table (if bit 7 is set)
+		let color_depth = u32::from((packed >> 4) & 0x07) + 1;
+		let num_colors = if packed & 0x80 == 0x80 {
+			1 << (u32::from(packed & 0x07) + 1)
+		} else {
+			0
+		};
+
+		Ok(Self {
+			width,
+			height,
+			color_depth,
+			num_colors,
+		})
+	}
+
+	/// Attempt to extract [`PictureInformation`] from a BMP
+	///
+	/// # Errors
+	///
+	/// * `reader` is not a BMP image
+	pub fn from_bmp(mut data: &[u8]) -> Result<Self> {
+		let reader = &mut data;
+
+		let mut sig = [0; 2];
+		reader.read_exact(&mut sig)?;
+
+		if &sig != b"BM" {
+			return Err(LoftyError::new(ErrorKind::NotAPicture));
+		}
+
+		// Skip the rest of the file header
+		// File size (4)
+		// Reserved (4)
+		// Pixel data offset (4)
+		let mut file_header = [0; 12];
+		reader.read_exact(&mut file_header)?;
+
+		let dib_header_size = reader.read_u32::<LittleEndian>()?;
+
+		let (width, height, bit_count, colors_used);
+		match dib_header_size {
+			// BITMAPCOREHEADER
+			12 => {
+				width = u32::from(reader.read_u16::<LittleEndian>()?);
+				height = u32::from(reader.read_u16::<LittleEndian>()?);
+
+				// Planes
+				reader.read_u16::<LittleEndian>()?;
+				bit_count = reader.read_u16::<LittleEndian>()?;
+				colors_used = 0;
+			},
+			// BITMAPINFOHEADER and later, which all share the same layout
+			40.. => {
+				width = reader.read_i32::<LittleEndian>()?.unsigned_abs();
+				// A negative height means the image is stored top-down
+				height = reader.read_i32::<LittleEndian>()?.unsigned_abs();
+
+				// Planes
+				reader.read_u16::<LittleEndian>()?;
+				bit_count = reader.read_u16::<LittleEndian>()?;
+
+				// Skip 16 bytes
+				// Compression (4)
+				// Image size (4)
+				// Horizontal resolution (4)
+				// Vertical resolution (4)
+				let mut skip = [0; 16];
+				reader.read_exact(&mut skip)?;
+
+				colors_used = reader.read_u32::<LittleEndian>()?;
+			},
+			_ => return Err(LoftyError::new(ErrorKind::NotAPicture)),
+		}
+
+		// Only indexed-color images have a color table
+		let num_colors = match bit_count {
+			1 | 2 | 4 | 8 if colors_used == 0 => 1 << bit_count,
+			1 | 2 | 4 | 8 => colors_used,
+			_ => 0,
+		};
+
+		Ok(Self {
+			width,
+			height,
+			color_depth: u32::from(bit_count),
+			num_colors,
+		})
+	}
+
+	/// Attempt to extract [`PictureInformation`] from a TIFF
+	///
+	/// NOTE: Only the first image in the file is used
+	///
+	/// # Errors
+	///
+	/// * `reader` is not a TIFF image
+	pub fn from_tiff(data: &[u8]) -> Result<Self> {
+		match data.get(..4) {
+			Some(b"II*\0") => Self::read_tiff::<LittleEndian>(data),
+			Some(b"MM\0*") => Self::read_tiff::<BigEndian>(data),
+			_ => Err(LoftyError::new(ErrorKind::NotAPicture)),
+		}
+	}
+
+	fn read_tiff<B: ByteOrder>(data: &[u8]) -> Result<Self> {
+		const SHORT: u16 = 3;
+		const LONG: u16 = 4;
+
+		let not_a_picture = || LoftyError::new(ErrorKind::NotAPicture);
+
+		let ifd_offset = data.get(4..8).map(B::read_u32).ok_or_else(not_a_picture)? as usize;
+		let entries_start = ifd_offset.checked_add(2).ok_or_else(not_a_picture)?;
+		let entry_count = data
+			.get(ifd_offset..entries_start)
+			.map(B::read_u16)
+			.ok_or_else(not_a_picture)? as usize;
+
+		let entries_end = entry_count
+			.checked_mul(12)
+			.and_then(|len| entries_start.checked_add(len))
+			.ok_or_else(not_a_picture)?;
+		let entries = data
+			.get(entries_start..entries_end)
+			.ok_or_else(not_a_picture)?;
+
+		let mut width = 0;
+		let mut height = 0;
+		let mut bits_per_sample = 1;
+		let mut samples_per_pixel = 1;
+		let mut palette = false;
+
+		// Each entry is a tag (2), field type (2), value count (4), and the value or its offset (4)
+		for entry in entries.chunks_exact(12) {
+			let tag = B::read_u16(&entry[..2]);
+			let field_type = B::read_u16(&entry[2..4]);
+			let count = B::read_u32(&entry[4..8]);
+			let value = &entry[8..];
+
+			let int_value = match field_type {
+				SHORT => u32::from(B::read_u16(value)),
+				LONG => B::read_u32(value),
+				_ => continue,
+			};
+
+			match tag {
+				256 => width = int_value,
+				257 => height = int_value,
+				// All samples are assumed to have the same size, so only the first is read.
+				// If the values don't fit in the entry, they are stored at an offset.
+				258 => {
+					bits_per_sample = if count <= 2 {
+						int_value
+					} else {
+						let offset = B::read_u32(value) as usize;
+						let end = offset.checked_add(2).ok_or_else(not_a_picture)?;
+						data.get(offset..end)
+							.map(|b| u32::from(B::read_u16(b)))
+							.ok_or_else(not_a_picture)?
+					}
+				},
+				// Photometric interpretation, 3 being a palette color image
+				262 => palette = int_value == 3,
+				277 => samples_per_pixel = int_value,
+				_ => {},
+			}
+		}
+
+		let num_colors = if palette {
+			1_u32
+				.checked_shl(bits_per_sample)
+				.ok_or_else(not_a_picture)?
+		} else {
+			0
+		};
+
+		let color_depth = bits_per_sample
+			.checked_mul(samples_per_pixel)
+			.ok_or_else(not_a_picture)?;
+
+		Ok(Self {
+			width,
+			height,
+			color_depth,
+			num_colors,
+		})
+	}
+
+	/// Attempt to extract [`PictureInformation`] from a WebP
+	///
+	/// # Errors
+	///
+	/// * `reader` is not a WebP image
+	pub fn from_webp(data: &[u8]) -> Result<Self> {
+		let not_a_picture = || LoftyError::new(ErrorKind::NotAPicture);
+
+		if data.len() < 20 || &data[..4] != b"RIFF" || &data[8..12] != b"WEBP" {
+			return Err(not_a_picture());
+		}
+
+		// The first chunk's content, following its fourcc (4) and size (4)
+		let chunk = &data[20..];
+
+		let (width, height, alpha) = match &data[12..16] {
+			// Lossy, starting with a frame tag (3) and start code (3)
+			b"VP8 " => {
+				let frame_header = chunk.get(..10).ok_or_else(not_a_picture)?;
+				if frame_header[3..6] != [0x9D, 0x01, 0x2A] {
+					return Err(not_a_picture());
+				}
+
+				// The upper 2 bits of each are the scale
+				let width = LittleEndian::read_u16(&frame_header[6..8]) & 0x3FFF;
+				let height = LittleEndian::read_u16(&frame_header[8..10]) & 0x3FFF;
+
+				(u32::from(width), u32::from(height), false)
+			},
+			// Lossless, starting with a signature (1)
+			b"VP8L" => {
+				let header = chunk.get(..5).ok_or_else(not_a_picture)?;
+				if header[0] != 0x2F {
+					return Err(not_a_picture());
+				}
+
+				// 14 bits for each of the width and height (minus one), followed by the alpha flag
+				let bits = LittleEndian::read_u32(&header[1..]);
+
+				(
+					(bits & 0x3FFF) + 1,
+					((bits >> 14) & 0x3FFF) + 1,
+					(bits >> 28) & 1 == 1,
+				)
+			},
+			// Extended, starting with flags (1) and reserved bytes (3)
+			b"VP8X" => {
+				let header = chunk.get(..10).ok_or_else(not_a_picture)?;
+
+				(
+					LittleEndian::read_u24(&header[4..7]) + 1,
+					LittleEndian::read_u24(&header[7..10]) + 1,
+					header[0] & 0x10 == 0x10,
+				)
+			},
+			_ => return Err(not_a_picture()),
+		};
+
+		Ok(Self {
+			width,
+			height,
+			color_depth: if alpha { 32 } else { 24 },
+			num_colors: 0,
+		})
+	}
 }
 
 /// Represents a picture.
 #[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Picture {
 	/// The picture type according to ID3v2 APIC
 	pub(crate) pic_type: PictureType,
@@ -448,6 +800,7 @@ pub struct Picture {
 	/// The picture's description
 	pub(crate) description: Option<Cow<'static, str>>,
 	/// The binary data of the picture
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::base64"))]
 	pub(crate) data: Cow<'static, [u8]>,
 }
 
@@ -488,7 +841,8 @@ impl Picture {
 			return Err(LoftyError::new(ErrorKind::NotAPicture));
 		}
 
-		let mime_type = Self::mimetype_from_bin(&data[..8])?;
+		let mime_type =
+			MimeType::from_bytes(&data).ok_or_else(|| LoftyError::new(ErrorKind::NotAPicture))?;
 
 		Ok(Self {
 			pic_type: PictureType::Other,
@@ -498,6 +852,44 @@ impl Picture {
 		})
 	}
 
+	/// Create a [`Picture`] from a file
+	///
+	/// The [`MimeType`] is determined from the file's content, and the
+	/// description will be set to the file name, without its extension.
+	///
+	/// # Errors
+	///
+	/// * `path` does not exist
+	/// * The file contains less than 8 bytes
+	/// * The file does not contain a supported format.
+	/// See [`MimeType`] for valid formats
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use lofty::LoftyError;
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::{MimeType, Picture, PictureType};
+	///
+	/// let picture = Picture::from_file("tests/picture/assets/png_640x628.png", PictureType::CoverFront)?;
+	///
+	/// assert_eq!(picture.pic_type(), PictureType::CoverFront);
+	/// assert_eq!(picture.mime_type(), &MimeType::Png);
+	/// assert_eq!(picture.description(), Some("png_640x628"));
+	/// # Ok(()) }
+	/// ```
+	pub fn from_file(path: impl AsRef<Path>, pic_type: PictureType) -> Result<Self> {
+		let path = path.as_ref();
+
+		let mut picture = Self::from_reader(&mut File::open(path)?)?;
+		picture.pic_type = pic_type;
+		picture.description = path
+			.file_stem()
+			.map(|stem| Cow::Owned(stem.to_string_lossy().into_owned()));
+
+		Ok(picture)
+	}
+
 	/// Create a new `Picture`
 	///
 	/// NOTE: This will **not** verify `data`'s signature.
@@ -517,6 +909,36 @@ impl Picture {
 		}
 	}
 
+	/// Create a new `Picture` that links to an image
+	///
+	/// The [`MimeType`] will be [`MimeType::Url`], with the image's URL stored
+	/// in place of its data.
+	///
+	/// NOTE: This is only supported by ID3v2 and FLAC pictures.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::{MimeType, Picture, PictureType};
+	///
+	/// let picture = Picture::new_url(
+	/// 	PictureType::CoverFront,
+	/// 	None,
+	/// 	String::from("https://example.com/cover.png"),
+	/// );
+	///
+	/// assert_eq!(picture.mime_type(), &MimeType::Url);
+	/// assert_eq!(picture.url(), Some("https://example.com/cover.png"));
+	/// ```
+	pub fn new_url(pic_type: PictureType, description: Option<String>, url: String) -> Self {
+		Self {
+			pic_type,
+			mime_type: MimeType::Url,
+			description: description.map(Cow::from),
+			data: Cow::from(url.into_bytes()),
+		}
+	}
+
 	/// Returns the [`PictureType`]
 	pub fn pic_type(&self) -> PictureType {
 		self.pic_type
@@ -546,10 +968,50 @@ impl Picture {
 	}
 
 	/// Returns the picture data
+	///
+	/// NOTE: For pictures with a [`MimeType::Url`], this will be the URL. See [`Picture::url`].
 	pub fn data(&self) -> &[u8] {
 		&self.data
 	}
 
+	/// Returns the URL of the image, if the picture is a link
+	///
+	/// This will only be the case if the [`MimeType`] is [`MimeType::Url`], and the data is valid text.
+	pub fn url(&self) -> Option<&str> {
+		match self.mime_type {
+			MimeType::Url => std::str::from_utf8(&self.data).ok(),
+			_ => None,
+		}
+	}
+
+	/// Returns the picture's dimensions in pixels as `(width, height)`
+	///
+	/// This supports JPEG, PNG, GIF, BMP, TIFF, and WebP images. See [`PictureInformation::from_picture`].
+	///
+	/// This will return `None` if the image format is unknown, or the data is corrupt.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::{MimeType, Picture, PictureType};
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// // A GIF header describing a 640x628 image
+	/// let data = b"GIF89a\x80\x02\x74\x02\x00\x00\x00".to_vec();
+	/// let picture = Picture::new_unchecked(PictureType::CoverFront, MimeType::Gif, None, data);
+	///
+	/// assert_eq!(picture.dimensions(), Some((640, 628)));
+	/// # Ok(()) }
+	/// ```
+	pub fn dimensions(&self) -> Option<(u32, u32)> {
+		match PictureInformation::from_picture(self) {
+			Ok(PictureInformation { width, height, .. }) if width > 0 && height > 0 => {
+				Some((width, height))
+			},
+			_ => None,
+		}
+	}
+
 	#[cfg(feature = "id3v2")]
 	/// Convert a [`Picture`] to a ID3v2 A/PIC byte Vec
 	///
@@ -561,7 +1023,7 @@ impl Picture {
 	///
 	/// ID3v2.2:
 	///
-	/// * The mimetype is not [`MimeType::Png`] or [`MimeType::Jpeg`]
+	/// * The mimetype is not [`MimeType::Png`], [`MimeType::Jpeg`], or [`MimeType::Url`]
 	pub fn as_apic_bytes(
 		&self,
 		version: Id3v2Version,
@@ -582,6 +1044,7 @@ impl Picture {
 			let format = match self.mime_type {
 				MimeType::Png => "PNG",
 				MimeType::Jpeg => "JPG",
+				MimeType::Url => "-->",
 				_ => {
 					return Err(Id3v2Error::new(Id3v2ErrorKind::BadPictureFormat(
 						self.mime_type.to_string(),
@@ -626,7 +1089,7 @@ impl Picture {
 	///
 	/// ID3v2.2:
 	///
-	/// * The format is not "PNG" or "JPG"
+	/// * The format is not "PNG", "JPG", or "-->"
 	pub fn from_apic_bytes(bytes: &[u8], version: Id3v2Version) -> Result<(Self, TextEncoding)> {
 		use crate::id3::v2::util::text_utils;
 
@@ -644,6 +1107,7 @@ impl Picture {
 			match format {
 				[b'P', b'N', b'G'] => MimeType::Png,
 				[b'J', b'P', b'G'] => MimeType::Jpeg,
+				[b'-', b'-', b'>'] => MimeType::Url,
 				_ => {
 					return Err(Id3v2Error::new(Id3v2ErrorKind::BadPictureFormat(
 						String::from_utf8_lossy(&format).into_owned(),
@@ -779,8 +1243,13 @@ impl Picture {
 		let desc_len = reader.read_u32::<BigEndian>()? as usize;
 		size -= 4;
 
+		// The description is followed by 20 bytes of picture information
+		if size < 20 || desc_len > size - 20 {
+			return Err(LoftyError::new(ErrorKind::TooMuchData));
+		}
+
 		let mut description = None;
-		if desc_len > 0 && desc_len < size {
+		if desc_len > 0 {
 			let pos = 12 + mime_len;
 
 			if let Ok(desc) = std::str::from_utf8(&content[pos..pos + desc_len]) {
@@ -857,34 +1326,28 @@ impl Picture {
 
 		let pic_type = PictureType::from_ape_key(key);
 
-		let reader = &mut &*bytes;
-		let mut pos = 0;
-
-		let mut description = None;
-		let mut desc_text = String::new();
-
-		while let Ok(ch) = reader.read_u8() {
-			pos += 1;
-
-			if ch == b'\0' {
-				break;
-			}
-
-			desc_text.push(char::from(ch));
-		}
-
-		if !desc_text.is_empty() {
-			description = Some(Cow::from(desc_text));
-		}
+		// The image is expected to be preceded by a null terminated filename, but some software
+		// writes it on its own. JPEG and PNG signatures can't be mistaken for UTF-8 text.
+		let (description, content) = if let [0xFF, 0xD8, ..] | [0x89, b'P', b'N', b'G', ..] = bytes
+		{
+			(None, bytes)
+		} else {
+			let pos = bytes
+				.iter()
+				.position(|b| *b == 0)
+				.ok_or_else(|| LoftyError::new(ErrorKind::NotAPicture))?;
 
-		let mime_type = {
-			let mut identifier = [0; 8];
-			reader.read_exact(&mut identifier)?;
+			let description = String::from_utf8_lossy(&bytes[..pos]);
 
-			Self::mimetype_from_bin(&identifier[..])?
+			(
+				(!description.is_empty()).then(|| Cow::from(description.into_owned())),
+				&bytes[pos + 1..],
+			)
 		};
 
-		let data = Cow::from(bytes[pos..].to_vec());
+		let mime_type =
+			MimeType::from_bytes(content).ok_or_else(|| LoftyError::new(ErrorKind::NotAPicture))?;
+		let data = Cow::from(content.to_vec());
 
 		Ok(Picture {
 			pic_type,
@@ -893,15 +1356,4 @@ impl Picture {
 			data,
 		})
 	}
-
-	fn mimetype_from_bin(bytes: &[u8]) -> Result<MimeType> {
-		match bytes[..8] {
-			[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A] => Ok(MimeType::Png),
-			[0xFF, 0xD8, ..] => Ok(MimeType::Jpeg),
-			[b'G', b'I', b'F', 0x38, 0x37 | 0x39, b'a', ..] => Ok(MimeType::Gif),
-			[b'B', b'M', ..] => Ok(MimeType::Bmp),
-			[b'I', b'I', b'*', 0x00, ..] | [b'M', b'M', 0x00, b'*', ..] => Ok(MimeType::Tiff),
-			_ => Err(LoftyError::new(ErrorKind::NotAPicture)),
-		}
-	}
 }
@@ -1,20 +1,54 @@
-use crate::ape::ApeFile;
-use crate::error::{ErrorKind, LoftyError, Result};
+use crate::aac::header::verify_adts_sync;
+use crate::asf::AsfFile;
+use crate::error::{ErrorKind, LoftyError, ParseWarning, Result, WarningCategory};
 use crate::file::{AudioFile, FileType, TaggedFile};
-use crate::flac::FlacFile;
-use crate::iff::aiff::AiffFile;
-use crate::iff::wav::WavFile;
 use crate::mp3::header::search_for_frame_sync;
-use crate::mp3::Mp3File;
-use crate::mp4::Mp4File;
 use crate::ogg::opus::OpusFile;
 use crate::ogg::speex::SpeexFile;
 use crate::ogg::vorbis::VorbisFile;
+use crate::properties::FileProperties;
 
 use std::fs::File;
 use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
 use std::path::Path;
 
+// The state of a single read, passed down to the parsers
+//
+// Unlike thread-local state, this stays with the read if it moves between threads, and can't leak
+// into other reads.
+#[derive(Default)]
+pub(crate) struct ParseContext {
+	// See `Probe::set_lenient_id3v2_parsing`
+	#[cfg(feature = "id3v2")]
+	pub(crate) lenient_id3v2: bool,
+	// `None` when warnings aren't being collected, so nothing is stored for normal reads
+	warnings: Option<Vec<ParseWarning>>,
+}
+
+impl ParseContext {
+	// A context that keeps every warning, see `ParseContext::into_warnings`
+	pub(crate) fn collecting() -> Self {
+		Self {
+			warnings: Some(Vec::new()),
+			..Self::default()
+		}
+	}
+
+	// Records a warning, if they're being collected
+	pub(crate) fn warn(&mut self, category: WarningCategory, message: impl Into<String>) {
+		if let Some(warnings) = self.warnings.as_mut() {
+			warnings.push(ParseWarning {
+				category,
+				message: message.into(),
+			});
+		}
+	}
+
+	pub(crate) fn into_warnings(self) -> Vec<ParseWarning> {
+		self.warnings.unwrap_or_default()
+	}
+}
+
 /// A format agnostic reader
 ///
 /// This provides a way to determine the [`FileType`] of a reader, for when a concrete
@@ -71,9 +105,31 @@ use std::path::Path;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// If the [`FileType`] is already known, it can be specified with [`Probe::with_file_type`] or
+/// [`Probe::set_file_type`]. No guessing will take place, the reader for that type will be used directly.
+///
+/// ```rust
+/// # use lofty::{LoftyError, Probe};
+/// # fn main() -> Result<(), LoftyError> {
+/// use lofty::FileType;
+/// use std::fs::File;
+///
+/// # let path = "tests/files/assets/minimal/full_test.flac";
+/// // A FLAC file with an unhelpful extension, such as "audio.bin"
+/// let file = File::open(path)?;
+///
+/// let tagged_file = Probe::with_file_type(file, FileType::FLAC).read(false)?;
+///
+/// assert_eq!(tagged_file.file_type(), FileType::FLAC);
+/// # Ok(())
+/// # }
+/// ```
 pub struct Probe<R: Read> {
 	inner: R,
 	f_ty: Option<FileType>,
+	#[cfg(feature = "id3v2")]
+	lenient_id3v2: bool,
 }
 
 impl<R: Read> Probe<R> {
@@ -82,6 +138,8 @@ impl<R: Read> Probe<R> {
 		Self {
 			inner: reader,
 			f_ty: None,
+			#[cfg(feature = "id3v2")]
+			lenient_id3v2: false,
 		}
 	}
 
@@ -90,6 +148,8 @@ impl<R: Read> Probe<R> {
 		Self {
 			inner: reader,
 			f_ty: Some(file_type),
+			#[cfg(feature = "id3v2")]
+			lenient_id3v2: false,
 		}
 	}
 
@@ -103,6 +163,23 @@ impl<R: Read> Probe<R> {
 		self.f_ty = Some(file_type)
 	}
 
+	/// Set whether ID3v2 tags are read leniently
+	///
+	/// By default, a frame that fails to parse will fail the entire tag. When enabled, a corrupt frame
+	/// will instead be skipped (using its declared size), and parsing will continue with the next frame.
+	/// A [`ParseWarning`] is emitted for each skipped frame, see [`Probe::read_with_warnings`].
+	/// Likewise, a CRC-32 mismatch (see [`Id3v2ErrorKind::CrcMismatch`](crate::error::Id3v2ErrorKind::CrcMismatch))
+	/// only results in a [`ParseWarning`].
+	///
+	/// NOTE: If a frame header itself is unreadable, there's no way to find the next frame, so the
+	/// remaining frames will be discarded.
+	///
+	/// This only applies to reads made through this `Probe`, and is disabled by default.
+	#[cfg(feature = "id3v2")]
+	pub fn set_lenient_id3v2_parsing(&mut self, lenient: bool) {
+		self.lenient_id3v2 = lenient
+	}
+
 	/// Extract the reader
 	pub fn into_inner(self) -> R {
 		self.inner
@@ -127,10 +204,65 @@ impl Probe<BufReader<File>> {
 		Ok(Self {
 			inner: BufReader::new(File::open(path)?),
 			f_ty: FileType::from_path(path),
+			#[cfg(feature = "id3v2")]
+			lenient_id3v2: false,
 		})
 	}
 }
 
+impl<'a> Probe<Cursor<&'a [u8]>> {
+	/// Create a new `Probe` from an in-memory buffer
+	///
+	/// The [`FileType`] can then be guessed from the content with [`Probe::guess_file_type`].
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// # use lofty::{LoftyError, Probe};
+	/// # fn main() -> Result<(), LoftyError> {
+	/// use lofty::FileType;
+	///
+	/// let probe = Probe::new_from_bytes(b"MAC").guess_file_type()?;
+	///
+	/// assert_eq!(probe.file_type(), Some(FileType::APE));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn new_from_bytes(bytes: &'a [u8]) -> Self {
+		Self::new(Cursor::new(bytes))
+	}
+
+	/// Attempts to guess the [`FileType`] from the start of a file
+	///
+	/// This is useful when only part of the file is available, such as when streaming it from a remote source.
+	/// This will return `None` if `prefix` is too short, or the file type can't be determined.
+	///
+	/// At most 36 bytes are needed, unless the file starts with an ID3v2 tag. The tag is skipped using the size
+	/// in its 10 byte header, and then 4 more bytes are needed (`10 + <tag size> + 4`). If an APE tag follows,
+	/// it is skipped in the same way, and 36 bytes are needed after it.
+	///
+	/// NOTE: MP3 frames can be preceded by junk, so if nothing else matches after an ID3v2 tag, the rest of
+	/// `prefix` will be searched for a frame sync.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use lofty::{FileType, Probe};
+	///
+	/// // Only the first few bytes are available
+	/// let prefix = b"fLaC\x00\x00\x00\x22";
+	///
+	/// assert_eq!(Probe::guess_from_prefix(prefix), Some(FileType::FLAC));
+	/// assert_eq!(Probe::guess_from_prefix(b"ID3"), None);
+	/// ```
+	pub fn guess_from_prefix(prefix: &'a [u8]) -> Option<FileType> {
+		Self::new_from_bytes(prefix)
+			.guess_file_type()
+			.ok()
+			.and_then(|probe| probe.file_type())
+	}
+}
+
 impl<R: Read + Seek> Probe<R> {
 	/// Attempts to get the [`FileType`] based on the data in the reader
 	///
@@ -142,14 +274,23 @@ impl<R: Read + Seek> Probe<R> {
 	/// If an error does occur, there is likely an issue with the provided
 	/// reader, and the entire `Probe` should be discarded.
 	pub fn guess_file_type(mut self) -> std::io::Result<Self> {
-		let f_ty = self.guess_inner()?;
+		let f_ty = self.guess_inner(0)?;
 		self.f_ty = f_ty.or(self.f_ty);
 
 		Ok(self)
 	}
 
+	// `skipped_tags` is the number of ID3v2/APE tags already skipped to get here
 	#[allow(clippy::shadow_unrelated)]
-	fn guess_inner(&mut self) -> std::io::Result<Option<FileType>> {
+	fn guess_inner(&mut self, skipped_tags: u8) -> std::io::Result<Option<FileType>> {
+		// A file should have at most an ID3v2 tag followed by an APE tag, anything more
+		// is likely crafted to exhaust the stack
+		const MAX_SKIPPED_TAGS: u8 = 4;
+
+		if skipped_tags >= MAX_SKIPPED_TAGS {
+			return Ok(None);
+		}
+
 		// temporary buffer for storing 36 bytes
 		// (36 is just a guess as to how long the data for estimating the file type might be)
 		let mut buf = [0; 36];
@@ -169,7 +310,7 @@ impl<R: Read + Seek> Probe<R> {
 			(Some(f_ty), _) => Ok(Some(f_ty)),
 			// The file starts with an ID3v2 tag; this means other data can follow (e.g. APE or MP3 frames)
 			(None, Some(id3_len)) => {
-				// `id3_len` is the size of the tag, not including the header (10 bytes)
+				// `id3_len` is the size of the tag (including the footer), not including the header (10 bytes)
 				let position_after_id3_block = self
 					.inner
 					.seek(SeekFrom::Current(i64::from(10 + id3_len)))?;
@@ -186,6 +327,10 @@ impl<R: Read + Seek> Probe<R> {
 				let file_type_after_id3_block = match &ident {
 					[b'M', b'A', b'C', ..] => Ok(Some(FileType::APE)),
 					b"fLaC" => Ok(Some(FileType::FLAC)),
+					b"MPCK" | [b'M', b'P', b'+', ..] => Ok(Some(FileType::Mpc)),
+					// An APE tag can follow the ID3v2 tag, the file type comes after it
+					b"APET" => self.guess_inner(skipped_tags + 1),
+					[0xFF, b, ..] if verify_adts_sync([0xFF, *b]) => Ok(Some(FileType::Aac)),
 					// Search for a frame sync, which may be preceded by junk
 					_ if search_for_frame_sync(&mut self.inner)?.is_some() => {
 						Ok(Some(FileType::MP3))
@@ -198,13 +343,27 @@ impl<R: Read + Seek> Probe<R> {
 
 				file_type_after_id3_block
 			},
+			// An APE tag at the start of the file is against the spec, but still possible
+			(None, None) if buf_len >= 32 && &buf[..8] == b"APETAGEX" => {
+				// The size doesn't include the header (32 bytes)
+				let size = u32::from_le_bytes([buf[12], buf[13], buf[14], buf[15]]);
+
+				self.inner.seek(SeekFrom::Current(32 + i64::from(size)))?;
+
+				let file_type_after_ape_tag = self.guess_inner(skipped_tags + 1);
+
+				self.inner.seek(SeekFrom::Start(starting_position))?;
+
+				file_type_after_ape_tag
+			},
 			_ => Ok(None),
 		}
 	}
 
 	/// Attempts to extract a [`TaggedFile`] from the reader
 	///
-	/// If `read_properties` is false, the properties will be zeroed out.
+	/// If `read_properties` is false, the properties will be zeroed out, and the audio stream
+	/// will only be read as far as needed to locate the tags. See [`AudioFile::read_from`].
 	///
 	/// # Errors
 	///
@@ -213,20 +372,166 @@ impl<R: Read + Seek> Probe<R> {
 	///       [`Probe::guess_file_type`] or [`Probe::set_file_type`]. When reading from
 	///       paths, this is not necessary.
 	/// * The reader contains invalid data
-	pub fn read(mut self, read_properties: bool) -> Result<TaggedFile> {
+	pub fn read(self, read_properties: bool) -> Result<TaggedFile> {
+		self.read_with_context(read_properties, &mut ParseContext::default())
+	}
+
+	/// Attempts to extract a [`TaggedFile`] from the reader, along with any [`ParseWarning`]s
+	///
+	/// Some problems are worked around while reading, rather than failing (Ex. a truncated atom,
+	/// or text in an unexpected encoding). This is the same as [`Probe::read`], but also returns
+	/// a warning for each of them.
+	///
+	/// # Errors
+	///
+	/// See [`Probe::read`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # fn main() -> lofty::Result<()> {
+	/// use lofty::Probe;
+	///
+	/// let (tagged_file, warnings) =
+	/// 	Probe::open("tests/files/assets/minimal/full_test.mp3")?.read_with_warnings(true)?;
+	///
+	/// for warning in warnings {
+	/// 	println!("{}", warning);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn read_with_warnings(
+		self,
+		read_properties: bool,
+	) -> Result<(TaggedFile, Vec<ParseWarning>)> {
+		let mut context = ParseContext::collecting();
+		let tagged_file = self.read_with_context(read_properties, &mut context)?;
+
+		Ok((tagged_file, context.into_warnings()))
+	}
+
+	// ASF and OGG files can't produce warnings or contain ID3v2 tags, so they don't need the context
+	fn read_with_context(
+		mut self,
+		read_properties: bool,
+		context: &mut ParseContext,
+	) -> Result<TaggedFile> {
+		#[cfg(feature = "id3v2")]
+		{
+			context.lenient_id3v2 = self.lenient_id3v2;
+		}
+
 		let reader = &mut self.inner;
 
 		match self.f_ty {
 			Some(f_type) => Ok(match f_type {
-				FileType::AIFF => AiffFile::read_from(reader, read_properties)?.into(),
-				FileType::APE => ApeFile::read_from(reader, read_properties)?.into(),
-				FileType::FLAC => FlacFile::read_from(reader, read_properties)?.into(),
-				FileType::MP3 => Mp3File::read_from(reader, read_properties)?.into(),
+				FileType::Aac => {
+					crate::aac::read::read_from(reader, true, read_properties, context)?.into()
+				},
+				FileType::AIFF => {
+					crate::iff::aiff::read::read_from(reader, true, read_properties, context)?
+						.into()
+				},
+				FileType::APE => {
+					crate::ape::read::read_from(reader, true, read_properties, context)?.into()
+				},
+				FileType::Asf => AsfFile::read_from(reader, read_properties)?.into(),
+				FileType::Dsf => {
+					crate::dsf::read::read_from(reader, true, read_properties, context)?.into()
+				},
+				FileType::FLAC => {
+					crate::flac::read::read_from(reader, true, read_properties, context)?.into()
+				},
+				FileType::MP3 => {
+					crate::mp3::read::read_from(reader, true, read_properties, false, context)?
+						.into()
+				},
 				FileType::Opus => OpusFile::read_from(reader, read_properties)?.into(),
 				FileType::Vorbis => VorbisFile::read_from(reader, read_properties)?.into(),
-				FileType::WAV => WavFile::read_from(reader, read_properties)?.into(),
-				FileType::MP4 => Mp4File::read_from(reader, read_properties)?.into(),
+				FileType::WAV => {
+					crate::iff::wav::read::read_from(reader, true, read_properties, context)?.into()
+				},
+				FileType::MP4 => {
+					crate::mp4::read::read_from(reader, true, read_properties, context)?.into()
+				},
+				FileType::Mpc => {
+					crate::mpc::read::read_from(reader, true, read_properties, context)?.into()
+				},
 				FileType::Speex => SpeexFile::read_from(reader, read_properties)?.into(),
+				FileType::WavPack => {
+					crate::wavpack::read::read_from(reader, true, read_properties, context)?.into()
+				},
+			}),
+			None => Err(LoftyError::new(ErrorKind::UnknownFormat)),
+		}
+	}
+
+	/// Attempts to read the [`FileProperties`] of the reader, without reading any tags
+	///
+	/// This is useful when only the properties are needed, such as when calculating the
+	/// durations of many files. Tags are still located in order to determine the length of the
+	/// audio stream, but their contents are not read.
+	///
+	/// # Errors
+	///
+	/// See [`Probe::read`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # fn main() -> lofty::Result<()> {
+	/// use lofty::Probe;
+	///
+	/// let properties = Probe::open("tests/files/assets/minimal/full_test.mp3")?.read_properties_only()?;
+	///
+	/// assert!(!properties.duration().is_zero());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn read_properties_only(mut self) -> Result<FileProperties> {
+		let reader = &mut self.inner;
+		// No tags are read, so there's nothing that could be parsed leniently
+		let context = &mut ParseContext::default();
+
+		match self.f_ty {
+			Some(f_type) => Ok(match f_type {
+				FileType::Aac => crate::aac::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::AIFF => {
+					crate::iff::aiff::read::read_from(reader, false, true, context)?.properties
+				},
+				FileType::APE => crate::ape::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::Asf => crate::asf::read::read_from(reader, false, true)?
+					.properties
+					.into(),
+				FileType::Dsf => crate::dsf::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::FLAC => crate::flac::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::MP3 => crate::mp3::read::read_from(reader, false, true, false, context)?
+					.properties
+					.into(),
+				FileType::Opus | FileType::Speex | FileType::Vorbis => {
+					crate::ogg::read_properties(reader, f_type)?
+				},
+				FileType::WAV => crate::iff::wav::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::MP4 => crate::mp4::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::Mpc => crate::mpc::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
+				FileType::WavPack => crate::wavpack::read::read_from(reader, false, true, context)?
+					.properties
+					.into(),
 			}),
 			None => Err(LoftyError::new(ErrorKind::UnknownFormat)),
 		}
@@ -247,6 +552,20 @@ pub fn read_from(file: &mut File, read_properties: bool) -> Result<TaggedFile> {
 		.read(read_properties)
 }
 
+/// Read a [`TaggedFile`] from an in-memory buffer
+///
+/// # Errors
+///
+/// See:
+///
+/// * [`Probe::guess_file_type`]
+/// * [`Probe::read`]
+pub fn read_from_bytes(bytes: &[u8], read_properties: bool) -> Result<TaggedFile> {
+	Probe::new_from_bytes(bytes)
+		.guess_file_type()?
+		.read(read_properties)
+}
+
 /// Read a [`TaggedFile`] from a path
 ///
 /// NOTE: This will determine the [`FileType`] from the extension
@@ -264,6 +583,147 @@ where
 	Probe::open(path)?.read(read_properties)
 }
 
+/// Read a [`TaggedFile`] from a path, along with any [`ParseWarning`]s
+///
+/// NOTE: This will determine the [`FileType`] from the extension
+///
+/// # Errors
+///
+/// See:
+///
+/// * [`Probe::open`]
+/// * [`Probe::read_with_warnings`]
+pub fn read_from_path_with_warnings<P>(
+	path: P,
+	read_properties: bool,
+) -> Result<(TaggedFile, Vec<ParseWarning>)>
+where
+	P: AsRef<Path>,
+{
+	Probe::open(path)?.read_with_warnings(read_properties)
+}
+
+/// Read the [`FileProperties`] of a file from a path, without reading any tags
+///
+/// NOTE: This will determine the [`FileType`] from the extension
+///
+/// # Errors
+///
+/// See:
+///
+/// * [`Probe::open`]
+/// * [`Probe::read_properties_only`]
+pub fn read_from_path_properties_only<P>(path: P) -> Result<FileProperties>
+where
+	P: AsRef<Path>,
+{
+	Probe::open(path)?.read_properties_only()
+}
+
+/// Read a [`TaggedFile`] from an async reader
+///
+/// The parsers themselves are synchronous, so the reader's content (from its current position) is
+/// read asynchronously into memory, and then parsed as with [`read_from_bytes`]. No IO takes place
+/// after the content has been read.
+///
+/// NOTE: The **entire** content is buffered in memory, not just the parts needed to read the tags and
+/// properties. The buffer is limited to [`max_async_buffer_size`](crate::max_async_buffer_size)
+/// (256 MiB by default) and [`max_allocation`](crate::max_allocation), whichever is smaller.
+///
+/// # Errors
+///
+/// * [`std::io::Error`]
+/// * The content is larger than [`max_async_buffer_size`](crate::max_async_buffer_size) or [`max_allocation`](crate::max_allocation)
+///
+/// See:
+///
+/// * [`Probe::guess_file_type`]
+/// * [`Probe::read`]
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # async fn run() -> lofty::Result<()> {
+/// use lofty::read_from_async;
+/// use tokio::fs::File;
+///
+/// let mut file = File::open("tests/files/assets/minimal/full_test.mp3").await?;
+///
+/// let tagged_file = read_from_async(&mut file, false).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn read_from_async<R>(reader: &mut R, read_properties: bool) -> Result<TaggedFile>
+where
+	R: tokio::io::AsyncRead + Unpin,
+{
+	let content = read_to_end_async(reader).await?;
+	read_from_bytes(&content, read_properties)
+}
+
+/// Read a [`TaggedFile`] from a path asynchronously
+///
+/// As with [`read_from_async`], the entire file is read asynchronously into memory, and then parsed with
+/// the synchronous parsers.
+///
+/// NOTE: This will determine the [`FileType`] from the extension
+///
+/// # Errors
+///
+/// * `path` does not exist
+/// * The file is larger than [`max_async_buffer_size`](crate::max_async_buffer_size) or [`max_allocation`](crate::max_allocation)
+///
+/// See:
+///
+/// * [`Probe::read`]
+#[cfg(feature = "tokio")]
+pub async fn read_from_path_async<P>(path: P, read_properties: bool) -> Result<TaggedFile>
+where
+	P: AsRef<Path>,
+{
+	let path = path.as_ref();
+	let mut file = tokio::fs::File::open(path).await?;
+	let content = read_to_end_async(&mut file).await?;
+
+	let mut probe = Probe::new_from_bytes(&content);
+	if let Some(file_type) = FileType::from_path(path) {
+		probe.set_file_type(file_type);
+	}
+
+	probe.read(read_properties)
+}
+
+#[cfg(feature = "tokio")]
+async fn read_to_end_async<R>(reader: &mut R) -> Result<Vec<u8>>
+where
+	R: tokio::io::AsyncRead + Unpin,
+{
+	let limit = std::cmp::min(crate::max_allocation(), crate::max_async_buffer_size());
+	read_to_end_limited_async(reader, limit).await
+}
+
+#[cfg(feature = "tokio")]
+async fn read_to_end_limited_async<R>(reader: &mut R, limit: usize) -> Result<Vec<u8>>
+where
+	R: tokio::io::AsyncRead + Unpin,
+{
+	use tokio::io::AsyncReadExt;
+
+	// Read at most one byte past the limit, so an oversized reader is caught without reading it all
+	let mut content = Vec::new();
+	reader
+		.take((limit as u64).saturating_add(1))
+		.read_to_end(&mut content)
+		.await?;
+
+	if content.len() > limit {
+		return Err(LoftyError::new(ErrorKind::TooMuchData));
+	}
+
+	Ok(content)
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{FileType, Probe};
@@ -296,8 +756,65 @@ mod tests {
 		assert_eq!(probe.file_type(), Some(crate::FileType::MP3));
 	}
 
+	#[test]
+	fn id3v2_footer() {
+		let data: [&[u8]; 4] = [
+			// ID3v2.4 header with the footer flag set (10 bytes)
+			b"ID3\x04\x00\x10\x00\x00\x00\x0E",
+			// TALB frame
+			b"TALB\x00\x00\x00\x04\x00\x00\x00Foo",
+			// Footer
+			b"3DI\x04\x00\x10\x00\x00\x00\x0E",
+			b"fLaC",
+		];
+		let data: Vec<u8> = data.into_iter().flatten().copied().collect();
+		let data = std::io::Cursor::new(&data);
+		let probe = Probe::new(data).guess_file_type().unwrap();
+		assert_eq!(probe.file_type(), Some(crate::FileType::FLAC));
+	}
+
+	#[test]
+	fn stacked_ape_tags() {
+		// An empty APE tag header, which only skips itself
+		let mut ape_header = b"APETAGEX\xD0\x07\x00\x00".to_vec();
+		ape_header.extend([0; 20]);
+
+		let mut data = ape_header.repeat(100_000);
+		data.extend(b"MAC ");
+
+		let probe = Probe::new(std::io::Cursor::new(&data))
+			.guess_file_type()
+			.unwrap();
+		assert_eq!(probe.file_type(), None);
+
+		// A reasonable amount of tags is still skipped
+		let mut data = ape_header.repeat(2);
+		data.extend(b"MAC \x96\x0F\x00\x00");
+		data.extend([0; 32]);
+
+		let probe = Probe::new(std::io::Cursor::new(&data))
+			.guess_file_type()
+			.unwrap();
+		assert_eq!(probe.file_type(), Some(FileType::APE));
+	}
+
+	#[test]
+	fn probe_explicit_file_type() {
+		let file = File::open("tests/files/assets/minimal/full_test.flac").unwrap();
+
+		let mut probe = Probe::new(file);
+		assert_eq!(probe.file_type(), None);
+
+		probe.set_file_type(FileType::FLAC);
+
+		let tagged_file = probe.read(true).unwrap();
+		assert_eq!(tagged_file.file_type(), FileType::FLAC);
+	}
+
 	fn test_probe(path: &str, expected_file_type_guess: FileType) {
 		test_probe_file(path, expected_file_type_guess);
+		test_probe_bytes(path, expected_file_type_guess);
+		test_probe_prefix(path, expected_file_type_guess);
 		test_probe_path(path, expected_file_type_guess);
 	}
 
@@ -308,12 +825,38 @@ mod tests {
 		assert_eq!(probe.file_type(), Some(expected_file_type_guess));
 	}
 
+	// Test from an in-memory buffer
+	fn test_probe_bytes(path: &str, expected_file_type_guess: FileType) {
+		let bytes = std::fs::read(path).unwrap();
+		let probe = Probe::new_from_bytes(&bytes).guess_file_type().unwrap();
+		assert_eq!(probe.file_type(), Some(expected_file_type_guess));
+	}
+
+	// Test from the start of the file
+	fn test_probe_prefix(path: &str, expected_file_type_guess: FileType) {
+		let bytes = std::fs::read(path).unwrap();
+
+		// All of the test files have (at most) a small ID3v2 tag
+		let prefix = &bytes[..128];
+		assert_eq!(
+			Probe::guess_from_prefix(prefix),
+			Some(expected_file_type_guess)
+		);
+
+		assert_eq!(Probe::guess_from_prefix(&bytes[..2]), None);
+	}
+
 	// Test from file extension
 	fn test_probe_path(path: &str, expected_file_type_guess: FileType) {
 		let probe = Probe::open(path).unwrap();
 		assert_eq!(probe.file_type(), Some(expected_file_type_guess));
 	}
 
+	#[test]
+	fn probe_aac() {
+		test_probe("tests/files/assets/minimal/full_test.aac", FileType::Aac);
+	}
+
 	#[test]
 	fn probe_aiff() {
 		test_probe("tests/files/assets/minimal/full_test.aiff", FileType::AIFF);
@@ -334,6 +877,16 @@ mod tests {
 		test_probe("tests/files/assets/flac_with_id3v2.flac", FileType::FLAC);
 	}
 
+	#[test]
+	fn probe_asf() {
+		test_probe("tests/files/assets/minimal/full_test.wma", FileType::Asf);
+	}
+
+	#[test]
+	fn probe_dsf() {
+		test_probe("tests/files/assets/minimal/full_test.dsf", FileType::Dsf);
+	}
+
 	#[test]
 	fn probe_mp3_with_id3v2() {
 		test_probe("tests/files/assets/minimal/full_test.mp3", FileType::MP3);
@@ -362,6 +915,11 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn probe_mpc() {
+		test_probe("tests/files/assets/minimal/full_test.mpc", FileType::Mpc);
+	}
+
 	#[test]
 	fn probe_wav() {
 		test_probe(
@@ -369,4 +927,55 @@ mod tests {
 			FileType::WAV,
 		);
 	}
+
+	#[test]
+	fn probe_wavpack() {
+		test_probe("tests/files/assets/minimal/full_test.wv", FileType::WavPack);
+	}
+
+	#[cfg(feature = "tokio")]
+	#[test]
+	fn read_async() {
+		use crate::AudioFile;
+
+		let path = "tests/files/assets/minimal/full_test.mp3";
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.build()
+			.unwrap();
+
+		let expected = crate::read_from_path(path, true).unwrap();
+
+		let from_reader = runtime.block_on(async {
+			let mut file = tokio::fs::File::open(path).await.unwrap();
+			crate::read_from_async(&mut file, true).await.unwrap()
+		});
+		let from_path = runtime
+			.block_on(crate::read_from_path_async(path, true))
+			.unwrap();
+
+		for tagged_file in [from_reader, from_path] {
+			assert_eq!(tagged_file.file_type(), expected.file_type());
+			assert_eq!(tagged_file.properties(), expected.properties());
+			assert_eq!(tagged_file.tags().len(), expected.tags().len());
+		}
+	}
+
+	#[cfg(feature = "tokio")]
+	#[test]
+	fn read_async_limit() {
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.build()
+			.unwrap();
+
+		let content = [0_u8; 16];
+
+		let read = runtime.block_on(super::read_to_end_limited_async(&mut &content[..], 16));
+		assert_eq!(read.unwrap().len(), 16);
+
+		let read = runtime.block_on(super::read_to_end_limited_async(&mut &content[..], 15));
+		assert!(matches!(
+			read.unwrap_err().kind(),
+			crate::error::ErrorKind::TooMuchData
+		));
+	}
 }
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// Various *immutable* audio properties
 pub struct FileProperties {
@@ -27,16 +28,34 @@ impl Default for FileProperties {
 
 impl FileProperties {
 	/// Duration
+	///
+	/// This will be [`Duration::ZERO`] if it can't be determined, such as when a file has no audio.
 	pub fn duration(&self) -> Duration {
 		self.duration
 	}
 
 	/// Overall bitrate (kbps)
+	///
+	/// This is calculated from the size of the file and its duration, so it includes
+	/// the size of any tags.
 	pub fn overall_bitrate(&self) -> Option<u32> {
 		self.overall_bitrate
 	}
 
 	/// Audio bitrate (kbps)
+	///
+	/// How this is determined varies between formats:
+	///
+	/// | Source                                        | Formats                                                           |
+	/// |-----------------------------------------------|-------------------------------------------------------------------|
+	/// | Calculated from the size of the audio stream  | `AAC`, `AIFF`, `APE`, `DSF`, `FLAC`, `MPC`, `Opus`, `WAV`, `WavPack` |
+	/// | Average bitrate reported by the codec         | `ASF`, `MP4`                                                      |
+	/// | Nominal bitrate reported by the codec         | `Speex`, `Vorbis`                                                 |
+	///
+	/// `MP3` files use the stream size from a Xing/VBRI header, falling back to the bitrate of the first frame.
+	/// Without a header, this is only an estimate for VBR files, see [`Mp3File::read_from_accurate`](crate::mp3::Mp3File::read_from_accurate).
+	///
+	/// NOTE: A nominal bitrate is the encoder's target, and may differ from the actual bitrate.
 	pub fn audio_bitrate(&self) -> Option<u32> {
 		self.audio_bitrate
 	}
@@ -59,15 +78,22 @@ impl FileProperties {
 
 #[cfg(test)]
 mod tests {
+	use crate::aac::{AacFile, AacProperties};
 	use crate::ape::{ApeFile, ApeProperties};
-	use crate::flac::FlacFile;
+	use crate::asf::{AsfFile, AsfProperties};
+	use crate::dsf::{DsfFile, DsfProperties};
+	use crate::flac::{FlacFile, FlacProperties};
 	use crate::iff::{AiffFile, WavFile, WavFormat, WavProperties};
-	use crate::mp3::{ChannelMode, Emphasis, Layer, Mp3File, Mp3Properties, MpegVersion};
+	use crate::mp3::{
+		ChannelMode, Emphasis, LameHeader, Layer, Mp3File, Mp3Properties, MpegVersion,
+	};
 	use crate::mp4::{AudioObjectType, Mp4Codec, Mp4File, Mp4Properties};
+	use crate::mpc::{MpcFile, MpcProperties};
 	use crate::ogg::{
 		OpusFile, OpusProperties, SpeexFile, SpeexProperties, VorbisFile, VorbisProperties,
 	};
-	use crate::{AudioFile, FileProperties};
+	use crate::wavpack::{WavPackFile, WavPackProperties};
+	use crate::{AudioFile, FileProperties, FileType};
 
 	use std::fs::File;
 	use std::time::Duration;
@@ -75,6 +101,17 @@ mod tests {
 	// These values are taken from FFmpeg's ffprobe
 	// They may be *slightly* different due to how ffprobe rounds
 
+	const AAC_PROPERTIES: AacProperties = AacProperties {
+		audio_object_type: AudioObjectType::AacLowComplexity,
+		duration: Duration::from_millis(1393),
+		overall_bitrate: 35,
+		audio_bitrate: 34,
+		sample_rate: 44100,
+		channels: 2,
+		copyright: false,
+		original: false,
+	};
+
 	const AIFF_PROPERTIES: FileProperties = FileProperties {
 		duration: Duration::from_millis(1428),
 		overall_bitrate: Some(1542),
@@ -94,13 +131,39 @@ mod tests {
 		channels: 2,
 	};
 
-	const FLAC_PROPERTIES: FileProperties = FileProperties {
+	const ASF_PROPERTIES: AsfProperties = AsfProperties {
+		codec_id: 0x161,
 		duration: Duration::from_millis(1428),
-		overall_bitrate: Some(321),
-		audio_bitrate: Some(275),
-		sample_rate: Some(48000),
-		bit_depth: Some(16),
-		channels: Some(2),
+		overall_bitrate: 19,
+		audio_bitrate: 128,
+		sample_rate: 48000,
+		bit_depth: 16,
+		channels: 2,
+	};
+
+	const DSF_PROPERTIES: DsfProperties = DsfProperties {
+		duration: Duration::from_millis(23),
+		overall_bitrate: 5741,
+		audio_bitrate: 5698,
+		sample_rate: 2_822_400,
+		channels: 2,
+		bit_depth: 1,
+	};
+
+	const FLAC_PROPERTIES: FlacProperties = FlacProperties {
+		duration: Duration::from_millis(1428),
+		overall_bitrate: 321,
+		audio_bitrate: 275,
+		sample_rate: 48000,
+		bit_depth: 16,
+		channels: 2,
+		min_block_size: 4608,
+		max_block_size: 4608,
+		total_samples: 68546,
+		md5_signature: [
+			0x7B, 0xC2, 0xB7, 0x37, 0xE1, 0xCD, 0x61, 0x1F, 0xD8, 0x91, 0xC7, 0x18, 0xDF, 0x68,
+			0x4B, 0xD3,
+		],
 	};
 
 	const MP3_PROPERTIES: Mp3Properties = Mp3Properties {
@@ -116,6 +179,16 @@ mod tests {
 		sample_rate: 48000,
 		channels: 2,
 		emphasis: Emphasis::None,
+		vbr: true,
+		approximate: false,
+		lame_header: Some(LameHeader {
+			encoder: *b"Lavc58.91",
+			revision: 0,
+			vbr_method: 0,
+			encoding_flags: 0,
+			encoder_delay: 576,
+			encoder_padding: 1150,
+		}),
 	};
 
 	const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -151,6 +224,15 @@ mod tests {
 		channels: 2,
 	};
 
+	const MPC_PROPERTIES: MpcProperties = MpcProperties {
+		version: 8,
+		duration: Duration::from_millis(1428),
+		overall_bitrate: 8,
+		audio_bitrate: 6,
+		sample_rate: 48000,
+		channels: 2,
+	};
+
 	const OPUS_PROPERTIES: OpusProperties = OpusProperties {
 		duration: Duration::from_millis(1428),
 		overall_bitrate: 120,
@@ -158,6 +240,9 @@ mod tests {
 		channels: 2,
 		version: 1,
 		input_sample_rate: 48000,
+		pre_skip: 312,
+		output_gain: 0,
+		channel_mapping_family: 0,
 	};
 
 	const SPEEX_PROPERTIES: SpeexProperties = SpeexProperties {
@@ -170,6 +255,7 @@ mod tests {
 		overall_bitrate: 32,
 		audio_bitrate: 29,
 		nominal_bitrate: 29600,
+		frames_per_packet: 1,
 	};
 
 	const VORBIS_PROPERTIES: VorbisProperties = VorbisProperties {
@@ -194,6 +280,17 @@ mod tests {
 		channels: 2,
 	};
 
+	const WAVPACK_PROPERTIES: WavPackProperties = WavPackProperties {
+		version: 0x410,
+		duration: Duration::from_millis(1428),
+		overall_bitrate: 8,
+		audio_bitrate: 6,
+		sample_rate: 48000,
+		channels: 2,
+		bit_depth: 16,
+		lossless: true,
+	};
+
 	fn get_properties<T>(path: &str) -> T::Properties
 	where
 		T: AudioFile,
@@ -206,6 +303,14 @@ mod tests {
 		audio_file.properties().clone()
 	}
 
+	#[test]
+	fn aac_properties() {
+		assert_eq!(
+			get_properties::<AacFile>("tests/files/assets/minimal/full_test.aac"),
+			AAC_PROPERTIES
+		)
+	}
+
 	#[test]
 	fn aiff_properties() {
 		assert_eq!(
@@ -222,6 +327,22 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn asf_properties() {
+		assert_eq!(
+			get_properties::<AsfFile>("tests/files/assets/minimal/full_test.wma"),
+			ASF_PROPERTIES
+		)
+	}
+
+	#[test]
+	fn dsf_properties() {
+		assert_eq!(
+			get_properties::<DsfFile>("tests/files/assets/minimal/full_test.dsf"),
+			DSF_PROPERTIES
+		)
+	}
+
 	#[test]
 	fn flac_properties() {
 		assert_eq!(
@@ -230,6 +351,19 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn flac_unknown_properties() {
+		// Zeroed values are unknown, and shouldn't be carried over as `Some(0)`
+		assert_eq!(
+			FileProperties::from(FlacProperties::default()),
+			FileProperties::default()
+		);
+
+		let properties = FileProperties::from(FLAC_PROPERTIES);
+		assert_eq!(properties.sample_rate(), Some(48000));
+		assert_eq!(properties.overall_bitrate(), Some(321));
+	}
+
 	#[test]
 	fn mp3_properties() {
 		assert_eq!(
@@ -262,6 +396,14 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn mpc_properties() {
+		assert_eq!(
+			get_properties::<MpcFile>("tests/files/assets/minimal/full_test.mpc"),
+			MPC_PROPERTIES
+		)
+	}
+
 	#[test]
 	fn opus_properties() {
 		assert_eq!(
@@ -293,4 +435,76 @@ mod tests {
 			WAV_PROPERTIES
 		)
 	}
+
+	#[test]
+	fn wavpack_properties() {
+		assert_eq!(
+			get_properties::<WavPackFile>("tests/files/assets/minimal/full_test.wv"),
+			WAVPACK_PROPERTIES
+		)
+	}
+
+	// One file per format, read through `TaggedFile`
+	const GENERIC_FILES: [&str; 16] = [
+		"tests/files/assets/minimal/full_test.aac",
+		"tests/files/assets/minimal/full_test.aiff",
+		"tests/files/assets/minimal/full_test.ape",
+		"tests/files/assets/minimal/full_test.dsf",
+		"tests/files/assets/minimal/full_test.flac",
+		"tests/files/assets/minimal/full_test.mp3",
+		"tests/files/assets/minimal/full_test.mpc",
+		"tests/files/assets/minimal/full_test.ogg",
+		"tests/files/assets/minimal/full_test.opus",
+		"tests/files/assets/minimal/full_test.spx",
+		"tests/files/assets/minimal/full_test.wma",
+		"tests/files/assets/minimal/full_test.wv",
+		"tests/files/assets/minimal/m4a_codec_aac.m4a",
+		"tests/files/assets/minimal/m4a_codec_alac.m4a",
+		"tests/files/assets/minimal/mp4_codec_als.mp4",
+		"tests/files/assets/minimal/wav_format_pcm.wav",
+	];
+
+	#[test]
+	fn bitrates_available() {
+		for path in GENERIC_FILES {
+			let tagged_file = crate::read_from_path(path, true).unwrap();
+			let properties = tagged_file.properties();
+
+			assert!(properties.overall_bitrate().is_some(), "{}", path);
+			assert!(properties.audio_bitrate().is_some(), "{}", path);
+		}
+	}
+
+	#[test]
+	fn properties_only() {
+		// Skipping the tags shouldn't affect the properties
+		for path in GENERIC_FILES {
+			let tagged_file = crate::read_from_path(path, true).unwrap();
+			let properties = crate::read_from_path_properties_only(path).unwrap();
+
+			assert_eq!(&properties, tagged_file.properties(), "{}", path);
+		}
+	}
+
+	#[test]
+	fn durations_available() {
+		// Every file is ~1.43s long, other than the DSF file
+		for path in GENERIC_FILES {
+			let tagged_file = crate::read_from_path(path, true).unwrap();
+			let duration = tagged_file.properties().duration();
+
+			let expected: u128 = if tagged_file.file_type() == FileType::Dsf {
+				23
+			} else {
+				1428
+			};
+
+			assert!(
+				duration.as_millis().abs_diff(expected) <= 50,
+				"{}: {:?}",
+				path,
+				duration
+			);
+		}
+	}
 }
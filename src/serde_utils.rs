@@ -0,0 +1,24 @@
+pub(crate) mod base64 {
+	use serde::de::Error;
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub(crate) fn serialize<T, S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: AsRef<[u8]>,
+		S: Serializer,
+	{
+		serializer.serialize_str(&::base64::encode(data))
+	}
+
+	pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: From<Vec<u8>>,
+		D: Deserializer<'de>,
+	{
+		let encoded = String::deserialize(deserializer)?;
+
+		::base64::decode(encoded)
+			.map(T::from)
+			.map_err(D::Error::custom)
+	}
+}
@@ -0,0 +1,154 @@
+use crate::error::Result;
+use crate::file::TaggedFile;
+use crate::picture::Picture;
+use crate::tag::item::ItemKey;
+use crate::tag::Tag;
+use crate::traits::{Accessor, FileLike};
+
+use std::path::Path;
+
+macro_rules! impl_setters {
+	($($name:ident),+; $($num_name:ident),+) => {
+		paste::paste! {
+			$(
+				#[doc = "Sets the " $name]
+				#[must_use]
+				pub fn [<set_ $name>](mut self, value: impl Into<String>) -> Self {
+					if let Some(tag) = self.tag() {
+						tag.[<set_ $name>](value.into());
+					}
+					self
+				}
+
+				#[doc = "Removes the " $name]
+				#[must_use]
+				pub fn [<remove_ $name>](mut self) -> Self {
+					if let Some(tag) = self.tag() {
+						tag.[<remove_ $name>]();
+					}
+					self
+				}
+			)+
+
+			$(
+				#[doc = "Sets the " $num_name]
+				#[must_use]
+				pub fn [<set_ $num_name>](mut self, value: u32) -> Self {
+					if let Some(tag) = self.tag() {
+						tag.[<set_ $num_name>](value);
+					}
+					self
+				}
+
+				#[doc = "Removes the " $num_name]
+				#[must_use]
+				pub fn [<remove_ $num_name>](mut self) -> Self {
+					if let Some(tag) = self.tag() {
+						tag.[<remove_ $num_name>]();
+					}
+					self
+				}
+			)+
+		}
+	}
+}
+
+/// A fluent editor for a [`TaggedFile`]'s primary tag
+///
+/// This is created with [`TaggedFile::edit`], which will return `None` if the file's primary tag
+/// can't be created.
+///
+/// All changes are made to the primary tag (see [`TaggedFile::primary_tag_mut_or_insert`]), which will
+/// be created if it doesn't exist. The changes are applied immediately, and can be written
+/// with [`TagEditor::save_to_path`] or [`TagEditor::save_to`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use lofty::LoftyError;
+/// # fn main() -> Result<(), LoftyError> {
+/// use lofty::{Accessor, ItemKey};
+///
+/// let mut tagged_file = lofty::read_from_path("tests/files/assets/minimal/full_test.mp3", false)?;
+///
+/// let _ = tagged_file
+/// 	.edit()
+/// 	.unwrap()
+/// 	.set_title("Foo title")
+/// 	.set_artist("Bar artist")
+/// 	.set_track(3)
+/// 	.insert_text(ItemKey::Composer, "Baz composer");
+///
+/// let primary_tag = tagged_file.primary_tag().unwrap();
+///
+/// assert_eq!(primary_tag.title(), Some("Foo title"));
+/// assert_eq!(primary_tag.track(), Some(3));
+/// assert_eq!(primary_tag.get_string(&ItemKey::Composer), Some("Baz composer"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct TagEditor<'a> {
+	file: &'a mut TaggedFile,
+}
+
+impl<'a> TagEditor<'a> {
+	pub(crate) fn new(file: &'a mut TaggedFile) -> Self {
+		Self { file }
+	}
+
+	impl_setters! {
+		artist, title, album, genre;
+		track, track_total, disk, disk_total
+	}
+
+	/// Inserts a text item, replacing any existing item with the same [`ItemKey`]
+	///
+	/// NOTE: This will do nothing if the [`ItemKey`] has no mapping for the tag. See [`Tag::insert_text`]
+	#[must_use]
+	pub fn insert_text(mut self, item_key: ItemKey, value: impl Into<String>) -> Self {
+		if let Some(tag) = self.tag() {
+			let _ = tag.insert_text(item_key, value.into());
+		}
+		self
+	}
+
+	/// Removes all items with the [`ItemKey`]
+	#[must_use]
+	pub fn remove_key(mut self, item_key: &ItemKey) -> Self {
+		if let Some(tag) = self.tag() {
+			tag.remove_key(item_key);
+		}
+		self
+	}
+
+	/// Pushes a [`Picture`] to the tag
+	#[must_use]
+	pub fn push_picture(mut self, picture: Picture) -> Self {
+		if let Some(tag) = self.tag() {
+			tag.push_picture(picture);
+		}
+		self
+	}
+
+	/// Writes the file's tags to a path
+	///
+	/// # Errors
+	///
+	/// See [`TaggedFile::save_to_path`]
+	pub fn save_to_path(self, path: impl AsRef<Path>) -> Result<()> {
+		self.file.save_to_path(path)
+	}
+
+	/// Writes the file's tags to a file
+	///
+	/// # Errors
+	///
+	/// See [`TaggedFile::save_to`]
+	pub fn save_to<F: FileLike>(self, file: &mut F) -> Result<()> {
+		self.file.save_to(file)
+	}
+
+	fn tag(&mut self) -> Option<&mut Tag> {
+		self.file.primary_tag_mut_or_insert()
+	}
+}
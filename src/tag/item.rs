@@ -1,5 +1,6 @@
 use crate::tag::TagType;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 macro_rules! first_key {
@@ -72,6 +73,49 @@ gen_map!(
 	"COMM" | "AUTH" => Comment
 );
 
+gen_map!(
+	#[cfg(feature = "asf_attributes")]
+	ASF_MAP;
+
+	"WM/AlbumTitle"				  => AlbumTitle,
+	"WM/SetSubTitle"			  => SetSubtitle,
+	"WM/ContentGroupDescription"  => ContentGroup,
+	"Title"						  => TrackTitle,
+	"WM/SubTitle"				  => TrackSubtitle,
+	"WM/OriginalAlbumTitle"		  => OriginalAlbumTitle,
+	"WM/OriginalArtist"			  => OriginalArtist,
+	"WM/OriginalLyricist"		  => OriginalLyricist,
+	"WM/AlbumSortOrder"			  => AlbumTitleSortOrder,
+	"WM/AlbumArtistSortOrder"	  => AlbumArtistSortOrder,
+	"WM/TitleSortOrder"			  => TrackTitleSortOrder,
+	"WM/ArtistSortOrder"		  => TrackArtistSortOrder,
+	"WM/AlbumArtist"			  => AlbumArtist,
+	"Author"					  => TrackArtist,
+	"WM/Composer"				  => Composer,
+	"WM/Conductor"				  => Conductor,
+	"WM/Writer"					  => Lyricist,
+	"WM/ModifiedBy"				  => Remixer,
+	"WM/Producer"				  => Producer,
+	"WM/Publisher"				  => Publisher,
+	"WM/PartOfSet"				  => DiscNumber,
+	"WM/TrackNumber"			  => TrackNumber,
+	"WM/Year"					  => Year,
+	"WM/ISRC"					  => ISRC,
+	"WM/Barcode"				  => Barcode,
+	"WM/CatalogNo"				  => CatalogNumber,
+	"WM/EncodedBy"				  => EncodedBy,
+	"WM/ToolName"				  => EncoderSoftware,
+	"WM/EncodingSettings"		  => EncoderSettings,
+	"WM/Genre"					  => Genre,
+	"WM/InitialKey"				  => InitialKey,
+	"WM/Mood"					  => Mood,
+	"WM/BeatsPerMinute"			  => BPM,
+	"Copyright"					  => CopyrightMessage,
+	"Description"				  => Comment,
+	"WM/Language"				  => Language,
+	"WM/Lyrics"					  => Lyrics
+);
+
 gen_map!(
 	#[cfg(feature = "ape")]
 	APE_MAP;
@@ -107,11 +151,20 @@ gen_map!(
 	"ISRC"						   => ISRC,
 	"Barcode"					   => Barcode,
 	"CatalogNumber"				   => CatalogNumber,
+	"MUSICBRAINZ_TRACKID"		   => MusicBrainzRecordingId,
+	"MUSICBRAINZ_RELEASETRACKID"   => MusicBrainzTrackId,
+	"MUSICBRAINZ_ALBUMID"		   => MusicBrainzReleaseId,
+	"MUSICBRAINZ_RELEASEGROUPID"   => MusicBrainzReleaseGroupId,
+	"MUSICBRAINZ_ARTISTID"		   => MusicBrainzArtistId,
 	"Compilation"				   => FlagCompilation,
 	"Media"						   => OriginalMediaType,
 	"EncodedBy"					   => EncodedBy,
 	"Genre"						   => Genre,
 	"Mood"						   => Mood,
+	"REPLAYGAIN_TRACK_GAIN"		   => ReplayGainTrackGain,
+	"REPLAYGAIN_TRACK_PEAK"		   => ReplayGainTrackPeak,
+	"REPLAYGAIN_ALBUM_GAIN"		   => ReplayGainAlbumGain,
+	"REPLAYGAIN_ALBUM_PEAK"		   => ReplayGainAlbumPeak,
 	"Copyright"					   => CopyrightMessage,
 	"Comment"					   => Comment,
 	"language"					   => Language,
@@ -144,7 +197,6 @@ gen_map! (
 	"TIPL"			=> InvolvedPeople,
 	"TEXT"			=> Lyricist,
 	"TMCL"			=> MusicianCredits,
-	"IPRO"			=> Producer,
 	"TPUB"			=> Publisher,
 	"TPUB"			=> Label,
 	"TRSN"			=> InternetRadioStationName,
@@ -159,7 +211,8 @@ gen_map! (
 	"TDOR"			=> OriginalReleaseDate,
 	"TSRC"			=> ISRC,
 	"MVNM"			=> Movement,
-	"MVIN"			=> MovementIndex,
+	"MVIN"			=> MovementNumber,
+	"MVIN"			=> MovementTotal,
 	"TCMP"			=> FlagCompilation,
 	"PCST"			=> FlagPodcast,
 	"TFLT"			=> FileType,
@@ -196,6 +249,34 @@ gen_map! (
 	"USLT"			=> Lyrics
 );
 
+// The roles in an ID3v2 "TIPL" frame that have an equivalent key
+gen_map! (
+	#[cfg(feature = "id3v2")]
+	ID3V2_TIPL_MAP;
+
+	"arranger"		=> Arranger,
+	"engineer"		=> Engineer,
+	"producer"		=> Producer,
+	"mix"			=> MixEngineer,
+	"DJ-mix"		=> MixDj
+);
+
+// ID3v2 has no dedicated frames for these, so they are stored in "TXXX" frames, identified by their descriptions
+gen_map! (
+	#[cfg(feature = "id3v2")]
+	ID3V2_TXXX_MAP;
+
+	"WORK"			=> Work,
+	"REPLAYGAIN_TRACK_GAIN" => ReplayGainTrackGain,
+	"REPLAYGAIN_TRACK_PEAK" => ReplayGainTrackPeak,
+	"REPLAYGAIN_ALBUM_GAIN" => ReplayGainAlbumGain,
+	"REPLAYGAIN_ALBUM_PEAK" => ReplayGainAlbumPeak,
+	"MusicBrainz Release Track Id" => MusicBrainzTrackId,
+	"MusicBrainz Album Id" => MusicBrainzReleaseId,
+	"MusicBrainz Release Group Id" => MusicBrainzReleaseGroupId,
+	"MusicBrainz Artist Id" => MusicBrainzArtistId
+);
+
 gen_map! (
 	#[cfg(feature = "mp4_ilst")]
 	ILST_MAP;
@@ -204,6 +285,7 @@ gen_map! (
 	"----:com.apple.iTunes:DISCSUBTITLE"	=> SetSubtitle,
 	"tvsh"									=> ShowName,
 	"\u{a9}grp"								=> ContentGroup,
+	"\u{a9}wrk"								=> Work,
 	"\u{a9}nam"								=> TrackTitle,
 	"----:com.apple.iTunes:SUBTITLE"		=> TrackSubtitle,
 	"soal"									=> AlbumTitleSortOrder,
@@ -233,6 +315,14 @@ gen_map! (
 	"----:com.apple.iTunes:ISRC"			=> ISRC,
 	"----:com.apple.iTunes:BARCODE"			=> Barcode,
 	"----:com.apple.iTunes:CATALOGNUMBER"	=> CatalogNumber,
+	"----:com.apple.iTunes:MusicBrainz Track Id" => MusicBrainzRecordingId,
+	"----:com.apple.iTunes:MusicBrainz Release Track Id" => MusicBrainzTrackId,
+	"----:com.apple.iTunes:MusicBrainz Album Id" => MusicBrainzReleaseId,
+	"----:com.apple.iTunes:MusicBrainz Release Group Id" => MusicBrainzReleaseGroupId,
+	"----:com.apple.iTunes:MusicBrainz Artist Id" => MusicBrainzArtistId,
+	"\u{a9}mvn"								=> Movement,
+	"\u{a9}mvi"								=> MovementNumber,
+	"\u{a9}mvc"								=> MovementTotal,
 	"cpil"									=> FlagCompilation,
 	"pcst"									=> FlagPodcast,
 	"----:com.apple.iTunes:MEDIA"			=> OriginalMediaType,
@@ -265,7 +355,9 @@ gen_map! (
 	"IWRI"			=> Writer,
 	"IMUS"			=> Composer,
 	"IPRO"			=> Producer,
-	"IPRT" | "ITRK" => TrackNumber,
+	"IENG"			=> Engineer,
+	"ISTR"			=> Performer,
+	"ITRK" | "IPRT" => TrackNumber,
 	"IFRM"			=> TrackTotal,
 	"IRTD"			=> Popularimeter,
 	"ICRD"			=> RecordingDate,
@@ -276,6 +368,7 @@ gen_map! (
 	"IGNR"			=> Genre,
 	"ICOP"			=> CopyrightMessage,
 	"ICMT"			=> Comment,
+	"ISBJ"			=> Description,
 	"ILNG"			=> Language
 );
 
@@ -286,6 +379,7 @@ gen_map!(
 	"ALBUM" 	      			   => AlbumTitle,
 	"DISCSUBTITLE"    			   => SetSubtitle,
 	"GROUPING"	   	  			   => ContentGroup,
+	"WORK"						   => Work,
 	"TITLE"		   	  			   => TrackTitle,
 	"SUBTITLE"	   	  			   => TrackSubtitle,
 	"ALBUMSORT"	   	  			   => AlbumTitleSortOrder,
@@ -317,6 +411,14 @@ gen_map!(
 	"ORIGINALDATE" 				   => OriginalReleaseDate,
 	"ISRC" 						   => ISRC,
 	"CATALOGNUMBER" 			   => CatalogNumber,
+	"MUSICBRAINZ_TRACKID"		   => MusicBrainzRecordingId,
+	"MUSICBRAINZ_RELEASETRACKID"   => MusicBrainzTrackId,
+	"MUSICBRAINZ_ALBUMID"		   => MusicBrainzReleaseId,
+	"MUSICBRAINZ_RELEASEGROUPID"   => MusicBrainzReleaseGroupId,
+	"MUSICBRAINZ_ARTISTID"		   => MusicBrainzArtistId,
+	"MOVEMENTNAME"				   => Movement,
+	"MOVEMENT"					   => MovementNumber,
+	"MOVEMENTTOTAL"				   => MovementTotal,
 	"COMPILATION" 				   => FlagCompilation,
 	"MEDIA" 					   => OriginalMediaType,
 	"ENCODED-BY" 				   => EncodedBy,
@@ -325,6 +427,10 @@ gen_map!(
 	"GENRE" 					   => Genre,
 	"MOOD" 						   => Mood,
 	"BPM" 						   => BPM,
+	"REPLAYGAIN_TRACK_GAIN"		   => ReplayGainTrackGain,
+	"REPLAYGAIN_TRACK_PEAK"		   => ReplayGainTrackPeak,
+	"REPLAYGAIN_ALBUM_GAIN"		   => ReplayGainAlbumGain,
+	"REPLAYGAIN_ALBUM_PEAK"		   => ReplayGainAlbumPeak,
 	"COPYRIGHT" 				   => CopyrightMessage,
 	"LICENSE" 					   => License,
 	"COMMENT" 					   => Comment,
@@ -346,6 +452,7 @@ macro_rules! gen_item_keys {
 		]
 	) => {
 		#[derive(PartialEq, Clone, Debug, Eq, Hash)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 		#[allow(missing_docs)]
 		#[non_exhaustive]
 		/// A generic representation of a tag's key
@@ -408,6 +515,9 @@ gen_item_keys!(
 		#[cfg(feature = "ape")]
 		[TagType::Ape, APE_MAP],
 
+		#[cfg(feature = "asf_attributes")]
+		[TagType::AsfAttributes, ASF_MAP],
+
 		#[cfg(feature = "id3v2")]
 		[TagType::Id3v2, ID3V2_MAP],
 
@@ -427,6 +537,7 @@ gen_item_keys!(
 		SetSubtitle,
 		ShowName,
 		ContentGroup,
+		Work,
 		TrackTitle,
 		TrackSubtitle,
 
@@ -481,8 +592,14 @@ gen_item_keys!(
 		ISRC,
 		Barcode,
 		CatalogNumber,
+		MusicBrainzRecordingId,
+		MusicBrainzTrackId,
+		MusicBrainzReleaseId,
+		MusicBrainzReleaseGroupId,
+		MusicBrainzArtistId,
 		Movement,
-		MovementIndex,
+		MovementNumber,
+		MovementTotal,
 
 		// Flags
 		FlagCompilation,
@@ -518,6 +635,12 @@ gen_item_keys!(
 		Mood,
 		BPM,
 
+		// ReplayGain
+		ReplayGainTrackGain,
+		ReplayGainTrackPeak,
+		ReplayGainAlbumGain,
+		ReplayGainAlbumPeak,
+
 		// Legal
 		CopyrightMessage,
 		License,
@@ -539,7 +662,81 @@ gen_item_keys!(
 	]
 );
 
+// Variants that have been renamed
+#[allow(non_upper_case_globals)]
+impl ItemKey {
+	#[deprecated(note = "Use `ItemKey::MovementNumber` instead")]
+	#[allow(missing_docs)]
+	pub const MovementIndex: ItemKey = ItemKey::MovementNumber;
+}
+
+#[cfg(feature = "id3v2")]
+impl ItemKey {
+	// Map the description of an ID3v2 "TXXX" frame to an `ItemKey`
+	pub(crate) fn from_id3v2_user_text(description: &str) -> Option<Self> {
+		ID3V2_TXXX_MAP.get_item_key(description)
+	}
+
+	// Maps the variant to the description of an ID3v2 "TXXX" frame
+	pub(crate) fn id3v2_user_text_description(&self) -> Option<&str> {
+		ID3V2_TXXX_MAP.get_key(self)
+	}
+
+	// Map a role in an ID3v2 "TIPL" frame to an `ItemKey`
+	pub(crate) fn from_id3v2_involved_people_role(role: &str) -> Option<Self> {
+		ID3V2_TIPL_MAP.get_item_key(role)
+	}
+
+	// Maps the variant to a role in an ID3v2 "TIPL" frame
+	pub(crate) fn id3v2_involved_people_role(&self) -> Option<&str> {
+		ID3V2_TIPL_MAP.get_key(self)
+	}
+
+	// Comments with a description are stored as `ItemKey::Unknown("COMM:<description>")`
+	pub(crate) fn id3v2_comment_description(&self) -> Option<&str> {
+		match self {
+			ItemKey::Unknown(unknown) => unknown.strip_prefix("COMM:"),
+			_ => None,
+		}
+	}
+}
+
+impl ItemKey {
+	/// Checks if an [`ItemValue`] is suitable for the key
+	///
+	/// * Numeric keys (Ex. [`ItemKey::TrackNumber`]) only accept [`ItemValue::Text`]
+	/// * URL keys (Ex. [`ItemKey::PaymentURL`]) only accept [`ItemValue::Text`] and [`ItemValue::Locator`]
+	/// * All other keys, other than [`ItemKey::Popularimeter`] and [`ItemKey::Unknown`], reject [`ItemValue::Binary`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::{ItemKey, ItemValue};
+	///
+	/// assert!(ItemKey::TrackNumber.accepts(&ItemValue::Text(String::from("1"))));
+	/// assert!(!ItemKey::TrackNumber.accepts(&ItemValue::Locator(String::from("1"))));
+	/// assert!(!ItemKey::TrackTitle.accepts(&ItemValue::Binary(vec![1, 2, 3])));
+	/// ```
+	pub fn accepts(&self, value: &ItemValue) -> bool {
+		match self {
+			ItemKey::DiscNumber
+			| ItemKey::DiscTotal
+			| ItemKey::TrackNumber
+			| ItemKey::TrackTotal
+			| ItemKey::Year
+			| ItemKey::MovementNumber
+			| ItemKey::MovementTotal
+			| ItemKey::FlagCompilation
+			| ItemKey::FlagPodcast
+			| ItemKey::BPM => matches!(value, ItemValue::Text(_)),
+			ItemKey::Popularimeter | ItemKey::Unknown(_) => true,
+			_ => !matches!(value, ItemValue::Binary(_)),
+		}
+	}
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a tag item's value
 pub enum ItemValue {
 	/// Any UTF-8 encoded text
@@ -550,7 +747,7 @@ pub enum ItemValue {
 	/// as a normal string in other tags
 	Locator(String),
 	/// Binary information
-	Binary(Vec<u8>),
+	Binary(#[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::base64"))] Vec<u8>),
 }
 
 impl ItemValue {
@@ -598,7 +795,18 @@ impl ItemValue {
 pub(crate) enum ItemValueRef<'a> {
 	Text(&'a str),
 	Locator(&'a str),
-	Binary(&'a [u8]),
+	Binary(Cow<'a, [u8]>),
+}
+
+impl PartialEq<ItemValue> for ItemValueRef<'_> {
+	fn eq(&self, other: &ItemValue) -> bool {
+		match (self, other) {
+			(ItemValueRef::Text(a), ItemValue::Text(b))
+			| (ItemValueRef::Locator(a), ItemValue::Locator(b)) => a == b,
+			(ItemValueRef::Binary(a), ItemValue::Binary(b)) => **a == **b,
+			_ => false,
+		}
+	}
 }
 
 impl<'a> Into<ItemValueRef<'a>> for &'a ItemValue {
@@ -606,12 +814,13 @@ impl<'a> Into<ItemValueRef<'a>> for &'a ItemValue {
 		match self {
 			ItemValue::Text(text) => ItemValueRef::Text(text),
 			ItemValue::Locator(locator) => ItemValueRef::Locator(locator),
-			ItemValue::Binary(binary) => ItemValueRef::Binary(binary),
+			ItemValue::Binary(binary) => ItemValueRef::Binary(Cow::Borrowed(binary)),
 		}
 	}
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a tag item (key/value)
 pub struct TagItem {
 	pub(crate) item_key: ItemKey,
@@ -625,15 +834,18 @@ impl TagItem {
 	///
 	/// * This will check for validity based on the [`TagType`].
 	/// * If the [`ItemKey`] does not map to a key in the target format, `None` will be returned.
+	/// * If the [`ItemValue`] is unsuitable for the [`ItemKey`], `None` will be returned. See [`ItemKey::accepts`].
 	/// * This is unnecessary if you plan on using [`Tag::insert_item`](crate::Tag::insert_item), as it does validity checks itself.
 	pub fn new_checked(
 		tag_type: TagType,
 		item_key: ItemKey,
 		item_value: ItemValue,
 	) -> Option<Self> {
-		item_key.map_key(tag_type, false).is_some().then(|| Self {
-			item_key,
-			item_value,
+		(item_key.map_key(tag_type, false).is_some() && item_key.accepts(&item_value)).then(|| {
+			Self {
+				item_key,
+				item_value,
+			}
 		})
 	}
 
@@ -678,6 +890,17 @@ impl TagItem {
 			return VALID_ITEMKEYS.contains(&self.item_key);
 		}
 
+		// Some keys are stored in "TXXX", "TIPL", "COMM", and "UFID" frames, rather than a dedicated frame
+		#[cfg(feature = "id3v2")]
+		if tag_type == TagType::Id3v2
+			&& (self.item_key.id3v2_user_text_description().is_some()
+				|| self.item_key.id3v2_involved_people_role().is_some()
+				|| self.item_key.id3v2_comment_description().is_some()
+				|| self.item_key == ItemKey::MusicBrainzRecordingId)
+		{
+			return true;
+		}
+
 		self.item_key.map_key(tag_type, false).is_some()
 	}
 }
@@ -0,0 +1,169 @@
+use crate::picture::{Picture, PictureType};
+use crate::tag::item::{ItemKey, ItemValue, TagItem};
+use crate::tag::{Tag, TagType};
+
+/// A read-only view over multiple [`Tag`]s
+///
+/// This is created with [`TaggedFile::merged_tag`](crate::TaggedFile::merged_tag).
+///
+/// When looking up an item, the tags are searched in order of priority, with the
+/// first tag containing the [`ItemKey`] being used. Tags listed in the [`TaggedFile`](crate::TaggedFile)'s
+/// tag priority (see [`TaggedFile::set_tag_priority`](crate::TaggedFile::set_tag_priority)) come first,
+/// followed by the file type's primary tag (see [`FileType::primary_tag_type`](crate::FileType::primary_tag_type)),
+/// and then the remaining tags in the order they were read.
+///
+/// Pictures are merged from all tags, with duplicate pictures only appearing once.
+#[derive(Clone)]
+pub struct MergedTag<'a> {
+	tags: Vec<&'a Tag>,
+	pictures: Vec<&'a Picture>,
+}
+
+impl<'a> MergedTag<'a> {
+	pub(crate) fn new(tags: &'a [Tag], priority: &[TagType]) -> Self {
+		let mut ordered = tags.iter().collect::<Vec<_>>();
+
+		// Tags not in the priority list keep the order they were read in
+		ordered.sort_by_key(|t| {
+			priority
+				.iter()
+				.position(|ty| *ty == t.tag_type())
+				.unwrap_or(priority.len())
+		});
+
+		let mut pictures: Vec<&'a Picture> = Vec::new();
+		for picture in ordered.iter().flat_map(|t| t.pictures()) {
+			if !pictures.contains(&picture) {
+				pictures.push(picture);
+			}
+		}
+
+		Self {
+			tags: ordered,
+			pictures,
+		}
+	}
+
+	/// Returns the merged tags, in order of priority
+	pub fn tags(&self) -> &[&'a Tag] {
+		&self.tags
+	}
+
+	/// Returns the first [`TagItem`] matching an [`ItemKey`], from the highest priority tag that has one
+	pub fn get_item_ref(&self, item_key: &ItemKey) -> Option<&'a TagItem> {
+		self.tags.iter().find_map(|t| t.get_item_ref(item_key))
+	}
+
+	/// Get a string value from an [`ItemKey`], from the highest priority tag that has one
+	pub fn get_string(&self, item_key: &ItemKey) -> Option<&'a str> {
+		self.tags.iter().find_map(|t| t.get_string(item_key))
+	}
+
+	/// Gets a byte slice from an [`ItemKey`], from the highest priority tag that has one
+	///
+	/// See [`Tag::get_binary`]
+	pub fn get_binary(&self, item_key: &ItemKey, convert: bool) -> Option<&'a [u8]> {
+		self.tags
+			.iter()
+			.find_map(|t| t.get_binary(item_key, convert))
+	}
+
+	/// Returns references to all [`TagItem`]s with the specified key
+	///
+	/// NOTE: Items are only taken from the highest priority tag containing the key, they are
+	/// **not** combined across tags.
+	pub fn get_items(&self, key: &'a ItemKey) -> impl Iterator<Item = &'a TagItem> + 'a {
+		let tag = self
+			.tags
+			.iter()
+			.find(|t| t.get_item_ref(key).is_some())
+			.copied();
+
+		tag.into_iter().flat_map(move |t| t.get_items(key))
+	}
+
+	/// Returns references to all texts of [`TagItem`]s with the specified key, and [`ItemValue::Text`]
+	///
+	/// See [`MergedTag::get_items`]
+	pub fn get_texts(&self, key: &'a ItemKey) -> impl Iterator<Item = &'a str> + 'a {
+		self.get_items(key).filter_map(|i| i.value().text())
+	}
+
+	/// Returns the value of every [`TagItem`] with the specified key, from **all** tags
+	pub fn get_all_values(&self, key: &'a ItemKey) -> impl Iterator<Item = &'a ItemValue> + 'a {
+		self.tags
+			.clone()
+			.into_iter()
+			.flat_map(move |t| t.get_items(key))
+			.map(TagItem::value)
+	}
+
+	/// Returns the pictures from every tag, without duplicates
+	pub fn pictures(&self) -> &[&'a Picture] {
+		&self.pictures
+	}
+
+	/// Returns the first occurrence of the [`PictureType`]
+	pub fn get_picture_type(&self, picture_type: PictureType) -> Option<&'a Picture> {
+		self.pictures
+			.iter()
+			.find(|picture| picture.pic_type() == picture_type)
+			.copied()
+	}
+
+	/// Whether the merged tags contain any items or pictures
+	pub fn is_empty(&self) -> bool {
+		self.tags
+			.iter()
+			.all(|t| t.items().is_empty() && t.pictures().is_empty())
+	}
+}
+
+#[cfg(all(test, feature = "ape", feature = "id3v2"))]
+mod tests {
+	use crate::tag::merged::MergedTag;
+	use crate::{ItemKey, MimeType, Picture, PictureType, Tag, TagType};
+
+	fn picture(data: u8) -> Picture {
+		Picture::new_unchecked(PictureType::CoverFront, MimeType::Png, None, vec![data; 8])
+	}
+
+	#[test]
+	fn merged_priority() {
+		let mut ape = Tag::new(TagType::Ape);
+		ape.insert_text(ItemKey::TrackTitle, String::from("Ape title"));
+		ape.insert_text(ItemKey::AlbumTitle, String::from("Ape album"));
+		ape.push_picture(picture(1));
+		ape.push_picture(picture(2));
+
+		let mut id3v2 = Tag::new(TagType::Id3v2);
+		id3v2.insert_text(ItemKey::TrackTitle, String::from("ID3v2 title"));
+		id3v2.push_picture(picture(1));
+
+		let tags = [ape, id3v2];
+		let merged = MergedTag::new(&tags, &[TagType::Id3v2]);
+
+		assert_eq!(merged.tags()[0].tag_type(), TagType::Id3v2);
+
+		// From the primary tag
+		assert_eq!(merged.get_string(&ItemKey::TrackTitle), Some("ID3v2 title"));
+		// Falls back to the APE tag
+		assert_eq!(merged.get_string(&ItemKey::AlbumTitle), Some("Ape album"));
+		assert_eq!(merged.get_string(&ItemKey::TrackArtist), None);
+
+		assert_eq!(
+			merged.get_texts(&ItemKey::TrackTitle).collect::<Vec<_>>(),
+			vec!["ID3v2 title"]
+		);
+		assert_eq!(merged.get_all_values(&ItemKey::TrackTitle).count(), 2);
+
+		// The duplicate picture should only appear once
+		assert_eq!(merged.pictures().len(), 2);
+		assert!(!merged.is_empty());
+
+		// A caller specified priority overrides the primary tag
+		let merged = MergedTag::new(&tags, &[TagType::Ape, TagType::Id3v2]);
+		assert_eq!(merged.tags()[0].tag_type(), TagType::Ape);
+		assert_eq!(merged.get_string(&ItemKey::TrackTitle), Some("Ape title"));
+	}
+}
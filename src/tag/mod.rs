@@ -1,19 +1,20 @@
+pub(crate) mod editor;
 pub(crate) mod item;
+pub(crate) mod merged;
 pub(crate) mod utils;
 
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::file::FileType;
 use crate::picture::{Picture, PictureType};
 use crate::probe::Probe;
-use crate::traits::{Accessor, TagExt};
+use crate::traits::{Accessor, FileLike, TagExt};
 use item::{ItemKey, ItemValue, TagItem};
 
-use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
 macro_rules! impl_accessor {
-	($($item_key:ident => $name:tt),+) => {
+	($($item_key:ident => $name:tt),+; $($num_item_key:ident => $num_name:tt),+) => {
 		paste::paste! {
 			impl Accessor for Tag {
 				$(
@@ -30,7 +31,21 @@ macro_rules! impl_accessor {
 					}
 
 					fn [<remove_ $name>](&mut self) {
-						self.retain_items(|i| i.item_key != ItemKey::$item_key)
+						self.retain(|i| i.item_key != ItemKey::$item_key)
+					}
+				)+
+
+				$(
+					fn $num_name(&self) -> Option<u32> {
+						self.get_string(&ItemKey::$num_item_key).and_then(|n| n.trim().parse().ok())
+					}
+
+					fn [<set_ $num_name>](&mut self, value: u32) {
+						self.insert_item(TagItem::new(ItemKey::$num_item_key, ItemValue::Text(value.to_string())));
+					}
+
+					fn [<remove_ $num_name>](&mut self) {
+						self.retain(|i| i.item_key != ItemKey::$num_item_key)
 					}
 				)+
 			}
@@ -39,6 +54,7 @@ macro_rules! impl_accessor {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a parsed tag
 ///
 /// This is a tag that is loosely bound to a specific [`TagType`].
@@ -52,6 +68,19 @@ macro_rules! impl_accessor {
 ///
 /// Any conversion will, of course, be lossy to a varying degree.
 ///
+/// ## Conversions
+///
+/// When remapping with [`Tag::re_map`]:
+///
+/// * Items with an [`ItemKey`] that has a mapping for the new [`TagType`] are kept.
+/// * All other items, including those with an [`ItemKey::Unknown`] key, are moved out of the tag.
+///   They can be accessed with [`Tag::unknown_items`], and will be restored when remapping back to the
+///   [`TagType`] they came from.
+/// * Pictures are always kept.
+///
+/// Items set aside by [`Tag::re_map`] are **not** written, and are **not** kept when converting into a
+/// concrete tag type (e.g. `Tag` -> [`Id3v2Tag`](crate::id3::v2::Id3v2Tag)).
+///
 /// ## Usage
 ///
 /// Accessing common items
@@ -99,6 +128,9 @@ pub struct Tag {
 	tag_type: TagType,
 	pub(crate) pictures: Vec<Picture>,
 	pub(crate) items: Vec<TagItem>,
+	// Items that didn't survive a `re_map`, along with the `TagType` they were mapped for
+	#[cfg_attr(feature = "serde", serde(skip))]
+	unknown_items: Vec<(TagType, TagItem)>,
 }
 
 impl IntoIterator for Tag {
@@ -114,7 +146,11 @@ impl_accessor!(
 	TrackArtist => artist,
 	TrackTitle => title,
 	AlbumTitle => album,
-	Genre => genre
+	Genre => genre;
+	TrackNumber => track,
+	TrackTotal => track_total,
+	DiscNumber => disk,
+	DiscTotal => disk_total
 );
 
 impl Tag {
@@ -124,15 +160,72 @@ impl Tag {
 			tag_type,
 			pictures: Vec::new(),
 			items: Vec::new(),
+			unknown_items: Vec::new(),
 		}
 	}
 
 	/// Change the [`TagType`], remapping all items
+	///
+	/// Items that can't be mapped to `tag_type` are set aside, rather than discarded.
+	/// See [`Tag::unknown_items`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+	/// tag.insert_text(ItemKey::FlagPodcast, String::from("1"));
+	///
+	/// // Vorbis comments have no mapping for `ItemKey::FlagPodcast`
+	/// tag.re_map(TagType::VorbisComments);
+	/// assert_eq!(tag.item_count(), 1);
+	/// assert_eq!(tag.unknown_items().count(), 1);
+	///
+	/// // Converting back restores the item
+	/// tag.re_map(TagType::Id3v2);
+	/// assert_eq!(tag.item_count(), 2);
+	/// assert_eq!(tag.unknown_items().count(), 0);
+	/// ```
 	pub fn re_map(&mut self, tag_type: TagType) {
-		self.retain_items(|i| i.re_map(tag_type));
+		if tag_type == self.tag_type {
+			return;
+		}
+
+		let previous_tag_type = self.tag_type;
+
+		let (restored, unknown) = std::mem::take(&mut self.unknown_items)
+			.into_iter()
+			.partition::<Vec<_>, _>(|(ty, item)| *ty == tag_type || item.re_map(tag_type));
+		self.unknown_items = unknown;
+
+		for item in std::mem::take(&mut self.items) {
+			if item.re_map(tag_type) {
+				self.items.push(item);
+			} else {
+				self.unknown_items.push((previous_tag_type, item));
+			}
+		}
+
+		self.items
+			.extend(restored.into_iter().map(|(_, item)| item));
 		self.tag_type = tag_type
 	}
 
+	/// Returns the items that were set aside by [`Tag::re_map`]
+	///
+	/// These are items that have no mapping for the current [`TagType`]. They will be
+	/// restored if the tag is remapped to a [`TagType`] that supports them.
+	pub fn unknown_items(&self) -> impl Iterator<Item = &TagItem> {
+		self.unknown_items.iter().map(|(_, item)| item)
+	}
+
+	/// Removes all items that were set aside by [`Tag::re_map`], and returns them
+	pub fn take_unknown_items(&mut self) -> impl Iterator<Item = TagItem> + '_ {
+		self.unknown_items.drain(..).map(|(_, item)| item)
+	}
+
 	/// Returns the [`TagType`]
 	pub fn tag_type(&self) -> TagType {
 		self.tag_type
@@ -185,11 +278,26 @@ impl Tag {
 
 	/// Insert a [`TagItem`], replacing any existing one of the same [`ItemKey`]
 	///
-	/// NOTE: This **will** verify an [`ItemKey`] mapping exists for the target [`TagType`]
+	/// NOTE: This **will** verify an [`ItemKey`] mapping exists for the target [`TagType`],
+	/// and that the [`ItemValue`] is suitable for the key (see [`ItemKey::accepts`])
 	///
 	/// This will return `true` if the item was inserted.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::{ItemKey, ItemValue, Tag, TagItem, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	///
+	/// assert!(tag.insert_item(TagItem::new(ItemKey::TrackNumber, ItemValue::Text(String::from("1")))));
+	///
+	/// // A track number can't be binary
+	/// assert!(!tag.insert_item(TagItem::new(ItemKey::TrackNumber, ItemValue::Binary(vec![1]))));
+	/// assert_eq!(tag.get_string(&ItemKey::TrackNumber), Some("1"));
+	/// ```
 	pub fn insert_item(&mut self, item: TagItem) -> bool {
-		if item.re_map(self.tag_type) {
+		if item.re_map(self.tag_type) && item.key().accepts(item.value()) {
 			self.insert_item_unchecked(item);
 			return true;
 		}
@@ -206,7 +314,7 @@ impl Tag {
 	///
 	/// This is only necessary if dealing with [`ItemKey::Unknown`].
 	pub fn insert_item_unchecked(&mut self, item: TagItem) {
-		self.retain_items(|i| i.item_key != item.item_key);
+		self.retain(|i| i.item_key != item.item_key);
 		self.items.push(item);
 	}
 
@@ -214,14 +322,15 @@ impl Tag {
 	///
 	/// This will not remove any items of the same [`ItemKey`], unlike [`Tag::insert_item`]
 	///
-	/// NOTE: This **will** verify an [`ItemKey`] mapping exists for the target [`TagType`]
+	/// NOTE: This **will** verify an [`ItemKey`] mapping exists for the target [`TagType`],
+	/// and that the [`ItemValue`] is suitable for the key (see [`ItemKey::accepts`])
 	///
 	/// Multiple items of the same [`ItemKey`] are not valid in all formats, in which case
 	/// the first available item will be used.
 	///
 	/// This will return `true` if the item was pushed.
 	pub fn push_item(&mut self, item: TagItem) -> bool {
-		if item.re_map(self.tag_type) {
+		if item.re_map(self.tag_type) && item.key().accepts(item.value()) {
 			self.items.push(item);
 			return true;
 		}
@@ -250,7 +359,8 @@ impl Tag {
 
 		for read_idx in 0..self.items.len() {
 			if self.items[read_idx].key() == key {
-				self.items.swap(split_idx, read_idx);
+				// Rotating (rather than swapping) keeps the remaining items in order
+				self.items[split_idx..=read_idx].rotate_right(1);
 				split_idx += 1;
 			}
 		}
@@ -311,13 +421,43 @@ impl Tag {
 	/// Retain tag items based on the predicate
 	///
 	/// See [`Vec::retain`](std::vec::Vec::retain)
-	pub fn retain_items<F>(&mut self, f: F)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+	/// tag.insert_text(ItemKey::Comment, String::from("Bar comment"));
+	///
+	/// // Only keep the title
+	/// tag.retain(|item| item.key() == &ItemKey::TrackTitle);
+	///
+	/// assert_eq!(tag.item_count(), 1);
+	/// assert_eq!(tag.get_string(&ItemKey::Comment), None);
+	/// ```
+	pub fn retain<F>(&mut self, f: F)
 	where
 		F: FnMut(&TagItem) -> bool,
 	{
 		self.items.retain(f)
 	}
 
+	#[deprecated(note = "Use `Tag::retain` instead")]
+	#[allow(missing_docs)]
+	pub fn retain_items<F>(&mut self, f: F)
+	where
+		F: FnMut(&TagItem) -> bool,
+	{
+		self.retain(f)
+	}
+
+	/// Removes all [`TagItem`]s, leaving the pictures untouched
+	pub fn clear_items(&mut self) {
+		self.items.clear()
+	}
+
 	/// Returns the stored [`Picture`]s as a slice
 	pub fn pictures(&self) -> &[Picture] {
 		&*self.pictures
@@ -339,6 +479,11 @@ impl Tag {
 	pub fn remove_picture_type(&mut self, picture_type: PictureType) {
 		self.pictures.retain(|p| p.pic_type != picture_type)
 	}
+
+	/// Removes all [`Picture`]s, leaving the items untouched
+	pub fn clear_pictures(&mut self) {
+		self.pictures.clear()
+	}
 }
 
 impl TagExt for Tag {
@@ -356,16 +501,16 @@ impl TagExt for Tag {
 	/// * Path is not writable
 	/// * See [`Tag::save_to`]
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err> {
-		self.save_to(&mut OpenOptions::new().read(true).write(true).open(path)?)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.save_to(file))
 	}
 
-	/// Save the `Tag` to a [`File`](std::fs::File)
+	/// Save the `Tag` to a [`FileLike`]
 	///
 	/// # Errors
 	///
 	/// * A [`FileType`](crate::FileType) couldn't be determined from the File
 	/// * Attempting to write a tag to a format that does not support it. See [`FileType::supports_tag_type`](crate::FileType::supports_tag_type)
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		let probe = Probe::new(file).guess_file_type()?;
 
 		match probe.file_type() {
@@ -393,23 +538,40 @@ impl TagExt for Tag {
 		self.tag_type.remove_from_path(path)
 	}
 
-	/// Remove a tag from a [`File`]
+	/// Remove a tag from a [`FileLike`]
 	///
 	/// # Errors
 	///
 	/// See [`TagType::remove_from`]
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err> {
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err> {
 		self.tag_type.remove_from(file)
 	}
 
 	fn clear(&mut self) {
-		self.items.clear();
-		self.pictures.clear();
+		self.clear_items();
+		self.clear_pictures();
+		self.unknown_items.clear();
+	}
+
+	fn collect_pictures(&self) -> Vec<Picture> {
+		self.pictures.clone()
+	}
+
+	fn replace_picture(&mut self, picture: Picture) -> std::result::Result<(), Self::Err> {
+		self.remove_picture_type(picture.pic_type);
+		self.push_picture(picture);
+
+		Ok(())
+	}
+
+	fn remove_picture_type(&mut self, picture_type: PictureType) {
+		self.remove_picture_type(picture_type)
 	}
 }
 
 /// The tag's format
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TagType {
 	/// This covers both APEv1 and APEv2 as it doesn't matter much
@@ -426,6 +588,10 @@ pub enum TagType {
 	RiffInfo,
 	/// Represents AIFF text chunks
 	AiffText,
+	/// Represents ASF attributes
+	///
+	/// NOTE: This tag is currently **read only**
+	AsfAttributes,
 }
 
 impl TagType {
@@ -435,19 +601,18 @@ impl TagType {
 	///
 	/// See [`TagType::remove_from`]
 	pub fn remove_from_path(&self, path: impl AsRef<Path>) -> Result<()> {
-		let mut file = OpenOptions::new().read(true).write(true).open(path)?;
-		self.remove_from(&mut file)
+		crate::tag::utils::write_to_path(path.as_ref(), |file| self.remove_from(file))
 	}
 
 	#[allow(clippy::shadow_unrelated)]
-	/// Remove a tag from a [`File`]
+	/// Remove a tag from a [`FileLike`]
 	///
 	/// # Errors
 	///
 	/// * It is unable to guess the file format
 	/// * The format doesn't support the tag
 	/// * It is unable to write to the file
-	pub fn remove_from(&self, file: &mut File) -> Result<()> {
+	pub fn remove_from<F: FileLike>(&self, file: &mut F) -> Result<()> {
 		let probe = Probe::new(file).guess_file_type()?;
 		let file_type = match probe.file_type() {
 			Some(f_ty) => f_ty,
@@ -466,7 +631,84 @@ impl TagType {
 	}
 }
 
-#[cfg(test)]
+/// Remove every tag from a [`Path`]
+///
+/// # Errors
+///
+/// See [`remove_tags_from`]
+pub fn remove_tags_from_path(path: impl AsRef<Path>) -> Result<()> {
+	crate::tag::utils::write_to_path(path.as_ref(), remove_tags_from)
+}
+
+/// Remove every tag from a [`FileLike`]
+///
+/// This removes all metadata the [`FileType`] supports (see [`FileType::supports_tag_type`]),
+/// including read only tags, such as ID3v2 in FLAC and APE files.
+///
+/// NOTES:
+///
+/// * For formats that require a metadata packet (Opus, Speex, and Vorbis), an empty one is written,
+///   which retains the vendor string.
+/// * For WAV files, the Broadcast Wave Format extension is also removed. See [`BroadcastExtension`](crate::iff::BroadcastExtension)
+/// * Unlike [`TaggedFile::clear`](crate::TaggedFile::clear), this will alter the file.
+///
+/// # Errors
+///
+/// * It is unable to guess the file format
+/// * The format's tags can't be written, such as ASF attributes
+/// * It is unable to write to the file
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use lofty::LoftyError;
+/// # fn main() -> Result<(), LoftyError> {
+/// lofty::remove_tags_from_path("foo.mp3")?;
+///
+/// let tagged_file = lofty::read_from_path("foo.mp3", false)?;
+/// assert!(tagged_file.tags().is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn remove_tags_from<F: FileLike>(file: &mut F) -> Result<()> {
+	const TAG_TYPES: [TagType; 8] = [
+		TagType::Ape,
+		TagType::Id3v1,
+		TagType::Id3v2,
+		TagType::Mp4Ilst,
+		TagType::VorbisComments,
+		TagType::RiffInfo,
+		TagType::AiffText,
+		TagType::AsfAttributes,
+	];
+
+	let probe = Probe::new(file).guess_file_type()?;
+	let file_type = match probe.file_type() {
+		Some(f_ty) => f_ty,
+		None => return Err(LoftyError::new(ErrorKind::UnknownFormat)),
+	};
+
+	let file = probe.into_inner();
+
+	for tag_type in TAG_TYPES {
+		let read_only = (file_type == FileType::APE || file_type == FileType::FLAC)
+			&& tag_type == TagType::Id3v2;
+
+		if read_only || file_type.supports_tag_type(tag_type) {
+			file.rewind()?;
+			utils::write_tag(&Tag::new(tag_type), file, file_type)?;
+		}
+	}
+
+	if file_type == FileType::WAV {
+		file.rewind()?;
+		crate::iff::BroadcastExtension::remove_from(file)?;
+	}
+
+	Ok(())
+}
+
+#[cfg(all(test, feature = "vorbis_comments"))]
 mod tests {
 	use crate::tag::utils::test_utils::read_path;
 	use crate::{Picture, PictureType, Tag, TagExt, TagType};
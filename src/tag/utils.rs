@@ -1,28 +1,34 @@
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::file::FileType;
 use crate::tag::{Tag, TagType};
-use crate::{ape, iff, mp3};
+use crate::traits::FileLike;
+use crate::{aac, ape, iff, mp3, mpc, wavpack};
 
 #[cfg(feature = "id3v1")]
 use crate::id3::v1::tag::Id3v1TagRef;
-#[cfg(feature = "id3v2")]
-use crate::id3::v2::{self, tag::Id3v2TagRef, Id3v2TagFlags};
-#[cfg(feature = "mp4_ilst")]
-use crate::mp4::ilst::IlstRef;
 #[cfg(feature = "vorbis_comments")]
 use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
+#[cfg(feature = "id3v2")]
+use crate::{
+	dsf,
+	id3::v2::{self, tag::Id3v2TagRef, Id3v2TagFlags},
+};
+#[cfg(feature = "mp4_ilst")]
+use crate::{mp4::Ilst, traits::TagExt};
 #[cfg(feature = "ape")]
-use ape::tag::ApeTagRef;
+use ape::{tag::ApeTagRef, ApeTagVersion};
 #[cfg(feature = "aiff_text_chunks")]
 use iff::aiff::tag::AiffTextChunksRef;
 #[cfg(feature = "riff_info_list")]
 use iff::wav::tag::RiffInfoListRef;
 
-use std::fs::File;
+use std::ffi::OsString;
+use std::fs::{File, OpenOptions};
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 #[allow(unreachable_patterns)]
-pub(crate) fn write_tag(tag: &Tag, file: &mut File, file_type: FileType) -> Result<()> {
+pub(crate) fn write_tag<F: FileLike>(tag: &Tag, file: &mut F, file_type: FileType) -> Result<()> {
 	match file_type {
 		FileType::AIFF => iff::aiff::write::write_to(file, tag),
 		FileType::APE => ape::write::write_to(file, tag),
@@ -32,8 +38,14 @@ pub(crate) fn write_tag(tag: &Tag, file: &mut File, file_type: FileType) -> Resu
 		},
 		FileType::MP3 => mp3::write::write_to(file, tag),
 		#[cfg(feature = "mp4_ilst")]
-		FileType::MP4 => crate::mp4::ilst::write::write_to(file, &mut Into::<IlstRef<'_>>::into(tag)),
+		// Converting the tag handles the atoms that need to be written as integers (Ex. "tmpo")
+		FileType::MP4 => Ilst::from(tag.clone()).save_to(file),
 		FileType::WAV => iff::wav::write::write_to(file, tag),
+		FileType::Aac => aac::write::write_to(file, tag),
+		#[cfg(feature = "id3v2")]
+		FileType::Dsf => dsf::write::write_to(file, tag),
+		FileType::Mpc => mpc::write::write_to(file, tag),
+		FileType::WavPack => wavpack::write::write_to(file, tag),
 		_ => Err(LoftyError::new(ErrorKind::UnsupportedTag)),
 	}
 }
@@ -44,7 +56,9 @@ pub(crate) fn dump_tag<W: Write>(tag: &Tag, writer: &mut W) -> Result<()> {
 		#[cfg(feature = "ape")]
 		TagType::Ape => ApeTagRef {
 			read_only: false,
-			items: ape::tag::tagitems_into_ape(tag.items()),
+			version: ApeTagVersion::V2,
+			location: None,
+			items: ape::tag::tagitems_into_ape(tag),
 		}
 		.dump_to(writer),
 		#[cfg(feature = "id3v1")]
@@ -52,11 +66,12 @@ pub(crate) fn dump_tag<W: Write>(tag: &Tag, writer: &mut W) -> Result<()> {
 		#[cfg(feature = "id3v2")]
 		TagType::Id3v2 => Id3v2TagRef {
 			flags: Id3v2TagFlags::default(),
+			version: v2::Id3v2Version::V4,
 			frames: v2::tag::tag_frames(tag),
 		}
 		.dump_to(writer),
 		#[cfg(feature = "mp4_ilst")]
-		TagType::Mp4Ilst => Into::<IlstRef<'_>>::into(tag).dump_to(writer),
+		TagType::Mp4Ilst => Ilst::from(tag.clone()).dump_to(writer),
 		#[cfg(feature = "vorbis_comments")]
 		TagType::VorbisComments => {
 			let (vendor, items, pictures) = create_vorbis_comments_ref(tag);
@@ -90,6 +105,57 @@ pub(crate) fn dump_tag<W: Write>(tag: &Tag, writer: &mut W) -> Result<()> {
 	}
 }
 
+/// Writes to a copy of the file at `path`, only replacing the original once `write` succeeds
+///
+/// This prevents the original file from being left in a corrupted state should an error occur midway.
+pub(crate) fn write_to_path<F>(path: &Path, write: F) -> Result<()>
+where
+	F: FnOnce(&mut File) -> Result<()>,
+{
+	// Replace the file the path points to, rather than a symlink
+	let path = std::fs::canonicalize(path)?;
+
+	let mut temp_name = OsString::from(".");
+	temp_name.push(path.file_name().unwrap_or_default());
+	temp_name.push(format!(".{}.lofty-tmp", std::process::id()));
+
+	let permissions = std::fs::metadata(&path)?.permissions();
+
+	// Removes the copy if anything fails (or panics) before it replaces the original
+	let mut temp = TempFile {
+		path: path.with_file_name(temp_name),
+		persisted: false,
+	};
+
+	std::fs::copy(&path, &temp.path)?;
+
+	{
+		let mut temp_file = OpenOptions::new().read(true).write(true).open(&temp.path)?;
+		write(&mut temp_file)?;
+		temp_file.sync_all()?;
+	}
+
+	// The copy is a new file, so make sure it ends up with the same permissions as the original
+	std::fs::set_permissions(&temp.path, permissions)?;
+	std::fs::rename(&temp.path, &path)?;
+	temp.persisted = true;
+
+	Ok(())
+}
+
+struct TempFile {
+	path: PathBuf,
+	persisted: bool,
+}
+
+impl Drop for TempFile {
+	fn drop(&mut self) {
+		if !self.persisted {
+			let _ = std::fs::remove_file(&self.path);
+		}
+	}
+}
+
 #[cfg(test)]
 // Used for tag conversion tests
 pub(crate) mod test_utils {
@@ -137,3 +203,96 @@ pub(crate) mod test_utils {
 		tag
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::error::{ErrorKind, LoftyError};
+
+	use std::io::{Read, Seek, SeekFrom, Write};
+
+	#[test]
+	fn write_to_path_preserves_original_on_error() {
+		let mut temp_file = tempfile::NamedTempFile::new().unwrap();
+		temp_file.as_file_mut().write_all(b"Original").unwrap();
+
+		let result = super::write_to_path(temp_file.path(), |file| {
+			file.write_all(b"Partial")?;
+			Err(LoftyError::new(ErrorKind::TooMuchData))
+		});
+		assert!(result.is_err());
+
+		let mut content = Vec::new();
+		temp_file
+			.reopen()
+			.unwrap()
+			.read_to_end(&mut content)
+			.unwrap();
+		assert_eq!(content, b"Original");
+
+		super::write_to_path(temp_file.path(), |file| {
+			file.seek(SeekFrom::End(0))?;
+			file.write_all(b" and more")?;
+			Ok(())
+		})
+		.unwrap();
+
+		let mut content = Vec::new();
+		temp_file
+			.reopen()
+			.unwrap()
+			.read_to_end(&mut content)
+			.unwrap();
+		assert_eq!(content, b"Original and more");
+
+		assert_no_staging_files(temp_file.path());
+	}
+
+	#[test]
+	fn write_to_path_panic() {
+		let mut temp_file = tempfile::NamedTempFile::new().unwrap();
+		temp_file.as_file_mut().write_all(b"Original").unwrap();
+
+		let path = temp_file.path().to_path_buf();
+		let result = std::panic::catch_unwind(|| {
+			super::write_to_path(&path, |file| {
+				file.write_all(b"Partial")?;
+				panic!("Failed midway");
+			})
+		});
+		assert!(result.is_err());
+
+		assert_eq!(std::fs::read(temp_file.path()).unwrap(), b"Original");
+		assert_no_staging_files(temp_file.path());
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn write_to_path_permissions() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let temp_file = tempfile::NamedTempFile::new().unwrap();
+		std::fs::set_permissions(temp_file.path(), std::fs::Permissions::from_mode(0o640)).unwrap();
+
+		super::write_to_path(temp_file.path(), |file| {
+			file.write_all(b"New")?;
+			Ok(())
+		})
+		.unwrap();
+
+		let metadata = std::fs::metadata(temp_file.path()).unwrap();
+		assert_eq!(metadata.permissions().mode() & 0o777, 0o640);
+	}
+
+	// No staging files should be left behind
+	fn assert_no_staging_files(path: &std::path::Path) {
+		let parent = path.parent().unwrap();
+		let file_name = path.file_name().unwrap().to_string_lossy();
+		assert!(std::fs::read_dir(parent).unwrap().all(|entry| {
+			!entry
+				.unwrap()
+				.file_name()
+				.to_string_lossy()
+				.starts_with(&format!(".{}", file_name))
+		}));
+	}
+}
@@ -1,5 +1,5 @@
 macro_rules! accessor_trait {
-	($($name:ident),+) => {
+	($($name:ident),+; $($num_name:ident => $num_desc:literal),+) => {
 		/// Provides accessors for common items
 		///
 		/// This attempts to only provide methods for items that all tags have in common,
@@ -49,21 +49,189 @@ macro_rules! accessor_trait {
 					/// ```
 					fn [<remove_ $name>](&mut self) {}
 				)+
+
+				$(
+					#[doc = "Returns the " $num_desc]
+					/// # Example
+					///
+					/// ```rust
+					/// use lofty::{Tag, Accessor};
+					/// # let tag_type = lofty::TagType::Id3v2;
+					///
+					/// let mut tag = Tag::new(tag_type);
+					///
+					#[doc = "assert_eq!(tag." $num_name "(), None);"]
+					/// ```
+					fn $num_name(&self) -> Option<u32> { None }
+					#[doc = "Sets the " $num_desc]
+					/// # Example
+					///
+					/// ```rust
+					/// use lofty::{Tag, Accessor};
+					/// # let tag_type = lofty::TagType::Id3v2;
+					///
+					#[doc = "let mut tag = Tag::new(tag_type);\ntag.set_" $num_name "(5);"]
+					///
+					#[doc = "assert_eq!(tag." $num_name "(), Some(5));"]
+					/// ```
+					fn [<set_ $num_name>](&mut self, _value: u32) {}
+					#[doc = "Removes the " $num_desc]
+					///
+					/// # Example
+					///
+					/// ```rust
+					/// use lofty::{Tag, Accessor};
+					/// # let tag_type = lofty::TagType::Id3v2;
+					///
+					#[doc = "let mut tag = Tag::new(tag_type);\ntag.set_" $num_name "(5);"]
+					///
+					#[doc = "assert_eq!(tag." $num_name "(), Some(5));"]
+					///
+					#[doc = "tag.remove_" $num_name "();"]
+					///
+					#[doc = "assert_eq!(tag." $num_name "(), None);"]
+					/// ```
+					fn [<remove_ $num_name>](&mut self) {}
+				)+
 			}
+
+			/// Returns the rating, on a scale of 0-5 stars
+			///
+			/// A rating of 0 means the item is unrated.
+			///
+			/// NOTE: Only [`Id3v2Tag`](crate::id3::v2::Id3v2Tag) currently supports ratings
+			///
+			/// # Example
+			///
+			/// ```rust
+			/// use lofty::{Tag, Accessor};
+			/// # let tag_type = lofty::TagType::Id3v2;
+			///
+			/// let mut tag = Tag::new(tag_type);
+			///
+			/// assert_eq!(tag.rating(), None);
+			/// ```
+			fn rating(&self) -> Option<u8> { None }
+			/// Sets the rating, on a scale of 0-5 stars
+			///
+			/// Values greater than 5 will be treated as 5.
+			///
+			/// # Example
+			///
+			/// ```rust
+			/// use lofty::Accessor;
+			/// use lofty::id3::v2::Id3v2Tag;
+			///
+			/// let mut tag = Id3v2Tag::default();
+			/// tag.set_rating(4);
+			///
+			/// assert_eq!(tag.rating(), Some(4));
+			/// ```
+			fn set_rating(&mut self, _rating: u8) {}
+			/// Removes the rating
+			///
+			/// # Example
+			///
+			/// ```rust
+			/// use lofty::Accessor;
+			/// use lofty::id3::v2::Id3v2Tag;
+			///
+			/// let mut tag = Id3v2Tag::default();
+			/// tag.set_rating(4);
+			///
+			/// assert_eq!(tag.rating(), Some(4));
+			///
+			/// tag.remove_rating();
+			///
+			/// assert_eq!(tag.rating(), None);
+			/// ```
+			fn remove_rating(&mut self) {}
 		}
 	};
 }
 
 accessor_trait! {
 	artist, title,
-	album, genre
+	album, genre;
+	track       => "track number",
+	track_total => "total number of tracks",
+	disk        => "disk number",
+	disk_total  => "total number of disks"
 }
 
+use crate::picture::{Picture, PictureType};
 use crate::tag::Tag;
 
 use std::fs::File;
+use std::io::{Cursor, Read, Seek, Write};
 use std::path::Path;
 
+/// Provides a method to truncate an object to the specified length
+///
+/// This is used when writing tags, as the new content may be shorter than the original.
+pub trait Truncate {
+	/// Truncate the object to `new_len` bytes
+	///
+	/// # Errors
+	///
+	/// * [`std::io::Error`]
+	fn truncate(&mut self, new_len: u64) -> std::io::Result<()>;
+}
+
+impl Truncate for File {
+	fn truncate(&mut self, new_len: u64) -> std::io::Result<()> {
+		self.set_len(new_len)
+	}
+}
+
+impl Truncate for Cursor<Vec<u8>> {
+	fn truncate(&mut self, new_len: u64) -> std::io::Result<()> {
+		self.get_mut().truncate(new_len as usize);
+		Ok(())
+	}
+}
+
+impl<T: Truncate + ?Sized> Truncate for &mut T {
+	fn truncate(&mut self, new_len: u64) -> std::io::Result<()> {
+		(**self).truncate(new_len)
+	}
+}
+
+impl<T: Truncate + ?Sized> Truncate for Box<T> {
+	fn truncate(&mut self, new_len: u64) -> std::io::Result<()> {
+		(**self).truncate(new_len)
+	}
+}
+
+/// Anything that tags can be written to
+///
+/// This is automatically implemented for everything that implements [`Read`], [`Write`], [`Seek`], and
+/// [`Truncate`], which includes [`File`] and in-memory buffers such as `Cursor<Vec<u8>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use lofty::{Accessor, AudioFile, Probe, TagExt};
+/// use std::io::Cursor;
+///
+/// # fn main() -> lofty::Result<()> {
+/// let mut file = Cursor::new(std::fs::read("tests/files/assets/minimal/full_test.mp3")?);
+///
+/// let mut tag = Probe::new(&mut file).guess_file_type()?.read(false)?.primary_tag().unwrap().clone();
+/// tag.set_title(String::from("Foo title"));
+///
+/// file.set_position(0);
+/// tag.save_to(&mut file)?;
+///
+/// file.set_position(0);
+/// let tagged_file = Probe::new(&mut file).guess_file_type()?.read(false)?;
+/// assert_eq!(tagged_file.primary_tag().unwrap().title(), Some("Foo title"));
+/// # Ok(()) }
+/// ```
+pub trait FileLike: Read + Write + Seek + Truncate {}
+
+impl<T> FileLike for T where T: Read + Write + Seek + Truncate {}
+
 /// A set of common methods between tags
 ///
 /// This provides a set of methods to make interaction with all tags a similar
@@ -92,45 +260,134 @@ pub trait TagExt: Accessor + Into<Tag> + Sized {
 
 	/// Save the tag to a path
 	///
+	/// The tag is written to a copy of the file, which then replaces the original.
+	/// Should an error occur, the original file is left untouched.
+	///
 	/// # Errors
 	///
 	/// * Path doesn't exist
-	/// * Path is not writable
+	/// * Path is not writable, or its directory is not writable
 	/// * See [`TagExt::save_to`]
 	fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err>;
 
-	/// Save the tag to a [`File`]
+	/// Save the tag to a [`FileLike`], such as a [`File`]
 	///
 	/// # Errors
 	///
 	/// * The file format could not be determined
 	/// * Attempting to write a tag to a format that does not support it.
-	fn save_to(&self, file: &mut File) -> std::result::Result<(), Self::Err>;
+	fn save_to<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err>;
 
 	#[allow(clippy::missing_errors_doc)]
 	/// Dump the tag to a writer
 	///
 	/// This will only write the tag, it will not produce a usable file.
-	fn dump_to<W: std::io::Write>(&self, writer: &mut W) -> std::result::Result<(), Self::Err>;
+	fn dump_to<W: Write>(&self, writer: &mut W) -> std::result::Result<(), Self::Err>;
+
+	/// The encoded length of the tag, in bytes
+	///
+	/// This is the length of what [`TagExt::dump_to`] produces, including any headers, padding, and the
+	/// text in its encoded form (not its character count). Nothing is buffered. Formats that embed the
+	/// tag in a container (Ex. a RIFF chunk) may add some overhead when writing to a file.
+	///
+	/// # Errors
+	///
+	/// See [`TagExt::dump_to`]
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use lofty::id3::v1::Id3v1Tag;
+	/// use lofty::TagExt;
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// // ID3v1 tags are always 128 bytes
+	/// assert_eq!(Id3v1Tag::default().len_in_bytes()?, 128);
+	/// # Ok(()) }
+	/// ```
+	fn len_in_bytes(&self) -> std::result::Result<u64, Self::Err> {
+		let mut counter = ByteCounter(0);
+		self.dump_to(&mut counter)?;
+
+		Ok(counter.0)
+	}
 
 	/// Remove a tag from a [`Path`]
 	///
+	/// As with [`TagExt::save_to_path`], the original file is only replaced once the tag is removed.
+	///
 	/// # Errors
 	///
 	/// See [`TagExt::remove_from`]
 	fn remove_from_path<P: AsRef<Path>>(&self, path: P) -> std::result::Result<(), Self::Err>;
 
-	/// Remove a tag from a [`File`]
+	/// Remove a tag from a [`FileLike`], such as a [`File`]
 	///
 	/// # Errors
 	///
 	/// * It is unable to guess the file format
 	/// * The format doesn't support the tag
 	/// * It is unable to write to the file
-	fn remove_from(&self, file: &mut File) -> std::result::Result<(), Self::Err>;
+	fn remove_from<F: FileLike>(&self, file: &mut F) -> std::result::Result<(), Self::Err>;
 
 	/// Clear the tag, removing all items
 	///
 	/// NOTE: This will **not** remove any format-specific extras, such as flags
 	fn clear(&mut self);
+
+	/// Returns a copy of all of the tag's pictures
+	///
+	/// NOTE: Not all formats store pictures as a [`Picture`] (APE stores them as binary items),
+	/// so the pictures will be cloned. To borrow them, use the concrete tag type's methods (Ex. [`Tag::pictures`]).
+	fn collect_pictures(&self) -> Vec<Picture>;
+
+	/// Inserts a [`Picture`], replacing any existing pictures of the same [`PictureType`]
+	///
+	/// This will replace **all** existing pictures of the same [`PictureType`], and formats
+	/// that do not store a [`PictureType`] (Ex. MP4) will have all of their pictures replaced.
+	/// To have multiple pictures of the same type, use the concrete tag type's methods.
+	///
+	/// # Errors
+	///
+	/// * The tag does not support pictures (Ex. ID3v1)
+	/// * The picture can't be represented in the format (Ex. APE has no key for [`PictureType::Undefined`])
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use lofty::{MimeType, Picture, PictureType, Tag, TagExt};
+	///
+	/// # fn main() -> lofty::Result<()> {
+	/// # let tag_type = lofty::TagType::Id3v2;
+	/// let mut tag = Tag::new(tag_type);
+	///
+	/// let front_cover = Picture::new_unchecked(PictureType::CoverFront, MimeType::Png, None, vec![1; 8]);
+	/// let new_front_cover = Picture::new_unchecked(PictureType::CoverFront, MimeType::Png, None, vec![2; 8]);
+	///
+	/// tag.replace_picture(front_cover)?;
+	/// tag.replace_picture(new_front_cover.clone())?;
+	///
+	/// assert_eq!(tag.pictures(), &[new_front_cover]);
+	/// # Ok(()) }
+	/// ```
+	fn replace_picture(&mut self, picture: Picture) -> std::result::Result<(), Self::Err>;
+
+	/// Removes all pictures of a [`PictureType`]
+	///
+	/// NOTE: Formats that do not store a [`PictureType`] (Ex. MP4) will have all of their pictures removed.
+	fn remove_picture_type(&mut self, picture_type: PictureType);
+}
+
+// A writer that only keeps track of how much has been written
+struct ByteCounter(u64);
+
+impl Write for ByteCounter {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.0 += buf.len() as u64;
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
 }
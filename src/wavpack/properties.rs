@@ -0,0 +1,369 @@
+use crate::error::{FileDecodingError, Result};
+use crate::file::FileType;
+use crate::macros::try_vec;
+use crate::properties::FileProperties;
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[derive(Clone, Debug, PartialEq, Default)]
+#[non_exhaustive]
+/// A WavPack file's audio properties
+pub struct WavPackProperties {
+	pub(crate) version: u16,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+	pub(crate) bit_depth: u8,
+	pub(crate) lossless: bool,
+}
+
+impl From<WavPackProperties> for FileProperties {
+	fn from(input: WavPackProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+		}
+	}
+}
+
+impl WavPackProperties {
+	/// Duration
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// WavPack stream version
+	pub fn version(&self) -> u16 {
+		self.version
+	}
+
+	/// Whether the audio is lossless
+	///
+	/// This will be `false` for files encoded in hybrid mode, which
+	/// require a correction file (`.wvc`) to be restored losslessly.
+	pub fn is_lossless(&self) -> bool {
+		self.lossless
+	}
+}
+
+// https://www.wavpack.com/WavPack5FileFormat.pdf
+const BLOCK_HEADER_SIZE: u32 = 32;
+
+const MIN_STREAM_VERSION: u16 = 0x402;
+const MAX_STREAM_VERSION: u16 = 0x410;
+
+const BYTES_STORED: u32 = 3;
+const MONO_FLAG: u32 = 4;
+const HYBRID_FLAG: u32 = 8;
+const INITIAL_BLOCK: u32 = 0x800;
+const SHIFT_LSB: u32 = 13;
+const SHIFT_MASK: u32 = 0x1F << SHIFT_LSB;
+const SRATE_LSB: u32 = 23;
+const SRATE_MASK: u32 = 0xF << SRATE_LSB;
+
+const ID_UNIQUE: u8 = 0x3F;
+const ID_ODD_SIZE: u8 = 0x40;
+const ID_LARGE: u8 = 0x80;
+
+const ID_CHANNEL_INFO: u8 = 0x0D;
+const ID_SAMPLE_RATE: u8 = 0x27;
+
+const SAMPLE_RATES: [u32; 15] = [
+	6000, 8000, 9600, 11025, 12000, 16000, 22050, 24000, 32000, 44100, 48000, 64000, 88200, 96000,
+	192_000,
+];
+
+struct BlockHeader {
+	block_size: u32,
+	version: u16,
+	total_samples: Option<u64>,
+	block_samples: u32,
+	flags: u32,
+}
+
+fn read_block_header<R>(reader: &mut R) -> Result<BlockHeader>
+where
+	R: Read,
+{
+	let mut ident = [0; 4];
+	reader.read_exact(&mut ident)?;
+
+	if &ident != b"wvpk" {
+		return Err(
+			FileDecodingError::new(FileType::WavPack, "Found an invalid block header").into(),
+		);
+	}
+
+	// The size doesn't include the identifier and itself
+	let block_size = reader.read_u32::<LittleEndian>()?.saturating_add(8);
+
+	if block_size < BLOCK_HEADER_SIZE {
+		return Err(
+			FileDecodingError::new(FileType::WavPack, "Found an invalid block size").into(),
+		);
+	}
+
+	let version = reader.read_u16::<LittleEndian>()?;
+
+	// Skip 1 byte
+	// Block index (upper 8 bits)
+	reader.read_u8()?;
+
+	let total_samples_u8 = reader.read_u8()?;
+	let total_samples = reader.read_u32::<LittleEndian>()?;
+
+	// Skip 4 bytes
+	// Block index (lower 32 bits)
+	reader.read_u32::<LittleEndian>()?;
+
+	let block_samples = reader.read_u32::<LittleEndian>()?;
+	let flags = reader.read_u32::<LittleEndian>()?;
+
+	// Skip 4 bytes
+	// CRC (4)
+	reader.read_u32::<LittleEndian>()?;
+
+	// A total sample count of -1 means it is unknown, and needs to be
+	// calculated from the blocks themselves
+	let total_samples = if total_samples == u32::MAX {
+		None
+	} else {
+		Some(
+			u64::from(total_samples) + (u64::from(total_samples_u8) << 32)
+				- u64::from(total_samples_u8),
+		)
+	};
+
+	Ok(BlockHeader {
+		block_size,
+		version,
+		total_samples,
+		block_samples,
+		flags,
+	})
+}
+
+pub(super) fn read_properties<R>(
+	reader: &mut R,
+	stream_length: u64,
+	file_length: u64,
+) -> Result<WavPackProperties>
+where
+	R: Read + Seek,
+{
+	let first_block = read_block_header(reader)?;
+
+	if !(MIN_STREAM_VERSION..=MAX_STREAM_VERSION).contains(&first_block.version) {
+		return Err(FileDecodingError::new(
+			FileType::WavPack,
+			"Found an unsupported stream version",
+		)
+		.into());
+	}
+
+	let flags = first_block.flags;
+
+	let mut properties = WavPackProperties {
+		version: first_block.version,
+		channels: if flags & MONO_FLAG == MONO_FLAG { 1 } else { 2 },
+		bit_depth: (((flags & BYTES_STORED) + 1) * 8)
+			.saturating_sub((flags & SHIFT_MASK) >> SHIFT_LSB) as u8,
+		lossless: flags & HYBRID_FLAG == 0,
+		..WavPackProperties::default()
+	};
+
+	let sample_rate_idx = ((flags & SRATE_MASK) >> SRATE_LSB) as usize;
+	if let Some(sample_rate) = SAMPLE_RATES.get(sample_rate_idx) {
+		properties.sample_rate = *sample_rate;
+	}
+
+	if u64::from(first_block.block_size) > stream_length {
+		return Err(FileDecodingError::new(
+			FileType::WavPack,
+			"Block size is larger than the stream",
+		)
+		.into());
+	}
+
+	// The metadata sub-blocks may contain a channel count (for multichannel audio),
+	// or a sample rate not present in the table above
+	let mut block_content = try_vec![0; (first_block.block_size - BLOCK_HEADER_SIZE) as usize];
+	reader.read_exact(&mut block_content)?;
+
+	parse_metadata_sub_blocks(&mut &*block_content, &mut properties)?;
+
+	if properties.sample_rate == 0 {
+		return Err(
+			FileDecodingError::new(FileType::WavPack, "Unable to determine sample rate").into(),
+		);
+	}
+
+	let total_samples = match first_block.total_samples {
+		Some(total_samples) => total_samples,
+		None => {
+			let mut total_samples = u64::from(first_block.block_samples);
+			let mut offset = u64::from(first_block.block_size);
+
+			// Multichannel audio is split into multiple blocks, so only the
+			// samples of the initial block of each sequence are counted
+			while offset + u64::from(BLOCK_HEADER_SIZE) <= stream_length {
+				let block = read_block_header(reader)?;
+
+				if block.flags & INITIAL_BLOCK == INITIAL_BLOCK {
+					total_samples += u64::from(block.block_samples);
+				}
+
+				offset += u64::from(block.block_size);
+				reader.seek(SeekFrom::Current(i64::from(
+					block.block_size - BLOCK_HEADER_SIZE,
+				)))?;
+			}
+
+			total_samples
+		},
+	};
+
+	let length = (total_samples * 1000) / u64::from(properties.sample_rate);
+
+	if length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = ((file_length * 8) / length) as u32;
+		properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+	}
+
+	Ok(properties)
+}
+
+fn parse_metadata_sub_blocks(reader: &mut &[u8], properties: &mut WavPackProperties) -> Result<()> {
+	while !reader.is_empty() {
+		let id = reader.read_u8()?;
+
+		// The size is stored in words
+		let mut size = if id & ID_LARGE == ID_LARGE {
+			reader.read_u24::<LittleEndian>()? * 2
+		} else {
+			u32::from(reader.read_u8()?) * 2
+		} as usize;
+
+		if size > reader.len() {
+			return Err(FileDecodingError::new(
+				FileType::WavPack,
+				"Metadata sub-block is larger than its block",
+			)
+			.into());
+		}
+
+		let (content, remaining) = reader.split_at(size);
+		*reader = remaining;
+
+		// The final byte is padding
+		if id & ID_ODD_SIZE == ID_ODD_SIZE && size > 0 {
+			size -= 1;
+		}
+
+		let content = &content[..size];
+
+		match id & ID_UNIQUE {
+			ID_CHANNEL_INFO if !content.is_empty() => properties.channels = content[0],
+			ID_SAMPLE_RATE if content.len() >= 3 => {
+				properties.sample_rate = u32::from(content[0])
+					| (u32::from(content[1]) << 8)
+					| (u32::from(content[2]) << 16);
+			},
+			_ => {},
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::read_properties;
+
+	use std::io::Cursor;
+	use std::time::Duration;
+
+	fn block(total_samples: u32, block_samples: u32, flags: u32, content: &[u8]) -> Vec<u8> {
+		let mut block = Vec::new();
+		block.extend(b"wvpk");
+		block.extend((content.len() as u32 + 24).to_le_bytes());
+		block.extend(0x410_u16.to_le_bytes());
+		block.extend([0, 0]);
+		block.extend(total_samples.to_le_bytes());
+		block.extend(0_u32.to_le_bytes());
+		block.extend(block_samples.to_le_bytes());
+		block.extend(flags.to_le_bytes());
+		block.extend(0_u32.to_le_bytes());
+		block.extend(content);
+
+		block
+	}
+
+	#[test]
+	fn unknown_total_samples() {
+		// 16-bit, custom sample rate, initial block
+		let flags = 1 | (0xF << 23) | 0x800;
+
+		// ID_SAMPLE_RATE (odd size, 3 bytes of 22000 Hz + padding), followed by
+		// ID_CHANNEL_INFO (1 byte of 6 channels + padding)
+		let content = [0x67, 2, 0xF0, 0x55, 0, 0, 0x4D, 1, 6, 0];
+
+		let mut stream = block(u32::MAX, 11000, flags, &content);
+		stream.extend(block(u32::MAX, 11000, flags, &[]));
+		// Not an initial block, so it isn't counted
+		stream.extend(block(u32::MAX, 11000, flags & !0x800, &[]));
+
+		let len = stream.len() as u64;
+		let properties = read_properties(&mut Cursor::new(stream), len, len).unwrap();
+
+		assert_eq!(properties.sample_rate, 22000);
+		assert_eq!(properties.channels, 6);
+		assert_eq!(properties.bit_depth, 16);
+		assert_eq!(properties.duration, Duration::from_secs(1));
+		assert!(properties.lossless);
+	}
+
+	#[test]
+	fn invalid_block() {
+		let mut stream = block(44100, 44100, 1 | (9 << 23), &[]);
+		stream[0] = b'W';
+
+		let len = stream.len() as u64;
+		assert!(read_properties(&mut Cursor::new(stream), len, len).is_err());
+	}
+}
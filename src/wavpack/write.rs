@@ -0,0 +1,25 @@
+#[cfg(feature = "ape")]
+use crate::ape;
+use crate::error::{ErrorKind, LoftyError, Result};
+#[cfg(feature = "id3v1")]
+use crate::id3::v1;
+#[allow(unused_imports)]
+use crate::tag::{Tag, TagType};
+use crate::traits::FileLike;
+
+#[allow(unused_variables)]
+pub(crate) fn write_to<F: FileLike>(data: &mut F, tag: &Tag) -> Result<()> {
+	match tag.tag_type() {
+		#[cfg(feature = "ape")]
+		TagType::Ape => ape::tag::ApeTagRef {
+			read_only: false,
+			version: ape::ApeTagVersion::V2,
+			location: None,
+			items: ape::tag::tagitems_into_ape(tag),
+		}
+		.write_to(data),
+		#[cfg(feature = "id3v1")]
+		TagType::Id3v1 => Into::<v1::tag::Id3v1TagRef<'_>>::into(tag).write_to(data),
+		_ => Err(LoftyError::new(ErrorKind::UnsupportedTag)),
+	}
+}
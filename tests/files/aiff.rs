@@ -1,6 +1,7 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::{FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
-use std::io::{Seek, Write};
+use lofty::iff::{AiffFile, AiffTextChunks};
+use lofty::{Accessor, AudioFile, FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
+use std::io::{Read, Seek, Write};
 
 #[test]
 fn read() {
@@ -51,3 +52,51 @@ fn remove_text_chunks() {
 fn remove_id3v2() {
 	crate::remove_tag!("tests/files/assets/minimal/full_test.aiff", TagType::Id3v2);
 }
+
+#[test]
+fn write_text_chunks_and_id3v2_independently() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.aiff");
+
+	// Remove the text chunks, so they have to be created from scratch
+	TagType::AiffText.remove_from(&mut file).unwrap();
+
+	let text_chunks = AiffTextChunks {
+		author: Some(String::from("Baz artist")),
+		annotations: Some(vec![
+			String::from("Qux annotation"),
+			String::from("Quux annotation"),
+		]),
+		..AiffTextChunks::default()
+	};
+
+	file.rewind().unwrap();
+	text_chunks.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let aiff_file = AiffFile::read_from(&mut file, true).unwrap();
+
+	assert_eq!(aiff_file.text_chunks(), Some(&text_chunks));
+	assert_eq!(aiff_file.id3v2_tag().unwrap().artist(), Some("Foo artist"));
+	assert_eq!(aiff_file.properties().channels(), Some(2));
+
+	// Removing the ID3v2 tag should leave the text chunks alone
+	file.rewind().unwrap();
+	TagType::Id3v2.remove_from(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let aiff_file = AiffFile::read_from(&mut file, true).unwrap();
+
+	assert!(aiff_file.id3v2_tag().is_none());
+	assert_eq!(aiff_file.text_chunks(), Some(&text_chunks));
+
+	// The FORM size should still be correct
+	let mut header = [0; 8];
+	file.rewind().unwrap();
+	file.read_exact(&mut header).unwrap();
+
+	let form_size = u32::from_be_bytes(header[4..].try_into().unwrap());
+	assert_eq!(
+		u64::from(form_size),
+		file.seek(std::io::SeekFrom::End(0)).unwrap() - 8
+	);
+}
@@ -1,6 +1,7 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::{FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
-use std::io::{Seek, Write};
+use lofty::ape::{ApeFile, ApeItem, ApeTagLocation};
+use lofty::{Accessor, AudioFile, FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
+use std::io::{Read, Seek, Write};
 
 #[test]
 fn read() {
@@ -57,3 +58,106 @@ fn remove_id3v1() {
 fn remove_id3v2() {
 	crate::remove_tag!("tests/files/assets/minimal/full_test.ape", TagType::Id3v2);
 }
+
+#[test]
+fn read_only_items() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.ape");
+
+	let mut ape_file = ApeFile::read_from(&mut file, false).unwrap();
+	let ape_tag = ape_file.ape_tag_mut().unwrap();
+
+	let mut item = ApeItem::new(String::from("Foo"), ItemValue::Text(String::from("Bar"))).unwrap();
+	item.read_only = true;
+	ape_tag.insert(item);
+
+	file.rewind().unwrap();
+	ape_tag.save_to(&mut file).unwrap();
+
+	// Editing the file through a `Tag`, which has no concept of read only items
+	file.rewind().unwrap();
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+	let tag = tagged_file.primary_tag_mut().unwrap();
+	tag.insert_text(ItemKey::TrackArtist, String::from("Baz artist"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mut ape_file = ApeFile::read_from(&mut file, false).unwrap();
+	let ape_tag = ape_file.ape_tag_mut().unwrap();
+
+	// The other edits shouldn't be discarded, and the item should still be read only
+	assert_eq!(ape_tag.artist(), Some("Baz artist"));
+	assert!(ape_tag.get_key("Foo").unwrap().read_only);
+
+	let replacement =
+		ApeItem::new(String::from("Foo"), ItemValue::Text(String::from("Qux"))).unwrap();
+	assert!(ape_tag.try_insert(replacement.clone()).is_err());
+
+	// Inserting explicitly overrides the item
+	ape_tag.insert(replacement);
+
+	file.rewind().unwrap();
+	ape_tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let ape_file = ApeFile::read_from(&mut file, false).unwrap();
+	let item = ape_file.ape_tag().unwrap().get_key("Foo").unwrap();
+
+	assert_eq!(item.value(), &ItemValue::Text(String::from("Qux")));
+	assert!(!item.read_only);
+}
+
+#[test]
+fn tag_location() {
+	fn offsets(file: &mut std::fs::File) -> (usize, usize) {
+		file.rewind().unwrap();
+
+		let mut bytes = Vec::new();
+		file.read_to_end(&mut bytes).unwrap();
+
+		let ape_tag = bytes.windows(8).position(|w| w == b"APETAGEX").unwrap();
+		let audio = bytes.windows(4).position(|w| w == b"MAC ").unwrap();
+
+		(ape_tag, audio)
+	}
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.ape");
+
+	// Moving the tag to the start of the file
+	let mut ape_file = ApeFile::read_from(&mut file, false).unwrap();
+	let ape_tag = ape_file.ape_tag_mut().unwrap();
+	ape_tag.location = Some(ApeTagLocation::Start);
+
+	file.rewind().unwrap();
+	ape_tag.save_to(&mut file).unwrap();
+
+	let (ape_tag_offset, audio_offset) = offsets(&mut file);
+	assert!(ape_tag_offset < audio_offset);
+
+	// Editing through a `Tag` should keep the tag where it was found
+	file.rewind().unwrap();
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	let (ape_tag_offset, audio_offset) = offsets(&mut file);
+	assert!(ape_tag_offset < audio_offset);
+
+	file.rewind().unwrap();
+	let mut ape_file = ApeFile::read_from(&mut file, false).unwrap();
+	let ape_tag = ape_file.ape_tag_mut().unwrap();
+	assert_eq!(ape_tag.artist(), Some("Bar artist"));
+
+	// And back to the end
+	ape_tag.location = Some(ApeTagLocation::End);
+
+	file.rewind().unwrap();
+	ape_tag.save_to(&mut file).unwrap();
+
+	let (ape_tag_offset, audio_offset) = offsets(&mut file);
+	assert!(ape_tag_offset > audio_offset);
+
+	file.rewind().unwrap();
+	let ape_file = ApeFile::read_from(&mut file, false).unwrap();
+	assert_eq!(ape_file.ape_tag().unwrap().artist(), Some("Bar artist"));
+}
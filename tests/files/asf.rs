@@ -0,0 +1,28 @@
+use crate::{temp_file, verify_artist};
+use lofty::{FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
+use std::io::{Seek, Write};
+
+#[test]
+fn read() {
+	// Here we have an ASF file with a Content Description Object
+	let file = lofty::read_from_path("tests/files/assets/minimal/full_test.wma", false).unwrap();
+
+	assert_eq!(file.file_type(), FileType::Asf);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+}
+
+#[test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.wma");
+
+	let tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Asf);
+
+	// Writing isn't supported yet
+	let tag = tagged_file.tag(&TagType::AsfAttributes).unwrap();
+
+	file.rewind().unwrap();
+	assert!(tag.save_to(&mut file).is_err());
+}
@@ -0,0 +1,63 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::error::ErrorKind;
+use lofty::{AudioFile, FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+#[test]
+fn read() {
+	// Here we have a DSF file with an ID3v2 tag
+	let file = lofty::read_from_path("tests/files/assets/minimal/full_test.dsf", false).unwrap();
+
+	assert_eq!(file.file_type(), FileType::Dsf);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+}
+
+#[test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dsf");
+
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Dsf);
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = lofty::read_from(&mut file, true).unwrap();
+
+	// The properties should be unaffected by the new tag size
+	assert_eq!(tagged_file.properties().sample_rate(), Some(2_822_400));
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+}
+
+#[test]
+fn write_bad_metadata_offset() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dsf");
+	let tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	// Point the metadata chunk into the "fmt " chunk
+	file.seek(SeekFrom::Start(20)).unwrap();
+	file.write_all(&40_u64.to_le_bytes()).unwrap();
+
+	let mut original = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut original).unwrap();
+
+	file.rewind().unwrap();
+	let err = tagged_file.save_to(&mut file).unwrap_err();
+	assert!(matches!(err.kind(), ErrorKind::FileDecoding(_)));
+
+	// The file should be untouched
+	let mut contents = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut contents).unwrap();
+	assert_eq!(contents, original);
+}
+
+#[test]
+fn remove_id3v2() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.dsf", TagType::Id3v2);
+}
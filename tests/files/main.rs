@@ -1,7 +1,13 @@
+mod aac;
 mod aiff;
 mod ape;
+mod asf;
+mod dsf;
 mod mp4;
+mod mpc;
 mod mpeg;
 mod ogg;
+mod remove;
 pub(crate) mod util;
 mod wav;
+mod wavpack;
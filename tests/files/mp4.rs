@@ -1,4 +1,5 @@
 use crate::{set_artist, temp_file, verify_artist};
+use lofty::error::WarningCategory;
 use lofty::{FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
 use std::io::{Seek, Write};
 
@@ -40,3 +41,20 @@ fn remove() {
 		TagType::Mp4Ilst
 	);
 }
+
+#[test]
+fn read_with_warnings() {
+	let (_, warnings) =
+		lofty::read_from_path_with_warnings("tests/files/assets/minimal/m4a_codec_aac.m4a", true)
+			.unwrap();
+	assert!(warnings.is_empty());
+
+	// The "moov" atom's length extends past the end of the file
+	let (_, warnings) =
+		lofty::read_from_path_with_warnings("tests/files/assets/non_full_meta_atom.m4a", true)
+			.unwrap();
+	assert!(!warnings.is_empty());
+	assert!(warnings
+		.iter()
+		.all(|warning| warning.category == WarningCategory::Structure));
+}
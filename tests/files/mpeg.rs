@@ -1,6 +1,7 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::{Accessor, FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
-use std::io::{Seek, Write};
+use lofty::mp3::Mp3File;
+use lofty::{Accessor, AudioFile, FileType, ItemKey, ItemValue, Probe, TagExt, TagItem, TagType};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 #[test]
 fn read() {
@@ -43,6 +44,31 @@ fn read_with_junk_bytes_between_frames() {
 	assert_eq!(id3v1_tag.title(), Some("title test"));
 }
 
+#[test]
+fn read_from_stream() {
+	let bytes = std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap();
+
+	let file = Mp3File::read_from(&mut Cursor::new(&bytes), true).unwrap();
+	let streamed = Mp3File::read_from_stream(&mut &bytes[..], true).unwrap();
+
+	// Only the ID3v2 tag can be read
+	assert_eq!(
+		streamed.id3v2_tag().unwrap().artist(),
+		file.id3v2_tag().unwrap().artist()
+	);
+	assert!(streamed.id3v1_tag().is_none());
+	assert!(streamed.ape_tag().is_none());
+
+	let (properties, estimate) = (file.properties(), streamed.properties());
+	assert!(!properties.is_approximate());
+	assert!(estimate.is_approximate());
+
+	assert_eq!(estimate.duration(), properties.duration());
+	assert_eq!(estimate.audio_bitrate(), properties.audio_bitrate());
+	assert_eq!(estimate.sample_rate(), properties.sample_rate());
+	assert_eq!(estimate.channels(), properties.channels());
+}
+
 #[test]
 fn write() {
 	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
@@ -71,6 +97,115 @@ fn write() {
 	crate::set_artist!(tagged_file, tag_mut, TagType::Ape, "Qux artist", 1 => file, "Baz artist");
 }
 
+#[test]
+fn write_concrete() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let mut mpeg_file = Mp3File::read_from(&mut file, false).unwrap();
+
+	mpeg_file
+		.id3v2_tag_mut()
+		.unwrap()
+		.set_artist(String::from("Foo artist"));
+	mpeg_file
+		.ape_tag_mut()
+		.unwrap()
+		.set_artist(String::from("Bar artist"));
+
+	mpeg_file.save_to(&mut file).unwrap();
+
+	// Every tag should have been written
+	file.rewind().unwrap();
+	let mpeg_file = Mp3File::read_from(&mut file, false).unwrap();
+
+	assert_eq!(mpeg_file.id3v2_tag().unwrap().artist(), Some("Foo artist"));
+	assert_eq!(mpeg_file.id3v1_tag().unwrap().artist(), Some("Bar artist"));
+	assert_eq!(mpeg_file.ape_tag().unwrap().artist(), Some("Bar artist"));
+}
+
+#[test]
+fn write_id3v2_in_place() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let mut tag = lofty::id3::v2::Id3v2Tag::default();
+	tag.set_title("A".repeat(1024));
+	tag.save_to(&mut file).unwrap();
+
+	let len = file.metadata().unwrap().len();
+
+	// A smaller tag should fit in the space of the previous one
+	file.rewind().unwrap();
+	tag.set_title(String::from("B"));
+	tag.save_to(&mut file).unwrap();
+
+	assert_eq!(file.metadata().unwrap().len(), len);
+
+	file.rewind().unwrap();
+	let mpeg_file = Mp3File::read_from(&mut file, true).unwrap();
+
+	assert_eq!(mpeg_file.id3v2_tag().unwrap().title(), Some("B"));
+	assert_eq!(mpeg_file.id3v1_tag().unwrap().artist(), Some("Bar artist"));
+	assert!(mpeg_file.properties().duration().as_millis() > 0);
+}
+
+#[test]
+fn write_id3v2_with_footer() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let original = Mp3File::read_from(&mut file, true).unwrap();
+
+	let mut tag = lofty::id3::v2::Id3v2Tag::default();
+	tag.set_title(String::from("Foo title"));
+	tag.set_flags(lofty::id3::v2::Id3v2TagFlags {
+		footer: true,
+		..lofty::id3::v2::Id3v2TagFlags::default()
+	});
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	// The footer is the header, with the identifier reversed
+	file.rewind().unwrap();
+	let mut header = [0; 10];
+	file.read_exact(&mut header).unwrap();
+
+	let size = header[6..]
+		.iter()
+		.fold(0_u64, |size, b| (size << 7) | u64::from(*b));
+	file.seek(SeekFrom::Start(10 + size)).unwrap();
+
+	let mut footer = [0; 10];
+	file.read_exact(&mut footer).unwrap();
+
+	assert_eq!(&footer[..3], b"3DI");
+	assert_eq!(footer[3..], header[3..]);
+
+	// The audio should still be found directly after the footer
+	file.rewind().unwrap();
+	let tagged_file = lofty::read_from(&mut file, true).unwrap();
+	assert_eq!(tagged_file.file_type(), FileType::MP3);
+
+	file.rewind().unwrap();
+	let mpeg_file = Mp3File::read_from(&mut file, true).unwrap();
+
+	let id3v2 = mpeg_file.id3v2_tag().unwrap();
+	assert!(id3v2.flags().footer);
+	assert_eq!(id3v2.title(), Some("Foo title"));
+	assert_eq!(mpeg_file.properties(), original.properties());
+
+	// Replacing the tag should remove the footer along with it
+	tag.set_flags(lofty::id3::v2::Id3v2TagFlags::default());
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file = Mp3File::read_from(&mut file, true).unwrap();
+
+	assert!(!mpeg_file.id3v2_tag().unwrap().flags().footer);
+	assert_eq!(mpeg_file.properties(), original.properties());
+}
+
 #[test]
 fn remove_id3v2() {
 	crate::remove_tag!("tests/files/assets/minimal/full_test.mp3", TagType::Id3v2);
@@ -85,3 +220,106 @@ fn remove_id3v1() {
 fn remove_ape() {
 	crate::remove_tag!("tests/files/assets/minimal/full_test.mp3", TagType::Ape);
 }
+
+#[test]
+fn merge_into_flac() {
+	let mut mp3_file =
+		lofty::read_from_path("tests/files/assets/minimal/full_test.mp3", false).unwrap();
+
+	// The ID3v2 tag has the highest priority, the APE tag should only fill in the gaps
+	let ape = mp3_file.tag_mut(&TagType::Ape).unwrap();
+	ape.set_artist(String::from("Bar artist"));
+	ape.set_title(String::from("Baz title"));
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+	let mut flac_file = lofty::read_from(&mut file, false).unwrap();
+
+	flac_file.merge_from(&mp3_file);
+	assert_eq!(flac_file.tags().len(), 1);
+
+	file.rewind().unwrap();
+	flac_file.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let flac_file = lofty::read_from(&mut file, false).unwrap();
+	let vorbis_comments = flac_file.tag(&TagType::VorbisComments).unwrap();
+
+	assert_eq!(vorbis_comments.artist(), Some("Foo artist"));
+	assert_eq!(vorbis_comments.title(), Some("Baz title"));
+}
+
+#[test]
+fn edit_primary_tag() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	// The ID3v2 tag is created again by the editor
+	assert!(tagged_file.remove(TagType::Id3v2).is_some());
+
+	file.rewind().unwrap();
+	tagged_file
+		.edit()
+		.unwrap()
+		.set_title("Foo title")
+		.set_artist("Bar artist")
+		.set_track(3)
+		.set_track_total(10)
+		.remove_track_total()
+		.save_to(&mut file)
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	let id3v2 = tagged_file.tag(&TagType::Id3v2).unwrap();
+	assert_eq!(id3v2.album(), None);
+	assert_eq!(id3v2.title(), Some("Foo title"));
+	assert_eq!(id3v2.artist(), Some("Bar artist"));
+	assert_eq!(id3v2.track(), Some(3));
+	assert_eq!(id3v2.track_total(), None);
+}
+
+#[test]
+fn remove_single_tag() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	assert!(tagged_file.remove(TagType::Id3v1).is_some());
+	// Removing it again, or removing an unsupported tag, does nothing
+	assert!(tagged_file.remove(TagType::Id3v1).is_none());
+	assert!(tagged_file.remove(TagType::Mp4Ilst).is_none());
+
+	file.rewind().unwrap();
+	tagged_file.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file = Mp3File::read_from(&mut file, false).unwrap();
+
+	assert!(!mpeg_file.contains_tag_type(TagType::Id3v1));
+	assert!(mpeg_file.contains_tag_type(TagType::Id3v2));
+	assert!(mpeg_file.contains_tag_type(TagType::Ape));
+}
+
+#[test]
+fn lenient_id3v2_parsing() {
+	let mp3 = std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap();
+
+	// The original "TPE1" frame, followed by a "COMM" frame too short to contain
+	// the encoding and language
+	let mut frames = mp3[10..31].to_vec();
+	frames.extend_from_slice(b"COMM\x00\x00\x00\x03\x00\x00\x00en");
+
+	let mut file = b"ID3\x04\x00\x00\x00\x00\x00".to_vec();
+	file.push(frames.len() as u8);
+	file.extend_from_slice(&frames);
+	file.extend_from_slice(&mp3[31..]);
+
+	assert!(lofty::read_from_bytes(&file, false).is_err());
+
+	let mut probe = Probe::new(Cursor::new(&file)).guess_file_type().unwrap();
+	probe.set_lenient_id3v2_parsing(true);
+
+	let (tagged_file, warnings) = probe.read_with_warnings(false).unwrap();
+	crate::verify_artist!(tagged_file, primary_tag, "Foo artist", 1);
+	assert_eq!(warnings.len(), 1);
+}
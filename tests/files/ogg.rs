@@ -1,6 +1,7 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::{FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
-use std::io::{Seek, Write};
+use lofty::ogg::{OpusFile, VorbisFile};
+use lofty::{AudioFile, FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
+use std::io::{Read, Seek, Write};
 
 // The tests for OGG Opus/Vorbis are nearly identical
 // We have the vendor string and a title stored in the tag
@@ -23,6 +24,39 @@ fn opus_remove() {
 	)
 }
 
+#[test]
+fn opus_set_output_gain() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.opus");
+
+	let mut opus_file = OpusFile::read_from(&mut file, true).unwrap();
+	assert_eq!(opus_file.properties().output_gain(), 0);
+
+	opus_file.set_output_gain(&mut file, -256).unwrap();
+	assert_eq!(opus_file.properties().output_gain(), -256);
+
+	// The page checksum should have been updated
+	file.rewind().unwrap();
+	let mut page = [0; 47];
+	file.read_exact(&mut page).unwrap();
+
+	let checksum = u32::from_le_bytes(page[22..26].try_into().unwrap());
+	page[22..26].copy_from_slice(&[0; 4]);
+	assert_eq!(ogg_pager::crc32(&page), checksum);
+
+	file.rewind().unwrap();
+	let mut opus_file = OpusFile::read_from(&mut file, true).unwrap();
+
+	assert_eq!(opus_file.properties().output_gain(), -256);
+	assert_eq!(
+		opus_file.vorbis_comments().get("ARTIST"),
+		Some("Foo artist")
+	);
+
+	// A Vorbis file doesn't have an Opus identification header
+	let mut vorbis = temp_file!("tests/files/assets/minimal/full_test.ogg");
+	assert!(opus_file.set_output_gain(&mut vorbis, 0).is_err());
+}
+
 #[test]
 fn flac_read() {
 	// FLAC does **not** require a Vorbis comment block be present, this file has one
@@ -52,6 +86,122 @@ fn vorbis_write() {
 	write("tests/files/assets/minimal/full_test.ogg", FileType::Vorbis)
 }
 
+#[test]
+fn vorbis_write_large_picture() {
+	use lofty::{MimeType, Picture, PictureInformation, PictureType};
+
+	let original = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.ogg");
+
+	// Large enough that the comment header needs to span multiple pages
+	let data = (0..100 * 1024).map(|i| i as u8).collect::<Vec<u8>>();
+	let picture = Picture::new_unchecked(PictureType::CoverFront, MimeType::Jpeg, None, data);
+
+	let mut vorbis_file = VorbisFile::read_from(&mut file, false).unwrap();
+	vorbis_file
+		.vorbis_comments_mut()
+		.insert_picture(picture.clone(), Some(PictureInformation::default()))
+		.unwrap();
+
+	file.rewind().unwrap();
+	vorbis_file.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let mut written = Vec::new();
+	file.read_to_end(&mut written).unwrap();
+
+	let (original_packets, original_granules) = read_packets(&original);
+	let (packets, granules) = read_packets(&written);
+
+	// The identification and setup headers, as well as the audio, should be untouched
+	assert_eq!(packets.len(), original_packets.len());
+	assert_eq!(packets[0], original_packets[0]);
+	assert!(packets[1].starts_with(b"\x03vorbis"));
+	assert_eq!(packets[2..], original_packets[2..]);
+
+	// The granule positions of the audio pages should be unchanged
+	assert!(granules.len() > original_granules.len());
+	assert_eq!(
+		granules[granules.len() - (original_granules.len() - 2)..],
+		original_granules[2..]
+	);
+
+	file.rewind().unwrap();
+	let tagged_file = lofty::read_from(&mut file, true).unwrap();
+
+	assert_eq!(tagged_file.primary_tag().unwrap().pictures(), &[picture]);
+	assert_eq!(
+		tagged_file.properties().duration(),
+		lofty::read_from_path("tests/files/assets/minimal/full_test.ogg", true)
+			.unwrap()
+			.properties()
+			.duration()
+	);
+}
+
+// Splits an Ogg stream into its packets, verifying the structure of each page along the way
+fn read_packets(mut stream: &[u8]) -> (Vec<Vec<u8>>, Vec<u64>) {
+	let mut packets = Vec::new();
+	let mut granules = Vec::new();
+
+	let mut packet = Vec::new();
+	let mut expected_seq_num = 0;
+
+	while !stream.is_empty() {
+		assert_eq!(&stream[..4], b"OggS");
+
+		let header_type = stream[5];
+		let granule = u64::from_le_bytes(stream[6..14].try_into().unwrap());
+		let seq_num = u32::from_le_bytes(stream[18..22].try_into().unwrap());
+		let checksum = u32::from_le_bytes(stream[22..26].try_into().unwrap());
+		let segment_count = stream[26] as usize;
+		let segment_table = &stream[27..27 + segment_count];
+
+		let content_len = segment_table.iter().map(|s| *s as usize).sum::<usize>();
+		let page_len = 27 + segment_count + content_len;
+
+		let mut page = stream[..page_len].to_vec();
+		page[22..26].copy_from_slice(&[0; 4]);
+		assert_eq!(
+			ogg_pager::crc32(&page),
+			checksum,
+			"bad checksum on page {}",
+			seq_num
+		);
+
+		assert_eq!(seq_num, expected_seq_num);
+		expected_seq_num += 1;
+
+		// A page only continues a packet if one was left unfinished
+		assert_eq!(header_type & 0x01 == 0x01, !packet.is_empty());
+
+		let mut content = &stream[27 + segment_count..page_len];
+		let mut packet_finished = false;
+
+		for segment in segment_table {
+			packet.extend_from_slice(&content[..*segment as usize]);
+			content = &content[*segment as usize..];
+
+			if *segment < 255 {
+				packets.push(std::mem::take(&mut packet));
+				packet_finished = true;
+			}
+		}
+
+		// -1 indicates that no packet finishes on the page
+		if !packet_finished {
+			assert_eq!(granule, u64::MAX);
+		}
+
+		granules.push(granule);
+		stream = &stream[page_len..];
+	}
+
+	assert!(packet.is_empty(), "stream ends with an unfinished packet");
+
+	(packets, granules)
+}
+
 #[test]
 fn vorbis_remove() {
 	remove(
@@ -140,3 +290,208 @@ fn flac_with_id3v2() {
 fn flac_remove_id3v2() {
 	crate::remove_tag!("tests/files/assets/flac_with_id3v2.flac", TagType::Id3v2);
 }
+
+#[test]
+fn flac_write_id3v2() {
+	use lofty::flac::FlacFile;
+	use lofty::{Accessor, AudioFile};
+
+	let mut file = temp_file!("tests/files/assets/flac_with_id3v2.flac");
+	let mut flac_file = FlacFile::read_from(&mut file, true).unwrap();
+	let properties = flac_file.properties().clone();
+
+	// Writing ID3v2 to FLAC must be opted into
+	file.rewind().unwrap();
+	assert!(flac_file.id3v2_tag().unwrap().save_to(&mut file).is_err());
+
+	flac_file
+		.id3v2_tag_mut()
+		.unwrap()
+		.set_artist(String::from("Bar artist with a much longer name"));
+
+	file.rewind().unwrap();
+	flac_file.save_id3v2_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let flac_file = FlacFile::read_from(&mut file, true).unwrap();
+
+	assert_eq!(
+		flac_file.id3v2_tag().unwrap().artist(),
+		Some("Bar artist with a much longer name")
+	);
+
+	// The FLAC stream should be untouched
+	assert!(flac_file.vorbis_comments().is_some());
+	assert_eq!(flac_file.properties(), &properties);
+
+	// Vorbis comments should still be writable with the ID3v2 tag present
+	file.rewind().unwrap();
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	crate::set_artist!(tagged_file, tag_mut, TagType::VorbisComments, "Foo artist", 2 => file, "Baz artist");
+
+	file.rewind().unwrap();
+	let flac_file = FlacFile::read_from(&mut file, true).unwrap();
+
+	assert_eq!(
+		flac_file.id3v2_tag().unwrap().artist(),
+		Some("Bar artist with a much longer name")
+	);
+	assert_eq!(
+		flac_file.vorbis_comments().unwrap().artist(),
+		Some("Baz artist")
+	);
+}
+
+#[test]
+fn flac_save_without_vorbis_comments() {
+	use lofty::flac::FlacFile;
+	use lofty::{AudioFile, PictureType};
+	use std::io::Read;
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+	let mut flac_file = FlacFile::read_from(&mut file, false).unwrap();
+
+	flac_file.remove_vorbis_comments();
+	flac_file.remove_picture_type(PictureType::CoverFront);
+
+	let mut original = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut original).unwrap();
+
+	// With nothing to write, the file should be left untouched
+	file.rewind().unwrap();
+	AudioFile::save_to(&flac_file, &mut file).unwrap();
+
+	let mut contents = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut contents).unwrap();
+	assert_eq!(contents, original);
+}
+
+// Returns the (type, is last) of each metadata block
+fn flac_blocks(file: &mut std::fs::File) -> Vec<(u8, bool)> {
+	use std::io::Read;
+
+	file.rewind().unwrap();
+
+	let mut content = Vec::new();
+	file.read_to_end(&mut content).unwrap();
+
+	// Skip the stream marker
+	let mut pos = 4;
+	let mut blocks = Vec::new();
+
+	loop {
+		let byte = content[pos];
+		let size = u32::from_be_bytes([0, content[pos + 1], content[pos + 2], content[pos + 3]]);
+
+		blocks.push((byte & 0x7F, byte & 0x80 != 0));
+
+		if byte & 0x80 != 0 {
+			return blocks;
+		}
+
+		pos += 4 + size as usize;
+	}
+}
+
+#[test]
+fn flac_picture_blocks() {
+	use lofty::flac::FlacFile;
+	use lofty::{Accessor, AudioFile, Picture, PictureType};
+
+	let picture_data = std::fs::read("tests/picture/assets/png_640x628.png").unwrap();
+	let mut picture = Picture::from_reader(&mut &*picture_data).unwrap();
+	picture.set_pic_type(PictureType::CoverFront);
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+	let mut flac_file = FlacFile::read_from(&mut file, true).unwrap();
+	let properties = flac_file.properties().clone();
+
+	assert!(flac_file.pictures().is_empty());
+
+	flac_file.insert_picture(picture.clone(), None).unwrap();
+
+	file.rewind().unwrap();
+	flac_file.save_to(&mut file).unwrap();
+
+	// STREAMINFO, VORBIS_COMMENT, PICTURE, PADDING
+	assert_eq!(
+		flac_blocks(&mut file),
+		vec![(0, false), (4, false), (6, false), (1, true)]
+	);
+
+	file.rewind().unwrap();
+	let mut flac_file = FlacFile::read_from(&mut file, true).unwrap();
+
+	assert_eq!(flac_file.pictures().len(), 1);
+	assert_eq!(flac_file.pictures()[0].0, picture);
+	// The overall bitrate will differ, as the file has grown
+	assert_eq!(flac_file.properties().duration(), properties.duration());
+	assert_eq!(flac_file.properties().bitrate(), properties.bitrate());
+	assert_eq!(
+		flac_file.vorbis_comments().unwrap().artist(),
+		Some("Foo artist")
+	);
+
+	// The pictures are visible through the generic `Tag`
+	file.rewind().unwrap();
+	let tagged_file = lofty::read_from(&mut file, false).unwrap();
+	assert_eq!(
+		tagged_file
+			.tag(&TagType::VorbisComments)
+			.unwrap()
+			.pictures(),
+		&[picture]
+	);
+
+	// Removing the comments and pictures leaves the other blocks in place
+	flac_file.remove_vorbis_comments();
+	flac_file.remove_picture_type(PictureType::CoverFront);
+
+	file.rewind().unwrap();
+	flac_file.save_to(&mut file).unwrap();
+
+	assert_eq!(flac_blocks(&mut file), vec![(0, false), (1, true)]);
+
+	file.rewind().unwrap();
+	let flac_file = FlacFile::read_from(&mut file, true).unwrap();
+
+	assert!(flac_file.pictures().is_empty());
+	assert!(flac_file.vorbis_comments().is_none());
+	assert_eq!(flac_file.properties().duration(), properties.duration());
+	assert_eq!(flac_file.properties().bitrate(), properties.bitrate());
+}
+
+#[test]
+fn flac_write_last_block() {
+	use std::io::Read;
+
+	let mut content = Vec::new();
+	temp_file!("tests/files/assets/minimal/full_test.flac")
+		.read_to_end(&mut content)
+		.unwrap();
+
+	// Drop the padding block, making the comment block the last block
+	let comment_block_start = 4 + 4 + 34;
+	let padding_start = comment_block_start + 4 + 42;
+	content.drain(padding_start..padding_start + 4 + 8171);
+	content[comment_block_start] |= 0x80;
+
+	let mut file = tempfile::tempfile().unwrap();
+	file.write_all(&content).unwrap();
+
+	assert_eq!(flac_blocks(&mut file), vec![(0, false), (4, true)]);
+
+	file.rewind().unwrap();
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 2 => file, "Bar artist");
+
+	// A padding block is added, which is now the last block
+	assert_eq!(
+		flac_blocks(&mut file),
+		vec![(0, false), (4, false), (1, true)]
+	);
+}
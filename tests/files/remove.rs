@@ -0,0 +1,76 @@
+use crate::temp_file;
+use lofty::{AudioFile, FileLike, ItemKey, Probe, TaggedFile};
+use std::io::{Cursor, Seek, Write};
+
+const PATHS: [&str; 13] = [
+	"tests/files/assets/minimal/full_test.aac",
+	"tests/files/assets/minimal/full_test.aiff",
+	"tests/files/assets/minimal/full_test.ape",
+	"tests/files/assets/minimal/full_test.dsf",
+	"tests/files/assets/minimal/full_test.flac",
+	"tests/files/assets/minimal/full_test.mp3",
+	"tests/files/assets/minimal/full_test.mpc",
+	"tests/files/assets/minimal/full_test.ogg",
+	"tests/files/assets/minimal/full_test.opus",
+	"tests/files/assets/minimal/full_test.spx",
+	"tests/files/assets/minimal/full_test.wv",
+	"tests/files/assets/minimal/m4a_codec_aac.m4a",
+	"tests/files/assets/minimal/wav_format_pcm.wav",
+];
+
+fn read<F: FileLike>(file: &mut F) -> TaggedFile {
+	file.rewind().unwrap();
+	Probe::new(file)
+		.guess_file_type()
+		.unwrap()
+		.read(true)
+		.unwrap()
+}
+
+fn remove_tags<F: FileLike>(path: &str, mut file: F) {
+	let original = read(&mut file);
+	assert!(!original.tags().is_empty());
+
+	file.rewind().unwrap();
+	lofty::remove_tags_from(&mut file).unwrap();
+
+	// The file should still be readable, with the same audio
+	let tagged_file = read(&mut file);
+
+	assert_eq!(tagged_file.file_type(), original.file_type());
+
+	// The overall bitrate will change, as the file is smaller
+	let (properties, original_properties) = (tagged_file.properties(), original.properties());
+	assert_eq!(properties.duration(), original_properties.duration());
+	assert_eq!(properties.sample_rate(), original_properties.sample_rate());
+	assert_eq!(properties.channels(), original_properties.channels());
+
+	// The vendor string is required in Vorbis comments, and is kept
+	assert!(
+		tagged_file.tags().iter().all(|tag| {
+			tag.pictures().is_empty()
+				&& tag
+					.items()
+					.iter()
+					.all(|item| item.key() == &ItemKey::EncoderSoftware)
+		}),
+		"{} still contains tags",
+		path
+	);
+}
+
+#[test]
+fn remove_all_tags() {
+	for path in PATHS {
+		println!("REMOVE: {}", path);
+		remove_tags(path, temp_file!(path));
+	}
+}
+
+#[test]
+fn remove_all_tags_in_memory() {
+	for path in PATHS {
+		println!("REMOVE (IN MEMORY): {}", path);
+		remove_tags(path, Cursor::new(std::fs::read(path).unwrap()));
+	}
+}
@@ -1,6 +1,8 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::{FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
-use std::io::{Seek, Write};
+use lofty::iff::{BroadcastExtension, WavFile};
+use lofty::Probe;
+use lofty::{AudioFile, FileType, ItemKey, ItemValue, TagExt, TagItem, TagType};
+use std::io::{Cursor, Seek, SeekFrom, Write};
 
 #[test]
 fn read() {
@@ -55,3 +57,101 @@ fn remove_riff_info() {
 		TagType::RiffInfo
 	);
 }
+
+#[test]
+fn write_bext() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+
+	let mut wav_file = WavFile::read_from(&mut file, true).unwrap();
+	assert!(wav_file.bext().is_none());
+
+	let bext = BroadcastExtension {
+		description: String::from("Foo description"),
+		origination_date: String::from("2022-05-14"),
+		origination_time: String::from("13:37:00"),
+		time_reference: 0x0001_0000_0000,
+		version: 1,
+		..BroadcastExtension::default()
+	};
+
+	wav_file.set_bext(bext.clone());
+
+	file.rewind().unwrap();
+	wav_file.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, true).unwrap();
+
+	assert_eq!(wav_file.bext(), Some(&bext));
+	// The other chunks should be untouched
+	assert!(wav_file.contains_tag_type(TagType::Id3v2));
+	assert!(wav_file.contains_tag_type(TagType::RiffInfo));
+	assert_eq!(wav_file.properties().channels(), 2);
+
+	file.rewind().unwrap();
+	BroadcastExtension::remove_from(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, true).unwrap();
+	assert!(wav_file.bext().is_none());
+	assert!(wav_file.contains_tag_type(TagType::RiffInfo));
+}
+
+#[test]
+fn rewrite_bext_same_size() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+	let mut wav_file = WavFile::read_from(&mut file, true).unwrap();
+
+	wav_file.set_bext(BroadcastExtension {
+		description: String::from("Foo description"),
+		..BroadcastExtension::default()
+	});
+
+	file.rewind().unwrap();
+	wav_file.save_to(&mut file).unwrap();
+	let len = file.seek(SeekFrom::End(0)).unwrap();
+
+	// The fields are fixed width, so this will replace the existing chunk in place
+	let bext = BroadcastExtension {
+		description: String::from("Bar description, which is longer"),
+		..BroadcastExtension::default()
+	};
+	wav_file.set_bext(bext.clone());
+
+	file.rewind().unwrap();
+	wav_file.save_to(&mut file).unwrap();
+	assert_eq!(file.seek(SeekFrom::End(0)).unwrap(), len);
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, true).unwrap();
+	assert_eq!(wav_file.bext(), Some(&bext));
+	assert!(wav_file.contains_tag_type(TagType::RiffInfo));
+}
+
+#[test]
+fn read_short_bext() {
+	let wav = std::fs::read("tests/files/assets/minimal/wav_format_pcm.wav").unwrap();
+
+	// A "bext" chunk far smaller than the required 602 bytes
+	let mut malformed = wav[..12].to_vec();
+	malformed.extend_from_slice(b"bext\x0A\x00\x00\x00");
+	malformed.extend_from_slice(&[0; 10]);
+	malformed.extend_from_slice(&wav[12..]);
+
+	let riff_size = (malformed.len() - 8) as u32;
+	malformed[4..8].copy_from_slice(&riff_size.to_le_bytes());
+
+	let (tagged_file, warnings) = Probe::new(Cursor::new(&malformed))
+		.guess_file_type()
+		.unwrap()
+		.read_with_warnings(true)
+		.unwrap();
+
+	assert_eq!(warnings.len(), 1);
+	// The rest of the file is still read
+	assert!(tagged_file.tag(&TagType::RiffInfo).is_some());
+	assert_eq!(tagged_file.properties().channels(), Some(2));
+
+	let wav_file = WavFile::read_from(&mut Cursor::new(&malformed), true).unwrap();
+	assert!(wav_file.bext().is_none());
+}
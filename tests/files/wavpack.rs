@@ -0,0 +1,103 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::{
+	FileType, ItemKey, ItemValue, MimeType, Picture, PictureType, TagExt, TagItem, TagType,
+};
+use std::io::{Read, Seek, Write};
+
+#[test]
+fn read() {
+	// Here we have a WavPack file with both an ID3v1 tag and an APEv2 tag
+	let file = lofty::read_from_path("tests/files/assets/minimal/full_test.wv", false).unwrap();
+
+	assert_eq!(file.file_type(), FileType::WavPack);
+
+	// Verify the APEv2 tag first
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+
+	// Now verify the ID3v1 tag
+	crate::verify_artist!(file, tag, TagType::Id3v1, "Bar artist", 1);
+}
+
+#[test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.wv");
+
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::WavPack);
+
+	// APEv2
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// ID3v1
+	crate::set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Bar artist", 1 => file, "Baz artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+
+	crate::set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Baz artist", 1 => file, "Bar artist");
+}
+
+#[test]
+fn remove_ape() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.wv", TagType::Ape);
+}
+
+#[test]
+fn remove_id3v1() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.wv", TagType::Id3v1);
+}
+
+#[test]
+fn write_pictures() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.wv");
+
+	let mut tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	let front = Picture::new_unchecked(
+		PictureType::CoverFront,
+		MimeType::Png,
+		Some(String::from("front.png")),
+		vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0xFF],
+	);
+	let back = Picture::new_unchecked(
+		PictureType::CoverBack,
+		MimeType::Jpeg,
+		None,
+		vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10],
+	);
+
+	let tag = tagged_file.primary_tag_mut().unwrap();
+	tag.push_picture(front.clone());
+	tag.push_picture(back.clone());
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file).unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = lofty::read_from(&mut file, false).unwrap();
+
+	let tag = tagged_file.primary_tag().unwrap();
+	assert_eq!(tag.pictures(), &[front, back]);
+	// The pictures shouldn't be duplicated as items
+	assert!(tag
+		.items()
+		.iter()
+		.all(|i| !matches!(i.value(), ItemValue::Binary(_))));
+
+	// The binary flag should be set on both items
+	let mut file_bytes = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut file_bytes).unwrap();
+
+	for key in [&b"Cover Art (Front)\0"[..], &b"Cover Art (Back)\0"[..]] {
+		let pos = file_bytes
+			.windows(key.len())
+			.position(|w| w == key)
+			.unwrap();
+		assert_eq!(file_bytes[pos - 4..pos], [2, 0, 0, 0]);
+	}
+}
@@ -0,0 +1,108 @@
+use lofty::error::ErrorKind;
+use lofty::mp3::Mp3File;
+use lofty::AudioFile;
+
+use std::io::{Cursor, Write};
+
+fn synchsafe(n: usize) -> [u8; 4] {
+	let n = n as u32;
+	[
+		((n >> 21) & 0x7F) as u8,
+		((n >> 14) & 0x7F) as u8,
+		((n >> 7) & 0x7F) as u8,
+		(n & 0x7F) as u8,
+	]
+}
+
+// This is kept separate from the other tests, since the limit is global
+#[test]
+fn max_allocation() {
+	let mp3 = std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap();
+
+	// An ID3v2 tag claiming to be ~256 MB
+	let mut malformed = b"ID3\x04\x00\x00\x7F\x7F\x7F\x7F".to_vec();
+	malformed.extend_from_slice(&mp3[10..]);
+
+	lofty::set_max_allocation(1024 * 1024);
+
+	let err = Mp3File::read_from(&mut Cursor::new(&malformed), false)
+		.err()
+		.unwrap();
+	assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+	// The file's ID3v2 tag is 21 bytes
+	assert!(Mp3File::read_from(&mut Cursor::new(&mp3), false).is_ok());
+
+	lofty::set_max_allocation(20);
+
+	let err = Mp3File::read_from(&mut Cursor::new(&mp3), false)
+		.err()
+		.unwrap();
+	assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+	// A compressed frame without a data length indicator, decompressing to 1 MiB
+	let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+	encoder.write_all(&[0; 1024 * 1024]).unwrap();
+	let compressed = encoder.finish().unwrap();
+
+	let mut frame = b"PRIV".to_vec();
+	frame.extend_from_slice(&synchsafe(compressed.len()));
+	frame.extend_from_slice(&[0x00, 0x08]);
+	frame.extend_from_slice(&compressed);
+
+	let mut compressed_frame = b"ID3\x04\x00\x00".to_vec();
+	compressed_frame.extend_from_slice(&synchsafe(frame.len()));
+	compressed_frame.extend_from_slice(&frame);
+	compressed_frame.extend_from_slice(&mp3[31..]);
+
+	lofty::set_max_allocation(64 * 1024);
+
+	let err = Mp3File::read_from(&mut Cursor::new(&compressed_frame), false)
+		.err()
+		.unwrap();
+	assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+	// The same frame, with a data length indicator
+	let mut frame = b"PRIV".to_vec();
+	frame.extend_from_slice(&synchsafe(compressed.len() + 4));
+	frame.extend_from_slice(&[0x00, 0x09]);
+	frame.extend_from_slice(&synchsafe(1024 * 1024));
+	frame.extend_from_slice(&compressed);
+
+	let mut indicated_frame = b"ID3\x04\x00\x00".to_vec();
+	indicated_frame.extend_from_slice(&synchsafe(frame.len()));
+	indicated_frame.extend_from_slice(&frame);
+	indicated_frame.extend_from_slice(&mp3[31..]);
+
+	let err = Mp3File::read_from(&mut Cursor::new(&indicated_frame), false)
+		.err()
+		.unwrap();
+	assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+	// The async readers hold the entire file in memory
+	#[cfg(feature = "tokio")]
+	{
+		let path = "tests/files/assets/minimal/full_test.mp3";
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.build()
+			.unwrap();
+
+		lofty::set_max_allocation(mp3.len() - 1);
+
+		let err = runtime
+			.block_on(lofty::read_from_path_async(path, false))
+			.err()
+			.unwrap();
+		assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+		lofty::set_max_allocation(mp3.len());
+		assert!(runtime
+			.block_on(lofty::read_from_async(&mut &mp3[..], false))
+			.is_ok());
+	}
+
+	lofty::set_max_allocation(usize::MAX);
+	assert!(Mp3File::read_from(&mut Cursor::new(&mp3), false).is_ok());
+	assert!(Mp3File::read_from(&mut Cursor::new(&compressed_frame), false).is_ok());
+	assert!(Mp3File::read_from(&mut Cursor::new(&indicated_frame), false).is_ok());
+}
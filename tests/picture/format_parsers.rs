@@ -1,5 +1,5 @@
 use lofty::id3::v2::{Id3v2Version, TextEncoding};
-use lofty::{Picture, PictureInformation, PictureType};
+use lofty::{MimeType, Picture, PictureInformation, PictureType};
 
 use std::fs::File;
 use std::io::Read;
@@ -77,6 +77,16 @@ fn ape_binary_item() {
 	assert_eq!(create_original_picture(), pic);
 }
 
+#[test]
+fn ape_binary_item_without_filename() {
+	let pic = Picture::from_ape_bytes("Cover Art (Back)", ORIGINAL_IMAGE).unwrap();
+
+	assert_eq!(pic.pic_type(), PictureType::CoverBack);
+	assert_eq!(pic.mime_type(), &MimeType::Png);
+	assert!(pic.description().is_none());
+	assert_eq!(pic.data(), ORIGINAL_IMAGE);
+}
+
 #[test]
 fn as_ape_bytes() {
 	let buf = get_buf("tests/picture/assets/png_640x628.apev2");
@@ -109,3 +119,89 @@ fn as_flac_bytes() {
 
 	assert_eq!(&*buf, original_as_flac);
 }
+
+#[test]
+fn flac_metadata_block_picture_bad_lengths() {
+	let original_picture = create_original_picture();
+	let original_picture_information =
+		PictureInformation::from_png(original_picture.data()).unwrap();
+
+	let original_as_flac = original_picture.as_flac_bytes(original_picture_information, false);
+
+	// Picture type (4) + MIME length (4) + "image/png" (9)
+	let desc_len_pos = 17;
+	// Description length (4) + "png_640x628.png" (15) + picture information (16)
+	let data_len_pos = desc_len_pos + 35;
+
+	let mut bad_description_len = original_as_flac.clone();
+	bad_description_len[desc_len_pos..desc_len_pos + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+	assert!(Picture::from_flac_bytes(&bad_description_len, false).is_err());
+
+	let mut bad_data_len = original_as_flac.clone();
+	let data_len = (ORIGINAL_IMAGE.len() + 1) as u32;
+	bad_data_len[data_len_pos..data_len_pos + 4].copy_from_slice(&data_len.to_be_bytes());
+	assert!(Picture::from_flac_bytes(&bad_data_len, false).is_err());
+
+	// Too short to hold the picture information
+	assert!(Picture::from_flac_bytes(&original_as_flac[..desc_len_pos + 20], false).is_err());
+
+	assert!(Picture::from_flac_bytes(&original_as_flac, false).is_ok());
+}
+
+#[test]
+fn webp_and_tiff_round_trip() {
+	let webp = b"RIFF\x12\0\0\0WEBPVP8L\x05\0\0\0\x2F\x7F\xC2\x9C\0".to_vec();
+	let tiff = get_buf("tests/picture/assets/tiff_640x628.tiff");
+
+	for (data, mime_type) in [(webp, MimeType::Webp), (tiff, MimeType::Tiff)] {
+		let mut picture = Picture::from_reader(&mut &*data).unwrap();
+		picture.set_pic_type(PictureType::CoverFront);
+
+		assert_eq!(picture.mime_type(), &mime_type);
+		assert_eq!(picture.dimensions(), Some((640, 628)));
+
+		// The mime type is stored as a string, so it shouldn't fall back to `MimeType::Unknown`
+		let apic = picture
+			.as_apic_bytes(Id3v2Version::V4, TextEncoding::UTF8)
+			.unwrap();
+		let (apic_picture, _) = Picture::from_apic_bytes(&apic, Id3v2Version::V4).unwrap();
+		assert_eq!(apic_picture, picture);
+
+		let information = PictureInformation::from_picture(&picture).unwrap();
+		let flac = picture.as_flac_bytes(information, false);
+		let (flac_picture, flac_information) = Picture::from_flac_bytes(&flac, false).unwrap();
+		assert_eq!(flac_picture, picture);
+		assert_eq!(flac_information, information);
+	}
+}
+
+#[test]
+fn url_picture() {
+	let picture = Picture::new_url(
+		PictureType::CoverFront,
+		Some(String::from("Foo description")),
+		String::from("https://example.com/cover.png"),
+	);
+
+	for version in [Id3v2Version::V2, Id3v2Version::V3, Id3v2Version::V4] {
+		let apic = picture
+			.as_apic_bytes(version, TextEncoding::Latin1)
+			.unwrap();
+
+		// The mime type (or ID3v2.2 image format) is "-->"
+		assert_eq!(&apic[1..4], b"-->");
+
+		let (apic_picture, _) = Picture::from_apic_bytes(&apic, version).unwrap();
+		assert_eq!(apic_picture.mime_type(), &MimeType::Url);
+		assert_eq!(apic_picture.url(), Some("https://example.com/cover.png"));
+		assert_eq!(apic_picture, picture);
+	}
+
+	let flac = picture.as_flac_bytes(PictureInformation::default(), false);
+	let (flac_picture, _) = Picture::from_flac_bytes(&flac, false).unwrap();
+	assert_eq!(flac_picture.url(), Some("https://example.com/cover.png"));
+
+	// Only links have a URL
+	assert_eq!(create_original_picture().url(), None);
+	assert_eq!(picture.dimensions(), None);
+}
@@ -1,4 +1,5 @@
-use lofty::{MimeType, Picture};
+use lofty::error::ErrorKind;
+use lofty::{MimeType, Picture, PictureType};
 
 use std::fs::File;
 use std::io::Read;
@@ -48,3 +49,43 @@ fn picture_from_reader_tiff() {
 
 	assert_eq!(pic.mime_type(), &MimeType::Tiff);
 }
+
+#[test]
+fn picture_from_reader_webp() {
+	// A WebP image is stored in a RIFF container
+	let pic = Picture::from_reader(
+		&mut &b"RIFF\x1A\0\0\0WEBPVP8L\x0E\0\0\0\x2F\0\0\0\0\0\0\0\0\0\0\0\0\0"[..],
+	)
+	.unwrap();
+
+	assert_eq!(pic.mime_type(), &MimeType::Webp);
+	assert_eq!(pic.mime_type().as_str(), "image/webp");
+
+	// Other RIFF files are not pictures
+	assert!(Picture::from_reader(&mut &b"RIFF\x04\0\0\0WAVE"[..]).is_err());
+}
+
+#[test]
+fn picture_from_file() {
+	let pic = Picture::from_file(
+		"tests/picture/assets/jpeg_640x628.jpg",
+		PictureType::CoverBack,
+	)
+	.unwrap();
+
+	assert_eq!(pic.pic_type(), PictureType::CoverBack);
+	assert_eq!(pic.mime_type(), &MimeType::Jpeg);
+	assert_eq!(pic.description(), Some("jpeg_640x628"));
+	assert_eq!(
+		pic.data(),
+		&*get_buf("tests/picture/assets/jpeg_640x628.jpg")
+	);
+
+	// Not an image
+	let err = Picture::from_file(
+		"tests/picture/assets/png_640x628.apic",
+		PictureType::CoverBack,
+	)
+	.unwrap_err();
+	assert!(matches!(err.kind(), ErrorKind::NotAPicture));
+}
@@ -1,4 +1,4 @@
-use lofty::PictureInformation;
+use lofty::{MimeType, Picture, PictureInformation, PictureType};
 
 use std::fs::File;
 use std::io::Read;
@@ -56,3 +56,197 @@ fn read_jpeg() {
 	// Always 0, not applicable for JPEG
 	assert_eq!(information.num_colors, 0);
 }
+
+#[test]
+fn read_gif() {
+	let mut f = File::open("tests/picture/assets/gif_640x628.gif").unwrap();
+
+	let mut buf = Vec::new();
+	f.read_to_end(&mut buf).unwrap();
+
+	let information = PictureInformation::from_gif(&*buf).unwrap();
+
+	assert_eq!(information.width, 640);
+	assert_eq!(information.height, 628);
+	assert_eq!(information.color_depth, 8);
+
+	// Size of the global color table
+	assert_eq!(information.num_colors, 256);
+}
+
+#[test]
+fn read_bmp() {
+	let mut f = File::open("tests/picture/assets/bmp_640x628.bmp").unwrap();
+
+	let mut buf = Vec::new();
+	f.read_to_end(&mut buf).unwrap();
+
+	let information = PictureInformation::from_bmp(&*buf).unwrap();
+
+	assert_eq!(information.width, 640);
+	assert_eq!(information.height, 628);
+	assert_eq!(information.color_depth, 32);
+
+	// Not an indexed-color image
+	assert_eq!(information.num_colors, 0);
+}
+
+#[test]
+fn read_tiff() {
+	let mut f = File::open("tests/picture/assets/tiff_640x628.tiff").unwrap();
+
+	let mut buf = Vec::new();
+	f.read_to_end(&mut buf).unwrap();
+
+	let information = PictureInformation::from_tiff(&*buf).unwrap();
+
+	assert_eq!(information.width, 640);
+	assert_eq!(information.height, 628);
+	// 4 samples (RGBA) of 8 bits
+	assert_eq!(information.color_depth, 32);
+
+	// Not a palette color image
+	assert_eq!(information.num_colors, 0);
+
+	fn tiff(entries: &[(u16, u16, u32)]) -> Vec<u8> {
+		let mut tiff = b"II*\0".to_vec();
+		tiff.extend(8_u32.to_le_bytes());
+		tiff.extend((entries.len() as u16).to_le_bytes());
+		for (tag, field_type, value) in entries {
+			tiff.extend(tag.to_le_bytes());
+			tiff.extend(field_type.to_le_bytes());
+			tiff.extend(1_u32.to_le_bytes());
+			tiff.extend(value.to_le_bytes());
+		}
+
+		tiff
+	}
+
+	// The color depth overflows
+	let overflowing = tiff(&[(258, 3, 0xFFFF), (277, 4, 0x1000_0000)]);
+	assert!(PictureInformation::from_tiff(&overflowing).is_err());
+
+	// The number of colors overflows
+	let overflowing = tiff(&[(258, 3, 32), (262, 3, 3)]);
+	assert!(PictureInformation::from_tiff(&overflowing).is_err());
+
+	// The IFD offset is at the end of the address space
+	let mut overflowing = b"II*\0".to_vec();
+	overflowing.extend(u32::MAX.to_le_bytes());
+	assert!(PictureInformation::from_tiff(&overflowing).is_err());
+}
+
+#[test]
+fn read_webp() {
+	fn webp(chunk: &[u8], content: &[u8]) -> Vec<u8> {
+		let mut webp = b"RIFF".to_vec();
+		webp.extend((content.len() as u32 + 12).to_le_bytes());
+		webp.extend(b"WEBP");
+		webp.extend(chunk);
+		webp.extend((content.len() as u32).to_le_bytes());
+		webp.extend(content);
+
+		webp
+	}
+
+	// Lossy
+	let lossy = webp(
+		b"VP8 ",
+		&[0x30, 0x01, 0x00, 0x9D, 0x01, 0x2A, 0x80, 0x02, 0x74, 0x02],
+	);
+	let information = PictureInformation::from_webp(&lossy).unwrap();
+	assert_eq!((information.width, information.height), (640, 628));
+	assert_eq!(information.color_depth, 24);
+
+	// Lossless, with an alpha channel
+	let bits: u32 = 639 | (627 << 14) | (1 << 28);
+	let mut content = vec![0x2F];
+	content.extend(bits.to_le_bytes());
+
+	let lossless = webp(b"VP8L", &content);
+	let information = PictureInformation::from_webp(&lossless).unwrap();
+	assert_eq!((information.width, information.height), (640, 628));
+	assert_eq!(information.color_depth, 32);
+
+	// Extended, with an alpha channel
+	let extended = webp(
+		b"VP8X",
+		&[0x10, 0x00, 0x00, 0x00, 0x7F, 0x02, 0x00, 0x73, 0x02, 0x00],
+	);
+	let information = PictureInformation::from_webp(&extended).unwrap();
+	assert_eq!((information.width, information.height), (640, 628));
+	assert_eq!(information.color_depth, 32);
+
+	// Always 0, not applicable for WebP
+	assert_eq!(information.num_colors, 0);
+
+	let picture = Picture::from_reader(&mut &*lossy).unwrap();
+	assert_eq!(picture.mime_type(), &MimeType::Webp);
+	assert_eq!(picture.dimensions(), Some((640, 628)));
+
+	// Truncated data
+	assert!(PictureInformation::from_webp(&lossy[..25]).is_err());
+}
+
+#[test]
+fn picture_dimensions() {
+	for path in [
+		"tests/picture/assets/png_640x628.png",
+		"tests/picture/assets/jpeg_640x628.jpg",
+		"tests/picture/assets/gif_640x628.gif",
+		"tests/picture/assets/bmp_640x628.bmp",
+		"tests/picture/assets/tiff_640x628.tiff",
+	] {
+		let mut f = File::open(path).unwrap();
+		let picture = Picture::from_reader(&mut f).unwrap();
+
+		assert_eq!(picture.dimensions(), Some((640, 628)), "{}", path);
+	}
+
+	// Unknown format
+	let picture = Picture::new_unchecked(
+		PictureType::CoverFront,
+		MimeType::Unknown(String::from("image/foo")),
+		None,
+		vec![0; 16],
+	);
+	assert_eq!(picture.dimensions(), None);
+
+	// Truncated data
+	let truncated = Picture::new_unchecked(
+		PictureType::CoverFront,
+		MimeType::Png,
+		None,
+		vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A],
+	);
+	assert_eq!(truncated.dimensions(), None);
+}
+
+#[test]
+fn from_picture() {
+	let mut f = File::open("tests/picture/assets/gif_640x628.gif").unwrap();
+	let picture = Picture::from_reader(&mut f).unwrap();
+
+	assert_eq!(
+		PictureInformation::from_picture(&picture).unwrap(),
+		PictureInformation {
+			width: 640,
+			height: 628,
+			color_depth: 8,
+			num_colors: 256,
+		}
+	);
+
+	// Unsupported formats are zeroed out
+	let picture = Picture::new_unchecked(
+		PictureType::CoverFront,
+		MimeType::Unknown(String::from("image/foo")),
+		None,
+		vec![0; 16],
+	);
+
+	assert_eq!(
+		PictureInformation::from_picture(&picture).unwrap(),
+		PictureInformation::default()
+	);
+}
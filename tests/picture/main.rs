@@ -1,3 +1,4 @@
 mod format_parsers;
 mod from_reader;
 mod information;
+mod tag_ext;
@@ -0,0 +1,81 @@
+use lofty::ape::ApeTag;
+use lofty::id3::v1::Id3v1Tag;
+use lofty::id3::v2::Id3v2Tag;
+use lofty::mp4::Ilst;
+use lofty::ogg::VorbisComments;
+use lofty::{MimeType, Picture, PictureType, Tag, TagExt, TagType};
+
+use std::fs::File;
+
+fn png(pic_type: PictureType) -> Picture {
+	let mut f = File::open("tests/picture/assets/png_640x628.png").unwrap();
+
+	let mut picture = Picture::from_reader(&mut f).unwrap();
+	picture.set_pic_type(pic_type);
+
+	picture
+}
+
+fn verify_replace<T: TagExt<Err = lofty::LoftyError>>(mut tag: T) {
+	tag.replace_picture(png(PictureType::CoverFront)).unwrap();
+	tag.replace_picture(png(PictureType::CoverBack)).unwrap();
+
+	let mut replacement = png(PictureType::CoverFront);
+	replacement.set_description(Some(String::from("Replacement")));
+	tag.replace_picture(replacement).unwrap();
+
+	let pictures = tag.collect_pictures();
+	assert_eq!(pictures.len(), 2);
+	assert!(pictures
+		.iter()
+		.any(|p| p.pic_type() == PictureType::CoverFront && p.description() == Some("Replacement")));
+
+	tag.remove_picture_type(PictureType::CoverFront);
+
+	let pictures = tag.collect_pictures();
+	assert_eq!(pictures.len(), 1);
+	assert_eq!(pictures[0].pic_type(), PictureType::CoverBack);
+}
+
+#[test]
+fn tag_ext_pictures() {
+	verify_replace(Id3v2Tag::default());
+	verify_replace(VorbisComments::default());
+	verify_replace(ApeTag::default());
+	verify_replace(Tag::new(TagType::Id3v2));
+}
+
+#[test]
+fn tag_ext_pictures_ilst() {
+	let mut tag = Ilst::default();
+
+	tag.replace_picture(png(PictureType::CoverFront)).unwrap();
+	// ilst can't differentiate the picture types, so this replaces the front cover
+	tag.replace_picture(png(PictureType::CoverBack)).unwrap();
+
+	let pictures = tag.collect_pictures();
+	assert_eq!(pictures.len(), 1);
+	assert_eq!(pictures[0].pic_type(), PictureType::CoverFront);
+
+	tag.remove_picture_type(PictureType::CoverFront);
+	assert!(tag.collect_pictures().is_empty());
+
+	// The inherent method still appends
+	tag.insert_picture(png(PictureType::CoverFront));
+	tag.insert_picture(png(PictureType::CoverBack));
+	assert_eq!(tag.pictures().count(), 2);
+}
+
+#[test]
+fn tag_ext_pictures_unsupported() {
+	let mut tag = Id3v1Tag::default();
+
+	assert!(tag.replace_picture(png(PictureType::CoverFront)).is_err());
+	assert!(tag.collect_pictures().is_empty());
+
+	let mut ape = ApeTag::default();
+	let undefined =
+		Picture::new_unchecked(PictureType::Undefined(0), MimeType::Png, None, vec![0; 8]);
+
+	assert!(ape.replace_picture(undefined).is_err());
+}
@@ -1,7 +1,12 @@
 // Tests for special case conversions
 
-use lofty::id3::v2::{Frame, FrameFlags, FrameValue, Id3v2Tag, LanguageFrame, TextEncoding};
-use lofty::{ItemKey, Tag, TagType};
+use lofty::ape::{ApeItem, ApeTag};
+use lofty::id3::v2::{
+	CommentFrame, Frame, FrameFlags, FrameValue, Id3v2Tag, TextEncoding, UnsynchronizedTextFrame,
+};
+use lofty::mp4::Ilst;
+use lofty::ogg::VorbisComments;
+use lofty::{ItemKey, ItemValue, Tag, TagItem, TagType};
 
 #[test]
 fn tag_to_id3v2_lang_frame() {
@@ -15,9 +20,9 @@ fn tag_to_id3v2_lang_frame() {
 		id3.get("USLT"),
 		Frame::new(
 			"USLT",
-			FrameValue::UnSyncText(LanguageFrame {
-				encoding: TextEncoding::UTF8,
-				language: String::from("eng"),
+			FrameValue::UnSyncText(UnsynchronizedTextFrame {
+				encoding: TextEncoding::Latin1,
+				language: *b"eng",
 				description: String::new(),
 				content: String::from("Test lyrics")
 			}),
@@ -31,9 +36,9 @@ fn tag_to_id3v2_lang_frame() {
 		id3.get("COMM"),
 		Frame::new(
 			"COMM",
-			FrameValue::Comment(LanguageFrame {
-				encoding: TextEncoding::UTF8,
-				language: String::from("eng"),
+			FrameValue::Comment(CommentFrame {
+				encoding: TextEncoding::Latin1,
+				language: *b"eng",
 				description: String::new(),
 				content: String::from("Test comment")
 			}),
@@ -43,3 +48,255 @@ fn tag_to_id3v2_lang_frame() {
 		.as_ref()
 	);
 }
+
+#[test]
+fn id3v2_to_tag_lyrics() {
+	let mut id3 = Id3v2Tag::default();
+	id3.insert(
+		Frame::new(
+			"USLT",
+			FrameValue::UnSyncText(UnsynchronizedTextFrame::new(
+				*b"eng",
+				String::from("Test lyrics"),
+			)),
+			FrameFlags::default(),
+		)
+		.unwrap(),
+	);
+
+	let tag: Tag = id3.into();
+
+	assert_eq!(tag.get_string(&ItemKey::Lyrics), Some("Test lyrics"));
+}
+
+#[test]
+fn id3v2_to_tag_comments() {
+	let mut id3 = Id3v2Tag::default();
+	id3.insert_comment(CommentFrame::new(*b"eng", String::from("Test comment")));
+	id3.insert_comment(CommentFrame {
+		description: String::from("iTunNORM"),
+		..CommentFrame::new(*b"eng", String::from(" 0000044E 00000061"))
+	});
+
+	assert_eq!(id3.comments().count(), 2);
+
+	// Only the comment without a description is converted to `ItemKey::Comment`
+	let tag: Tag = id3.clone().into();
+	assert_eq!(tag.get_items(&ItemKey::Comment).count(), 1);
+	assert_eq!(tag.get_string(&ItemKey::Comment), Some("Test comment"));
+	assert_eq!(
+		tag.get_string(&ItemKey::Unknown(String::from("COMM:iTunNORM"))),
+		Some(" 0000044E 00000061")
+	);
+
+	// The other comments survive a round trip
+	let round_trip: Id3v2Tag = tag.into();
+	assert_eq!(round_trip.comments().count(), 2);
+	assert_eq!(
+		round_trip.get_comment("").map(|c| c.content.as_str()),
+		Some("Test comment")
+	);
+	assert_eq!(
+		round_trip
+			.get_comment("iTunNORM")
+			.map(|c| c.content.as_str()),
+		Some(" 0000044E 00000061")
+	);
+
+	// The other comments can be targeted by their descriptions
+	id3.remove_comment("");
+	assert!(id3.get_comment("").is_none());
+	assert_eq!(
+		id3.get_comment("iTunNORM").map(|c| c.content.as_str()),
+		Some(" 0000044E 00000061")
+	);
+}
+
+#[test]
+fn replay_gain() {
+	let replay_gain = [
+		(
+			ItemKey::ReplayGainTrackGain,
+			"-6.20 dB",
+			"REPLAYGAIN_TRACK_GAIN",
+		),
+		(
+			ItemKey::ReplayGainTrackPeak,
+			"0.988",
+			"REPLAYGAIN_TRACK_PEAK",
+		),
+		(
+			ItemKey::ReplayGainAlbumGain,
+			"-5.80 dB",
+			"REPLAYGAIN_ALBUM_GAIN",
+		),
+		(
+			ItemKey::ReplayGainAlbumPeak,
+			"0.999",
+			"REPLAYGAIN_ALBUM_PEAK",
+		),
+	];
+
+	let mut tag = Tag::new(TagType::Id3v2);
+	for (key, value, _) in &replay_gain {
+		tag.insert_text(key.clone(), String::from(*value));
+	}
+
+	// ID3v2 stores these in "TXXX" frames
+	let id3: Id3v2Tag = tag.clone().into();
+	assert_eq!(id3.len(), 4);
+	for (_, value, description) in &replay_gain {
+		assert_eq!(id3.get_user_text(description), Some(*value));
+	}
+
+	let id3_tag: Tag = id3.into();
+	for (key, value, _) in &replay_gain {
+		assert_eq!(id3_tag.get_string(key), Some(*value));
+	}
+
+	tag.re_map(TagType::VorbisComments);
+	let vorbis_comments: VorbisComments = tag.clone().into();
+	for (_, value, key) in &replay_gain {
+		assert_eq!(vorbis_comments.get(key), Some(*value));
+	}
+
+	tag.re_map(TagType::Ape);
+	let ape: ApeTag = tag.into();
+	for (_, value, key) in &replay_gain {
+		assert_eq!(
+			ape.get_key(key).map(ApeItem::value),
+			Some(&ItemValue::Text(String::from(*value)))
+		);
+	}
+}
+
+#[test]
+fn musicbrainz_ids() {
+	// (key, value, Vorbis comments/APE key, ID3v2 "TXXX" description)
+	let musicbrainz_ids = [
+		(
+			ItemKey::MusicBrainzTrackId,
+			"d9a4a3b2-5f1e-4c6b-9b0a-1e2f3a4b5c6d",
+			"MUSICBRAINZ_RELEASETRACKID",
+			"MusicBrainz Release Track Id",
+		),
+		(
+			ItemKey::MusicBrainzReleaseId,
+			"0b1c2d3e-4f5a-4b6c-8d7e-9f0a1b2c3d4e",
+			"MUSICBRAINZ_ALBUMID",
+			"MusicBrainz Album Id",
+		),
+		(
+			ItemKey::MusicBrainzReleaseGroupId,
+			"5e6f7a8b-9c0d-4e1f-a2b3-c4d5e6f7a8b9",
+			"MUSICBRAINZ_RELEASEGROUPID",
+			"MusicBrainz Release Group Id",
+		),
+		(
+			ItemKey::MusicBrainzArtistId,
+			"f1e2d3c4-b5a6-4978-8695-a4b3c2d1e0f9",
+			"MUSICBRAINZ_ARTISTID",
+			"MusicBrainz Artist Id",
+		),
+	];
+
+	let mut tag = Tag::new(TagType::Id3v2);
+	for (key, value, _, _) in &musicbrainz_ids {
+		assert!(tag.insert_text(key.clone(), String::from(*value)));
+	}
+
+	// ID3v2 stores these in "TXXX" frames
+	let id3: Id3v2Tag = tag.clone().into();
+	assert_eq!(id3.len(), 4);
+	for (_, value, _, description) in &musicbrainz_ids {
+		assert_eq!(id3.get_user_text(description), Some(*value));
+	}
+
+	let id3_tag: Tag = id3.into();
+	for (key, value, _, _) in &musicbrainz_ids {
+		assert_eq!(id3_tag.get_string(key), Some(*value));
+	}
+
+	tag.re_map(TagType::VorbisComments);
+	let vorbis_comments: VorbisComments = tag.clone().into();
+	for (_, value, key, _) in &musicbrainz_ids {
+		assert_eq!(vorbis_comments.get(key), Some(*value));
+	}
+
+	tag.re_map(TagType::Mp4Ilst);
+	let ilst: Ilst = tag.clone().into();
+	for (_, value, _, description) in &musicbrainz_ids {
+		assert_eq!(
+			ilst.get_freeform("com.apple.iTunes", description),
+			Some(*value)
+		);
+	}
+
+	let ilst_tag: Tag = ilst.into();
+	for (key, value, _, _) in &musicbrainz_ids {
+		assert_eq!(ilst_tag.get_string(key), Some(*value));
+	}
+
+	tag.re_map(TagType::Ape);
+	let ape: ApeTag = tag.into();
+	for (_, value, key, _) in &musicbrainz_ids {
+		assert_eq!(
+			ape.get_key(key).map(ApeItem::value),
+			Some(&ItemValue::Text(String::from(*value)))
+		);
+	}
+}
+
+#[test]
+fn re_map_unknown_items() {
+	let mut id3 = Id3v2Tag::default();
+	id3.insert(
+		Frame::new(
+			"TIT2",
+			FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("Foo title"),
+			},
+			FrameFlags::default(),
+		)
+		.unwrap(),
+	);
+	id3.insert(
+		Frame::new(
+			"TFOO",
+			FrameValue::Text {
+				encoding: TextEncoding::UTF8,
+				value: String::from("Bar value"),
+			},
+			FrameFlags::default(),
+		)
+		.unwrap(),
+	);
+
+	let mut tag: Tag = id3.into();
+	assert_eq!(tag.item_count(), 2);
+
+	// The unknown frame has no Vorbis comments equivalent
+	tag.re_map(TagType::VorbisComments);
+	assert_eq!(tag.item_count(), 1);
+	assert_eq!(
+		tag.unknown_items().map(TagItem::key).collect::<Vec<_>>(),
+		vec![&ItemKey::Unknown(String::from("TFOO"))]
+	);
+
+	let vorbis_comments: VorbisComments = tag.clone().into();
+	assert_eq!(vorbis_comments.get("TITLE"), Some("Foo title"));
+
+	// It is still set aside when converting to another format
+	tag.re_map(TagType::Ape);
+	assert_eq!(tag.item_count(), 1);
+	assert_eq!(tag.unknown_items().count(), 1);
+
+	// And restored when converting back to ID3v2
+	tag.re_map(TagType::Id3v2);
+	assert_eq!(tag.item_count(), 2);
+	assert_eq!(tag.unknown_items().count(), 0);
+
+	let id3: Id3v2Tag = tag.into();
+	assert!(id3.get("TFOO").is_some());
+}